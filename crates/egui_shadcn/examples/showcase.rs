@@ -6,7 +6,7 @@
 use egui_shadcn::{
     ShadcnTheme,
     Badge, BadgeVariant,
-    Avatar, AvatarSize,
+    Avatar, AvatarGroup, AvatarShape, AvatarSize, AvatarStatus,
     Card, card_title, card_description,
     Alert, AlertVariant,
     Skeleton,
@@ -14,17 +14,18 @@ use egui_shadcn::{
     Kbd,
     Button, ButtonVariant, ButtonSize,
     shadcn_input, shadcn_input_with_error, shadcn_textarea, form_label, form_helper,
+    InputMask, MaskedInput, NumberInput, InputOTP,
     Checkbox,
     Switch,
     Slider,
     Progress,
     Separator,
-    Tabs,
+    Tabs, TabsOrientation, TabsOverflow,
     Toggle, ToggleVariant, ToggleSize,
     RadioGroup,
-    Select,
-    DropdownMenu,
-    Combobox, ComboboxOption,
+    Select, SelectOption,
+    DropdownMenu, MenuItem, MenuModel,
+    Combobox, ComboboxOption, AsyncOptions, OptionPage, OptionsFuture,
     Toast, ToastVariant, Toaster,
     Dialog, confirm_dialog, ConfirmResult,
     TooltipExt,
@@ -43,21 +44,27 @@ use egui_shadcn::{
     AspectRatio, AspectRatioPreset,
     simple_table,
     Command,
-    Calendar, CalendarSelection,
-    DatePicker,
+    Calendar, CalendarLocale, CalendarMode, CalendarSelection, DayDecoration, EnglishLocale,
+    DatePicker, DatePreset, DateRangePicker, DateTimePicker,
     Carousel,
     Chart, ChartType, DataPoint,
     ResizablePanelGroup, ResizableDirection,
     // Phase 7: Navigation & Forms
     Menubar,
     NavigationMenu,
-    Sidebar,
+    Sidebar, SidebarEvent, SidebarNode,
+    SortableList, Reorder,
     Field,
+    Shortcut, ShortcutRegistry, ShortcutScope, shortcuts_dialog,
 };
+use egui::{Key, KeyboardShortcut, Modifiers};
 
 /// App state for the showcase
 struct ShowcaseApp {
     dark_mode: bool,
+    word_wrap: bool,
+    show_minimap: bool,
+    editor_zoom: u32,
     email: String,
     message: String,
     checkbox_checked: bool,
@@ -78,8 +85,13 @@ struct ShowcaseApp {
     radio_selected: usize,
     // Select state
     select_fruit: usize,
+    select_relay: Option<&'static str>,
     // Combobox state
     combobox_framework: Option<String>,
+    combobox_tags: Vec<String>,
+    hashtag_options: Vec<ComboboxOption>,
+    profile_directory: AsyncOptions<fn(&str, usize) -> OptionsFuture>,
+    combobox_author: Option<String>,
     // Toaster
     toaster: Toaster,
     // Popover state
@@ -96,9 +108,17 @@ struct ShowcaseApp {
     current_page: usize,
     command_open: bool,
     command_search: String,
+    shortcuts_help_open: bool,
+    composer_text: String,
     calendar_selection: CalendarSelection,
     calendar_view_date: chrono::NaiveDate,
+    booking_selection: CalendarSelection,
+    booking_view_date: chrono::NaiveDate,
+    events_selection: CalendarSelection,
+    events_view_date: chrono::NaiveDate,
     datepicker_date: Option<chrono::NaiveDate>,
+    date_range: Option<(chrono::NaiveDate, chrono::NaiveDate)>,
+    meeting_time: Option<chrono::NaiveDateTime>,
     carousel_index: usize,
     resizable_split: f32,
     resizable_vertical_split: f32,
@@ -106,8 +126,17 @@ struct ShowcaseApp {
     field_username: String,
     field_email: String,
     field_email_error: Option<String>,
+    field_phone: String,
+    field_amount: String,
+    field_quantity: u32,
+    field_code: String,
     sidebar_open: bool,
-    sidebar_selected: usize,
+    sidebar_selected: Option<&'static str>,
+    sidebar_unread: u32,
+    sidebar_projects: Vec<&'static str>,
+    deck_columns: Vec<&'static str>,
+    open_files: Vec<&'static str>,
+    active_file: &'static str,
     account_menu_open: bool,
     // AspectRatio demo state
     selected_aspect_ratio: usize,
@@ -117,6 +146,9 @@ impl Default for ShowcaseApp {
     fn default() -> Self {
         Self {
             dark_mode: false,
+            word_wrap: true,
+            show_minimap: false,
+            editor_zoom: 100,
             email: String::new(),
             message: String::new(),
             checkbox_checked: false,
@@ -132,7 +164,15 @@ impl Default for ShowcaseApp {
             confirm_result: None,
             radio_selected: 0,
             select_fruit: 0,
+            select_relay: None,
             combobox_framework: None,
+            combobox_tags: vec!["nostr".to_string()],
+            hashtag_options: ["nostr", "bitcoin", "lightning", "zaps", "plebchain", "grownostr"]
+                .into_iter()
+                .map(ComboboxOption::new)
+                .collect(),
+            profile_directory: AsyncOptions::new(search_profiles as fn(&str, usize) -> OptionsFuture),
+            combobox_author: None,
             toaster: Toaster::new(),
             popover_width: 200.0,
             collapsible_open: false,
@@ -144,9 +184,17 @@ impl Default for ShowcaseApp {
             current_page: 1,
             command_open: false,
             command_search: String::new(),
+            shortcuts_help_open: false,
+            composer_text: String::new(),
             calendar_selection: CalendarSelection::None,
             calendar_view_date: chrono::Local::now().date_naive(),
+            booking_selection: CalendarSelection::None,
+            booking_view_date: chrono::Local::now().date_naive(),
+            events_selection: CalendarSelection::None,
+            events_view_date: chrono::Local::now().date_naive(),
             datepicker_date: None,
+            date_range: None,
+            meeting_time: None,
             carousel_index: 0,
             resizable_split: 0.5,
             resizable_vertical_split: 0.6,
@@ -154,8 +202,17 @@ impl Default for ShowcaseApp {
             field_username: String::new(),
             field_email: String::new(),
             field_email_error: None,
+            field_phone: String::new(),
+            field_amount: String::new(),
+            field_quantity: 1,
+            field_code: String::new(),
             sidebar_open: true,
-            sidebar_selected: 0,
+            sidebar_selected: Some("docs"),
+            sidebar_unread: 12,
+            sidebar_projects: vec!["Design Engineering", "Sales & Marketing", "Travel"],
+            deck_columns: vec!["Home", "Notifications", "Messages", "Bookmarks"],
+            open_files: vec!["main.rs", "lib.rs", "theme.rs", "tabs.rs", "sidebar.rs", "calendar.rs", "date_picker.rs", "dnd.rs"],
+            active_file: "main.rs",
             account_menu_open: false,
             selected_aspect_ratio: 0,
         }
@@ -178,6 +235,27 @@ impl eframe::App for ShowcaseApp {
         };
         theme.apply(ctx);
 
+        let command = |key| KeyboardShortcut::new(Modifiers::COMMAND, key);
+        for shortcut in [
+            Shortcut::new("command_palette", "Open command palette", command(Key::K)),
+            Shortcut::new("shortcuts_help", "Show keyboard shortcuts", command(Key::Slash)),
+            Shortcut::new("toggle_theme", "Toggle dark mode", KeyboardShortcut::new(Modifiers::COMMAND | Modifiers::SHIFT, Key::L)),
+            Shortcut::new("send_note", "Send note", command(Key::Enter)).scope(ShortcutScope::named("Composer")),
+        ] {
+            ShortcutRegistry::register(ctx, shortcut).expect("showcase shortcuts don't conflict");
+        }
+        match ShortcutRegistry::dispatch(ctx).as_deref() {
+            Some("command_palette") => self.command_open = !self.command_open,
+            Some("shortcuts_help") => self.shortcuts_help_open = true,
+            Some("toggle_theme") => self.dark_mode = !self.dark_mode,
+            Some("send_note") => {
+                self.toaster.success(format!("Sent: {}", self.composer_text));
+                self.composer_text.clear();
+            }
+            _ => {}
+        }
+        shortcuts_dialog(ctx, "shortcuts_help", &mut self.shortcuts_help_open);
+
         egui::CentralPanel::default().show(ctx, |ui| {
             egui::ScrollArea::vertical()
                 .scroll_bar_visibility(egui::scroll_area::ScrollBarVisibility::AlwaysVisible)
@@ -489,6 +567,21 @@ impl eframe::App for ShowcaseApp {
                 ui.add(Avatar::new("XL").size(AvatarSize::ExtraLarge));
             });
 
+            ui.horizontal(|ui| {
+                ui.label("Status & shape:");
+                // Falls back to initials unless an image loader is installed
+                ui.add(Avatar::new("Jack Dorsey").image("https://robohash.org/jack.png").status(AvatarStatus::Online));
+                ui.add(Avatar::new("Will Casarin").status(AvatarStatus::Away));
+                ui.add(Avatar::new("Fiatjaf").status(AvatarStatus::Busy));
+                ui.add(Avatar::new("Satoshi Nakamoto").shape(AvatarShape::Rounded).status(AvatarStatus::Offline));
+            });
+
+            ui.horizontal(|ui| {
+                ui.label("Group:");
+                let followers = ["Alice Smith", "Bob Jones", "Carol White", "Dave Brown", "Eve Black", "Frank Green"];
+                ui.add(AvatarGroup::new(followers.map(Avatar::new)).max(3).size(AvatarSize::Small));
+            });
+
             ui.add_space(16.0);
 
             ui.label("Cards:");
@@ -582,6 +675,23 @@ impl eframe::App for ShowcaseApp {
                 ui.add(Kbd::new("K"));
                 ui.label("to search");
             });
+            ui.horizontal(|ui| {
+                ui.label("Platform-aware:");
+                ui.add(Kbd::shortcut(KeyboardShortcut::new(Modifiers::COMMAND | Modifiers::SHIFT, Key::L)));
+                ui.label("toggles dark mode,");
+                ui.add(Kbd::shortcut(KeyboardShortcut::new(Modifiers::COMMAND, Key::Slash)));
+                ui.label("lists all shortcuts");
+                if ui.add(Button::new("Keyboard shortcuts").variant(ButtonVariant::Outline).size(ButtonSize::Small)).clicked() {
+                    self.shortcuts_help_open = true;
+                }
+            });
+            ShortcutRegistry::scope(ui, ShortcutScope::named("Composer"), |ui| {
+                ui.horizontal(|ui| {
+                    ui.add(egui::TextEdit::singleline(&mut self.composer_text).hint_text("Write a note..."));
+                    ui.add(Kbd::shortcut(KeyboardShortcut::new(Modifiers::COMMAND, Key::Enter)));
+                    ui.label("sends while the composer is focused");
+                });
+            });
 
             ui.add_space(16.0);
 
@@ -622,6 +732,22 @@ impl eframe::App for ShowcaseApp {
 
             ui.add_space(8.0);
 
+            ui.label("Select (groups, icons, descriptions, disabled):");
+            Select::new("relay_select", &mut self.select_relay)
+                .placeholder("Select a relay...")
+                .width(260.0)
+                .group("Public", |g| {
+                    g.item_with(SelectOption::new(Some("damus"), "relay.damus.io").icon("🌐").description("General purpose"))
+                        .item_with(SelectOption::new(Some("nos"), "nos.lol").icon("🌐"));
+                })
+                .group("Paid", |g| {
+                    g.item_with(SelectOption::new(Some("nostr.wine"), "nostr.wine").icon("🍷").description("Paid, spam-free"))
+                        .item_with(SelectOption::new(Some("primal"), "premium.primal.net").icon("💎").enabled(false));
+                })
+                .show(ui);
+
+            ui.add_space(8.0);
+
             ui.label("Combobox (searchable select):");
             let frameworks = vec![
                 ComboboxOption::with_label("next", "Next.js"),
@@ -638,19 +764,49 @@ impl eframe::App for ShowcaseApp {
 
             ui.add_space(8.0);
 
+            ui.label("Combobox (multi-select, max 5, creatable):");
+            let mut created = None;
+            Combobox::multiple("hashtag_combo", &self.hashtag_options, &mut self.combobox_tags)
+                .placeholder("Add hashtags...")
+                .search_placeholder("Search or create...")
+                .width(280.0)
+                .max_selected(5)
+                .creatable(true)
+                .on_create(|tag| created = Some(tag.to_string()))
+                .show(ui);
+            if let Some(tag) = created {
+                self.hashtag_options.push(ComboboxOption::new(tag));
+            }
+
+            ui.add_space(8.0);
+
+            ui.label("Combobox (remote search, paged):");
+            Combobox::new("author_combo", &[], &mut self.combobox_author)
+                .source(&mut self.profile_directory)
+                .placeholder("Find an author...")
+                .search_placeholder("Search profiles...")
+                .width(240.0)
+                .show(ui);
+
+            ui.add_space(8.0);
+
             ui.label("Dropdown Menu:");
             ui.horizontal(|ui| {
-                let menu = DropdownMenu::new("actions_menu")
-                    .trigger_text("Actions")
-                    .item_with_shortcut("Copy", "Ctrl+C")
-                    .item_with_shortcut("Paste", "Ctrl+V")
+                let menu = MenuModel::new()
+                    .item(MenuItem::new("copy", "Copy").shortcut(KeyboardShortcut::new(Modifiers::COMMAND | Modifiers::SHIFT, Key::C)))
+                    .item(MenuItem::new("paste", "Paste").disabled("The clipboard is empty"))
+                    .submenu("Share", MenuModel::new()
+                        .item(MenuItem::new("share_email", "Email link"))
+                        .item(MenuItem::new("share_messages", "Messages")))
                     .separator()
                     .label("Danger Zone")
-                    .destructive_item("Delete")
+                    .item(MenuItem::new("delete", "Delete").destructive(true));
+                let menu = DropdownMenu::new("actions_menu", menu)
+                    .trigger_text("Actions")
                     .show(ui);
 
-                if let Some(idx) = menu.clicked_item {
-                    ui.label(format!("Clicked item {}", idx));
+                if let Some(action) = menu.action {
+                    self.toaster.info(format!("Dropdown action: {action}"));
                 }
             });
 
@@ -733,6 +889,67 @@ impl eframe::App for ShowcaseApp {
 
             ui.add_space(16.0);
 
+            ui.label("Reorderable tabs (drag, or focus a tab and use Space + arrows):");
+            let mut moved_tab = None;
+            let mut deck_tabs = Tabs::new(ui, "demo-reorder-tabs");
+            for &column in &self.deck_columns {
+                deck_tabs = deck_tabs.tab(column, column, move |ui| {
+                    ui.label(format!("{column} timeline"));
+                });
+            }
+            deck_tabs.on_reorder(|reorder| moved_tab = Some(reorder)).show();
+            if let Some(reorder) = moved_tab {
+                reorder.apply(&mut self.deck_columns);
+            }
+
+            ui.add_space(16.0);
+
+            ui.label("Closable editor tabs (Delete or middle-click closes, overflow goes to a menu):");
+            let mut closed_file = None;
+            let mut file_tabs = Tabs::new(ui, "demo-file-tabs")
+                .selected(&mut self.active_file)
+                .overflow(TabsOverflow::Menu);
+            for &file in &self.open_files {
+                file_tabs = file_tabs.tab(file, file, move |ui| {
+                    ui.label(format!("// {file}"));
+                });
+            }
+            file_tabs.on_close(|file| closed_file = Some(file)).show();
+            if let Some(file) = closed_file {
+                self.open_files.retain(|open| *open != file);
+            }
+            if self.open_files.is_empty() && ui.button("Reopen files").clicked() {
+                self.open_files = vec!["main.rs", "lib.rs", "theme.rs", "tabs.rs", "sidebar.rs", "calendar.rs", "date_picker.rs", "dnd.rs"];
+                self.active_file = "main.rs";
+            }
+
+            ui.add_space(16.0);
+
+            ui.label("Vertical tabs (state kept while hidden):");
+            Tabs::new(ui, "demo-vertical-tabs")
+                .orientation(TabsOrientation::Vertical)
+                .keep_state(true)
+                .tab("general", "General", |ui| {
+                    ui.label("General preferences");
+                })
+                .tab("appearance", "Appearance", |ui| {
+                    ui.label("Theme and density");
+                })
+                .tab("shortcuts", "Shortcuts", |ui| {
+                    ui.label("Keyboard shortcuts");
+                })
+                .show();
+
+            ui.add_space(8.0);
+            ui.label("SortableList (same deck columns):");
+            ui.allocate_ui(egui::vec2(320.0, 0.0), |ui| {
+                SortableList::new("demo_sortable_columns", &mut self.deck_columns).show(ui, |ui, column| {
+                    ui.label(*column);
+                });
+            });
+
+            ui.add_space(16.0);
+
             ui.label("Collapsible:");
             Collapsible::new("demo_collapsible", &mut self.collapsible_open)
                 .trigger(|ui, _is_open| {
@@ -825,22 +1042,37 @@ impl eframe::App for ShowcaseApp {
             ui.add_space(16.0);
 
             ui.label("Breadcrumb:");
-            Breadcrumb::new()
-                .item("Home", || {})
-                .item("Products", || {})
-                .item("Electronics", || {})
+            let path = ["Home", "Products", "Electronics"];
+            let breadcrumb = path.iter()
+                .fold(Breadcrumb::new(), |breadcrumb, label| breadcrumb.item(*label))
                 .current("Laptop")
                 .show(ui);
+            if let Some(index) = breadcrumb.clicked {
+                self.toaster.info(format!("Navigate to {}", path[index]));
+            }
 
             ui.add_space(8.0);
             ui.label("Breadcrumb (slash separator):");
             Breadcrumb::new()
                 .separator(BreadcrumbSeparator::Slash)
-                .item("docs", || {})
-                .item("components", || {})
+                .item("docs")
+                .item("components")
                 .current("breadcrumb")
                 .show(ui);
 
+            ui.add_space(8.0);
+            ui.label("Breadcrumb (collapsed deep path, truncated labels):");
+            let thread = ["Relays", "wss://relay.nostr.band", "Bitcoin Builders & Tinkerers Community", "Lightning thread"];
+            ui.allocate_ui(egui::vec2(420.0, 24.0), |ui| {
+                let breadcrumb = thread.iter()
+                    .fold(Breadcrumb::new().id_salt("thread_path").max_label_width(140.0), |breadcrumb, label| breadcrumb.item(*label))
+                    .current("Reply from @jack")
+                    .show(ui);
+                if let Some(index) = breadcrumb.clicked {
+                    self.toaster.info(format!("Navigate to {}", thread[index]));
+                }
+            });
+
             ui.add_space(16.0);
 
             ui.heading("Phase 5: Overlays & Interactions");
@@ -949,6 +1181,7 @@ impl eframe::App for ShowcaseApp {
                 .side(DrawerSide::Bottom)
                 .title("Move Goal")
                 .description("Set your daily activity goal.")
+                .snap_points([0.5, 1.0])
                 .show(ui, |ui| {
                     ui.add_space(8.0);
                     form_label(ui, "Goal (calories)");
@@ -1057,13 +1290,16 @@ impl eframe::App for ShowcaseApp {
             ui.add_space(16.0);
 
             ui.label("Context Menu (right-click the box below):");
-            let menu_response = ContextMenu::new("demo_context")
-                .item("Edit")
-                .item("Duplicate")
+            let context_menu = MenuModel::new()
+                .item(MenuItem::new("edit", "Edit"))
+                .item(MenuItem::new("duplicate", "Duplicate").shortcut(KeyboardShortcut::new(Modifiers::COMMAND, Key::D)))
                 .separator()
-                .item("Share")
+                .submenu("Share", MenuModel::new()
+                    .item(MenuItem::new("share_link", "Copy link"))
+                    .item(MenuItem::new("share_email", "Email")))
                 .separator()
-                .destructive_item("Delete")
+                .item(MenuItem::new("delete", "Delete").destructive(true));
+            let menu_response = ContextMenu::new("demo_context", context_menu)
                 .show(ui, |ui| {
                     let (rect, _) = ui.allocate_exact_size(
                         egui::vec2(200.0, 60.0),
@@ -1085,8 +1321,8 @@ impl eframe::App for ShowcaseApp {
                     }
                 });
 
-            if let Some(idx) = menu_response.clicked_item {
-                self.toaster.info(format!("Context menu item {} clicked", idx));
+            if let Some(action) = menu_response.action {
+                self.toaster.info(format!("Context menu action: {action}"));
             }
 
             ui.add_space(16.0);
@@ -1186,16 +1422,9 @@ impl eframe::App for ShowcaseApp {
                 if ui.add(Button::new("Open Command Palette")).clicked() {
                     self.command_open = true;
                 }
-                ui.add(Kbd::new("Ctrl"));
-                ui.label("+");
-                ui.add(Kbd::new("K"));
+                ui.add(Kbd::shortcut(KeyboardShortcut::new(Modifiers::COMMAND, Key::K)));
             });
 
-            // Check for Ctrl+K shortcut
-            if ui.input(|i| i.modifiers.command && i.key_pressed(egui::Key::K)) {
-                self.command_open = !self.command_open;
-            }
-
             // Show command palette
             if let Some((group, item)) = Command::new("demo_command", &mut self.command_open, &mut self.command_search)
                 .placeholder("Type a command or search...")
@@ -1205,9 +1434,9 @@ impl eframe::App for ShowcaseApp {
                     cmd.item("Calculator");
                 })
                 .group("Settings", |cmd| {
-                    cmd.item_with_shortcut("Profile", "Ctrl+P");
-                    cmd.item_with_shortcut("Billing", "Ctrl+B");
-                    cmd.item_with_shortcut("Settings", "Ctrl+S");
+                    cmd.item_with_shortcut("Profile", KeyboardShortcut::new(Modifiers::COMMAND, Key::P));
+                    cmd.item_with_shortcut("Billing", KeyboardShortcut::new(Modifiers::COMMAND, Key::B));
+                    cmd.item_with_shortcut("Settings", KeyboardShortcut::new(Modifiers::COMMAND, Key::Comma));
                 })
                 .show(ui)
             {
@@ -1217,9 +1446,11 @@ impl eframe::App for ShowcaseApp {
 
             ui.add_space(16.0);
 
-            ui.label("Calendar:");
+            ui.label("Calendar (Monday first, week numbers):");
             ui.horizontal(|ui| {
                 Calendar::new("demo_calendar", &mut self.calendar_selection, &mut self.calendar_view_date)
+                    .locale(&EnglishLocale::GB)
+                    .show_week_numbers(true)
                     .show(ui);
 
                 ui.add_space(16.0);
@@ -1232,35 +1463,100 @@ impl eframe::App for ShowcaseApp {
                             ui.label(egui::RichText::new("None").color(ui.visuals().weak_text_color()));
                         }
                         CalendarSelection::Single(date) => {
-                            ui.label(date.format("%B %d, %Y").to_string());
+                            ui.label(EnglishLocale::GB.format_date(*date, "%A %d %B %Y"));
                         }
                         CalendarSelection::Range(start, end) => {
                             ui.label(format!("{} - {}", start.format("%b %d"), end.format("%b %d, %Y")));
                         }
+                        CalendarSelection::Multiple(dates) => {
+                            ui.label(format!("{} dates", dates.len()));
+                        }
                     }
                 });
             });
 
             ui.add_space(16.0);
 
+            ui.label("Calendar (two months, range, weekends disabled):");
+            Calendar::new("booking_calendar", &mut self.booking_selection, &mut self.booking_view_date)
+                .mode(CalendarMode::Range)
+                .number_of_months(2)
+                .disabled(|date| {
+                    use chrono::Datelike;
+                    date.weekday().number_from_monday() > 5
+                })
+                .show(ui);
+
+            ui.add_space(16.0);
+
+            ui.label("Calendar (multiple dates, activity dots):");
+            Calendar::new("events_calendar", &mut self.events_selection, &mut self.events_view_date)
+                .mode(CalendarMode::Multiple)
+                .decorations(|date| {
+                    use chrono::Datelike;
+                    match date.day() % 7 {
+                        0 => Some(DayDecoration::Dot),
+                        3 => Some(DayDecoration::Dots(vec![
+                            egui::Color32::from_rgb(34, 197, 94),
+                            egui::Color32::from_rgb(59, 130, 246),
+                        ])),
+                        5 => Some(DayDecoration::Badge((date.day() % 4 + 1).to_string())),
+                        _ => None,
+                    }
+                })
+                .show(ui);
+
+            ui.add_space(16.0);
+
             ui.label("DatePicker (Calendar in popover):");
             ui.horizontal(|ui| {
                 DatePicker::new("demo_datepicker", &mut self.datepicker_date)
                     .placeholder("Pick a date")
                     .show(ui);
 
+                ui.add_space(8.0);
+
+                DatePicker::new("demo_datepicker_gb", &mut self.datepicker_date)
+                    .placeholder("Pick a date (GB)")
+                    .locale(&EnglishLocale::GB)
+                    .show_week_numbers(true)
+                    .show(ui);
+
                 if let Some(date) = self.datepicker_date {
                     ui.add_space(8.0);
                     ui.label(format!("Selected: {}", date.format("%Y-%m-%d")));
                 }
             });
 
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                DatePicker::new("demo_datepicker_typed", &mut self.datepicker_date)
+                    .placeholder("Type a date (dd/mm/yyyy)")
+                    .format("%d/%m/%Y")
+                    .editable(true)
+                    .show(ui);
+
+                ui.add_space(8.0);
+
+                DateRangePicker::new("demo_date_range", &mut self.date_range)
+                    .presets(DatePreset::defaults())
+                    .show(ui);
+            });
+
+            ui.add_space(8.0);
+            DateTimePicker::new("demo_datetime", &mut self.meeting_time)
+                .placeholder("Schedule a meeting")
+                .minute_step(15)
+                .show(ui);
+
             ui.add_space(16.0);
 
             ui.label("Carousel:");
             let carousel_items = ["Slide 1", "Slide 2", "Slide 3", "Slide 4", "Slide 5"];
             Carousel::new("demo_carousel", &mut self.carousel_index, carousel_items.len())
                 .item_size(egui::vec2(280.0, 160.0))
+                .loop_items(true)
+                .autoplay(std::time::Duration::from_secs(5))
                 .show(ui, |ui, index| {
                     ui.centered_and_justified(|ui| {
                         ui.label(
@@ -1360,33 +1656,45 @@ impl eframe::App for ShowcaseApp {
             ui.add_space(8.0);
 
             ui.label("Menubar (application-style menu):");
-            Menubar::new("demo_menubar")
-                .menu("File", |m| {
-                    m.item("New");
-                    m.item_with_shortcut("Open", "Ctrl+O");
-                    m.item_with_shortcut("Save", "Ctrl+S");
-                    m.separator();
-                    m.item("Exit");
-                })
-                .menu("Edit", |m| {
-                    m.item_with_shortcut("Undo", "Ctrl+Z");
-                    m.item_with_shortcut("Redo", "Ctrl+Y");
-                    m.separator();
-                    m.item_with_shortcut("Cut", "Ctrl+X");
-                    m.item_with_shortcut("Copy", "Ctrl+C");
-                    m.item_with_shortcut("Paste", "Ctrl+V");
-                })
-                .menu("View", |m| {
-                    m.item("Zoom In");
-                    m.item("Zoom Out");
-                    m.separator();
-                    m.item("Full Screen");
-                })
-                .menu("Help", |m| {
-                    m.item("Documentation");
-                    m.item("About");
-                })
+            let command = |key| KeyboardShortcut::new(Modifiers::COMMAND, key);
+            let menubar = Menubar::new("demo_menubar")
+                .menu("File", MenuModel::new()
+                    .item(MenuItem::new("new", "New").shortcut(command(Key::N)))
+                    .item(MenuItem::new("open", "Open").shortcut(command(Key::O)))
+                    .submenu("Open Recent", MenuModel::new()
+                        .item(MenuItem::new("recent_notes", "notes.md"))
+                        .item(MenuItem::new("recent_todo", "todo.txt")))
+                    .item(MenuItem::new("save", "Save").shortcut(command(Key::S)).disabled("No unsaved changes"))
+                    .separator()
+                    .item(MenuItem::new("exit", "Exit")))
+                .menu("Edit", MenuModel::new()
+                    .item(MenuItem::new("undo", "Undo").shortcut(command(Key::Z)))
+                    .item(MenuItem::new("redo", "Redo").shortcut(KeyboardShortcut::new(Modifiers::COMMAND | Modifiers::SHIFT, Key::Z)))
+                    .separator()
+                    .item(MenuItem::new("cut", "Cut"))
+                    .item(MenuItem::new("copy", "Copy"))
+                    .item(MenuItem::new("paste", "Paste")))
+                .menu("View", MenuModel::new()
+                    .checkbox(MenuItem::new("word_wrap", "Word Wrap").shortcut(KeyboardShortcut::new(Modifiers::ALT, Key::Z)), &mut self.word_wrap)
+                    .checkbox(MenuItem::new("minimap", "Show Minimap"), &mut self.show_minimap)
+                    .separator()
+                    .label("Zoom")
+                    .radio_group(&mut self.editor_zoom, [
+                        (MenuItem::new("zoom_90", "90%"), 90),
+                        (MenuItem::new("zoom_100", "100%"), 100),
+                        (MenuItem::new("zoom_125", "125%"), 125),
+                    ]))
+                .menu("Help", MenuModel::new()
+                    .item(MenuItem::new("docs", "Documentation"))
+                    .item(MenuItem::new("about", "About")))
                 .show(ui);
+            if let Some(action) = menubar.action {
+                self.toaster.info(format!("Menu action: {action}"));
+            }
+            ui.label(format!(
+                "Word wrap: {}, minimap: {}, zoom: {}%",
+                self.word_wrap, self.show_minimap, self.editor_zoom
+            ));
 
             ui.add_space(16.0);
 
@@ -1435,7 +1743,7 @@ impl eframe::App for ShowcaseApp {
                 let sidebar_fg = theme.colors.sidebar_foreground;
                 let sidebar_muted = theme.colors.sidebar_foreground.linear_multiply(0.7);
 
-                let sidebar_response = Sidebar::new("demo_sidebar", &mut self.sidebar_open)
+                let sidebar_response = Sidebar::tree("demo_sidebar", &mut self.sidebar_open, &mut self.sidebar_selected)
                     .width(220.0)
                     .collapsed_width(56.0)
                     .header(move |ui| {
@@ -1512,26 +1820,44 @@ impl eframe::App for ShowcaseApp {
                             }
                         });
                     })
-                    .section("Platform", |s| {
-                        // Regular items with simple ASCII icons
-                        s.item("Documentation", "D", self.sidebar_selected == 0);
-                        s.item("Settings", "S", self.sidebar_selected == 1);
-                    })
-                    // Collapsible menu items with sub-items
-                    .menu("Playground", "P", |s| {
-                        s.sub_item("History", self.sidebar_selected == 2);
-                        s.sub_item("Starred", self.sidebar_selected == 3);
-                        s.sub_item("Settings", self.sidebar_selected == 4);
-                    })
-                    .menu("Models", "M", |s| {
-                        s.sub_item("Genesis", self.sidebar_selected == 5);
-                        s.sub_item("Explorer", self.sidebar_selected == 6);
-                        s.sub_item("Quantum", self.sidebar_selected == 7);
-                    })
+                    .node(
+                        SidebarNode::group("platform", "Platform")
+                            .child(SidebarNode::item("docs", "Documentation").icon("D"))
+                            .child(SidebarNode::item("inbox", "Inbox").icon("I").badge(self.sidebar_unread))
+                            .child(SidebarNode::item("settings", "Settings").icon("S")),
+                    )
+                    // Collapsible sections nest to any depth; expansion survives restarts
+                    .node(
+                        SidebarNode::item("playground", "Playground")
+                            .icon("P")
+                            .expanded(true)
+                            .child(SidebarNode::item("history", "History"))
+                            .child(SidebarNode::item("starred", "Starred").badge(3))
+                            .child(
+                                SidebarNode::item("playground_settings", "Settings")
+                                    .child(SidebarNode::item("general", "General"))
+                                    .child(SidebarNode::item("limits", "Limits")),
+                            ),
+                    )
+                    .node(
+                        SidebarNode::item("models", "Models")
+                            .icon("M")
+                            .children(["Genesis", "Explorer", "Quantum"].map(|model| SidebarNode::item(model, model))),
+                    )
                     .separator()
                     // Projects with context menu (three dots -> View/Share/Delete)
-                    .project_item("Design Engineering", "E", self.sidebar_selected == 8, vec!["View Project", "Share Project", "Delete Project"])
-                    .project_item("Sales & Marketing", "M", self.sidebar_selected == 9, vec!["View Project", "Share Project", "Delete Project"])
+                    // Drag projects (or Space + arrows) to reorder them
+                    .node(
+                        SidebarNode::group("projects", "Projects")
+                            .reorderable(true)
+                            .children(self.sidebar_projects.iter().map(|&project| {
+                                SidebarNode::item(project, project)
+                                    .icon(&project[..1])
+                                    .action("View Project")
+                                    .action("Share Project")
+                                    .action("Delete Project")
+                            })),
+                    )
                     .footer(move |ui| {
                         ui.horizontal(|ui| {
                             ui.add(egui_shadcn::Avatar::new("SC").size(egui_shadcn::AvatarSize::Small));
@@ -1615,8 +1941,16 @@ impl eframe::App for ShowcaseApp {
                     })
                     .show(ui);
 
-                if let Some(idx) = sidebar_response.clicked_item {
-                    self.sidebar_selected = idx;
+                for event in sidebar_response.events {
+                    match event {
+                        SidebarEvent::Selected("inbox") => self.sidebar_unread = 0,
+                        SidebarEvent::Reorder { from, to, .. } => Reorder { from, to }.apply(&mut self.sidebar_projects),
+                        SidebarEvent::MenuAction { node, action } => {
+                            let verb = ["View", "Share", "Delete"][action];
+                            self.toaster.info(format!("{verb}: {node}"));
+                        }
+                        _ => {}
+                    }
                 }
 
                 // Content area next to sidebar
@@ -1624,7 +1958,7 @@ impl eframe::App for ShowcaseApp {
                     .inner_margin(16.0)
                     .show(ui, |ui| {
                         ui.label("Content area");
-                        ui.label(format!("Selected item: {}", self.sidebar_selected));
+                        ui.label(format!("Selected item: {}", self.sidebar_selected.unwrap_or("none")));
                         if ui.button("Toggle Sidebar").clicked() {
                             self.sidebar_open = !self.sidebar_open;
                         }
@@ -1662,6 +1996,37 @@ impl eframe::App for ShowcaseApp {
                         // Use error-aware input that shows red border on error
                         shadcn_input_with_error(ui, &mut self.field_email, "Enter email...", email_has_error);
                     });
+
+                ui.add_space(12.0);
+
+                Field::new("phone").label("Phone").show(ui, |ui| {
+                    MaskedInput::new(&mut self.field_phone, InputMask::phone()).show(ui);
+                });
+
+                ui.add_space(12.0);
+
+                Field::new("amount").label("Amount").show(ui, |ui| {
+                    MaskedInput::new(&mut self.field_amount, InputMask::currency("$")).show(ui);
+                });
+
+                ui.add_space(12.0);
+
+                Field::new("quantity").label("Quantity").show(ui, |ui| {
+                    ui.add(NumberInput::new(&mut self.field_quantity).range(1..=99));
+                });
+
+                ui.add_space(12.0);
+
+                let toaster = &mut self.toaster;
+                Field::new("code")
+                    .label("Verification code")
+                    .description("Enter the 6-digit code we sent you.")
+                    .show(ui, |ui| {
+                        InputOTP::new(&mut self.field_code, 6)
+                            .groups(&[3, 3])
+                            .on_complete(|code| toaster.success(format!("Code {} entered", code)))
+                            .show(ui);
+                    });
             });
 
             ui.add_space(16.0);
@@ -1689,3 +2054,29 @@ fn main() -> eframe::Result {
         Box::new(|cc| Ok(Box::new(ShowcaseApp::new(cc)))),
     )
 }
+
+/// Fake profile directory: 20 results per page after a short delay
+fn search_profiles(search: &str, page: usize) -> OptionsFuture {
+    const NAMES: [&str; 8] = ["alice", "bob", "carol", "dave", "erin", "frank", "grace", "heidi"];
+    let search = search.to_lowercase();
+    let ready_at = std::time::Instant::now() + std::time::Duration::from_millis(400);
+    Box::pin(std::future::poll_fn(move |_| {
+        if std::time::Instant::now() < ready_at {
+            return std::task::Poll::Pending;
+        }
+        if search == "error" {
+            return std::task::Poll::Ready(Err("Relay unreachable".to_string()));
+        }
+        let matches: Vec<_> = (0..100)
+            .map(|i| format!("{}{}", NAMES[i % NAMES.len()], i))
+            .filter(|name| name.contains(&search))
+            .collect();
+        let options = matches
+            .iter()
+            .skip(page * 20)
+            .take(20)
+            .map(|name| ComboboxOption::with_label(name.clone(), format!("@{}", name)))
+            .collect();
+        std::task::Poll::Ready(Ok(OptionPage { options, has_more: matches.len() > (page + 1) * 20 }))
+    }))
+}
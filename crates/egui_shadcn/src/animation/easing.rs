@@ -0,0 +1,142 @@
+//! Easing curves for tweens
+//!
+//! Cubic-bezier curves matching CSS `cubic-bezier()` timing functions,
+//! including the standard `ease`, `ease-in`, `ease-out` and `ease-in-out` presets.
+
+/// A CSS-style cubic-bezier easing curve
+///
+/// The curve runs from `(0, 0)` to `(1, 1)` with control points `(x1, y1)` and `(x2, y2)`.
+/// `x1` and `x2` must be within `0.0..=1.0` so the curve is a function of time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CubicBezier {
+    x1: f32,
+    y1: f32,
+    x2: f32,
+    y2: f32,
+}
+
+impl Default for CubicBezier {
+    fn default() -> Self {
+        Self::EASE
+    }
+}
+
+impl CubicBezier {
+    /// `linear`
+    pub const LINEAR: Self = Self { x1: 0.0, y1: 0.0, x2: 1.0, y2: 1.0 };
+    /// `ease` (CSS default)
+    pub const EASE: Self = Self { x1: 0.25, y1: 0.1, x2: 0.25, y2: 1.0 };
+    /// `ease-in`
+    pub const EASE_IN: Self = Self { x1: 0.42, y1: 0.0, x2: 1.0, y2: 1.0 };
+    /// `ease-out`
+    pub const EASE_OUT: Self = Self { x1: 0.0, y1: 0.0, x2: 0.58, y2: 1.0 };
+    /// `ease-in-out`
+    pub const EASE_IN_OUT: Self = Self { x1: 0.42, y1: 0.0, x2: 0.58, y2: 1.0 };
+
+    /// Create a curve from its two control points
+    ///
+    /// `x1` and `x2` are clamped to `0.0..=1.0`.
+    pub fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Self {
+            x1: x1.clamp(0.0, 1.0),
+            y1,
+            x2: x2.clamp(0.0, 1.0),
+            y2,
+        }
+    }
+
+    /// Eased progress for linear progress `t` in `0.0..=1.0`
+    pub fn sample(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        if t <= 0.0 || t >= 1.0 {
+            return t;
+        }
+        let s = self.solve_curve_x(t);
+        bezier(s, self.y1, self.y2)
+    }
+
+    /// Find the curve parameter whose x equals `x`
+    fn solve_curve_x(&self, x: f32) -> f32 {
+        // Newton-Raphson converges quickly for typical curves
+        let mut s = x;
+        for _ in 0..8 {
+            let err = bezier(s, self.x1, self.x2) - x;
+            if err.abs() < 1e-6 {
+                return s;
+            }
+            let slope = bezier_slope(s, self.x1, self.x2);
+            if slope.abs() < 1e-6 {
+                break;
+            }
+            s -= err / slope;
+        }
+
+        // Fall back to bisection for flat segments
+        let (mut lo, mut hi) = (0.0_f32, 1.0_f32);
+        s = x;
+        for _ in 0..32 {
+            let value = bezier(s, self.x1, self.x2);
+            if (value - x).abs() < 1e-6 {
+                break;
+            }
+            if value < x {
+                lo = s;
+            } else {
+                hi = s;
+            }
+            s = (lo + hi) / 2.0;
+        }
+        s
+    }
+}
+
+/// One coordinate of a cubic bezier with end points 0 and 1
+fn bezier(s: f32, p1: f32, p2: f32) -> f32 {
+    let inv = 1.0 - s;
+    3.0 * inv * inv * s * p1 + 3.0 * inv * s * s * p2 + s * s * s
+}
+
+/// Derivative of [`bezier`] with respect to `s`
+fn bezier_slope(s: f32, p1: f32, p2: f32) -> f32 {
+    let inv = 1.0 - s;
+    3.0 * inv * inv * p1 + 6.0 * inv * s * (p2 - p1) + 3.0 * s * s * (1.0 - p2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_end_points() {
+        for curve in [CubicBezier::EASE, CubicBezier::EASE_IN, CubicBezier::EASE_OUT] {
+            assert_eq!(curve.sample(0.0), 0.0);
+            assert_eq!(curve.sample(1.0), 1.0);
+        }
+    }
+
+    #[test]
+    fn test_linear_is_identity() {
+        for t in [0.1, 0.25, 0.5, 0.9] {
+            assert!((CubicBezier::LINEAR.sample(t) - t).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_ease_in_and_out_shapes() {
+        // ease-in starts slow, ease-out starts fast
+        assert!(CubicBezier::EASE_IN.sample(0.25) < 0.25);
+        assert!(CubicBezier::EASE_OUT.sample(0.25) > 0.25);
+        // ease-in-out is symmetric around the midpoint
+        assert!((CubicBezier::EASE_IN_OUT.sample(0.5) - 0.5).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_monotonic() {
+        let mut last = 0.0;
+        for i in 1..=100 {
+            let value = CubicBezier::EASE.sample(i as f32 / 100.0);
+            assert!(value >= last);
+            last = value;
+        }
+    }
+}
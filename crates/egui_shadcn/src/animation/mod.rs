@@ -0,0 +1,294 @@
+//! Animation utilities for smooth UI transitions
+//!
+//! Time-based animation primitives, integrated over each frame's `dt` so motion
+//! looks the same at any frame rate:
+//! - [`Spring`] / [`SpringState`]: damped mass-spring physics
+//! - [`Tween`]: duration-based interpolation shaped by a [`CubicBezier`] curve
+//!
+//! The keyed helpers ([`spring_value`], [`spring_bool`], [`tween_value`]) keep their
+//! state in egui memory by `Id`, so a component animates with one call per frame.
+//! Changing the target mid-flight keeps the spring's current velocity.
+//! With [`MotionPreference::None`] every helper jumps straight to its target.
+//!
+//! ## Example
+//! ```rust,ignore
+//! let openness = animation::spring_bool(ui.ctx(), id.with("open"), is_open, Spring::DEFAULT);
+//! let x = animation::tween_value(ui.ctx(), id.with("x"), target_x, 0.2, CubicBezier::EASE_OUT);
+//! ```
+
+pub mod easing;
+pub mod spring;
+pub mod tween;
+
+pub use easing::CubicBezier;
+pub use spring::{Spring, SpringState};
+pub use tween::Tween;
+
+use egui::{Context, Id};
+
+use crate::theme::{MotionPreference, ShadcnTheme};
+
+/// Time elapsed since the last frame, in seconds
+fn frame_dt(ctx: &Context) -> f32 {
+    ctx.input(|i| i.stable_dt)
+}
+
+/// Motion preference of the theme applied to `ctx`
+pub fn motion(ctx: &Context) -> MotionPreference {
+    ctx.data(|d| d.get_temp::<ShadcnTheme>(Id::new("shadcn_theme")))
+        .map(|theme| theme.motion)
+        .unwrap_or_default()
+}
+
+/// Spring state stored in egui memory by [`spring_value_from`]
+#[derive(Clone, Copy, Debug)]
+struct KeyedSpring {
+    state: SpringState,
+}
+
+/// Animate the value keyed by `id` towards `target` with a damped spring
+///
+/// The first call starts at `target` (no animation). Returns the current value.
+pub fn spring_value(ctx: &Context, id: Id, target: f32, spring: Spring) -> f32 {
+    spring_value_from(ctx, id, target, target, spring)
+}
+
+/// Like [`spring_value`], but the first call starts at `initial`
+///
+/// Useful for entrance animations (e.g. a toast sliding in from off-screen).
+pub fn spring_value_from(ctx: &Context, id: Id, initial: f32, target: f32, spring: Spring) -> f32 {
+    let mut keyed = ctx
+        .data(|d| d.get_temp::<KeyedSpring>(id))
+        .unwrap_or(KeyedSpring { state: SpringState::at(initial) });
+
+    if !motion(ctx).animates() {
+        keyed.state = SpringState::at(target);
+    } else if !keyed.state.is_settled(target) {
+        keyed.state.step(&spring, target, frame_dt(ctx));
+        ctx.request_repaint();
+    }
+
+    ctx.data_mut(|d| d.insert_temp(id, keyed));
+    keyed.state.value
+}
+
+/// Animate between `0.0` (off) and `1.0` (on) with a damped spring
+pub fn spring_bool(ctx: &Context, id: Id, on: bool, spring: Spring) -> f32 {
+    spring_value(ctx, id, if on { 1.0 } else { 0.0 }, spring)
+}
+
+/// Current position and velocity of the spring keyed by `id`
+pub fn spring_state(ctx: &Context, id: Id) -> Option<SpringState> {
+    ctx.data(|d| d.get_temp::<KeyedSpring>(id)).map(|k| k.state)
+}
+
+/// Overwrite the spring keyed by `id`, e.g. to hand off a drag release velocity
+pub fn set_spring_state(ctx: &Context, id: Id, state: SpringState) {
+    ctx.data_mut(|d| d.insert_temp(id, KeyedSpring { state }));
+}
+
+/// Animate the value keyed by `id` towards `target` over `duration` seconds
+///
+/// The first call starts at `target` (no animation). When `target` changes, the
+/// tween restarts from the current value. Returns the current value.
+pub fn tween_value(ctx: &Context, id: Id, target: f32, duration: f32, easing: CubicBezier) -> f32 {
+    let mut tween = ctx
+        .data(|d| d.get_temp::<Tween>(id))
+        .unwrap_or_else(|| Tween::at(target));
+
+    if (tween.to - target).abs() > f32::EPSILON {
+        tween.duration = duration.max(0.0);
+        tween.easing = easing;
+        tween.retarget(target);
+    }
+
+    if !motion(ctx).animates() {
+        tween = Tween::at(target);
+    } else if !tween.is_finished() {
+        tween.step(frame_dt(ctx));
+        ctx.request_repaint();
+    }
+
+    ctx.data_mut(|d| d.insert_temp(id, tween));
+    tween.value()
+}
+
+/// Animation state for slide-in/out components
+#[derive(Clone, Copy, Debug)]
+pub struct SlideAnimation {
+    /// Current offset (0.0 = fully visible, 1.0 = fully hidden)
+    pub offset: f32,
+    /// Offset velocity in units per second (kept when the target changes)
+    pub velocity: f32,
+    /// Whether we're animating towards open (true) or closed (false)
+    pub opening: bool,
+    /// Whether animation is currently in progress
+    pub animating: bool,
+}
+
+impl Default for SlideAnimation {
+    fn default() -> Self {
+        Self {
+            offset: 1.0,  // Start fully closed/hidden
+            velocity: 0.0,
+            opening: false,
+            animating: false,
+        }
+    }
+}
+
+impl SlideAnimation {
+    /// Load animation state from egui's temporary storage
+    pub fn load(ctx: &Context, id: Id) -> Self {
+        ctx.data(|d| d.get_temp(id).unwrap_or_default())
+    }
+
+    /// Store animation state in egui's temporary storage
+    pub fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.insert_temp(id, self));
+    }
+
+    /// Start opening animation
+    pub fn start_open(&mut self) {
+        self.opening = true;
+        self.animating = true;
+    }
+
+    /// Start closing animation
+    pub fn start_close(&mut self) {
+        self.opening = false;
+        self.animating = true;
+    }
+
+    /// Update animation state, returns true if still animating
+    pub fn update(&mut self, ctx: &Context) -> bool {
+        let target = if self.opening { 0.0 } else { 1.0 };
+        self.update_to(ctx, target)
+    }
+
+    /// Update animation towards an arbitrary offset (e.g. a drawer snap point)
+    ///
+    /// Returns true if still animating.
+    pub fn update_to(&mut self, ctx: &Context, target: f32) -> bool {
+        if !self.animating {
+            return false;
+        }
+
+        if !motion(ctx).animates() {
+            self.offset = target;
+            self.velocity = 0.0;
+            self.animating = false;
+            return false;
+        }
+
+        let mut state = SpringState {
+            value: self.offset,
+            velocity: self.velocity,
+        };
+        state.step(&Spring::DEFAULT, target, frame_dt(ctx));
+
+        // Never slide past fully open or fully closed
+        self.offset = state.value.clamp(0.0, 1.0);
+        self.velocity = if self.offset == state.value { state.velocity } else { 0.0 };
+
+        if state.is_settled(target) {
+            self.offset = target;
+            self.velocity = 0.0;
+            self.animating = false;
+            false
+        } else {
+            ctx.request_repaint();
+            true
+        }
+    }
+
+    /// Check if the component should be considered "open" (visible or animating open)
+    pub fn is_visible(&self) -> bool {
+        self.offset < 1.0 || self.opening
+    }
+
+    /// Check if fully closed (animation complete and hidden)
+    pub fn is_fully_closed(&self) -> bool {
+        self.offset >= 1.0 && !self.opening && !self.animating
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slide_animation_moves_towards_target() {
+        let ctx = Context::default();
+        let mut anim = SlideAnimation::default();
+        anim.start_open();
+        // stable_dt defaults to 1/60s before the first frame
+        assert!(anim.update(&ctx));
+        assert!(anim.offset < 1.0);
+    }
+
+    #[test]
+    fn test_slide_animation_completes() {
+        let ctx = Context::default();
+        let mut anim = SlideAnimation::default();
+        anim.start_open();
+        for _ in 0..120 {
+            anim.update(&ctx);
+        }
+        assert_eq!(anim.offset, 0.0);
+        assert!(!anim.animating);
+    }
+
+    #[test]
+    fn test_slide_animation_keeps_velocity_when_interrupted() {
+        let ctx = Context::default();
+        let mut anim = SlideAnimation::default();
+        anim.start_open();
+        for _ in 0..5 {
+            anim.update(&ctx);
+        }
+        let velocity = anim.velocity;
+        assert!(velocity < 0.0);
+
+        anim.start_close();
+        anim.update(&ctx);
+        // Still moving towards open, just slower
+        assert!(anim.velocity < 0.0 && anim.velocity > velocity);
+    }
+
+    #[test]
+    fn test_keyed_spring_starts_at_target() {
+        let ctx = Context::default();
+        let id = Id::new("value");
+        assert_eq!(spring_value(&ctx, id, 5.0, Spring::DEFAULT), 5.0);
+
+        let next = spring_value(&ctx, id, 10.0, Spring::DEFAULT);
+        assert!(next > 5.0 && next < 10.0);
+        assert!(spring_state(&ctx, id).unwrap().velocity > 0.0);
+    }
+
+    #[test]
+    fn test_keyed_spring_from_initial() {
+        let ctx = Context::default();
+        let value = spring_value_from(&ctx, Id::new("enter"), 0.0, 1.0, Spring::DEFAULT);
+        assert!(value > 0.0 && value < 1.0);
+    }
+
+    #[test]
+    fn test_keyed_tween() {
+        let ctx = Context::default();
+        let id = Id::new("tween");
+        assert_eq!(tween_value(&ctx, id, 0.0, 0.2, CubicBezier::EASE), 0.0);
+
+        let value = tween_value(&ctx, id, 1.0, 0.2, CubicBezier::LINEAR);
+        assert!(value > 0.0 && value < 1.0);
+    }
+
+    #[test]
+    fn test_slide_animation_default() {
+        let anim = SlideAnimation::default();
+        assert_eq!(anim.offset, 1.0);  // Starts fully closed
+        assert!(!anim.opening);
+        assert!(!anim.animating);
+    }
+}
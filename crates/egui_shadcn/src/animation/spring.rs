@@ -0,0 +1,161 @@
+//! Damped spring physics
+//!
+//! A mass-spring-damper integrated over real elapsed time, so animation speed
+//! is independent of frame rate. Springs keep their velocity when the target
+//! changes mid-flight, which makes interrupted animations look continuous.
+
+/// Largest integration step (seconds); larger frame times are sub-stepped
+const MAX_STEP: f32 = 1.0 / 240.0;
+
+/// Longest frame time integrated at once; longer stalls are clamped
+const MAX_DT: f32 = 0.25;
+
+/// Distance from the target below which a spring may come to rest
+const REST_DISTANCE: f32 = 0.001;
+
+/// Speed below which a spring may come to rest (units per second)
+const REST_VELOCITY: f32 = 0.01;
+
+/// Spring parameters: stiffness, damping and mass
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring {
+    /// Spring constant `k` (higher = faster)
+    pub stiffness: f32,
+    /// Damping coefficient `c` (higher = less oscillation)
+    pub damping: f32,
+    /// Mass `m` (higher = more inertia)
+    pub mass: f32,
+}
+
+impl Default for Spring {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Spring {
+    /// Critically damped, settles in roughly 300ms without overshoot
+    pub const DEFAULT: Self = Self { stiffness: 300.0, damping: 35.0, mass: 1.0 };
+    /// Slower, softer motion for large surfaces (sheets, drawers)
+    pub const GENTLE: Self = Self { stiffness: 170.0, damping: 26.0, mass: 1.0 };
+    /// Quick response for small controls (switch thumbs, indicators)
+    pub const SNAPPY: Self = Self { stiffness: 500.0, damping: 45.0, mass: 1.0 };
+    /// Under-damped with a visible bounce
+    pub const WOBBLY: Self = Self { stiffness: 180.0, damping: 12.0, mass: 1.0 };
+
+    /// Create a spring from stiffness, damping and mass
+    pub fn new(stiffness: f32, damping: f32, mass: f32) -> Self {
+        Self {
+            stiffness: stiffness.max(0.0),
+            damping: damping.max(0.0),
+            mass: mass.max(f32::EPSILON),
+        }
+    }
+
+    /// Damping ratio `ζ` (1.0 = critically damped, < 1.0 oscillates)
+    pub fn damping_ratio(&self) -> f32 {
+        self.damping / (2.0 * (self.stiffness * self.mass).sqrt())
+    }
+}
+
+/// Position and velocity of a spring-driven value
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SpringState {
+    /// Current value
+    pub value: f32,
+    /// Current velocity (units per second)
+    pub velocity: f32,
+}
+
+impl SpringState {
+    /// Create a state resting at `value`
+    pub fn at(value: f32) -> Self {
+        Self { value, velocity: 0.0 }
+    }
+
+    /// Advance the spring towards `target` by `dt` seconds
+    ///
+    /// Uses semi-implicit Euler with fixed sub-steps, which stays stable for stiff
+    /// springs and gives the same result regardless of frame rate.
+    pub fn step(&mut self, spring: &Spring, target: f32, dt: f32) {
+        let mut remaining = dt.clamp(0.0, MAX_DT);
+        while remaining > 0.0 {
+            let h = remaining.min(MAX_STEP);
+            let displacement = self.value - target;
+            let force = -spring.stiffness * displacement - spring.damping * self.velocity;
+            self.velocity += force / spring.mass * h;
+            self.value += self.velocity * h;
+            remaining -= h;
+        }
+
+        if self.is_settled(target) {
+            self.value = target;
+            self.velocity = 0.0;
+        }
+    }
+
+    /// Whether the spring is at rest at `target`
+    pub fn is_settled(&self, target: f32) -> bool {
+        (self.value - target).abs() < REST_DISTANCE && self.velocity.abs() < REST_VELOCITY
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(spring: &Spring, state: &mut SpringState, target: f32, seconds: f32, fps: f32) {
+        let dt = 1.0 / fps;
+        let frames = (seconds * fps).round() as usize;
+        for _ in 0..frames {
+            state.step(spring, target, dt);
+        }
+    }
+
+    #[test]
+    fn test_spring_settles_at_target() {
+        let mut state = SpringState::at(0.0);
+        run(&Spring::DEFAULT, &mut state, 1.0, 1.0, 60.0);
+        assert!(state.is_settled(1.0));
+        assert_eq!(state.value, 1.0);
+    }
+
+    #[test]
+    fn test_frame_rate_independent() {
+        let mut slow = SpringState::at(0.0);
+        let mut fast = SpringState::at(0.0);
+        run(&Spring::DEFAULT, &mut slow, 1.0, 0.1, 30.0);
+        run(&Spring::DEFAULT, &mut fast, 1.0, 0.1, 120.0);
+        assert!((slow.value - fast.value).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_keeps_velocity_when_retargeted() {
+        let mut state = SpringState::at(0.0);
+        run(&Spring::DEFAULT, &mut state, 1.0, 0.05, 60.0);
+        let velocity = state.velocity;
+        assert!(velocity > 0.0);
+
+        // Reversing the target decelerates instead of jumping
+        state.step(&Spring::DEFAULT, 0.0, 1.0 / 60.0);
+        assert!(state.velocity > 0.0);
+        assert!(state.velocity < velocity);
+    }
+
+    #[test]
+    fn test_damping_ratio() {
+        assert!((Spring::DEFAULT.damping_ratio() - 1.0).abs() < 0.05);
+        assert!(Spring::WOBBLY.damping_ratio() < 1.0);
+    }
+
+    #[test]
+    fn test_underdamped_spring_overshoots() {
+        let mut state = SpringState::at(0.0);
+        let mut max = 0.0_f32;
+        for _ in 0..120 {
+            state.step(&Spring::WOBBLY, 1.0, 1.0 / 60.0);
+            max = max.max(state.value);
+        }
+        assert!(max > 1.0);
+    }
+}
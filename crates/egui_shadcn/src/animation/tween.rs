@@ -0,0 +1,107 @@
+//! Duration-based tweens
+//!
+//! A tween moves from one value to another over a fixed duration, shaped by a
+//! [`CubicBezier`] easing curve.
+
+use super::easing::CubicBezier;
+
+/// A value interpolated over a fixed duration
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tween {
+    /// Start value
+    pub from: f32,
+    /// End value
+    pub to: f32,
+    /// Total duration in seconds
+    pub duration: f32,
+    /// Elapsed time in seconds
+    pub elapsed: f32,
+    /// Easing curve
+    pub easing: CubicBezier,
+}
+
+impl Tween {
+    /// Create a tween from `from` to `to`
+    pub fn new(from: f32, to: f32, duration: f32, easing: CubicBezier) -> Self {
+        Self {
+            from,
+            to,
+            duration: duration.max(0.0),
+            elapsed: 0.0,
+            easing,
+        }
+    }
+
+    /// A finished tween resting at `value`
+    pub fn at(value: f32) -> Self {
+        Self::new(value, value, 0.0, CubicBezier::LINEAR)
+    }
+
+    /// Advance the tween by `dt` seconds
+    pub fn step(&mut self, dt: f32) {
+        self.elapsed = (self.elapsed + dt.max(0.0)).min(self.duration);
+    }
+
+    /// Linear progress in `0.0..=1.0`
+    pub fn progress(&self) -> f32 {
+        if self.duration <= 0.0 {
+            1.0
+        } else {
+            (self.elapsed / self.duration).clamp(0.0, 1.0)
+        }
+    }
+
+    /// Current eased value
+    pub fn value(&self) -> f32 {
+        self.from + (self.to - self.from) * self.easing.sample(self.progress())
+    }
+
+    /// Whether the tween reached its end value
+    pub fn is_finished(&self) -> bool {
+        self.progress() >= 1.0
+    }
+
+    /// Restart towards a new end value from the current value
+    ///
+    /// Does nothing if `to` is already the end value.
+    pub fn retarget(&mut self, to: f32) {
+        if (to - self.to).abs() < f32::EPSILON {
+            return;
+        }
+        self.from = self.value();
+        self.to = to;
+        self.elapsed = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tween_reaches_end() {
+        let mut tween = Tween::new(0.0, 10.0, 0.2, CubicBezier::EASE);
+        assert_eq!(tween.value(), 0.0);
+        for _ in 0..12 {
+            tween.step(1.0 / 60.0);
+        }
+        assert!(tween.is_finished());
+        assert_eq!(tween.value(), 10.0);
+    }
+
+    #[test]
+    fn test_retarget_starts_from_current_value() {
+        let mut tween = Tween::new(0.0, 1.0, 1.0, CubicBezier::LINEAR);
+        tween.step(0.5);
+        tween.retarget(0.0);
+        assert!((tween.value() - 0.5).abs() < 1e-3);
+        assert!(!tween.is_finished());
+    }
+
+    #[test]
+    fn test_zero_duration_is_instant() {
+        let tween = Tween::new(0.0, 1.0, 0.0, CubicBezier::EASE);
+        assert!(tween.is_finished());
+        assert_eq!(tween.value(), 1.0);
+    }
+}
@@ -5,6 +5,7 @@
 //! Reference: <https://ui.shadcn.com/docs/components/accordion>
 
 use egui::{Id, Ui, Sense, Vec2, Pos2};
+use crate::animation::{self, Spring};
 use crate::theme::ShadcnTheme;
 
 /// Accordion type determining expansion behavior
@@ -129,11 +130,13 @@ impl<'a> Accordion<'a> {
             });
 
             // Draw chevron at fixed x position (outside the horizontal layout)
-            let rotation = ui.ctx().animate_bool_with_time(
-                item_id.with("chevron"),
-                is_expanded,
-                ui.style().animation_time,
-            );
+            let rotation = if theme.motion.allows_movement() {
+                animation::spring_bool(ui.ctx(), item_id.with("chevron"), is_expanded, Spring::SNAPPY)
+            } else if is_expanded {
+                1.0
+            } else {
+                0.0
+            };
 
             let row_rect = trigger_response.response.rect;
             let cx = row_start_x + chevron_x_offset;
@@ -206,11 +209,8 @@ impl<'a> Accordion<'a> {
             }
 
             // Draw content with animation
-            let openness = ui.ctx().animate_bool_with_time(
-                item_id.with("content"),
-                is_expanded,
-                ui.style().animation_time,
-            );
+            let openness = animation::spring_bool(ui.ctx(), item_id.with("content"), is_expanded, Spring::DEFAULT)
+                .clamp(0.0, 1.0);
 
             if openness > 0.0 {
                 ui.scope(|ui| {
@@ -259,6 +259,8 @@ impl<'a> Accordion<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::transition_frames;
+    use crate::theme::MotionPreference;
 
     #[test]
     fn test_accordion_creation() {
@@ -271,4 +273,19 @@ mod tests {
         assert!(!accordion.collapsible);
         assert_eq!(accordion.default_value, Some("item-1".to_string()));
     }
+
+    #[test]
+    fn test_accordion_motion() {
+        let accordion = |ui: &mut Ui, open: bool| {
+            let expanded = if open { vec!["a".to_string()] } else { Vec::new() };
+            ui.data_mut(|d| d.insert_temp(Id::new("faq").with("state"), expanded));
+            Accordion::new("faq").item("a", "Question", |ui| {
+                ui.label("Answer");
+            }).show(ui);
+        };
+        assert_eq!(transition_frames(MotionPreference::Full, accordion), (true, true));
+        // Reduced motion still fades the content in
+        assert_eq!(transition_frames(MotionPreference::Reduced, accordion), (true, true));
+        assert_eq!(transition_frames(MotionPreference::None, accordion), (false, false));
+    }
 }
@@ -6,6 +6,7 @@
 //! Reference: <https://ui.shadcn.com/docs/components/alert-dialog>
 
 use egui::{Color32, Id, Vec2};
+use crate::overlay::{OverlayKind, OverlayStack};
 use crate::theme::ShadcnTheme;
 
 /// Alert Dialog component for confirmations and destructive actions
@@ -111,9 +112,12 @@ impl AlertDialog {
     /// Show the alert dialog
     pub fn show(self, ctx: &egui::Context, open: &mut bool) -> AlertDialogResult {
         if !*open {
+            OverlayStack::remove(ctx, self.id);
             return AlertDialogResult::Pending;
         }
 
+        let overlay = OverlayStack::register(ctx, self.id, OverlayKind::Modal);
+
         let theme = ctx.data(|d| {
             d.get_temp::<ShadcnTheme>(Id::new("shadcn_theme"))
                 .unwrap_or_else(ShadcnTheme::light)
@@ -124,12 +128,7 @@ impl AlertDialog {
         // Draw backdrop
         #[allow(deprecated)]
         let screen_rect = ctx.screen_rect();
-        let backdrop_layer = egui::LayerId::new(egui::Order::Middle, self.id.with("backdrop"));
-        ctx.layer_painter(backdrop_layer).rect_filled(
-            screen_rect,
-            0.0,
-            Color32::from_black_alpha(128),
-        );
+        overlay.paint_backdrop(ctx, screen_rect, 128);
 
         // Calculate dialog position (centered)
         let dialog_width = self.max_width.min(screen_rect.width() - 32.0);
@@ -137,7 +136,7 @@ impl AlertDialog {
         let dialog_pos = screen_rect.center() - Vec2::new(dialog_width / 2.0, dialog_height / 2.0);
 
         // Draw dialog
-        egui::Area::new(self.id.with("dialog"))
+        let area_response = egui::Area::new(self.id.with("dialog"))
             .order(egui::Order::Foreground)
             .fixed_pos(dialog_pos)
             .show(ctx, |ui| {
@@ -259,14 +258,17 @@ impl AlertDialog {
                 });
             });
 
-        // Handle escape key
-        if ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+        overlay.raise(ctx, area_response.response.layer_id);
+
+        // Handle escape key (only when this is the top-most overlay)
+        if overlay.escape_pressed(ctx) {
             result = AlertDialogResult::Cancel;
         }
 
         // Close dialog if user made a choice
         if result != AlertDialogResult::Pending {
             *open = false;
+            OverlayStack::remove(ctx, self.id);
         }
 
         result
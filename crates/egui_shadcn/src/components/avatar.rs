@@ -6,23 +6,35 @@
 //! Reference: <https://ui.shadcn.com/docs/components/avatar>
 //!
 //! ## Features
-//! - Circular shape with configurable size
-//! - Fallback to initials when no image provided
-//! - Multiple size variants
-//! - Semantic color mapping from theme
+//! - Circular or rounded-square shape with configurable size
+//! - Profile pictures loaded through egui's image loaders
+//! - Fallback to initials while loading, on error or without an image
+//! - Initials background derived from the name, so it is stable per user
+//! - Status dot overlay (online, away, busy, offline)
+//! - [`AvatarGroup`] of overlapping avatars with a "+N" overflow chip
 //!
 //! ## Usage
 //! ```rust,ignore
-//! use egui_shadcn::components::Avatar;
+//! use egui_shadcn::components::{Avatar, AvatarGroup, AvatarStatus};
 //!
 //! // Display initials
 //! ui.add(Avatar::new("John Doe"));
 //!
 //! // Custom size
 //! ui.add(Avatar::new("AB").size(AvatarSize::Large));
+//!
+//! // Profile picture with an online dot
+//! ui.add(Avatar::new("Alice").image("https://example.com/alice.png").status(AvatarStatus::Online));
+//!
+//! // Three avatars and a "+2" chip
+//! ui.add(AvatarGroup::new(names.iter().map(|name| Avatar::new(*name))).max(3));
 //! ```
+//!
+//! Images need an image loader, e.g. `egui_extras::install_image_loaders(ctx)`.
+//! Without one the initials are shown.
 
-use egui::{Response, Ui, Vec2, Widget};
+use egui::load::{SizeHint, SizedTexture, TexturePoll};
+use egui::{Color32, Context, CornerRadius, Rect, Response, TextureOptions, Ui, Vec2, Widget};
 
 use crate::theme::ShadcnTheme;
 
@@ -51,11 +63,71 @@ impl AvatarSize {
     }
 }
 
+/// Shape of an avatar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AvatarShape {
+    /// Circle (default)
+    #[default]
+    Circle,
+    /// Square with rounded corners
+    Rounded,
+}
+
+/// Presence shown as a dot in the avatar's bottom-right corner
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AvatarStatus {
+    /// Green dot
+    Online,
+    /// Amber dot
+    Away,
+    /// Red dot
+    Busy,
+    /// Grey dot
+    Offline,
+}
+
+impl AvatarStatus {
+    fn color(self, theme: &ShadcnTheme) -> Color32 {
+        match self {
+            AvatarStatus::Online => Color32::from_rgb(0x22, 0xC5, 0x5E),
+            AvatarStatus::Away => Color32::from_rgb(0xF5, 0x9E, 0x0B),
+            AvatarStatus::Busy => theme.colors.destructive,
+            AvatarStatus::Offline => theme.colors.muted_foreground,
+        }
+    }
+}
+
+/// Initials backgrounds, all with at least 4.5:1 contrast against white
+const FALLBACK_COLORS: [Color32; 12] = [
+    Color32::from_rgb(0xDC, 0x26, 0x26), // red
+    Color32::from_rgb(0xC2, 0x41, 0x0C), // orange
+    Color32::from_rgb(0xB4, 0x53, 0x09), // amber
+    Color32::from_rgb(0x15, 0x80, 0x3D), // green
+    Color32::from_rgb(0x0F, 0x76, 0x6E), // teal
+    Color32::from_rgb(0x0E, 0x74, 0x90), // cyan
+    Color32::from_rgb(0x25, 0x63, 0xEB), // blue
+    Color32::from_rgb(0x4F, 0x46, 0xE5), // indigo
+    Color32::from_rgb(0x7C, 0x3A, 0xED), // violet
+    Color32::from_rgb(0x93, 0x33, 0xEA), // purple
+    Color32::from_rgb(0xDB, 0x27, 0x77), // pink
+    Color32::from_rgb(0xE1, 0x1D, 0x48), // rose
+];
+
+/// Background color of the initials for `name`
+///
+/// Uses FNV-1a rather than `std`'s hasher, whose output may change between
+/// Rust releases, so a user keeps the same color across builds.
+pub fn avatar_color(name: &str) -> Color32 {
+    let hash = name.bytes().fold(0xCBF2_9CE4_8422_2325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01B3)
+    });
+    FALLBACK_COLORS[(hash % FALLBACK_COLORS.len() as u64) as usize]
+}
+
 /// Avatar component for displaying user profile images or initials
 ///
-/// A circular element that shows user representation. Currently supports
-/// fallback to initials (image support can be added later with egui's
-/// texture system).
+/// Shows the image once it has loaded and the initials of the name until
+/// then, or when there is no image or it fails to load.
 ///
 /// ## Example
 /// ```rust,ignore
@@ -69,11 +141,20 @@ impl AvatarSize {
 ///
 /// // Large avatar
 /// ui.add(Avatar::new("Admin").size(AvatarSize::Large));
+///
+/// // Profile picture, rounded square, busy
+/// ui.add(Avatar::new(&profile.name)
+///     .image(&profile.picture)
+///     .shape(AvatarShape::Rounded)
+///     .status(AvatarStatus::Busy));
 /// ```
 #[derive(Debug, Clone)]
 pub struct Avatar {
     text: String,
     size: AvatarSize,
+    image: Option<String>,
+    shape: AvatarShape,
+    status: Option<AvatarStatus>,
 }
 
 impl Avatar {
@@ -87,6 +168,9 @@ impl Avatar {
         Self {
             text: text.into(),
             size: AvatarSize::Medium,
+            image: None,
+            shape: AvatarShape::Circle,
+            status: None,
         }
     }
 
@@ -96,6 +180,26 @@ impl Avatar {
         self
     }
 
+    /// Show the image at `uri`, loaded with the context's image loaders
+    ///
+    /// The initials are shown while it loads or if it fails to.
+    pub fn image(mut self, uri: impl Into<String>) -> Self {
+        self.image = Some(uri.into());
+        self
+    }
+
+    /// Set the shape (default: circle)
+    pub fn shape(mut self, shape: AvatarShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Show a status dot
+    pub fn status(mut self, status: AvatarStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
     /// Extract initials from the text
     ///
     /// Takes the first letter of each word, up to 2 letters.
@@ -127,6 +231,86 @@ impl Avatar {
     }
 }
 
+impl Avatar {
+    fn corner_radius(&self, theme: &ShadcnTheme) -> CornerRadius {
+        match self.shape {
+            AvatarShape::Circle => theme.radii.avatar(),
+            AvatarShape::Rounded => theme.radii.uniform_md(),
+        }
+    }
+
+    fn paint(&self, ui: &Ui, rect: Rect, theme: &ShadcnTheme) {
+        let corner_radius = self.corner_radius(theme);
+
+        let texture = self
+            .image
+            .as_deref()
+            .and_then(|uri| load_texture(ui.ctx(), uri, rect.size()));
+        if let Some(texture) = texture {
+            egui::Image::from_texture(texture)
+                .uv(cover_uv(texture.size))
+                .corner_radius(corner_radius)
+                .paint_at(ui, rect);
+        } else {
+            ui.painter().rect_filled(rect, corner_radius, avatar_color(&self.text));
+
+            // Calculate font size based on avatar size
+            let font_size = match self.size {
+                AvatarSize::Small => theme.typography.small().size,
+                AvatarSize::Medium => theme.typography.body().size,
+                AvatarSize::Large => theme.typography.large().size,
+                AvatarSize::ExtraLarge => theme.typography.h4().size,
+            };
+            let font_id = egui::FontId::new(font_size, egui::FontFamily::Proportional);
+            // Every fallback color has at least 4.5:1 contrast with white
+            let galley = ui.painter().layout_no_wrap(self.get_initials(), font_id, Color32::WHITE);
+            let text_pos = rect.center() - galley.size() / 2.0;
+            ui.painter().galley(text_pos, galley, Color32::WHITE);
+        }
+
+        if let Some(status) = self.status {
+            let radius = (rect.width() * 0.125).max(4.0);
+            let center = match self.shape {
+                // On the circle's edge at 45°
+                AvatarShape::Circle => rect.center() + Vec2::splat(rect.width() / 2.0 * std::f32::consts::FRAC_1_SQRT_2),
+                AvatarShape::Rounded => rect.max - Vec2::splat(radius * 0.5),
+            };
+            ui.painter().circle(
+                center,
+                radius,
+                status.color(theme),
+                egui::Stroke::new(2.0, theme.colors.background),
+            );
+        }
+    }
+}
+
+/// The texture for `uri`, or `None` while it is loading or if it failed
+fn load_texture(ctx: &Context, uri: &str, size: Vec2) -> Option<SizedTexture> {
+    let pixels = (size * ctx.pixels_per_point()).round();
+    let size_hint = SizeHint::Size {
+        width: pixels.x as u32,
+        height: pixels.y as u32,
+        maintain_aspect_ratio: true,
+    };
+    match ctx.try_load_texture(uri, TextureOptions::LINEAR, size_hint) {
+        Ok(TexturePoll::Ready { texture }) => Some(texture),
+        Ok(TexturePoll::Pending { .. }) | Err(_) => None,
+    }
+}
+
+/// UV rect cropping a texture of `size` to a centered square
+fn cover_uv(size: Vec2) -> Rect {
+    let aspect = size.x / size.y;
+    if aspect > 1.0 {
+        let inset = (1.0 - 1.0 / aspect) / 2.0;
+        Rect::from_min_max(egui::pos2(inset, 0.0), egui::pos2(1.0 - inset, 1.0))
+    } else {
+        let inset = (1.0 - aspect) / 2.0;
+        Rect::from_min_max(egui::pos2(0.0, inset), egui::pos2(1.0, 1.0 - inset))
+    }
+}
+
 impl Widget for Avatar {
     fn ui(self, ui: &mut Ui) -> Response {
         // Get theme from context or fall back to light mode
@@ -135,48 +319,116 @@ impl Widget for Avatar {
                 .unwrap_or_else(ShadcnTheme::light)
         });
 
-        let size = self.size.pixels();
-        let initials = self.get_initials();
-
-        // Avatar styling: circular with muted background
-        // Use foreground color for initials to ensure sufficient contrast (4.5:1 minimum)
-        let bg_color = theme.colors.muted;
-        let text_color = theme.colors.foreground;
-        let corner_radius = theme.radii.avatar(); // Full circle
-
-        // Calculate font size based on avatar size
-        let font_size = match self.size {
-            AvatarSize::Small => theme.typography.small().size,
-            AvatarSize::Medium => theme.typography.body().size,
-            AvatarSize::Large => theme.typography.large().size,
-            AvatarSize::ExtraLarge => theme.typography.h4().size,
-        };
-
         // Reserve space for the avatar
         let (rect, response) = ui.allocate_exact_size(
-            Vec2::splat(size),
+            Vec2::splat(self.size.pixels()),
             egui::Sense::hover(),
         );
 
         if ui.is_rect_visible(rect) {
-            // Draw background circle
-            ui.painter().rect_filled(
-                rect,
-                corner_radius,
-                bg_color,
-            );
+            self.paint(ui, rect, &theme);
+        }
 
-            // Draw initials centered
-            let font_id = egui::FontId::new(font_size, egui::FontFamily::Proportional);
+        response
+    }
+}
 
-            let galley = ui.painter().layout_no_wrap(
-                initials,
-                font_id,
-                text_color,
-            );
+/// A row of overlapping avatars
+///
+/// Avatars beyond [`max`](Self::max) are summarized by a "+N" chip whose
+/// tooltip lists their names.
+///
+/// ## Example
+/// ```rust,ignore
+/// ui.add(AvatarGroup::new(followers.iter().map(|f| Avatar::new(&f.name).image(&f.picture)))
+///     .max(4)
+///     .size(AvatarSize::Small));
+/// ```
+pub struct AvatarGroup {
+    avatars: Vec<Avatar>,
+    max: Option<usize>,
+    size: AvatarSize,
+    shape: AvatarShape,
+}
 
-            let text_pos = rect.center() - galley.size() / 2.0;
-            ui.painter().galley(text_pos, galley, text_color);
+impl AvatarGroup {
+    /// Create a group of `avatars`
+    pub fn new(avatars: impl IntoIterator<Item = Avatar>) -> Self {
+        Self {
+            avatars: avatars.into_iter().collect(),
+            max: None,
+            size: AvatarSize::Medium,
+            shape: AvatarShape::Circle,
+        }
+    }
+
+    /// Show at most `max` avatars followed by a "+N" chip (default: show all)
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Set the size of every avatar (default: medium)
+    pub fn size(mut self, size: AvatarSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the shape of every avatar (default: circle)
+    pub fn shape(mut self, shape: AvatarShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Number of avatars shown and hidden behind the chip
+    fn split(&self) -> (usize, usize) {
+        let shown = self.max.map_or(self.avatars.len(), |max| max.min(self.avatars.len()));
+        (shown, self.avatars.len() - shown)
+    }
+}
+
+impl Widget for AvatarGroup {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let theme = ui.ctx().data(|d| {
+            d.get_temp::<ShadcnTheme>(egui::Id::new("shadcn_theme"))
+                .unwrap_or_else(ShadcnTheme::light)
+        });
+
+        let size = self.size.pixels();
+        let step = size * 0.75;
+        let (shown, hidden) = self.split();
+        let slots = shown + usize::from(hidden > 0);
+        let width = if slots == 0 { 0.0 } else { size + step * (slots - 1) as f32 };
+        let (rect, response) = ui.allocate_exact_size(Vec2::new(width, size), egui::Sense::hover());
+        let slot = |index: usize| Rect::from_min_size(rect.min + Vec2::new(step * index as f32, 0.0), Vec2::splat(size));
+
+        if ui.is_rect_visible(rect) {
+            // Later avatars overlap earlier ones; a ring separates them
+            for (index, avatar) in self.avatars.iter().take(shown).enumerate() {
+                let avatar = avatar.clone().size(self.size).shape(self.shape);
+                let slot = slot(index);
+                ui.painter().rect_filled(slot.expand(2.0), avatar.corner_radius(&theme), theme.colors.background);
+                avatar.paint(ui, slot, &theme);
+            }
+            if hidden > 0 {
+                let chip = slot(shown);
+                let corner_radius = Avatar::new("").shape(self.shape).corner_radius(&theme);
+                ui.painter().rect_filled(chip.expand(2.0), corner_radius, theme.colors.background);
+                ui.painter().rect_filled(chip, corner_radius, theme.colors.muted);
+                ui.painter().text(
+                    chip.center(),
+                    egui::Align2::CENTER_CENTER,
+                    format!("+{hidden}"),
+                    egui::FontId::proportional(theme.typography.small().size),
+                    theme.colors.foreground,
+                );
+            }
+        }
+
+        if hidden > 0 {
+            let names: Vec<&str> = self.avatars[shown..].iter().map(|a| a.text.as_str()).collect();
+            ui.interact(slot(shown), response.id.with("overflow"), egui::Sense::hover())
+                .on_hover_text(names.join("\n"));
         }
 
         response
@@ -226,6 +478,76 @@ mod tests {
         assert_eq!(avatar.get_initials(), "?");
     }
 
+    #[test]
+    fn test_avatar_color_is_deterministic() {
+        assert_eq!(avatar_color("Alice"), avatar_color("Alice"));
+        assert!(FALLBACK_COLORS.contains(&avatar_color("")));
+        // Not every name maps to the same color
+        let colors: std::collections::HashSet<_> = ["Alice", "Bob", "Carol", "Dave", "Eve", "Frank"]
+            .iter()
+            .map(|name| avatar_color(name))
+            .collect();
+        assert!(colors.len() > 1);
+    }
+
+    #[test]
+    fn test_group_overflow() {
+        let group = AvatarGroup::new(["A", "B", "C", "D", "E"].map(Avatar::new)).max(3);
+        assert_eq!(group.split(), (3, 2));
+        let group = AvatarGroup::new(["A", "B"].map(Avatar::new)).max(3);
+        assert_eq!(group.split(), (2, 0));
+        let group = AvatarGroup::new(["A", "B"].map(Avatar::new));
+        assert_eq!(group.split(), (2, 0));
+    }
+
+    #[test]
+    fn test_cover_uv_crops_to_square() {
+        let uv = cover_uv(Vec2::new(200.0, 100.0));
+        assert_eq!(uv, Rect::from_min_max(egui::pos2(0.25, 0.0), egui::pos2(0.75, 1.0)));
+        let uv = cover_uv(Vec2::new(100.0, 100.0));
+        assert_eq!(uv, Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0)));
+    }
+
+    struct TestLoader;
+
+    impl egui::load::ImageLoader for TestLoader {
+        fn id(&self) -> &str {
+            "egui_shadcn::avatar::TestLoader"
+        }
+
+        fn load(&self, _ctx: &Context, uri: &str, _size_hint: SizeHint) -> egui::load::ImageLoadResult {
+            match uri {
+                "test://ready" => Ok(egui::load::ImagePoll::Ready {
+                    image: std::sync::Arc::new(egui::ColorImage::filled([4, 4], Color32::RED)),
+                }),
+                "test://pending" => Ok(egui::load::ImagePoll::Pending { size: None }),
+                _ => Err(egui::load::LoadError::NotSupported),
+            }
+        }
+
+        fn forget(&self, _uri: &str) {}
+
+        fn forget_all(&self) {}
+
+        fn byte_size(&self) -> usize {
+            0
+        }
+    }
+
+    #[test]
+    fn test_image_falls_back_until_loaded() {
+        let ctx = Context::default();
+        let size = Vec2::splat(40.0);
+        // No image loaders installed
+        assert!(load_texture(&ctx, "test://ready", size).is_none());
+
+        ctx.add_image_loader(std::sync::Arc::new(TestLoader));
+        let _ = ctx.run(Default::default(), |_| {});
+        assert!(load_texture(&ctx, "test://pending", size).is_none());
+        assert!(load_texture(&ctx, "test://missing", size).is_none());
+        assert_eq!(load_texture(&ctx, "test://ready", size).map(|t| t.size), Some(Vec2::splat(4.0)));
+    }
+
     #[test]
     fn test_initials_case() {
         let avatar = Avatar::new("john doe");
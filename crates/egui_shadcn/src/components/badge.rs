@@ -18,6 +18,11 @@
 //! ui.add(Badge::new("New").variant(BadgeVariant::Default));
 //! ui.add(Badge::new("3").variant(BadgeVariant::Secondary));
 //! ui.add(Badge::new("Error").variant(BadgeVariant::Destructive));
+//!
+//! // Removable chip
+//! if Badge::new("#nostr").removable(true).show(ui).removed {
+//!     // remove the tag
+//! }
 //! ```
 
 use egui::{Response, RichText, Ui, Widget};
@@ -67,6 +72,15 @@ pub enum BadgeVariant {
 pub struct Badge {
     text: String,
     variant: BadgeVariant,
+    removable: bool,
+}
+
+/// Response from [`Badge::show`]
+pub struct BadgeResponse {
+    /// Response of the whole badge
+    pub response: Response,
+    /// Whether the remove button was clicked
+    pub removed: bool,
 }
 
 impl Badge {
@@ -77,6 +91,7 @@ impl Badge {
         Self {
             text: text.into(),
             variant: BadgeVariant::Default,
+            removable: false,
         }
     }
 
@@ -85,10 +100,15 @@ impl Badge {
         self.variant = variant;
         self
     }
-}
 
-impl Widget for Badge {
-    fn ui(self, ui: &mut Ui) -> Response {
+    /// Show a remove button after the text (for chips in tag pickers)
+    pub fn removable(mut self, removable: bool) -> Self {
+        self.removable = removable;
+        self
+    }
+
+    /// Show the badge and report clicks on the remove button
+    pub fn show(self, ui: &mut Ui) -> BadgeResponse {
         // Get theme from context or fall back to light mode
         let theme = ui.ctx().data(|d| {
             d.get_temp::<ShadcnTheme>(egui::Id::new("shadcn_theme"))
@@ -136,15 +156,45 @@ impl Widget for Badge {
             .corner_radius(corner_radius);
 
         // Render the badge
-        frame.show(ui, |ui| {
-            // Use small text size for compact display
-            let text = RichText::new(&self.text)
-                .size(theme.typography.small().size)
-                .color(text_color);
-
-            ui.label(text);
+        let mut removed = false;
+        let response = frame.show(ui, |ui| {
+            ui.spacing_mut().item_spacing.x = 4.0;
+            ui.horizontal(|ui| {
+                // Use small text size for compact display
+                let text = RichText::new(&self.text)
+                    .size(theme.typography.small().size)
+                    .color(text_color);
+
+                ui.label(text);
+
+                if self.removable {
+                    let size = theme.typography.small().size * 0.75;
+                    let (rect, remove) = ui.allocate_exact_size(egui::Vec2::splat(size), egui::Sense::click());
+                    let color = if remove.hovered() {
+                        text_color
+                    } else {
+                        text_color.linear_multiply(0.7)
+                    };
+                    let stroke = egui::Stroke::new(1.5, color);
+                    let r = rect.shrink(1.5);
+                    ui.painter().line_segment([r.left_top(), r.right_bottom()], stroke);
+                    ui.painter().line_segment([r.right_top(), r.left_bottom()], stroke);
+                    if remove.hovered() {
+                        ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
+                    }
+                    removed = remove.clicked();
+                }
+            });
         })
-        .response
+        .response;
+
+        BadgeResponse { response, removed }
+    }
+}
+
+impl Widget for Badge {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
     }
 }
 
@@ -157,6 +207,8 @@ mod tests {
         let badge = Badge::new("Test");
         assert_eq!(badge.text, "Test");
         assert_eq!(badge.variant, BadgeVariant::Default);
+        assert!(!badge.removable);
+        assert!(Badge::new("Tag").removable(true).removable);
     }
 
     #[test]
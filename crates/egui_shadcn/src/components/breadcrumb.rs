@@ -2,9 +2,17 @@
 //!
 //! A navigation component showing the current page's location in a hierarchy.
 //!
+//! Deep paths collapse to fit the available width (or [`Breadcrumb::max_items`]):
+//! the first and last items stay visible and the middle ones move into an
+//! ellipsis menu. Long labels are truncated and show the full text on hover.
+//!
 //! Reference: <https://ui.shadcn.com/docs/components/breadcrumb>
 
-use egui::{Response, Ui, Sense, Vec2, Pos2};
+use std::sync::Arc;
+use egui::{Galley, Id, Response, Ui, Sense, Vec2, Pos2};
+use egui::text::{LayoutJob, TextWrapping};
+use crate::components::menu::{show_menu, MenuItem, MenuLayout, MenuModel};
+use crate::overlay::OverlayStack;
 use crate::theme::ShadcnTheme;
 
 /// Breadcrumb separator style
@@ -20,31 +28,67 @@ pub enum BreadcrumbSeparator {
 ///
 /// ## Example
 /// ```rust,ignore
-/// Breadcrumb::new()
-///     .item("Home", || { /* navigate home */ })
-///     .item("Products", || { /* navigate to products */ })
-///     .item("Electronics", || { /* navigate to electronics */ })
+/// let response = Breadcrumb::new()
+///     .item("Home")
+///     .item("Products")
+///     .item("Electronics")
 ///     .current("Laptop")
+///     .max_items(3)
 ///     .show(ui);
+/// if let Some(index) = response.clicked {
+///     navigate_to(index);
+/// }
 /// ```
-pub struct Breadcrumb<'a> {
-    items: Vec<BreadcrumbItem<'a>>,
+pub struct Breadcrumb {
+    items: Vec<BreadcrumbItem>,
     current: Option<String>,
     separator: BreadcrumbSeparator,
+    max_items: Option<usize>,
+    items_before_collapse: usize,
+    items_after_collapse: usize,
+    max_label_width: f32,
+    id_salt: Option<Id>,
 }
 
-struct BreadcrumbItem<'a> {
+struct BreadcrumbItem {
     label: String,
-    on_click: Option<Box<dyn FnOnce() + 'a>>,
+    clickable: bool,
+}
+
+/// Result of showing a breadcrumb
+pub struct BreadcrumbResponse {
+    /// The response of the whole breadcrumb
+    pub response: Response,
+    /// Index of the item clicked this frame, inline or in the ellipsis menu
+    ///
+    /// Indices count the items in the order they were added; the current
+    /// page is not clickable.
+    pub clicked: Option<usize>,
+}
+
+/// Which entries to show: all of them, or the first `before`, an ellipsis
+/// and the last `after`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Collapse {
+    None,
+    Middle { before: usize, after: usize },
 }
 
-impl<'a> Breadcrumb<'a> {
+/// Width of the ellipsis trigger
+const ELLIPSIS_WIDTH: f32 = 24.0;
+
+impl Breadcrumb {
     /// Create a new breadcrumb
     pub fn new() -> Self {
         Self {
             items: Vec::new(),
             current: None,
             separator: BreadcrumbSeparator::Chevron,
+            max_items: None,
+            items_before_collapse: 1,
+            items_after_collapse: 2,
+            max_label_width: 160.0,
+            id_salt: None,
         }
     }
 
@@ -54,11 +98,13 @@ impl<'a> Breadcrumb<'a> {
         self
     }
 
-    /// Add a breadcrumb item with click handler
-    pub fn item(mut self, label: impl Into<String>, on_click: impl FnOnce() + 'a) -> Self {
+    /// Add a clickable breadcrumb item
+    ///
+    /// Clicks are reported by index in [`BreadcrumbResponse::clicked`].
+    pub fn item(mut self, label: impl Into<String>) -> Self {
         self.items.push(BreadcrumbItem {
             label: label.into(),
-            on_click: Some(Box::new(on_click)),
+            clickable: true,
         });
         self
     }
@@ -67,7 +113,7 @@ impl<'a> Breadcrumb<'a> {
     pub fn item_text(mut self, label: impl Into<String>) -> Self {
         self.items.push(BreadcrumbItem {
             label: label.into(),
-            on_click: None,
+            clickable: false,
         });
         self
     }
@@ -78,111 +124,252 @@ impl<'a> Breadcrumb<'a> {
         self
     }
 
+    /// Collapse when there are more than `max_items` entries, even if they fit
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Entries kept before the ellipsis when collapsed (default: 1)
+    pub fn items_before_collapse(mut self, count: usize) -> Self {
+        self.items_before_collapse = count;
+        self
+    }
+
+    /// Entries kept after the ellipsis when collapsed, including the current
+    /// page (default: 2)
+    pub fn items_after_collapse(mut self, count: usize) -> Self {
+        self.items_after_collapse = count.max(1);
+        self
+    }
+
+    /// Truncate labels wider than `width` (default: 160px)
+    pub fn max_label_width(mut self, width: f32) -> Self {
+        self.max_label_width = width;
+        self
+    }
+
+    /// Set an id salt for the ellipsis menu, needed to tell several
+    /// breadcrumbs in the same `Ui` apart
+    pub fn id_salt(mut self, salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(salt));
+        self
+    }
+
+    /// Pick the widest layout that fits in `available`
+    ///
+    /// Entries are hidden from the middle first, then from the end, keeping
+    /// the last entry. When nothing fits the narrowest layout is used.
+    fn collapse(&self, widths: &[f32], gap: f32, available: f32) -> Collapse {
+        let count = widths.len();
+        let width = |collapse: Collapse| {
+            let (shown, slots) = match collapse {
+                Collapse::None => (widths.iter().sum::<f32>(), count),
+                Collapse::Middle { before, after } => (
+                    widths[..before].iter().sum::<f32>() + ELLIPSIS_WIDTH + widths[count - after..].iter().sum::<f32>(),
+                    before + after + 1,
+                ),
+            };
+            shown + gap * slots.saturating_sub(1) as f32
+        };
+
+        let too_many = self.max_items.is_some_and(|max| count > max);
+        if !too_many && width(Collapse::None) <= available {
+            return Collapse::None;
+        }
+
+        let before = self.items_before_collapse;
+        let after = self.items_after_collapse;
+        let candidates: Vec<Collapse> = [(before, after), (before, 1), (0, 1)]
+            .into_iter()
+            .filter(|&(before, after)| before + after < count)
+            .map(|(before, after)| Collapse::Middle { before, after })
+            .collect();
+        candidates
+            .iter()
+            .copied()
+            .find(|&collapse| width(collapse) <= available)
+            .or(candidates.last().copied())
+            .unwrap_or(Collapse::None)
+    }
+
     /// Show the breadcrumb
-    pub fn show(self, ui: &mut Ui) -> Response {
+    pub fn show(self, ui: &mut Ui) -> BreadcrumbResponse {
         let theme = ui.ctx().data(|d| {
             d.get_temp::<ShadcnTheme>(egui::Id::new("shadcn_theme"))
                 .unwrap_or_else(ShadcnTheme::light)
         });
 
+        let font_id = egui::FontId::proportional(theme.typography.small().size);
+        let layout = |text: &str, color| {
+            let mut job = LayoutJob::simple_singleline(text.to_owned(), font_id.clone(), color);
+            job.wrap = TextWrapping::truncate_at_width(self.max_label_width);
+            ui.painter().layout_job(job)
+        };
+
+        // Items, then the current page
+        let entries: Vec<Arc<Galley>> = self.items.iter()
+            .map(|item| layout(&item.label, theme.colors.muted_foreground))
+            .chain(self.current.iter().map(|current| layout(current, theme.colors.foreground)))
+            .collect();
+        let separator_width = match self.separator {
+            BreadcrumbSeparator::Chevron => 12.0,
+            BreadcrumbSeparator::Slash => ui.painter()
+                .layout_no_wrap("/".to_owned(), font_id.clone(), theme.colors.muted_foreground)
+                .size().x,
+        };
+        let gap = separator_width + 2.0 * theme.spacing.sm;
+        let widths: Vec<f32> = entries.iter().map(|galley| galley.size().x).collect();
+        let collapse = self.collapse(&widths, gap, ui.available_width());
+
+        let mut clicked = None;
         let response = ui.horizontal(|ui| {
             ui.spacing_mut().item_spacing.x = theme.spacing.sm;
+            let base_id = self.id_salt.unwrap_or_else(|| ui.id().with("breadcrumb"));
 
-            let item_count = self.items.len();
-
-            for (idx, item) in self.items.into_iter().enumerate() {
-                // Render item
-                if item.on_click.is_some() {
-                    // Clickable link
-                    let link_response = ui.add(
-                        egui::Label::new(
-                            egui::RichText::new(&item.label)
-                                .size(theme.typography.small().size)
-                                .color(theme.colors.muted_foreground)
-                        ).sense(Sense::click())
-                    );
+            let (before, after) = match collapse {
+                Collapse::None => (entries.len(), 0),
+                Collapse::Middle { before, after } => (before, after),
+            };
+            let hidden = before..entries.len() - after;
+
+            for (idx, galley) in entries.iter().enumerate() {
+                if idx > 0 && !(hidden.contains(&idx) && idx != hidden.start) {
+                    self.paint_separator(ui, &theme);
+                }
+                if hidden.contains(&idx) {
+                    if idx == hidden.start {
+                        clicked = clicked.or(self.ellipsis(ui, &theme, base_id.with("ellipsis"), hidden.clone()));
+                    }
+                    continue;
+                }
 
-                    if link_response.hovered() {
-                        ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
+                let clickable = self.items.get(idx).is_some_and(|item| item.clickable);
+                let rect = ui.allocate_space(galley.size()).1;
+                let sense = if clickable { Sense::click() } else { Sense::hover() };
+                let mut link_response = ui.interact(rect, base_id.with(("item", idx)), sense);
+
+                if ui.is_rect_visible(rect) {
+                    ui.painter().galley(rect.min, galley.clone(), theme.colors.muted_foreground);
+                    if clickable && link_response.hovered() {
                         // Draw underline on hover
-                        let rect = link_response.rect;
                         ui.painter().line_segment(
-                            [
-                                egui::pos2(rect.min.x, rect.max.y),
-                                egui::pos2(rect.max.x, rect.max.y),
-                            ],
+                            [rect.left_bottom(), rect.right_bottom()],
                             egui::Stroke::new(1.0, theme.colors.muted_foreground),
                         );
                     }
-
-                    if link_response.clicked() {
-                        if let Some(on_click) = item.on_click {
-                            on_click();
-                        }
-                    }
-                } else {
-                    // Non-clickable text
-                    ui.label(
-                        egui::RichText::new(&item.label)
-                            .size(theme.typography.small().size)
-                            .color(theme.colors.muted_foreground)
-                    );
                 }
 
-                // Separator (if not last item or if there's a current page)
-                let has_more = idx < item_count - 1 || self.current.is_some();
-                if has_more {
-                    match self.separator {
-                        BreadcrumbSeparator::Chevron => {
-                            // Draw a proper chevron using line segments
-                            let sep_size = Vec2::new(12.0, 16.0);
-                            let (sep_rect, _) = ui.allocate_exact_size(sep_size, Sense::hover());
-
-                            if ui.is_rect_visible(sep_rect) {
-                                let chevron_x = sep_rect.center().x;
-                                let chevron_y = sep_rect.center().y;
-                                let chevron_size = 3.5;
-                                let stroke = egui::Stroke::new(1.5, theme.colors.muted_foreground);
-
-                                // Right chevron (>)
-                                ui.painter().line_segment(
-                                    [Pos2::new(chevron_x - chevron_size * 0.5, chevron_y - chevron_size),
-                                     Pos2::new(chevron_x + chevron_size * 0.5, chevron_y)],
-                                    stroke,
-                                );
-                                ui.painter().line_segment(
-                                    [Pos2::new(chevron_x + chevron_size * 0.5, chevron_y),
-                                     Pos2::new(chevron_x - chevron_size * 0.5, chevron_y + chevron_size)],
-                                    stroke,
-                                );
-                            }
-                        }
-                        BreadcrumbSeparator::Slash => {
-                            ui.label(
-                                egui::RichText::new("/")
-                                    .size(theme.typography.small().size)
-                                    .color(theme.colors.muted_foreground)
-                            );
-                        }
-                    }
+                if clickable {
+                    link_response = link_response.on_hover_cursor(egui::CursorIcon::PointingHand);
+                }
+                if galley.elided {
+                    let label = self.items.get(idx).map_or(self.current.as_deref(), |item| Some(&item.label));
+                    link_response = link_response.on_hover_text(label.unwrap_or_default());
+                }
+                if link_response.clicked() {
+                    clicked = Some(idx);
                 }
             }
+        });
+
+        BreadcrumbResponse {
+            response: response.response,
+            clicked,
+        }
+    }
+
+    fn paint_separator(&self, ui: &mut Ui, theme: &ShadcnTheme) {
+        match self.separator {
+            BreadcrumbSeparator::Chevron => {
+                // Draw a proper chevron using line segments
+                let sep_size = Vec2::new(12.0, 16.0);
+                let (sep_rect, _) = ui.allocate_exact_size(sep_size, Sense::hover());
 
-            // Current page (shown at the end)
-            if let Some(current) = self.current {
+                if ui.is_rect_visible(sep_rect) {
+                    let chevron_x = sep_rect.center().x;
+                    let chevron_y = sep_rect.center().y;
+                    let chevron_size = 3.5;
+                    let stroke = egui::Stroke::new(1.5, theme.colors.muted_foreground);
+
+                    // Right chevron (>)
+                    ui.painter().line_segment(
+                        [Pos2::new(chevron_x - chevron_size * 0.5, chevron_y - chevron_size),
+                         Pos2::new(chevron_x + chevron_size * 0.5, chevron_y)],
+                        stroke,
+                    );
+                    ui.painter().line_segment(
+                        [Pos2::new(chevron_x + chevron_size * 0.5, chevron_y),
+                         Pos2::new(chevron_x - chevron_size * 0.5, chevron_y + chevron_size)],
+                        stroke,
+                    );
+                }
+            }
+            BreadcrumbSeparator::Slash => {
                 ui.label(
-                    egui::RichText::new(current)
+                    egui::RichText::new("/")
                         .size(theme.typography.small().size)
-                        .color(theme.colors.foreground)
+                        .color(theme.colors.muted_foreground)
                 );
             }
-        });
+        }
+    }
+
+    /// The "…" trigger and its menu of the `hidden` items
+    fn ellipsis(&self, ui: &mut Ui, theme: &ShadcnTheme, id: Id, hidden: std::ops::Range<usize>) -> Option<usize> {
+        let popup_id = id.with("popup");
+        let open_id = id.with("open");
+        let mut open = ui.ctx().data(|d| d.get_temp::<bool>(open_id).unwrap_or(false));
+        let (rect, _) = ui.allocate_exact_size(Vec2::new(ELLIPSIS_WIDTH, 20.0), Sense::hover());
+        let trigger = ui.interact(rect, id, Sense::click())
+            .on_hover_cursor(egui::CursorIcon::PointingHand);
+        if trigger.clicked() {
+            open = !open;
+        }
+
+        if ui.is_rect_visible(rect) {
+            let color = if trigger.hovered() {
+                ui.painter().rect_filled(rect, theme.radii.sm, theme.colors.accent);
+                theme.colors.accent_foreground
+            } else {
+                theme.colors.muted_foreground
+            };
+            for i in -1..=1 {
+                ui.painter().circle_filled(rect.center() + egui::vec2(i as f32 * 5.0, 0.0), 1.5, color);
+            }
+        }
+
+        let mut clicked = None;
+        if open {
+            let mut menu = MenuModel::new();
+            for idx in hidden {
+                menu = match self.items.get(idx) {
+                    Some(item) if item.clickable => menu.item(MenuItem::new(idx, item.label.clone())),
+                    Some(item) => menu.label(item.label.clone()),
+                    None => menu.label(self.current.clone().unwrap_or_default()),
+                };
+            }
+            let layout = MenuLayout { width: 180.0, row_height: 32.0 };
+            let output = show_menu(
+                ui.ctx(), theme, popup_id, &mut menu,
+                rect.left_bottom() + egui::vec2(0.0, 4.0),
+                rect, layout,
+            );
+            if output.action.is_some() || output.dismissed {
+                open = false;
+            }
+            clicked = output.action;
+        }
 
-        response.response
+        ui.ctx().data_mut(|d| d.insert_temp(open_id, open));
+        if !open {
+            OverlayStack::remove(ui.ctx(), popup_id);
+        }
+        clicked
     }
 }
 
-impl Default for Breadcrumb<'_> {
+impl Default for Breadcrumb {
     fn default() -> Self {
         Self::new()
     }
@@ -191,6 +378,8 @@ impl Default for Breadcrumb<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::{center, click, context_with_motion, run_frame};
+    use crate::theme::MotionPreference;
 
     #[test]
     fn test_breadcrumb_creation() {
@@ -201,4 +390,71 @@ mod tests {
         assert_eq!(breadcrumb.separator, BreadcrumbSeparator::Slash);
         assert_eq!(breadcrumb.current, Some("Current Page".to_string()));
     }
+
+    #[test]
+    fn test_collapse() {
+        let breadcrumb = Breadcrumb::new();
+        let widths = [50.0; 5];
+        // 5 * 50 + 4 gaps of 10
+        assert_eq!(breadcrumb.collapse(&widths, 10.0, 290.0), Collapse::None);
+        // First, ellipsis, last two: 150 + 24 + 3 gaps
+        assert_eq!(breadcrumb.collapse(&widths, 10.0, 289.0), Collapse::Middle { before: 1, after: 2 });
+        assert_eq!(breadcrumb.collapse(&widths, 10.0, 150.0), Collapse::Middle { before: 1, after: 1 });
+        assert_eq!(breadcrumb.collapse(&widths, 10.0, 90.0), Collapse::Middle { before: 0, after: 1 });
+        // Nothing fits: the narrowest layout
+        assert_eq!(breadcrumb.collapse(&widths, 10.0, 10.0), Collapse::Middle { before: 0, after: 1 });
+        // Too few entries to hide any
+        assert_eq!(breadcrumb.collapse(&widths[..1], 10.0, 10.0), Collapse::None);
+
+        let breadcrumb = Breadcrumb::new().max_items(4).items_before_collapse(2);
+        assert_eq!(breadcrumb.collapse(&widths, 10.0, 1000.0), Collapse::Middle { before: 2, after: 2 });
+        assert_eq!(breadcrumb.collapse(&widths[..4], 10.0, 1000.0), Collapse::None);
+    }
+
+    #[test]
+    fn test_clicks_report_index() {
+        let ctx = context_with_motion(MotionPreference::Full);
+        let clicked = std::cell::Cell::new(None);
+        let mut show = |ui: &mut Ui| {
+            let response = Breadcrumb::new()
+                .id_salt("path")
+                .item("Relays")
+                .item("nostr.wine")
+                .item("Bitcoin")
+                .item_text("Thread")
+                .current("Reply")
+                .max_items(3)
+                .show(ui);
+            clicked.set(clicked.get().or(response.clicked));
+        };
+        run_frame(&ctx, 0.0, &mut show);
+        let id = Id::new("path");
+
+        // "Relays", "…", "Thread", "Reply"
+        assert!(ctx.read_response(id.with(("item", 1))).is_none());
+        click(&ctx, 0.1, center(&ctx, id.with(("item", 0))), &mut show);
+        assert_eq!(clicked.take(), Some(0));
+
+        // The ellipsis menu lists "nostr.wine" and "Bitcoin"
+        let ellipsis = id.with("ellipsis");
+        let is_open = || ctx.data(|d| d.get_temp::<bool>(ellipsis.with("open"))) == Some(true);
+        click(&ctx, 0.2, center(&ctx, ellipsis), &mut show);
+        assert!(is_open());
+        run_frame(&ctx, 1.0, &mut show);
+        click(&ctx, 1.1, center(&ctx, ellipsis.with("popup").with("panel").with(("row", 1))), &mut show);
+        assert_eq!(clicked.get(), Some(2));
+        assert!(!is_open());
+    }
+
+    #[test]
+    fn test_long_labels_truncate() {
+        let ctx = context_with_motion(MotionPreference::Full);
+        let label = "A very long community name that goes on and on";
+        let mut show = |ui: &mut Ui| {
+            Breadcrumb::new().id_salt("long").item(label).current("Post").max_label_width(80.0).show(ui);
+        };
+        run_frame(&ctx, 0.0, &mut show);
+        let rect = ctx.read_response(Id::new("long").with(("item", 0))).unwrap().rect;
+        assert!(rect.width() <= 80.0);
+    }
 }
@@ -4,8 +4,10 @@
 //!
 //! Reference: <https://ui.shadcn.com/docs/components/calendar>
 
-use egui::{Id, Response, Ui, Sense, Vec2, Pos2};
-use chrono::Datelike;
+use std::collections::BTreeSet;
+use egui::{Color32, EventFilter, Id, Key, Modifiers, Response, Ui, Sense, Vec2, Pos2};
+use chrono::{Datelike, NaiveDate, Weekday};
+use crate::overlay::{OverlayKind, OverlayStack};
 use crate::theme::ShadcnTheme;
 
 /// Calendar selection mode
@@ -16,6 +18,8 @@ pub enum CalendarMode {
     Single,
     /// Date range selection
     Range,
+    /// Any number of individual dates (clicking toggles a date)
+    Multiple,
 }
 
 /// A selected date or range
@@ -27,16 +31,278 @@ pub enum CalendarSelection {
     Single(chrono::NaiveDate),
     /// Date range selected (start, end)
     Range(chrono::NaiveDate, chrono::NaiveDate),
+    /// Individual dates selected
+    Multiple(BTreeSet<chrono::NaiveDate>),
 }
 
+impl CalendarSelection {
+    /// Whether `date` is selected (inside the range, for ranges)
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        match self {
+            CalendarSelection::None => false,
+            CalendarSelection::Single(d) => *d == date,
+            CalendarSelection::Range(start, end) => date >= *start && date <= *end,
+            CalendarSelection::Multiple(dates) => dates.contains(&date),
+        }
+    }
+
+    /// Apply a click on `date` in `mode`
+    fn click(&mut self, date: NaiveDate, mode: CalendarMode) {
+        match mode {
+            CalendarMode::Single => *self = CalendarSelection::Single(date),
+            CalendarMode::Range => match self {
+                CalendarSelection::Single(start) => {
+                    let start = *start;
+                    *self = CalendarSelection::Range(start.min(date), start.max(date));
+                }
+                _ => *self = CalendarSelection::Single(date),
+            },
+            CalendarMode::Multiple => {
+                let mut dates = match std::mem::replace(self, CalendarSelection::None) {
+                    CalendarSelection::Multiple(dates) => dates,
+                    CalendarSelection::Single(d) => BTreeSet::from([d]),
+                    _ => BTreeSet::new(),
+                };
+                if !dates.remove(&date) {
+                    dates.insert(date);
+                }
+                *self = CalendarSelection::Multiple(dates);
+            }
+        }
+    }
+}
+
+/// A marker drawn on a day, e.g. to show days with activity
+#[derive(Debug, Clone, PartialEq)]
+pub enum DayDecoration {
+    /// A small dot under the day number in the primary color
+    Dot,
+    /// Colored dots under the day number (at most three are drawn)
+    Dots(Vec<Color32>),
+    /// A small badge in the top-right corner (e.g. an event count)
+    Badge(String),
+}
+
+/// Predicate for dates that can't be selected
+type DatePredicate<'a> = Box<dyn Fn(NaiveDate) -> bool + 'a>;
+
+/// Decoration lookup per date
+type DecorationFn<'a> = Box<dyn Fn(NaiveDate) -> Option<DayDecoration> + 'a>;
+
 impl Default for CalendarSelection {
     fn default() -> Self {
         Self::None
     }
 }
 
+/// Language and regional conventions for calendars and date pickers
+///
+/// [`EnglishLocale`] is built in; implement this to supply other languages.
+///
+/// ## Example
+/// ```rust,ignore
+/// struct German;
+///
+/// impl CalendarLocale for German {
+///     fn first_weekday(&self) -> Weekday { Weekday::Mon }
+///     fn month_name(&self, month: u32) -> &str { ["Januar", /* ... */][month as usize - 1] }
+///     fn month_short(&self, month: u32) -> &str { ["Jan", /* ... */][month as usize - 1] }
+///     fn weekday_short(&self, day: Weekday) -> &str { ["Mo", /* ... */][day.num_days_from_monday() as usize] }
+///     fn weekday_name(&self, day: Weekday) -> &str { ["Montag", /* ... */][day.num_days_from_monday() as usize] }
+///     fn date_format(&self) -> &str { "%d. %B %Y" }
+/// }
+/// ```
+pub trait CalendarLocale {
+    /// Day the week starts on (first column of the grid)
+    fn first_weekday(&self) -> Weekday;
+
+    /// Full month name; `month` is 1-12
+    fn month_name(&self, month: u32) -> &str;
+
+    /// Abbreviated month name; `month` is 1-12
+    fn month_short(&self, month: u32) -> &str;
+
+    /// Two-letter weekday name for the grid header
+    fn weekday_short(&self, day: Weekday) -> &str;
+
+    /// Full weekday name
+    fn weekday_name(&self, day: Weekday) -> &str;
+
+    /// Default chrono format for a date (e.g. `"%B %d, %Y"`)
+    fn date_format(&self) -> &str;
+
+    /// Format `date` with a chrono `pattern`
+    ///
+    /// `%B`, `%b`, `%A` and `%a` use this locale's names (chrono only knows
+    /// English ones); everything else is passed to chrono.
+    fn format_date(&self, date: NaiveDate, pattern: &str) -> String {
+        date.format(&localize_pattern(self, date, pattern)).to_string()
+    }
+
+    /// Format a date and time with a chrono `pattern` (see [`CalendarLocale::format_date`])
+    fn format_datetime(&self, datetime: chrono::NaiveDateTime, pattern: &str) -> String {
+        datetime.format(&localize_pattern(self, datetime.date(), pattern)).to_string()
+    }
+}
+
+/// `pattern` with the name specifiers replaced by `locale`'s names for `date`
+fn localize_pattern<L: CalendarLocale + ?Sized>(locale: &L, date: NaiveDate, pattern: &str) -> String {
+    let mut localized = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            localized.push(c);
+            continue;
+        }
+        // Names are escaped so chrono prints them literally
+        let name = match chars.next() {
+            Some('B') => locale.month_name(date.month()),
+            Some('b') => locale.month_short(date.month()),
+            Some('A') => locale.weekday_name(date.weekday()),
+            Some('a') => locale.weekday_short(date.weekday()),
+            Some(other) => {
+                localized.push('%');
+                localized.push(other);
+                continue;
+            }
+            None => {
+                localized.push_str("%%");
+                continue;
+            }
+        };
+        localized.push_str(&name.replace('%', "%%"));
+    }
+    localized
+}
+
+/// English month and day names
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnglishLocale {
+    first_weekday: Weekday,
+    date_format: &'static str,
+}
+
+impl EnglishLocale {
+    /// US English: Sunday-first weeks, "January 05, 2025"
+    pub const US: Self = Self {
+        first_weekday: Weekday::Sun,
+        date_format: "%B %d, %Y",
+    };
+
+    /// British English: Monday-first weeks, "05 January 2025"
+    pub const GB: Self = Self {
+        first_weekday: Weekday::Mon,
+        date_format: "%d %B %Y",
+    };
+
+    /// Use a different first day of the week
+    pub const fn with_first_weekday(mut self, first_weekday: Weekday) -> Self {
+        self.first_weekday = first_weekday;
+        self
+    }
+}
+
+impl Default for EnglishLocale {
+    fn default() -> Self {
+        Self::US
+    }
+}
+
+const MONTHS: [&str; 12] = [
+    "January", "February", "March", "April", "May", "June",
+    "July", "August", "September", "October", "November", "December",
+];
+const WEEKDAYS: [&str; 7] = ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"];
+
+impl CalendarLocale for EnglishLocale {
+    fn first_weekday(&self) -> Weekday {
+        self.first_weekday
+    }
+
+    fn month_name(&self, month: u32) -> &str {
+        MONTHS[month as usize - 1]
+    }
+
+    fn month_short(&self, month: u32) -> &str {
+        &MONTHS[month as usize - 1][..3]
+    }
+
+    fn weekday_short(&self, day: Weekday) -> &str {
+        &WEEKDAYS[day.num_days_from_monday() as usize][..2]
+    }
+
+    fn weekday_name(&self, day: Weekday) -> &str {
+        WEEKDAYS[day.num_days_from_monday() as usize]
+    }
+
+    fn date_format(&self) -> &str {
+        self.date_format
+    }
+}
+
+/// The seven weekdays in column order, starting at `first`
+fn weekday_columns(first: Weekday) -> [Weekday; 7] {
+    let mut days = [first; 7];
+    for i in 1..7 {
+        days[i] = days[i - 1].succ();
+    }
+    days
+}
+
+/// First date shown in the grid for `month_start`'s month
+///
+/// For any other date this is the start of its week.
+fn grid_start(month_start: NaiveDate, first: Weekday) -> NaiveDate {
+    let offset = (month_start.weekday().num_days_from_monday() + 7 - first.num_days_from_monday()) % 7;
+    month_start - chrono::Days::new(offset as u64)
+}
+
+/// ISO week number for a grid row starting at `row_start`
+///
+/// Uses the row's Thursday, whose ISO week covers most of the row whatever
+/// the first weekday is.
+fn row_week_number(row_start: NaiveDate) -> u32 {
+    let to_thursday = (Weekday::Thu.num_days_from_monday() + 7 - row_start.weekday().num_days_from_monday()) % 7;
+    (row_start + chrono::Days::new(to_thursday as u64)).iso_week().week()
+}
+
+/// Where a grid navigation key moves the focused date (WAI-ARIA date grid)
+fn navigate(date: NaiveDate, key: Key, shift: bool, first_weekday: Weekday) -> Option<NaiveDate> {
+    let days = |n: i64| date.checked_add_signed(chrono::Duration::days(n));
+    let months = if shift { 12 } else { 1 };
+    match key {
+        Key::ArrowLeft => days(-1),
+        Key::ArrowRight => days(1),
+        Key::ArrowUp => days(-7),
+        Key::ArrowDown => days(7),
+        Key::PageUp => date.checked_sub_months(chrono::Months::new(months)),
+        Key::PageDown => date.checked_add_months(chrono::Months::new(months)),
+        Key::Home => Some(grid_start(date, first_weekday)),
+        Key::End => grid_start(date, first_weekday).checked_add_days(chrono::Days::new(6)),
+        _ => None,
+    }
+}
+
+/// First month to show so that `focused` is visible in `months` months from `first_month`
+fn view_for(focused: NaiveDate, first_month: NaiveDate, months: usize) -> NaiveDate {
+    let focused_month = focused.with_day(1).unwrap_or(focused);
+    let after_last = first_month + chrono::Months::new(months as u32);
+    if focused_month < first_month {
+        focused_month
+    } else if focused_month >= after_last {
+        focused_month - chrono::Months::new(months as u32 - 1)
+    } else {
+        first_month
+    }
+}
+
 /// Calendar component for date selection
 ///
+/// Keyboard (WAI-ARIA date grid): arrows move by day or week, PageUp/PageDown
+/// by month, Shift+PageUp/PageDown by year, Home/End to the start or end of
+/// the week. Enter or Space selects the focused day. In range mode the
+/// hovered or focused day previews the range before the second pick.
+///
 /// ## Example
 /// ```rust,ignore
 /// let mut selected = CalendarSelection::None;
@@ -44,6 +310,16 @@ impl Default for CalendarSelection {
 ///
 /// Calendar::new("my_calendar", &mut selected, &mut view_date)
 ///     .show_outside_days(true)
+///     .locale(&EnglishLocale::GB)
+///     .show_week_numbers(true)
+///     .show(ui);
+///
+/// // Two months, weekends disabled, dots on days with events
+/// Calendar::new("booking", &mut selected, &mut view_date)
+///     .mode(CalendarMode::Range)
+///     .number_of_months(2)
+///     .disabled(|date| date.weekday().number_from_monday() > 5)
+///     .decorations(|date| events.contains_key(&date).then_some(DayDecoration::Dot))
 ///     .show(ui);
 /// ```
 pub struct Calendar<'a> {
@@ -54,6 +330,17 @@ pub struct Calendar<'a> {
     show_outside_days: bool,
     min_date: Option<chrono::NaiveDate>,
     max_date: Option<chrono::NaiveDate>,
+    locale: &'a dyn CalendarLocale,
+    show_week_numbers: bool,
+    number_of_months: usize,
+    disabled: Option<DatePredicate<'a>>,
+    decorations: Option<DecorationFn<'a>>,
+    /// Keyboard-focused day (while the grid has focus)
+    focused: Option<NaiveDate>,
+    /// Tentative range end while picking a range
+    preview_end: Option<NaiveDate>,
+    /// Day under the pointer this frame
+    hovered: Option<NaiveDate>,
 }
 
 impl<'a> Calendar<'a> {
@@ -71,9 +358,49 @@ impl<'a> Calendar<'a> {
             show_outside_days: true,
             min_date: None,
             max_date: None,
+            locale: &EnglishLocale::US,
+            show_week_numbers: false,
+            number_of_months: 1,
+            disabled: None,
+            decorations: None,
+            focused: None,
+            preview_end: None,
+            hovered: None,
         }
     }
 
+    /// Show this many consecutive months side by side (default 1)
+    ///
+    /// `view_date` is the first month shown.
+    pub fn number_of_months(mut self, months: usize) -> Self {
+        self.number_of_months = months.max(1);
+        self
+    }
+
+    /// Disable dates for which `predicate` returns true (in addition to min/max)
+    pub fn disabled(mut self, predicate: impl Fn(NaiveDate) -> bool + 'a) -> Self {
+        self.disabled = Some(Box::new(predicate));
+        self
+    }
+
+    /// Decorate days, e.g. with dots for days that have activity
+    pub fn decorations(mut self, decorate: impl Fn(NaiveDate) -> Option<DayDecoration> + 'a) -> Self {
+        self.decorations = Some(Box::new(decorate));
+        self
+    }
+
+    /// Set the locale (first weekday, month and day names)
+    pub fn locale(mut self, locale: &'a dyn CalendarLocale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Show an ISO week number column
+    pub fn show_week_numbers(mut self, show: bool) -> Self {
+        self.show_week_numbers = show;
+        self
+    }
+
     /// Set the selection mode
     pub fn mode(mut self, mode: CalendarMode) -> Self {
         self.mode = mode;
@@ -109,29 +436,139 @@ impl<'a> Calendar<'a> {
         let padding = 12.0;
         let today = chrono::Local::now().date_naive();
 
+        let before = self.selection.clone();
+        let months = self.number_of_months;
+        let grid_id = self.id.with("grid");
+        let focus_id = self.id.with("focused_date");
+        let hover_id = self.id.with("hovered_date");
+
+        // Keyboard navigation while the grid has focus
+        let has_focus = ui.memory(|m| m.has_focus(grid_id));
+        if has_focus {
+            ui.memory_mut(|m| {
+                m.set_focus_lock_filter(
+                    grid_id,
+                    EventFilter {
+                        horizontal_arrows: true,
+                        vertical_arrows: true,
+                        ..Default::default()
+                    },
+                )
+            });
+            let first_month = self.view_date.with_day(1).unwrap_or(*self.view_date);
+            let mut focused = ui
+                .data(|d| d.get_temp::<NaiveDate>(focus_id))
+                .unwrap_or_else(|| self.initial_focus(today, first_month));
+            let first_weekday = self.locale.first_weekday();
+            let activate = ui.input_mut(|i| {
+                const KEYS: [Key; 8] = [
+                    Key::ArrowLeft, Key::ArrowRight, Key::ArrowUp, Key::ArrowDown,
+                    Key::PageUp, Key::PageDown, Key::Home, Key::End,
+                ];
+                for key in KEYS {
+                    // Shifted first: the unshifted pattern matches shifted presses too
+                    for (modifiers, shift) in [(Modifiers::SHIFT, true), (Modifiers::NONE, false)] {
+                        for _ in 0..i.count_and_consume_key(modifiers, key) {
+                            if let Some(next) = navigate(focused, key, shift, first_weekday) {
+                                focused = next;
+                            }
+                        }
+                    }
+                }
+                i.consume_key(Modifiers::NONE, Key::Enter) | i.consume_key(Modifiers::NONE, Key::Space)
+            });
+            if let Some(min) = self.min_date {
+                focused = focused.max(min);
+            }
+            if let Some(max) = self.max_date {
+                focused = focused.min(max);
+            }
+
+            let view = view_for(focused, first_month, months);
+            if view != first_month {
+                *self.view_date = view;
+            }
+            if activate && !self.is_date_disabled(focused) {
+                self.selection.click(focused, self.mode);
+            }
+            ui.data_mut(|d| d.insert_temp(focus_id, focused));
+            self.focused = Some(focused);
+        } else {
+            ui.data_mut(|d| d.remove::<NaiveDate>(focus_id));
+        }
+
+        // Range preview: from the picked start to the hovered or focused day
+        if self.mode == CalendarMode::Range && matches!(self.selection, CalendarSelection::Single(_)) {
+            self.preview_end = ui.data(|d| d.get_temp::<NaiveDate>(hover_id)).or(self.focused);
+        }
+
+        let first_month = self.view_date.with_day(1).unwrap_or(*self.view_date);
+
         let response = egui::Frame::NONE
             .inner_margin(egui::Margin::same(padding as i8))
             .show(ui, |ui| {
-                ui.vertical(|ui| {
-                    // Navigation header
-                    self.draw_header(ui, &theme, cell_size);
+                ui.horizontal_top(|ui| {
+                    ui.spacing_mut().item_spacing.x = 16.0;
+                    for index in 0..months {
+                        let month_start = first_month + chrono::Months::new(index as u32);
+                        ui.vertical(|ui| {
+                            // Navigation header
+                            self.draw_header(ui, &theme, cell_size, index, month_start);
 
-                    ui.add_space(8.0);
+                            ui.add_space(8.0);
 
-                    // Weekday headers
-                    self.draw_weekday_headers(ui, &theme, cell_size);
+                            // Weekday headers
+                            self.draw_weekday_headers(ui, &theme, cell_size);
 
-                    ui.add_space(4.0);
+                            ui.add_space(4.0);
 
-                    // Day grid
-                    self.draw_day_grid(ui, &theme, cell_size, today);
+                            // Day grid
+                            self.draw_day_grid(ui, &theme, cell_size, today, index, month_start);
+                        });
+                    }
                 });
             });
 
-        response.response
+        // Focus target for the keyboard grid (doesn't take clicks from the days)
+        ui.interact(response.response.rect, grid_id, Sense::focusable_noninteractive());
+
+        let previous_hover = ui.data(|d| d.get_temp::<NaiveDate>(hover_id));
+        if self.hovered != previous_hover {
+            ui.data_mut(|d| match self.hovered {
+                Some(date) => d.insert_temp(hover_id, date),
+                None => d.remove::<NaiveDate>(hover_id),
+            });
+            ui.ctx().request_repaint();
+        }
+
+        let mut response = response.response;
+        if *self.selection != before {
+            response.mark_changed();
+        }
+        response
+    }
+
+    /// Day to focus when the grid gains focus: the selection if visible,
+    /// then today if visible, else the first shown day of the view
+    fn initial_focus(&self, today: NaiveDate, first_month: NaiveDate) -> NaiveDate {
+        let selected = match &*self.selection {
+            CalendarSelection::None => None,
+            CalendarSelection::Single(date) | CalendarSelection::Range(date, _) => Some(*date),
+            CalendarSelection::Multiple(dates) => dates.first().copied(),
+        };
+        let after_last = first_month + chrono::Months::new(self.number_of_months as u32);
+        let visible = |date: &NaiveDate| *date >= first_month && *date < after_last;
+        selected
+            .filter(visible)
+            .or(Some(today).filter(visible))
+            .unwrap_or(first_month)
     }
 
-    fn draw_header(&mut self, ui: &mut Ui, theme: &ShadcnTheme, cell_size: f32) {
+    /// Header for month `index`: prev/next buttons on the outer months,
+    /// month/year dropdowns on the first and a caption on the others
+    fn draw_header(&mut self, ui: &mut Ui, theme: &ShadcnTheme, cell_size: f32, index: usize, month_start: NaiveDate) {
+        let is_first = index == 0;
+        let is_last = index + 1 == self.number_of_months;
         // Total width is 7 cells (7 days)
         let total_width = cell_size * 7.0;
         let button_width = cell_size;
@@ -139,16 +576,32 @@ impl<'a> Calendar<'a> {
 
         ui.horizontal(|ui| {
             ui.spacing_mut().item_spacing.x = 0.0;
+            if self.show_week_numbers {
+                ui.add_space(cell_size);
+            }
 
             // Previous month button
-            let prev_response = self.draw_nav_button(ui, theme, cell_size, true);
-            if prev_response.clicked() {
-                *self.view_date = *self.view_date - chrono::Months::new(1);
+            if is_first {
+                let prev_response = self.draw_nav_button(ui, theme, cell_size, true);
+                if prev_response.clicked() {
+                    *self.view_date = *self.view_date - chrono::Months::new(1);
+                }
+            } else {
+                ui.add_space(button_width);
             }
 
             // Month and Year dropdowns (centered in fixed-width area)
             let (center_rect, _) = ui.allocate_exact_size(Vec2::new(center_width, cell_size), Sense::hover());
-            if ui.is_rect_visible(center_rect) {
+            if !is_first {
+                let caption = format!("{} {}", self.locale.month_name(month_start.month()), month_start.year());
+                ui.painter().text(
+                    center_rect.center(),
+                    egui::Align2::CENTER_CENTER,
+                    caption,
+                    egui::FontId::proportional(theme.typography.small().size),
+                    theme.colors.foreground,
+                );
+            } else if ui.is_rect_visible(center_rect) {
                 // Create a child UI in the center area for the dropdowns
                 let mut child_ui = ui.new_child(egui::UiBuilder::new().max_rect(center_rect));
                 child_ui.with_layout(egui::Layout::left_to_right(egui::Align::Center).with_main_justify(true), |ui| {
@@ -156,21 +609,14 @@ impl<'a> Calendar<'a> {
                         // Total width of dropdowns: 58 + 4 + 62 = 124
                         ui.add_space((center_width - 124.0) / 2.0); // Center the dropdowns
 
-                        // Month dropdown (abbreviated names in button)
-                        let months_short = [
-                            "Jan", "Feb", "Mar", "Apr", "May", "Jun",
-                            "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"
-                        ];
-                        // Full names for dropdown list
-                        let months_full = [
-                            "January", "February", "March", "April", "May", "June",
-                            "July", "August", "September", "October", "November", "December"
-                        ];
-                        let current_month = self.view_date.month() as usize - 1;
+                        // Month dropdown (abbreviated names in button, full names in the list)
+                        let locale = self.locale;
+                        let months_full: [&str; 12] = std::array::from_fn(|i| locale.month_name(i as u32 + 1));
+                        let current_month = self.view_date.month();
 
                         let month_id = ui.id().with("month_dropdown");
                         let month_response = self.draw_dropdown_button(
-                            ui, theme, months_short[current_month], 58.0, month_id
+                            ui, theme, locale.month_short(current_month), 58.0, month_id
                         );
 
                         if month_response.clicked() {
@@ -204,9 +650,11 @@ impl<'a> Calendar<'a> {
             }
 
             // Next month button
-            let next_response = self.draw_nav_button(ui, theme, cell_size, false);
-            if next_response.clicked() {
-                *self.view_date = *self.view_date + chrono::Months::new(1);
+            if is_last {
+                let next_response = self.draw_nav_button(ui, theme, cell_size, false);
+                if next_response.clicked() {
+                    *self.view_date = *self.view_date + chrono::Months::new(1);
+                }
             }
         });
     }
@@ -268,9 +716,12 @@ impl<'a> Calendar<'a> {
         let is_open = ui.ctx().data(|d| d.get_temp::<bool>(open_id).unwrap_or(false));
 
         if !is_open {
+            OverlayStack::remove(ui.ctx(), dropdown_id);
             return;
         }
 
+        let overlay = OverlayStack::register(ui.ctx(), dropdown_id, OverlayKind::Popover);
+
         let area_id = dropdown_id.with("area");
         let area_response = egui::Area::new(area_id)
             .order(egui::Order::Foreground)
@@ -304,8 +755,12 @@ impl<'a> Calendar<'a> {
                     });
             });
 
-        // Close when clicking outside
-        if area_response.response.clicked_elsewhere() && !trigger.clicked() {
+        overlay.raise(ui.ctx(), area_response.response.layer_id);
+
+        // Close on Escape or when clicking outside
+        let clicked_outside = !trigger.clicked()
+            && overlay.clicked_outside(ui.ctx(), area_response.response.rect);
+        if clicked_outside || overlay.escape_pressed(ui.ctx()) {
             ui.ctx().data_mut(|d| d.insert_temp(open_id, false));
         }
     }
@@ -315,9 +770,12 @@ impl<'a> Calendar<'a> {
         let is_open = ui.ctx().data(|d| d.get_temp::<bool>(open_id).unwrap_or(false));
 
         if !is_open {
+            OverlayStack::remove(ui.ctx(), dropdown_id);
             return;
         }
 
+        let overlay = OverlayStack::register(ui.ctx(), dropdown_id, OverlayKind::Popover);
+
         // Show years in descending order: future years (current + 10) down to past years (current - 100)
         // This gives a natural scrollable list with newest at top
         let all_years: Vec<i32> = ((current_year - 100)..=(current_year + 10)).rev().collect();
@@ -354,8 +812,12 @@ impl<'a> Calendar<'a> {
                     });
             });
 
-        // Close when clicking outside
-        if area_response.response.clicked_elsewhere() && !trigger.clicked() {
+        overlay.raise(ui.ctx(), area_response.response.layer_id);
+
+        // Close on Escape or when clicking outside
+        let clicked_outside = !trigger.clicked()
+            && overlay.clicked_outside(ui.ctx(), area_response.response.rect);
+        if clicked_outside || overlay.escape_pressed(ui.ctx()) {
             ui.ctx().data_mut(|d| d.insert_temp(open_id, false));
         }
     }
@@ -456,11 +918,13 @@ impl<'a> Calendar<'a> {
     }
 
     fn draw_weekday_headers(&self, ui: &mut Ui, theme: &ShadcnTheme, cell_size: f32) {
-        let weekdays = ["Su", "Mo", "Tu", "We", "Th", "Fr", "Sa"];
-
         ui.horizontal(|ui| {
             ui.spacing_mut().item_spacing.x = 0.0;
-            for day in weekdays {
+            if self.show_week_numbers {
+                ui.allocate_exact_size(Vec2::splat(cell_size), Sense::hover());
+            }
+            for weekday in weekday_columns(self.locale.first_weekday()) {
+                let day = self.locale.weekday_short(weekday);
                 let (rect, _) = ui.allocate_exact_size(Vec2::splat(cell_size), Sense::hover());
                 if ui.is_rect_visible(rect) {
                     ui.painter().text(
@@ -475,37 +939,56 @@ impl<'a> Calendar<'a> {
         });
     }
 
-    fn draw_day_grid(&mut self, ui: &mut Ui, theme: &ShadcnTheme, cell_size: f32, today: chrono::NaiveDate) {
-        use chrono::NaiveDate;
-
-        let year = self.view_date.year();
-        let month = self.view_date.month();
-
-        // First day of the month
-        let first_of_month = NaiveDate::from_ymd_opt(year, month, 1).unwrap();
-        // Last day of the month
-        let last_of_month = if month == 12 {
-            NaiveDate::from_ymd_opt(year + 1, 1, 1).unwrap() - chrono::Days::new(1)
-        } else {
-            NaiveDate::from_ymd_opt(year, month + 1, 1).unwrap() - chrono::Days::new(1)
-        };
-
-        // Day of week for first day (0 = Sunday)
-        let first_weekday = first_of_month.weekday().num_days_from_sunday() as i32;
+    fn draw_day_grid(
+        &mut self,
+        ui: &mut Ui,
+        theme: &ShadcnTheme,
+        cell_size: f32,
+        today: chrono::NaiveDate,
+        index: usize,
+        first_of_month: NaiveDate,
+    ) {
+        let month = first_of_month.month();
 
         // Start date for the grid (may be in previous month)
-        let grid_start = first_of_month - chrono::Days::new(first_weekday as u64);
+        let grid_start = grid_start(first_of_month, self.locale.first_weekday());
 
         // Draw 6 weeks (42 days)
         for week in 0..6 {
             ui.horizontal(|ui| {
                 ui.spacing_mut().item_spacing.x = 0.0;
+                if self.show_week_numbers {
+                    let row_start = grid_start + chrono::Days::new((week * 7) as u64);
+                    let (rect, _) = ui.allocate_exact_size(Vec2::splat(cell_size), Sense::hover());
+                    if ui.is_rect_visible(rect) {
+                        ui.painter().text(
+                            rect.center(),
+                            egui::Align2::CENTER_CENTER,
+                            row_week_number(row_start).to_string(),
+                            egui::FontId::proportional(12.0),
+                            theme.colors.muted_foreground,
+                        );
+                    }
+                }
                 for day in 0..7 {
                     let day_offset = week * 7 + day;
                     let current_date = grid_start + chrono::Days::new(day_offset as u64);
                     let is_current_month = current_date.month() == month;
 
-                    self.draw_day_cell(
+                    // With several months, only the outer edges show outside days
+                    let outside_visible = self.show_outside_days
+                        && if current_date < first_of_month {
+                            index == 0
+                        } else {
+                            index + 1 == self.number_of_months
+                        };
+
+                    if !is_current_month && !outside_visible {
+                        ui.allocate_exact_size(Vec2::splat(cell_size), Sense::hover());
+                        continue;
+                    }
+
+                    let clicked = self.draw_day_cell(
                         ui,
                         theme,
                         cell_size,
@@ -513,11 +996,17 @@ impl<'a> Calendar<'a> {
                         today,
                         is_current_month,
                     );
+
+                    // Update view if clicking outside current month
+                    if clicked && !is_current_month {
+                        *self.view_date = current_date - chrono::Months::new(index as u32);
+                    }
                 }
             });
         }
     }
 
+    /// Draw one day; returns whether it was clicked (and selected)
     fn draw_day_cell(
         &mut self,
         ui: &mut Ui,
@@ -526,24 +1015,26 @@ impl<'a> Calendar<'a> {
         date: chrono::NaiveDate,
         today: chrono::NaiveDate,
         is_current_month: bool,
-    ) {
+    ) -> bool {
         let is_today = date == today;
-        let is_selected = match self.selection {
-            CalendarSelection::None => false,
-            CalendarSelection::Single(d) => *d == date,
-            CalendarSelection::Range(start, end) => date >= *start && date <= *end,
-        };
+        let is_selected = self.selection.contains(date);
         let is_range_start = matches!(self.selection, CalendarSelection::Range(start, _) if *start == date);
         let is_range_end = matches!(self.selection, CalendarSelection::Range(_, end) if *end == date);
         let is_disabled = self.is_date_disabled(date);
 
-        // Don't show outside days if disabled
-        if !is_current_month && !self.show_outside_days {
-            let (_, _) = ui.allocate_exact_size(Vec2::splat(size), Sense::hover());
-            return;
+        let (rect, response) = ui.allocate_exact_size(Vec2::splat(size), Sense::click());
+        if response.hovered() {
+            self.hovered = Some(date);
         }
 
-        let (rect, response) = ui.allocate_exact_size(Vec2::splat(size), Sense::click());
+        // Days between the picked range start and the hovered/focused end
+        let preview_end = self.preview_end.filter(|_| !is_disabled);
+        let in_preview = match (&self.selection, preview_end) {
+            (CalendarSelection::Single(start), Some(end)) => {
+                date != *start && date >= (*start).min(end) && date <= (*start).max(end)
+            }
+            _ => false,
+        };
 
         if ui.is_rect_visible(rect) {
             let cell_rect = rect.shrink(1.0);
@@ -551,9 +1042,13 @@ impl<'a> Calendar<'a> {
             // Full circle rounding for selected dates (shadcn style)
             let circle_rounding = size / 2.0;
 
+            if in_preview && !is_selected {
+                ui.painter().rect_filled(cell_rect, 0.0, theme.colors.accent.gamma_multiply(0.6));
+            }
+
             // Determine background, text color, and whether to draw border for today
             let (bg_color, text_color, rounding, draw_today_border) = if is_selected {
-                if is_range_start || is_range_end || matches!(self.selection, CalendarSelection::Single(_)) {
+                if is_range_start || is_range_end || !matches!(self.selection, CalendarSelection::Range(..)) {
                     // Filled circle for selected date
                     (Some(theme.colors.primary), theme.colors.primary_foreground, circle_rounding, false)
                 } else {
@@ -598,39 +1093,71 @@ impl<'a> Calendar<'a> {
                 egui::FontId::proportional(14.0),
                 final_text_color,
             );
-        }
 
-        // Handle click
-        if response.clicked() && !is_disabled && (is_current_month || self.show_outside_days) {
-            match self.mode {
-                CalendarMode::Single => {
-                    *self.selection = CalendarSelection::Single(date);
-                }
-                CalendarMode::Range => {
-                    match self.selection {
-                        CalendarSelection::None | CalendarSelection::Range(_, _) => {
-                            *self.selection = CalendarSelection::Single(date);
-                        }
-                        CalendarSelection::Single(start) => {
-                            if date < *start {
-                                *self.selection = CalendarSelection::Range(date, *start);
-                            } else {
-                                *self.selection = CalendarSelection::Range(*start, date);
-                            }
-                        }
-                    }
-                }
+            if let Some(decoration) = self.decorations.as_ref().and_then(|decorate| decorate(date)) {
+                self.draw_decoration(ui, theme, cell_rect, &decoration, bg_color.is_some() && is_selected);
             }
 
-            // Update view if clicking outside current month
-            if !is_current_month {
-                *self.view_date = date;
+            // Preview range end
+            if in_preview && preview_end == Some(date) {
+                ui.painter().rect_stroke(
+                    cell_rect,
+                    circle_rounding,
+                    egui::Stroke::new(1.5, theme.colors.primary),
+                    egui::StrokeKind::Inside,
+                );
+            }
+
+            if self.focused == Some(date) {
+                theme.draw_focus_ring(ui.painter(), cell_rect, circle_rounding as u8, true);
             }
         }
 
         if response.hovered() && !is_disabled {
             ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
         }
+
+        // Handle click
+        let clicked = response.clicked() && !is_disabled;
+        if clicked {
+            self.selection.click(date, self.mode);
+            // Continue from the clicked day with the keyboard
+            let grid_id = self.id.with("grid");
+            ui.memory_mut(|m| m.request_focus(grid_id));
+            ui.data_mut(|d| d.insert_temp(self.id.with("focused_date"), date));
+        }
+        clicked
+    }
+
+    /// Dots under the day number or a badge in the corner
+    fn draw_decoration(&self, ui: &mut Ui, theme: &ShadcnTheme, rect: egui::Rect, decoration: &DayDecoration, on_fill: bool) {
+        let default_color = if on_fill { theme.colors.primary_foreground } else { theme.colors.primary };
+        match decoration {
+            DayDecoration::Dot | DayDecoration::Dots(_) => {
+                let colors = match decoration {
+                    DayDecoration::Dots(colors) => colors.as_slice(),
+                    _ => std::slice::from_ref(&default_color),
+                };
+                let count = colors.len().min(3);
+                let spacing = 5.0;
+                let start_x = rect.center().x - spacing * (count as f32 - 1.0) / 2.0;
+                for (i, color) in colors.iter().take(count).enumerate() {
+                    ui.painter().circle_filled(
+                        Pos2::new(start_x + spacing * i as f32, rect.max.y - 4.0),
+                        1.5,
+                        *color,
+                    );
+                }
+            }
+            DayDecoration::Badge(text) => {
+                let font = egui::FontId::proportional(9.0);
+                let galley = ui.painter().layout_no_wrap(text.clone(), font, theme.colors.destructive_foreground);
+                let size = Vec2::new(galley.size().x.max(galley.size().y) + 4.0, galley.size().y + 2.0);
+                let badge = egui::Rect::from_min_size(Pos2::new(rect.max.x - size.x + 3.0, rect.min.y - 3.0), size);
+                ui.painter().rect_filled(badge, size.y / 2.0, theme.colors.destructive);
+                ui.painter().galley(badge.center() - galley.size() / 2.0, galley, theme.colors.destructive_foreground);
+            }
+        }
     }
 
     fn is_date_disabled(&self, date: chrono::NaiveDate) -> bool {
@@ -644,7 +1171,7 @@ impl<'a> Calendar<'a> {
                 return true;
             }
         }
-        false
+        self.disabled.as_ref().is_some_and(|disabled| disabled(date))
     }
 }
 
@@ -658,4 +1185,180 @@ mod tests {
         let selection = CalendarSelection::Single(date);
         assert!(matches!(selection, CalendarSelection::Single(_)));
     }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_selection_clicks() {
+        let (a, b, c) = (date(2025, 3, 10), date(2025, 3, 12), date(2025, 3, 4));
+
+        let mut selection = CalendarSelection::None;
+        selection.click(a, CalendarMode::Range);
+        selection.click(c, CalendarMode::Range);
+        assert_eq!(selection, CalendarSelection::Range(c, a));
+        assert!(selection.contains(date(2025, 3, 7)));
+
+        let mut selection = CalendarSelection::None;
+        selection.click(a, CalendarMode::Multiple);
+        selection.click(b, CalendarMode::Multiple);
+        selection.click(c, CalendarMode::Multiple);
+        // Clicking again deselects
+        selection.click(a, CalendarMode::Multiple);
+        assert_eq!(selection, CalendarSelection::Multiple(BTreeSet::from([c, b])));
+        assert!(!selection.contains(a));
+    }
+
+    #[test]
+    fn test_disabled_predicate_and_multiple_months() {
+        use crate::test_util::{context_with_motion, run_frame};
+        use crate::theme::MotionPreference;
+
+        let ctx = context_with_motion(MotionPreference::Full);
+        let mut selection = CalendarSelection::None;
+        let mut view = date(2025, 1, 20);
+        let mut sizes = Vec::new();
+        for months in [1, 2] {
+            run_frame(&ctx, 0.0, |ui| {
+                let calendar = Calendar::new("cal", &mut selection, &mut view)
+                    .number_of_months(months)
+                    .min_date(date(2025, 1, 5))
+                    .disabled(|d| d.weekday() == Weekday::Sun);
+                assert!(calendar.is_date_disabled(date(2025, 1, 12)));
+                assert!(calendar.is_date_disabled(date(2025, 1, 4)));
+                assert!(!calendar.is_date_disabled(date(2025, 1, 13)));
+                sizes.push(calendar.show(ui).rect.width());
+            });
+        }
+        // The second month sits beside the first
+        assert!(sizes[1] > sizes[0] * 1.8);
+    }
+
+    #[test]
+    fn test_navigate() {
+        let d = date(2025, 1, 31); // a Friday
+        let nav = |key, shift| navigate(d, key, shift, Weekday::Mon).unwrap();
+        assert_eq!(nav(Key::ArrowRight, false), date(2025, 2, 1));
+        assert_eq!(nav(Key::ArrowUp, false), date(2025, 1, 24));
+        // Months clamp to the last day
+        assert_eq!(nav(Key::PageDown, false), date(2025, 2, 28));
+        assert_eq!(nav(Key::PageUp, true), date(2024, 1, 31));
+        assert_eq!(nav(Key::Home, false), date(2025, 1, 27));
+        assert_eq!(nav(Key::End, false), date(2025, 2, 2));
+        assert_eq!(navigate(d, Key::Home, false, Weekday::Sun), Some(date(2025, 1, 26)));
+        assert_eq!(navigate(d, Key::A, false, Weekday::Sun), None);
+    }
+
+    #[test]
+    fn test_view_follows_focus() {
+        let jan = date(2025, 1, 1);
+        assert_eq!(view_for(date(2025, 1, 15), jan, 1), jan);
+        assert_eq!(view_for(date(2025, 2, 1), jan, 1), date(2025, 2, 1));
+        assert_eq!(view_for(date(2024, 12, 31), jan, 2), date(2024, 12, 1));
+        // With two months, moving past the second shifts by one
+        assert_eq!(view_for(date(2025, 2, 10), jan, 2), jan);
+        assert_eq!(view_for(date(2025, 3, 3), jan, 2), date(2025, 2, 1));
+    }
+
+    #[test]
+    fn test_keyboard_selects_range_across_months() {
+        use crate::test_util::{context_with_motion, key_press as key, run_frame_with_events};
+        use crate::theme::MotionPreference;
+        use egui::Event;
+
+        let ctx = context_with_motion(MotionPreference::Full);
+        let mut selection = CalendarSelection::Single(date(2025, 1, 30));
+        let mut view = date(2025, 1, 1);
+        let frame = |time: f64, events: Vec<Event>, selection: &mut CalendarSelection, view: &mut NaiveDate| {
+            run_frame_with_events(&ctx, time, events, |ui| {
+                Calendar::new("keys", &mut *selection, &mut *view).mode(CalendarMode::Range).show(ui);
+            });
+        };
+
+        frame(0.0, Vec::new(), &mut selection, &mut view);
+        ctx.memory_mut(|m| m.request_focus(Id::new("keys").with("grid")));
+        frame(0.1, Vec::new(), &mut selection, &mut view);
+
+        // Focus starts on the selected day; a week down crosses into February
+        frame(0.2, vec![key(Key::ArrowDown, Modifiers::NONE)], &mut selection, &mut view);
+        assert_eq!(view.month(), 2);
+        assert_eq!(
+            ctx.data(|d| d.get_temp::<NaiveDate>(Id::new("keys").with("focused_date"))),
+            Some(date(2025, 2, 6))
+        );
+
+        frame(0.3, vec![key(Key::ArrowRight, Modifiers::NONE), key(Key::Enter, Modifiers::NONE)], &mut selection, &mut view);
+        assert_eq!(selection, CalendarSelection::Range(date(2025, 1, 30), date(2025, 2, 7)));
+
+        // Shift+PageUp goes back a year
+        frame(0.4, vec![key(Key::PageUp, Modifiers::SHIFT)], &mut selection, &mut view);
+        assert_eq!((view.year(), view.month()), (2024, 2));
+    }
+
+    #[test]
+    fn test_grid_start_follows_first_weekday() {
+        // June 2025 starts on a Sunday
+        let june = date(2025, 6, 1);
+        assert_eq!(grid_start(june, Weekday::Sun), june);
+        assert_eq!(grid_start(june, Weekday::Mon), date(2025, 5, 26));
+        assert_eq!(grid_start(june, Weekday::Sat), date(2025, 5, 31));
+        assert_eq!(weekday_columns(Weekday::Mon)[6], Weekday::Sun);
+    }
+
+    #[test]
+    fn test_row_week_numbers() {
+        // 2024-12-30 (Monday) is in ISO week 1 of 2025
+        assert_eq!(row_week_number(date(2024, 12, 30)), 1);
+        // A Sunday-first row: Sunday belongs to the previous ISO week, the rest don't
+        assert_eq!(row_week_number(date(2024, 12, 29)), 1);
+        assert_eq!(row_week_number(date(2021, 1, 4)), 1);
+        assert_eq!(row_week_number(date(2020, 12, 28)), 53);
+    }
+
+    #[test]
+    fn test_english_locale() {
+        let us = EnglishLocale::US;
+        assert_eq!(us.first_weekday(), Weekday::Sun);
+        assert_eq!(us.month_short(9), "Sep");
+        assert_eq!(us.weekday_short(Weekday::Thu), "Th");
+        assert_eq!(us.format_date(date(2025, 1, 5), us.date_format()), "January 05, 2025");
+        assert_eq!(EnglishLocale::GB.format_date(date(2025, 1, 5), EnglishLocale::GB.date_format()), "05 January 2025");
+    }
+
+    struct German;
+
+    impl CalendarLocale for German {
+        fn first_weekday(&self) -> Weekday {
+            Weekday::Mon
+        }
+
+        fn month_name(&self, month: u32) -> &str {
+            ["Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"]
+                [month as usize - 1]
+        }
+
+        fn month_short(&self, month: u32) -> &str {
+            ["Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez"][month as usize - 1]
+        }
+
+        fn weekday_short(&self, day: Weekday) -> &str {
+            ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"][day.num_days_from_monday() as usize]
+        }
+
+        fn weekday_name(&self, day: Weekday) -> &str {
+            ["Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag", "Sonntag"][day.num_days_from_monday() as usize]
+        }
+
+        fn date_format(&self) -> &str {
+            "%A, %d. %B %Y"
+        }
+    }
+
+    #[test]
+    fn test_custom_locale_formatting() {
+        let day = date(2025, 3, 6);
+        assert_eq!(German.format_date(day, German.date_format()), "Donnerstag, 06. März 2025");
+        assert_eq!(German.format_date(day, "%a %b %%"), "Do Mär %");
+    }
 }
@@ -4,8 +4,19 @@
 //!
 //! Reference: <https://ui.shadcn.com/docs/components/carousel>
 
-use egui::{Id, Response, Ui, Sense, Vec2, Pos2};
+use egui::{Id, Key, Modifiers, Pos2, Rect, Response, Sense, Ui, UiBuilder, Vec2};
+use crate::animation::{Spring, SpringState};
 use crate::theme::ShadcnTheme;
+use std::time::Duration;
+
+/// Space between items when several are visible
+const SLIDE_GAP: f32 = 16.0;
+
+/// Release speed (pixels per second) that pages even if the drag was short
+const FLICK_VELOCITY: f32 = 400.0;
+
+/// How strongly dragging past the first/last item resists (0 = immovable)
+const RUBBER_BAND: f32 = 0.55;
 
 /// Carousel orientation
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -17,8 +28,64 @@ pub enum CarouselOrientation {
     Vertical,
 }
 
+/// Navigation requested by buttons, dots, keys or autoplay
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step {
+    Prev,
+    Next,
+    To(usize),
+}
+
+/// Persistent carousel state
+///
+/// Positions are in "virtual" slide units: looping carousels keep counting past
+/// the last item so wrapping around animates forwards instead of rewinding.
+#[derive(Debug, Clone, Copy)]
+struct CarouselState {
+    /// Slide the carousel is settling on
+    target: f32,
+    /// Displayed position and its velocity
+    position: SpringState,
+    /// Unclamped position following the pointer while dragging
+    drag_position: f32,
+    /// Whether a drag is in progress
+    dragging: bool,
+    /// Time of the last autoplay advance (or user interaction)
+    last_advance: f64,
+}
+
+/// Resist dragging past `0.0..=max`, approaching one extra slide asymptotically
+fn rubber_band(position: f32, max: f32) -> f32 {
+    let resist = |overscroll: f32| 1.0 - 1.0 / (overscroll * RUBBER_BAND + 1.0);
+    if position < 0.0 {
+        -resist(-position)
+    } else if position > max {
+        max + resist(position - max)
+    } else {
+        position
+    }
+}
+
+/// Slide to settle on when a drag is released
+///
+/// `velocity` is the release speed towards later slides in pixels per second;
+/// a fast flick pages in its direction, otherwise the nearest slide wins.
+fn release_target(position: f32, velocity: f32, flick_velocity: f32) -> f32 {
+    if velocity > flick_velocity {
+        position.floor() + 1.0
+    } else if velocity < -flick_velocity {
+        position.ceil() - 1.0
+    } else {
+        position.round()
+    }
+}
+
 /// Carousel component for cycling through content
 ///
+/// Items slide with a spring when paging. The viewport can be dragged or swiped
+/// (with a rubber band at the ends unless looping) and, once focused, paged with
+/// the arrow keys.
+///
 /// ## Example
 /// ```rust,ignore
 /// let items = vec!["Item 1", "Item 2", "Item 3"];
@@ -26,6 +93,8 @@ pub enum CarouselOrientation {
 ///
 /// Carousel::new("my_carousel", &mut current, items.len())
 ///     .item_width(200.0)
+///     .slides_per_view(2)
+///     .autoplay(Duration::from_secs(4))
 ///     .show(ui, |ui, index| {
 ///         ui.label(&items[index]);
 ///     });
@@ -39,6 +108,8 @@ pub struct Carousel<'a> {
     show_buttons: bool,
     show_dots: bool,
     loop_items: bool,
+    slides_per_view: usize,
+    autoplay: Option<Duration>,
 }
 
 impl<'a> Carousel<'a> {
@@ -53,6 +124,8 @@ impl<'a> Carousel<'a> {
             show_buttons: true,
             show_dots: true,
             loop_items: false,
+            slides_per_view: 1,
+            autoplay: None,
         }
     }
 
@@ -98,6 +171,67 @@ impl<'a> Carousel<'a> {
         self
     }
 
+    /// Show several items side by side (default 1)
+    ///
+    /// Each item keeps `item_size`; the viewport grows to fit them.
+    pub fn slides_per_view(mut self, count: usize) -> Self {
+        self.slides_per_view = count.max(1);
+        self
+    }
+
+    /// Advance automatically every `interval` (paused while hovered or dragged)
+    pub fn autoplay(mut self, interval: Duration) -> Self {
+        self.autoplay = Some(interval);
+        self
+    }
+
+    /// Highest index the first visible item can have
+    fn max_index(&self) -> usize {
+        if self.loop_items {
+            self.item_count.saturating_sub(1)
+        } else {
+            self.item_count.saturating_sub(self.slides_per_view)
+        }
+    }
+
+    /// Map a virtual slot to an item index (`None` past the ends of a non-looping carousel)
+    fn item_at(&self, slot: i64) -> Option<usize> {
+        if self.item_count == 0 {
+            None
+        } else if self.loop_items {
+            Some(slot.rem_euclid(self.item_count as i64) as usize)
+        } else if slot >= 0 && (slot as usize) < self.item_count {
+            Some(slot as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Index of the first visible item for a (rounded) virtual position
+    fn index_at(&self, position: f32) -> usize {
+        let slot = position.round() as i64;
+        if self.loop_items {
+            self.item_at(slot).unwrap_or(0)
+        } else {
+            slot.clamp(0, self.max_index() as i64) as usize
+        }
+    }
+
+    /// Apply a navigation step to a virtual target position
+    fn step_target(&self, target: f32, step: Step) -> f32 {
+        let target = target.round();
+        let next = match step {
+            Step::Prev => target - 1.0,
+            Step::Next => target + 1.0,
+            Step::To(index) => target + index as f32 - self.index_at(target) as f32,
+        };
+        if self.loop_items {
+            next
+        } else {
+            next.clamp(0.0, self.max_index() as f32)
+        }
+    }
+
     /// Show the carousel with a content builder
     pub fn show<F>(self, ui: &mut Ui, mut content: F) -> Response
     where
@@ -110,18 +244,51 @@ impl<'a> Carousel<'a> {
 
         let button_size = 40.0;
         let button_margin = 8.0;
+        let horizontal = self.orientation == CarouselOrientation::Horizontal;
 
-        // Calculate total size
-        let total_width = if self.show_buttons {
-            self.item_size.x + (button_size + button_margin) * 2.0
+        // Viewport fits `slides_per_view` items along the main axis
+        let per_view = self.slides_per_view as f32;
+        let gaps = SLIDE_GAP * (per_view - 1.0);
+        let viewport_size = if horizontal {
+            Vec2::new(self.item_size.x * per_view + gaps, self.item_size.y)
         } else {
-            self.item_size.x
+            Vec2::new(self.item_size.x, self.item_size.y * per_view + gaps)
         };
+        let stride = if horizontal { self.item_size.x } else { self.item_size.y } + SLIDE_GAP;
+
+        // Load state, following external changes to `current`
+        let state_id = self.id.with("state");
+        let now = ui.input(|i| i.time);
+        let mut state = ui.ctx().data(|d| d.get_temp::<CarouselState>(state_id)).unwrap_or_else(|| {
+            let start = self.index_at(*self.current as f32) as f32;
+            CarouselState {
+                target: start,
+                position: SpringState::at(start),
+                drag_position: start,
+                dragging: false,
+                last_advance: now,
+            }
+        });
+        if !state.dragging && self.index_at(state.target) != *self.current {
+            state.target = self.step_target(state.target, Step::To(*self.current));
+        }
+
+        let max_index = self.max_index();
+        let current_index = self.index_at(state.target);
+        let can_go_prev = self.loop_items || current_index > 0;
+        let can_go_next = self.loop_items || current_index < max_index;
+        let mut step = None;
 
         let dots_height = if self.show_dots { 24.0 } else { 0.0 };
-        let total_height = self.item_size.y + dots_height;
+        let buttons = if self.show_buttons { (button_size + button_margin) * 2.0 } else { 0.0 };
+        let total_size = if horizontal {
+            Vec2::new(viewport_size.x + buttons, viewport_size.y + dots_height)
+        } else {
+            Vec2::new(viewport_size.x, viewport_size.y + buttons)
+        };
 
-        let response = ui.allocate_ui(Vec2::new(total_width, total_height), |ui| {
+        let mut viewport_response = None;
+        let mut response = ui.allocate_ui(total_size, |ui| {
             match self.orientation {
                 CarouselOrientation::Horizontal => {
                     ui.horizontal(|ui| {
@@ -131,16 +298,11 @@ impl<'a> Carousel<'a> {
                                 Vec2::new(button_size, self.item_size.y),
                                 egui::Layout::centered_and_justified(egui::Direction::TopDown),
                                 |ui| {
-                                    let can_go_prev = self.loop_items || *self.current > 0;
                                     let prev_response = self.draw_nav_button(
                                         ui, &theme, button_size, true, can_go_prev
                                     );
                                     if prev_response.clicked() && can_go_prev {
-                                        if *self.current > 0 {
-                                            *self.current -= 1;
-                                        } else if self.loop_items && self.item_count > 0 {
-                                            *self.current = self.item_count - 1;
-                                        }
+                                        step = Some(Step::Prev);
                                     }
                                 }
                             );
@@ -149,28 +311,18 @@ impl<'a> Carousel<'a> {
 
                         // Content area
                         ui.vertical(|ui| {
-                            // Item frame
-                            egui::Frame::NONE
-                                .stroke(egui::Stroke::new(1.0, theme.colors.border))
-                                .corner_radius(theme.radii.lg)
-                                .inner_margin(egui::Margin::same(16))
-                                .show(ui, |ui| {
-                                    ui.set_min_size(self.item_size - Vec2::splat(32.0));
-                                    if self.item_count > 0 && *self.current < self.item_count {
-                                        content(ui, *self.current);
-                                    }
-                                });
+                            viewport_response = Some(self.show_viewport(ui, &theme, viewport_size, stride, &mut state, &mut content));
 
                             // Dot indicators
-                            if self.show_dots && self.item_count > 1 {
+                            if self.show_dots && max_index > 0 {
                                 ui.add_space(8.0);
                                 ui.horizontal(|ui| {
-                                    ui.add_space((self.item_size.x - (self.item_count as f32 * 12.0)) / 2.0);
-                                    for i in 0..self.item_count {
-                                        let is_active = i == *self.current;
+                                    ui.add_space((viewport_size.x - ((max_index + 1) as f32 * 12.0)) / 2.0);
+                                    for i in 0..=max_index {
+                                        let is_active = i == current_index;
                                         let dot_response = self.draw_dot(ui, &theme, is_active);
                                         if dot_response.clicked() {
-                                            *self.current = i;
+                                            step = Some(Step::To(i));
                                         }
                                     }
                                 });
@@ -184,16 +336,11 @@ impl<'a> Carousel<'a> {
                                 Vec2::new(button_size, self.item_size.y),
                                 egui::Layout::centered_and_justified(egui::Direction::TopDown),
                                 |ui| {
-                                    let can_go_next = self.loop_items || *self.current < self.item_count.saturating_sub(1);
                                     let next_response = self.draw_nav_button(
                                         ui, &theme, button_size, false, can_go_next
                                     );
                                     if next_response.clicked() && can_go_next {
-                                        if *self.current < self.item_count - 1 {
-                                            *self.current += 1;
-                                        } else if self.loop_items {
-                                            *self.current = 0;
-                                        }
+                                        step = Some(Step::Next);
                                     }
                                 }
                             );
@@ -204,59 +351,192 @@ impl<'a> Carousel<'a> {
                     ui.vertical(|ui| {
                         // Previous button (up)
                         if self.show_buttons {
-                            let can_go_prev = self.loop_items || *self.current > 0;
                             ui.horizontal(|ui| {
                                 ui.add_space((self.item_size.x - button_size) / 2.0);
                                 let prev_response = self.draw_nav_button_vertical(
                                     ui, &theme, button_size, true, can_go_prev
                                 );
                                 if prev_response.clicked() && can_go_prev {
-                                    if *self.current > 0 {
-                                        *self.current -= 1;
-                                    } else if self.loop_items && self.item_count > 0 {
-                                        *self.current = self.item_count - 1;
-                                    }
+                                    step = Some(Step::Prev);
                                 }
                             });
                             ui.add_space(button_margin);
                         }
 
                         // Content area
-                        egui::Frame::NONE
-                            .stroke(egui::Stroke::new(1.0, theme.colors.border))
-                            .corner_radius(theme.radii.lg)
-                            .inner_margin(egui::Margin::same(16))
-                            .show(ui, |ui| {
-                                ui.set_min_size(self.item_size - Vec2::splat(32.0));
-                                if self.item_count > 0 && *self.current < self.item_count {
-                                    content(ui, *self.current);
-                                }
-                            });
+                        viewport_response = Some(self.show_viewport(ui, &theme, viewport_size, stride, &mut state, &mut content));
 
                         // Next button (down)
                         if self.show_buttons {
                             ui.add_space(button_margin);
-                            let can_go_next = self.loop_items || *self.current < self.item_count.saturating_sub(1);
                             ui.horizontal(|ui| {
                                 ui.add_space((self.item_size.x - button_size) / 2.0);
                                 let next_response = self.draw_nav_button_vertical(
                                     ui, &theme, button_size, false, can_go_next
                                 );
                                 if next_response.clicked() && can_go_next {
-                                    if *self.current < self.item_count - 1 {
-                                        *self.current += 1;
-                                    } else if self.loop_items {
-                                        *self.current = 0;
-                                    }
+                                    step = Some(Step::Next);
                                 }
                             });
                         }
                     });
                 }
             }
-        });
+        }).response;
+
+        if let Some(viewport) = viewport_response {
+            // Arrow keys page while the viewport has keyboard focus
+            if viewport.has_focus() {
+                // Keep arrow keys from moving focus to neighbouring widgets
+                ui.memory_mut(|m| {
+                    m.set_focus_lock_filter(
+                        viewport.id,
+                        egui::EventFilter {
+                            horizontal_arrows: horizontal,
+                            vertical_arrows: !horizontal,
+                            ..Default::default()
+                        },
+                    )
+                });
+                let (prev_key, next_key) = if horizontal {
+                    (Key::ArrowLeft, Key::ArrowRight)
+                } else {
+                    (Key::ArrowUp, Key::ArrowDown)
+                };
+                ui.input_mut(|i| {
+                    if i.consume_key(Modifiers::NONE, prev_key) && can_go_prev {
+                        step = Some(Step::Prev);
+                    }
+                    if i.consume_key(Modifiers::NONE, next_key) && can_go_next {
+                        step = Some(Step::Next);
+                    }
+                });
+            }
+            if step.is_some() {
+                viewport.request_focus();
+            }
+
+            // Autoplay pauses while hovered or dragged; user navigation restarts the timer
+            if let Some(interval) = self.autoplay {
+                if step.is_some() || state.dragging || viewport.contains_pointer() {
+                    state.last_advance = now;
+                } else if max_index > 0 {
+                    let due = state.last_advance + interval.as_secs_f64();
+                    if now >= due {
+                        step = Some(if can_go_next { Step::Next } else { Step::To(0) });
+                        state.last_advance = now;
+                    } else {
+                        ui.ctx().request_repaint_after(Duration::from_secs_f64(due - now));
+                    }
+                }
+            }
+        }
+
+        if let Some(step) = step {
+            state.target = self.step_target(state.target, step);
+            ui.ctx().request_repaint();
+        }
+
+        let index = self.index_at(state.target);
+        if index != *self.current {
+            *self.current = index;
+            response.mark_changed();
+        }
+
+        ui.ctx().data_mut(|d| d.insert_temp(state_id, state));
+        response
+    }
+
+    /// Draw the sliding viewport and handle drag paging
+    fn show_viewport<F>(
+        &self,
+        ui: &mut Ui,
+        theme: &ShadcnTheme,
+        size: Vec2,
+        stride: f32,
+        state: &mut CarouselState,
+        content: &mut F,
+    ) -> Response
+    where
+        F: FnMut(&mut Ui, usize),
+    {
+        let horizontal = self.orientation == CarouselOrientation::Horizontal;
+        let main = |v: Vec2| if horizontal { v.x } else { v.y };
+
+        // Allocated before the slides so their widgets take priority over the drag
+        let (viewport, response) = ui.allocate_exact_size(size, Sense::click_and_drag());
+        if response.clicked() || response.drag_started() {
+            response.request_focus();
+        }
+
+        let max = self.max_index() as f32;
+        if response.dragged() && self.item_count > 0 {
+            if !state.dragging {
+                state.dragging = true;
+                state.drag_position = state.position.value;
+            }
+            // Dragging content left moves towards later slides
+            state.drag_position -= main(response.drag_delta()) / stride;
+            let shown = if self.loop_items {
+                state.drag_position
+            } else {
+                rubber_band(state.drag_position, max)
+            };
+            state.position = SpringState::at(shown);
+            ui.ctx().request_repaint();
+        } else if state.dragging {
+            state.dragging = false;
+            let velocity = main(ui.input(|i| i.pointer.velocity()));
+            let target = release_target(state.position.value, -velocity, FLICK_VELOCITY);
+            state.target = if self.loop_items { target } else { target.clamp(0.0, max) };
+            // Hand the release velocity to the spring so the settle continues the gesture
+            state.position.velocity = -velocity / stride;
+        }
+
+        if !state.dragging {
+            if !theme.motion.allows_movement() {
+                state.position = SpringState::at(state.target);
+            } else if !state.position.is_settled(state.target) {
+                let dt = ui.input(|i| i.stable_dt);
+                state.position.step(&Spring::DEFAULT, state.target, dt);
+                ui.ctx().request_repaint();
+            }
+        }
+
+        // Draw every slot that intersects the viewport
+        let position = state.position.value;
+        let first = position.floor() as i64;
+        let last = position.ceil() as i64 + self.slides_per_view as i64 - 1;
+        let clip = viewport.intersect(ui.clip_rect());
+        for slot in first..=last {
+            let Some(index) = self.item_at(slot) else {
+                continue;
+            };
+            let offset = (slot as f32 - position) * stride;
+            let min = if horizontal {
+                viewport.min + Vec2::new(offset, 0.0)
+            } else {
+                viewport.min + Vec2::new(0.0, offset)
+            };
+            let slot_rect = Rect::from_min_size(min, self.item_size);
+
+            let mut slot_ui = ui.new_child(UiBuilder::new().max_rect(slot_rect).id_salt(("slide", slot)));
+            slot_ui.set_clip_rect(clip);
+
+            // Item frame
+            egui::Frame::NONE
+                .stroke(egui::Stroke::new(1.0, theme.colors.border))
+                .corner_radius(theme.radii.lg)
+                .inner_margin(egui::Margin::same(16))
+                .show(&mut slot_ui, |ui| {
+                    ui.set_min_size(self.item_size - Vec2::splat(32.0));
+                    content(ui, index);
+                });
+        }
+
+        theme.draw_focus_ring(ui.painter(), viewport, theme.radii.lg, response.has_focus());
 
-        response.response
+        response
     }
 
     fn draw_nav_button(
@@ -450,4 +730,60 @@ mod tests {
         assert_eq!(carousel.item_count, 5);
         assert!(carousel.loop_items);
     }
+
+    #[test]
+    fn test_slides_per_view_limits_last_index() {
+        let mut current = 0;
+        let carousel = Carousel::new("test", &mut current, 5).slides_per_view(2);
+        assert_eq!(carousel.max_index(), 3);
+
+        let mut current = 0;
+        let carousel = Carousel::new("test", &mut current, 5).slides_per_view(2).loop_items(true);
+        assert_eq!(carousel.max_index(), 4);
+    }
+
+    #[test]
+    fn test_steps_clamp_without_looping() {
+        let mut current = 0;
+        let carousel = Carousel::new("test", &mut current, 3);
+        assert_eq!(carousel.step_target(0.0, Step::Prev), 0.0);
+        assert_eq!(carousel.step_target(1.0, Step::Next), 2.0);
+        assert_eq!(carousel.step_target(2.0, Step::Next), 2.0);
+        assert_eq!(carousel.step_target(2.0, Step::To(0)), 0.0);
+    }
+
+    #[test]
+    fn test_looping_keeps_counting_past_the_end() {
+        let mut current = 0;
+        let carousel = Carousel::new("test", &mut current, 3).loop_items(true);
+        // Wrapping forwards keeps moving forwards
+        assert_eq!(carousel.step_target(2.0, Step::Next), 3.0);
+        assert_eq!(carousel.index_at(3.0), 0);
+        assert_eq!(carousel.step_target(0.0, Step::Prev), -1.0);
+        assert_eq!(carousel.index_at(-1.0), 2);
+        assert_eq!(carousel.item_at(-1), Some(2));
+        // Jumping to an index moves relative to the current lap
+        assert_eq!(carousel.step_target(4.0, Step::To(0)), 3.0);
+    }
+
+    #[test]
+    fn test_rubber_band() {
+        assert_eq!(rubber_band(1.5, 3.0), 1.5);
+        let past_start = rubber_band(-1.0, 3.0);
+        assert!(past_start < 0.0 && past_start > -0.5);
+        let past_end = rubber_band(5.0, 3.0);
+        assert!(past_end > 3.0 && past_end < 4.0);
+        // Resistance grows the further you pull
+        assert!(rubber_band(-2.0, 3.0) - rubber_band(-1.0, 3.0) > -0.5);
+    }
+
+    #[test]
+    fn test_release_target() {
+        // Slow release settles on the nearest slide
+        assert_eq!(release_target(1.4, 0.0, FLICK_VELOCITY), 1.0);
+        assert_eq!(release_target(1.6, 0.0, FLICK_VELOCITY), 2.0);
+        // A flick pages in its direction even after a short drag
+        assert_eq!(release_target(1.1, 800.0, FLICK_VELOCITY), 2.0);
+        assert_eq!(release_target(0.9, -800.0, FLICK_VELOCITY), 0.0);
+    }
 }
@@ -5,6 +5,7 @@
 //! Reference: <https://ui.shadcn.com/docs/components/collapsible>
 
 use egui::{Id, Ui, Sense, Vec2, Pos2};
+use crate::animation::{self, Spring};
 use crate::theme::ShadcnTheme;
 
 /// Collapsible component for expandable content sections
@@ -155,41 +156,29 @@ impl<'a> Collapsible<'a> {
             }
         }
 
-        // Render content if open
-        if is_open {
-            if let Some(content_fn) = self.content {
-                // Use CollapsingState for animation if enabled
-                if self.animate {
-                    let collapsing_id = self.id.with("content");
-
-                    // Get or create animation state
-                    let openness = ui.ctx().animate_bool_with_time(
-                        collapsing_id,
-                        true,
-                        ui.style().animation_time,
-                    );
-
-                    if openness > 0.0 {
-                        ui.scope(|ui| {
-                            // Apply opacity animation
-                            if openness < 1.0 {
-                                ui.set_opacity(openness);
-                            }
-                            content_fn(ui);
-                        });
-                    }
-                } else {
-                    content_fn(ui);
-                }
+        // Render content while open or still fading out
+        let Some(content_fn) = self.content else {
+            return;
+        };
+
+        if !self.animate {
+            if is_open {
+                content_fn(ui);
             }
-        } else if self.animate {
-            // Animate close
-            let collapsing_id = self.id.with("content");
-            let _openness = ui.ctx().animate_bool_with_time(
-                collapsing_id,
-                false,
-                ui.style().animation_time,
-            );
+            return;
+        }
+
+        let openness = animation::spring_bool(ui.ctx(), self.id.with("content"), is_open, Spring::DEFAULT)
+            .clamp(0.0, 1.0);
+
+        if openness > 0.0 {
+            ui.scope(|ui| {
+                // Apply opacity animation
+                if openness < 1.0 {
+                    ui.set_opacity(openness);
+                }
+                content_fn(ui);
+            });
         }
     }
 }
@@ -265,6 +254,9 @@ pub fn collapsible_trigger(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::animation;
+    use crate::test_util::{context_with_motion, run_frame};
+    use crate::theme::MotionPreference;
 
     #[test]
     fn test_collapsible_creation() {
@@ -273,4 +265,32 @@ mod tests {
             .no_animation();
         assert!(!collapsible.animate);
     }
+
+    /// Content openness one frame after expanding
+    fn first_open_frame(motion: MotionPreference) -> f32 {
+        let ctx = context_with_motion(motion);
+        for (time, expanded) in [(0.0, false), (1.0 / 60.0, true)] {
+            let mut open = expanded;
+            run_frame(&ctx, time, |ui| {
+                Collapsible::new("collapsible", &mut open)
+                    .content(|ui| {
+                        ui.label("Content");
+                    })
+                    .show(ui);
+            });
+        }
+        animation::spring_state(&ctx, Id::new("collapsible").with("content")).unwrap().value
+    }
+
+    #[test]
+    fn test_collapsible_motion() {
+        // Full and Reduced fade the content in (no movement involved)
+        let openness = first_open_frame(MotionPreference::Full);
+        assert!(openness > 0.0 && openness < 1.0);
+        let openness = first_open_frame(MotionPreference::Reduced);
+        assert!(openness > 0.0 && openness < 1.0);
+
+        // None shows it immediately
+        assert_eq!(first_open_frame(MotionPreference::None), 1.0);
+    }
 }
@@ -2,9 +2,22 @@
 //!
 //! A searchable select component that combines an input field with a dropdown list.
 //!
+//! Options come from an [`OptionSource`]: a fixed list ([`StaticOptions`]) or
+//! a remote query such as a profile directory or relay index
+//! ([`AsyncOptions`]).
+//!
 //! Reference: <https://ui.shadcn.com/docs/components/combobox>
 
-use egui::{Id, Response, Ui, Sense, Vec2, Pos2};
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Poll;
+use std::time::Duration;
+use egui::{Id, Key, Modifiers, Response, Ui, Sense, Vec2, Pos2};
+use crate::components::badge::{Badge, BadgeVariant};
+use crate::components::form::poll_now;
+use crate::components::spinner::{Spinner, SpinnerSize};
+use crate::overlay::{OverlayKind, OverlayStack};
 use crate::theme::ShadcnTheme;
 
 /// A single combobox option
@@ -35,8 +48,298 @@ impl ComboboxOption {
     }
 }
 
+/// Load state of an [`OptionSource`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum OptionStatus {
+    /// The last request has finished
+    Ready,
+    /// A request is in flight
+    Loading,
+    /// The last request failed
+    Failed(String),
+}
+
+/// Where a combobox gets its options from
+///
+/// The combobox calls [`OptionSource::request`] with the search text (after
+/// the debounce) and page 0, then polls every frame while it is open. When
+/// the list is scrolled to the bottom and [`OptionSource::has_more`] is
+/// true, the next page is requested.
+pub trait OptionSource {
+    /// Start loading `page` (0-based) of the options matching `search`
+    ///
+    /// Page 0 starts a new search and replaces earlier results; later pages
+    /// append to them.
+    fn request(&mut self, search: &str, page: usize);
+
+    /// Check on the current request without blocking
+    fn poll(&mut self) -> OptionStatus;
+
+    /// Options loaded so far for the current search
+    fn options(&self) -> &[ComboboxOption];
+
+    /// Whether another page can be requested
+    fn has_more(&self) -> bool {
+        false
+    }
+
+    /// Display label for `value`, if the source knows it
+    fn label_for(&self, value: &str) -> Option<&str> {
+        self.options()
+            .iter()
+            .find(|o| o.value == value)
+            .map(|o| o.label.as_str())
+    }
+}
+
+impl<S: OptionSource + ?Sized> OptionSource for &mut S {
+    fn request(&mut self, search: &str, page: usize) {
+        (**self).request(search, page);
+    }
+
+    fn poll(&mut self) -> OptionStatus {
+        (**self).poll()
+    }
+
+    fn options(&self) -> &[ComboboxOption] {
+        (**self).options()
+    }
+
+    fn has_more(&self) -> bool {
+        (**self).has_more()
+    }
+
+    fn label_for(&self, value: &str) -> Option<&str> {
+        (**self).label_for(value)
+    }
+}
+
+/// A fixed, in-memory list of options filtered by the search text
+pub struct StaticOptions<'a> {
+    all: &'a [ComboboxOption],
+    matches: Vec<ComboboxOption>,
+}
+
+impl<'a> StaticOptions<'a> {
+    /// Serve `options`, matching on label or value (case-insensitive)
+    pub fn new(options: &'a [ComboboxOption]) -> Self {
+        Self {
+            all: options,
+            matches: options.to_vec(),
+        }
+    }
+}
+
+impl OptionSource for StaticOptions<'_> {
+    fn request(&mut self, search: &str, _page: usize) {
+        self.matches = filter_options(self.all, search).into_iter().cloned().collect();
+    }
+
+    fn poll(&mut self) -> OptionStatus {
+        OptionStatus::Ready
+    }
+
+    fn options(&self) -> &[ComboboxOption] {
+        &self.matches
+    }
+
+    fn label_for(&self, value: &str) -> Option<&str> {
+        self.all.iter().find(|o| o.value == value).map(|o| o.label.as_str())
+    }
+}
+
+/// One page of results from an async query
+#[derive(Debug, Clone, Default)]
+pub struct OptionPage {
+    /// Options on this page
+    pub options: Vec<ComboboxOption>,
+    /// Whether another page follows
+    pub has_more: bool,
+}
+
+/// A boxed future resolving to a page of options
+pub type OptionsFuture = Pin<Box<dyn Future<Output = Result<OptionPage, String>> + Send>>;
+
+/// Options loaded by a future per query
+///
+/// `fetch` is called with the search text and page number. The future is
+/// polled once per frame with a no-op waker, so it should complete by
+/// checking shared state (a channel, a cache filled by a background thread)
+/// rather than relying on wake-ups. A new search drops the pending future.
+///
+/// ## Example
+/// ```rust,ignore
+/// let mut directory = AsyncOptions::new(move |search: &str, page: usize| {
+///     let rx = relay.search_profiles(search, page);
+///     Box::pin(async move { rx.await.map_err(|e| e.to_string()) }) as OptionsFuture
+/// });
+/// Combobox::new("author", &[], &mut author).source(&mut directory).show(ui);
+/// ```
+pub struct AsyncOptions<F> {
+    fetch: F,
+    options: Vec<ComboboxOption>,
+    has_more: bool,
+    pending: Option<OptionsFuture>,
+    error: Option<String>,
+}
+
+impl<F: FnMut(&str, usize) -> OptionsFuture> AsyncOptions<F> {
+    /// Load options with `fetch(search, page)`
+    pub fn new(fetch: F) -> Self {
+        Self {
+            fetch,
+            options: Vec::new(),
+            has_more: false,
+            pending: None,
+            error: None,
+        }
+    }
+}
+
+impl<F: FnMut(&str, usize) -> OptionsFuture> OptionSource for AsyncOptions<F> {
+    fn request(&mut self, search: &str, page: usize) {
+        if page == 0 {
+            self.options.clear();
+            self.has_more = false;
+        }
+        self.error = None;
+        self.pending = Some((self.fetch)(search, page));
+    }
+
+    fn poll(&mut self) -> OptionStatus {
+        if let Some(future) = self.pending.as_mut() {
+            if let Poll::Ready(result) = poll_now(future) {
+                self.pending = None;
+                match result {
+                    Ok(page) => {
+                        self.options.extend(page.options);
+                        self.has_more = page.has_more;
+                    }
+                    Err(error) => self.error = Some(error),
+                }
+            }
+        }
+        match (&self.pending, &self.error) {
+            (Some(_), _) => OptionStatus::Loading,
+            (None, Some(error)) => OptionStatus::Failed(error.clone()),
+            (None, None) => OptionStatus::Ready,
+        }
+    }
+
+    fn options(&self) -> &[ComboboxOption] {
+        &self.options
+    }
+
+    fn has_more(&self) -> bool {
+        self.has_more
+    }
+}
+
+/// Where the open list's query stands (kept in temp data)
+#[derive(Debug, Clone, Default)]
+struct QueryState {
+    /// Search text last sent to the source
+    requested: Option<String>,
+    /// When the search text last changed (`None`: query right away)
+    changed_at: Option<f64>,
+    /// Last page requested
+    page: usize,
+}
+
+/// Called with a value created from the search text
+type CreateCallback<'a> = Box<dyn FnOnce(&str) + 'a>;
+
+/// What a combobox is bound to
+enum ComboboxSelection<'a> {
+    Single(&'a mut Option<String>),
+    Multiple(&'a mut Vec<String>),
+}
+
+impl ComboboxSelection<'_> {
+    fn contains(&self, value: &str) -> bool {
+        match self {
+            ComboboxSelection::Single(selected) => selected.as_deref() == Some(value),
+            ComboboxSelection::Multiple(selected) => selected.iter().any(|v| v == value),
+        }
+    }
+
+    /// Whether `value` may be selected without exceeding `max`
+    fn can_add(&self, value: &str, max: Option<usize>) -> bool {
+        match self {
+            ComboboxSelection::Single(_) => true,
+            ComboboxSelection::Multiple(selected) => {
+                selected.iter().any(|v| v == value) || max.map_or(true, |max| selected.len() < max)
+            }
+        }
+    }
+
+    /// Select `value` (multi-select toggles it)
+    fn activate(&mut self, value: &str, max: Option<usize>) {
+        if !self.can_add(value, max) {
+            return;
+        }
+        match self {
+            ComboboxSelection::Single(selected) => **selected = Some(value.to_string()),
+            ComboboxSelection::Multiple(selected) => match selected.iter().position(|v| v == value) {
+                Some(index) => {
+                    selected.remove(index);
+                }
+                None => selected.push(value.to_string()),
+            },
+        }
+    }
+}
+
+/// An entry in the open option list
+enum ListItem<'o> {
+    Option(&'o ComboboxOption),
+    /// Offer to create the typed value
+    Create(String),
+}
+
+/// Options whose label or value contains `search` (case-insensitive)
+fn filter_options<'o>(options: &'o [ComboboxOption], search: &str) -> Vec<&'o ComboboxOption> {
+    let search = search.to_lowercase();
+    options
+        .iter()
+        .filter(|o| {
+            search.is_empty()
+                || o.label.to_lowercase().contains(&search)
+                || o.value.to_lowercase().contains(&search)
+        })
+        .collect()
+}
+
+/// The typed value, if it matches no option and isn't selected yet
+fn create_candidate(options: &[ComboboxOption], selection: &ComboboxSelection<'_>, search: &str) -> Option<String> {
+    let value = search.trim();
+    let exists = options
+        .iter()
+        .any(|o| o.value.eq_ignore_ascii_case(value) || o.label.eq_ignore_ascii_case(value));
+    (!value.is_empty() && !exists && !selection.contains(value)).then(|| value.to_string())
+}
+
+/// Move the keyboard highlight by `delta`, wrapping around
+fn move_highlight(current: Option<usize>, count: usize, delta: isize) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+    let next = match current {
+        Some(current) => (current as isize + delta).rem_euclid(count as isize) as usize,
+        None if delta < 0 => count - 1,
+        None => 0,
+    };
+    Some(next)
+}
+
 /// Combobox component - searchable select
 ///
+/// Binds to a single value with [`Combobox::new`] or to many values with
+/// [`Combobox::multiple`], which shows the selection as removable chips.
+///
+/// Keyboard: Arrow Up/Down move through the options, Enter selects, Escape
+/// closes, and Backspace in an empty search removes the last chip.
+///
 /// ## Example
 /// ```rust,ignore
 /// let options = vec![
@@ -49,15 +352,34 @@ impl ComboboxOption {
 /// Combobox::new("fruit_combo", &options, &mut selected)
 ///     .placeholder("Select a fruit...")
 ///     .show(ui);
+///
+/// // Tag picker: many values, at most 5, new tags allowed
+/// let mut tags: Vec<String> = Vec::new();
+/// let mut created = None;
+/// Combobox::multiple("tags", &hashtags, &mut tags)
+///     .max_selected(5)
+///     .creatable(true)
+///     .on_create(|tag| created = Some(tag.to_string()))
+///     .show(ui);
+/// // `hashtags` is borrowed by the combobox until `show` returns
+/// if let Some(tag) = created {
+///     hashtags.push(ComboboxOption::new(tag));
+/// }
 /// ```
 pub struct Combobox<'a> {
     id: Id,
-    options: &'a [ComboboxOption],
-    selected: &'a mut Option<String>,
+    source: Box<dyn OptionSource + 'a>,
+    /// The source is the fixed list, rebuilt every frame and so re-filtered every frame
+    fixed_options: bool,
+    selection: ComboboxSelection<'a>,
+    debounce: f64,
     placeholder: String,
     search_placeholder: String,
     width: f32,
     empty_message: String,
+    max_selected: Option<usize>,
+    creatable: bool,
+    on_create: Option<CreateCallback<'a>>,
 }
 
 impl<'a> Combobox<'a> {
@@ -67,14 +389,35 @@ impl<'a> Combobox<'a> {
         options: &'a [ComboboxOption],
         selected: &'a mut Option<String>,
     ) -> Self {
+        Self::with_selection(id, options, ComboboxSelection::Single(selected))
+    }
+
+    /// Create a multi-select combobox
+    ///
+    /// Selected values are shown as chips in the trigger and stay in the
+    /// order they were picked.
+    pub fn multiple(
+        id: impl std::hash::Hash,
+        options: &'a [ComboboxOption],
+        selected: &'a mut Vec<String>,
+    ) -> Self {
+        Self::with_selection(id, options, ComboboxSelection::Multiple(selected))
+    }
+
+    fn with_selection(id: impl std::hash::Hash, options: &'a [ComboboxOption], selection: ComboboxSelection<'a>) -> Self {
         Self {
             id: Id::new(id),
-            options,
-            selected,
+            source: Box::new(StaticOptions::new(options)),
+            fixed_options: true,
+            selection,
+            debounce: 0.0,
             placeholder: "Select...".to_string(),
             search_placeholder: "Search...".to_string(),
             width: 200.0,
             empty_message: "No results found.".to_string(),
+            max_selected: None,
+            creatable: false,
+            on_create: None,
         }
     }
 
@@ -102,8 +445,58 @@ impl<'a> Combobox<'a> {
         self
     }
 
+    /// Query options from `source` instead of the fixed list
+    ///
+    /// Search changes are debounced by 250 ms unless [`Combobox::debounce`]
+    /// says otherwise.
+    pub fn source(mut self, source: &'a mut dyn OptionSource) -> Self {
+        self.source = Box::new(source);
+        self.fixed_options = false;
+        self.debounce = 0.25;
+        self
+    }
+
+    /// Wait this long after typing stops before querying the source
+    pub fn debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce.as_secs_f64();
+        self
+    }
+
+    /// Limit how many values a multi-select combobox may hold
+    pub fn max_selected(mut self, max: usize) -> Self {
+        self.max_selected = Some(max);
+        self
+    }
+
+    /// Offer to create the typed value when it matches no option
+    pub fn creatable(mut self, creatable: bool) -> Self {
+        self.creatable = creatable;
+        self
+    }
+
+    /// Called with a value created from the search text
+    ///
+    /// The value is selected either way; record it here and add it to the
+    /// options after `show` returns (they are borrowed until then).
+    pub fn on_create(mut self, callback: impl FnOnce(&str) + 'a) -> Self {
+        self.on_create = Some(Box::new(callback));
+        self
+    }
+
+    /// Display label for a selected value
+    ///
+    /// Falls back to labels remembered from earlier selections (a remote
+    /// source may no longer list them), then to the value itself.
+    fn label_for(&self, labels: &HashMap<String, String>, value: &str) -> String {
+        self.source
+            .label_for(value)
+            .or_else(|| labels.get(value).map(String::as_str))
+            .unwrap_or(value)
+            .to_string()
+    }
+
     /// Show the combobox
-    pub fn show(self, ui: &mut Ui) -> Response {
+    pub fn show(mut self, ui: &mut Ui) -> Response {
         let theme = ui.ctx().data(|d| {
             d.get_temp::<ShadcnTheme>(Id::new("shadcn_theme"))
                 .unwrap_or_else(ShadcnTheme::light)
@@ -112,25 +505,35 @@ impl<'a> Combobox<'a> {
         // State management
         let open_id = self.id.with("open");
         let search_id = self.id.with("search");
+        let highlight_id = self.id.with("highlight");
+        let query_id = self.id.with("query");
+        let labels_id = self.id.with("labels");
 
         let is_open = ui.ctx().data(|d| d.get_temp::<bool>(open_id).unwrap_or(false));
-        let search_text = ui.ctx().data(|d| d.get_temp::<String>(search_id).unwrap_or_default());
+        let mut labels = ui
+            .ctx()
+            .data(|d| d.get_temp::<HashMap<String, String>>(labels_id))
+            .unwrap_or_default();
 
-        // Get selected label for display
-        let selected_label = self.selected.as_ref().and_then(|val| {
-            self.options.iter().find(|o| &o.value == val).map(|o| o.label.clone())
-        });
+        let mut response = match &self.selection {
+            ComboboxSelection::Single(selected) => {
+                // Get selected label for display
+                let selected_label = selected.as_deref().map(|val| self.label_for(&labels, val));
 
-        // Trigger button (44px minimum for Apple HIG touch target)
-        let button_height = 44.0;
-        let (rect, response) = ui.allocate_exact_size(
-            Vec2::new(self.width, button_height),
-            Sense::click(),
-        );
+                // Trigger button (44px minimum for Apple HIG touch target)
+                let button_height = 44.0;
+                let (rect, response) = ui.allocate_exact_size(
+                    Vec2::new(self.width, button_height),
+                    Sense::click(),
+                );
 
-        if ui.is_rect_visible(rect) {
-            self.draw_trigger_button(ui, &theme, rect, &response, &selected_label);
-        }
+                if ui.is_rect_visible(rect) {
+                    self.draw_trigger_button(ui, &theme, rect, &response, &selected_label);
+                }
+                response
+            }
+            ComboboxSelection::Multiple(_) => self.show_chip_trigger(ui, &theme, &labels),
+        };
 
         // Toggle on click
         let mut new_open_state = is_open;
@@ -139,6 +542,10 @@ impl<'a> Combobox<'a> {
             // Clear search when opening
             if new_open_state {
                 ui.ctx().data_mut(|d| d.insert_temp(search_id, String::new()));
+                ui.ctx().data_mut(|d| {
+                    d.remove::<usize>(highlight_id);
+                    d.insert_temp(query_id, QueryState::default());
+                });
             }
         }
 
@@ -146,12 +553,23 @@ impl<'a> Combobox<'a> {
             ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
         }
 
+        // Backspace on the focused trigger removes the last chip
+        if !new_open_state && response.has_focus() && ui.input(|i| i.key_pressed(Key::Backspace)) {
+            if let ComboboxSelection::Multiple(selected) = &mut self.selection {
+                if selected.pop().is_some() {
+                    response.mark_changed();
+                }
+            }
+        }
+
         // Dropdown popup
+        let mut changed = false;
         if new_open_state {
+            let overlay = OverlayStack::register(ui.ctx(), self.id, OverlayKind::Popover);
             let area_id = self.id.with("area");
             let area_response = egui::Area::new(area_id)
                 .order(egui::Order::Foreground)
-                .fixed_pos(rect.left_bottom() + egui::vec2(0.0, 4.0))
+                .fixed_pos(response.rect.left_bottom() + egui::vec2(0.0, 4.0))
                 .show(ui.ctx(), |ui| {
                     egui::Frame::NONE
                         .fill(theme.colors.popover)
@@ -165,9 +583,18 @@ impl<'a> Combobox<'a> {
                             // Force vertical layout
                             ui.vertical(|ui| {
                                 // Search input
-                                let mut current_search = ui.ctx().data(|d| {
+                                let search_before = ui.ctx().data(|d| {
                                     d.get_temp::<String>(search_id).unwrap_or_default()
                                 });
+                                let mut current_search = search_before.clone();
+                                let mut highlight = ui.ctx().data(|d| d.get_temp::<usize>(highlight_id));
+
+                                // List navigation keys, taken before the text edit sees them
+                                let (steps, enter) = ui.input_mut(|i| {
+                                    let up = i.count_and_consume_key(Modifiers::NONE, Key::ArrowUp) as isize;
+                                    let down = i.count_and_consume_key(Modifiers::NONE, Key::ArrowDown) as isize;
+                                    (down - up, i.consume_key(Modifiers::NONE, Key::Enter))
+                                });
 
                                 // Search input field with border
                                 let search_response = ui.horizontal(|ui| {
@@ -180,6 +607,7 @@ impl<'a> Combobox<'a> {
                                         egui::TextEdit::singleline(&mut current_search)
                                             .hint_text(&self.search_placeholder)
                                             .frame(false)
+                                            .return_key(None)
                                             .desired_width(ui.available_width())
                                             .font(egui::FontId::proportional(theme.typography.small().size))
                                     )
@@ -190,8 +618,19 @@ impl<'a> Combobox<'a> {
                                     search_response.request_focus();
                                 }
 
-                                // Save search text
-                                ui.ctx().data_mut(|d| d.insert_temp(search_id, current_search.clone()));
+                                if current_search != search_before {
+                                    highlight = None;
+                                }
+
+                                // Backspace in an empty search removes the last chip
+                                if search_before.is_empty()
+                                    && search_response.has_focus()
+                                    && ui.input(|i| i.key_pressed(Key::Backspace))
+                                {
+                                    if let ComboboxSelection::Multiple(selected) = &mut self.selection {
+                                        changed |= selected.pop().is_some();
+                                    }
+                                }
 
                                 ui.add_space(8.0);
 
@@ -200,21 +639,57 @@ impl<'a> Combobox<'a> {
 
                                 ui.add_space(4.0);
 
-                                // Filter options
-                                let search_lower = current_search.to_lowercase();
-                                let filtered: Vec<_> = self.options.iter()
-                                    .filter(|o| {
-                                        search_lower.is_empty() ||
-                                        o.label.to_lowercase().contains(&search_lower) ||
-                                        o.value.to_lowercase().contains(&search_lower)
-                                    })
+                                // Query the source once the search has settled
+                                let now = ui.input(|i| i.time);
+                                let mut query = ui.ctx().data(|d| d.get_temp::<QueryState>(query_id)).unwrap_or_default();
+                                if current_search != search_before {
+                                    query.changed_at = Some(now);
+                                }
+                                let mut debouncing = false;
+                                if self.fixed_options || query.requested.as_deref() != Some(current_search.as_str()) {
+                                    let remaining = query.changed_at.map_or(0.0, |at| at + self.debounce - now);
+                                    if remaining > 0.0 {
+                                        debouncing = true;
+                                        ui.ctx().request_repaint_after(Duration::from_secs_f64(remaining));
+                                    } else {
+                                        self.source.request(&current_search, 0);
+                                        query.requested = Some(current_search.clone());
+                                        query.page = 0;
+                                    }
+                                }
+                                let status = self.source.poll();
+                                if status == OptionStatus::Loading {
+                                    ui.ctx().request_repaint();
+                                }
+                                let settled = status == OptionStatus::Ready && !debouncing;
+
+                                let mut items: Vec<ListItem<'_>> = self.source.options()
+                                    .iter()
+                                    .map(ListItem::Option)
                                     .collect();
+                                if self.creatable && settled {
+                                    if let Some(value) = create_candidate(self.source.options(), &self.selection, &current_search) {
+                                        items.push(ListItem::Create(value));
+                                    }
+                                }
+
+                                let moved = steps != 0;
+                                for _ in 0..steps.unsigned_abs() {
+                                    highlight = move_highlight(highlight, items.len(), steps.signum());
+                                }
+                                let highlight = highlight.filter(|h| *h < items.len());
+
+                                let mut activated = None;
+                                if enter {
+                                    activated = highlight.or(if items.len() == 1 { Some(0) } else { None });
+                                }
 
                                 // Options list in scroll area
+                                let mut load_more = false;
                                 egui::ScrollArea::vertical()
                                     .max_height(200.0)
                                     .show(ui, |ui| {
-                                        if filtered.is_empty() {
+                                        if items.is_empty() && settled {
                                             // Empty state
                                             ui.add_space(8.0);
                                             ui.label(
@@ -224,32 +699,177 @@ impl<'a> Combobox<'a> {
                                             );
                                             ui.add_space(8.0);
                                         } else {
-                                            for option in filtered {
-                                                let is_selected = self.selected.as_ref() == Some(&option.value);
+                                            for (index, item) in items.iter().enumerate() {
+                                                let (label, value) = match item {
+                                                    ListItem::Option(option) => (option.label.clone(), option.value.as_str()),
+                                                    ListItem::Create(value) => (format!("Create \"{}\"", value), value.as_str()),
+                                                };
+                                                let is_selected = self.selection.contains(value);
+                                                let enabled = self.selection.can_add(value, self.max_selected);
                                                 let item_response = self.draw_option_item(
-                                                    ui, &theme, &option.label, is_selected
+                                                    ui, &theme, &label, is_selected, highlight == Some(index), enabled
                                                 );
 
-                                                if item_response.clicked() {
-                                                    *self.selected = Some(option.value.clone());
-                                                    new_open_state = false;
+                                                if moved && highlight == Some(index) {
+                                                    item_response.scroll_to_me(None);
+                                                }
+                                                if item_response.clicked() && enabled {
+                                                    activated = Some(index);
                                                 }
                                             }
                                         }
+
+                                        match &status {
+                                            OptionStatus::Loading => self.draw_status_row(ui, &theme, None),
+                                            OptionStatus::Failed(error) => self.draw_status_row(ui, &theme, Some(error)),
+                                            // Reaching the end of the list loads the next page
+                                            OptionStatus::Ready if settled && self.source.has_more() => {
+                                                let (rect, _) = ui.allocate_exact_size(Vec2::new(ui.available_width(), 1.0), Sense::hover());
+                                                load_more = ui.is_rect_visible(rect);
+                                            }
+                                            OptionStatus::Ready => {}
+                                        }
                                     });
+
+                                if let Some(item) = activated.and_then(|index| items.get(index)) {
+                                    let value = match item {
+                                        ListItem::Option(option) => option.value.clone(),
+                                        ListItem::Create(value) => value.clone(),
+                                    };
+                                    if self.selection.can_add(&value, self.max_selected) {
+                                        if let ListItem::Option(option) = item {
+                                            labels.insert(option.value.clone(), option.label.clone());
+                                        }
+                                        self.selection.activate(&value, self.max_selected);
+                                        changed = true;
+                                        if let ListItem::Create(value) = item {
+                                            if let Some(on_create) = self.on_create.take() {
+                                                on_create(value);
+                                            }
+                                        }
+                                        match self.selection {
+                                            ComboboxSelection::Single(_) => new_open_state = false,
+                                            // Keep picking: clear the search for the next value
+                                            ComboboxSelection::Multiple(_) => current_search.clear(),
+                                        }
+                                    }
+                                }
+
+                                drop(items);
+                                if load_more {
+                                    query.page += 1;
+                                    self.source.request(&current_search, query.page);
+                                }
+
+                                // Save search text, highlight and query
+                                ui.ctx().data_mut(|d| {
+                                    d.insert_temp(query_id, query);
+                                    d.insert_temp(labels_id, labels.clone());
+                                    d.insert_temp(search_id, current_search);
+                                    match highlight {
+                                        Some(highlight) => d.insert_temp(highlight_id, highlight),
+                                        None => d.remove::<usize>(highlight_id),
+                                    }
+                                });
                             });
                         });
                 });
 
-            // Close when clicking outside
-            if is_open && area_response.response.clicked_elsewhere() && !response.clicked() {
-                new_open_state = false;
+            overlay.raise(ui.ctx(), area_response.response.layer_id);
+
+            // Close on Escape or when clicking outside
+            if is_open {
+                let clicked_outside = !response.clicked()
+                    && overlay.clicked_outside(ui.ctx(), area_response.response.rect);
+                if clicked_outside || overlay.escape_pressed(ui.ctx()) {
+                    new_open_state = false;
+                }
             }
         }
 
         // Save open state
         ui.ctx().data_mut(|d| d.insert_temp(open_id, new_open_state));
+        if !new_open_state {
+            OverlayStack::remove(ui.ctx(), self.id);
+            ui.ctx().data_mut(|d| d.remove::<usize>(highlight_id));
+        }
 
+        if changed {
+            response.mark_changed();
+        }
+        response
+    }
+
+    /// Multi-select trigger: selected values as removable chips
+    ///
+    /// The click area is registered before the chips so the chips' remove
+    /// buttons take precedence; its height comes from the previous frame.
+    fn show_chip_trigger(&mut self, ui: &mut Ui, theme: &ShadcnTheme, labels: &HashMap<String, String>) -> Response {
+        let min_height = 44.0;
+        let height_id = self.id.with("trigger_height");
+        let height = ui
+            .ctx()
+            .data(|d| d.get_temp::<f32>(height_id))
+            .unwrap_or(min_height);
+
+        let (rect, response) = ui.allocate_exact_size(Vec2::new(self.width, height), Sense::click());
+
+        let bg_color = if response.hovered() {
+            theme.colors.foreground.linear_multiply(0.05)
+        } else {
+            theme.colors.background
+        };
+        ui.painter().rect(
+            rect,
+            theme.radii.md,
+            bg_color,
+            egui::Stroke::new(1.0, theme.colors.input),
+            egui::StrokeKind::Inside,
+        );
+        theme.draw_focus_ring(ui.painter(), rect, theme.radii.md, response.has_focus());
+
+        let content_rect = rect.shrink2(Vec2::new(8.0, 8.0)).with_max_x(rect.max.x - 28.0);
+        let mut chips = ui.new_child(
+            egui::UiBuilder::new()
+                .max_rect(content_rect)
+                .layout(egui::Layout::left_to_right(egui::Align::Center).with_main_wrap(true)),
+        );
+        chips.spacing_mut().item_spacing = Vec2::splat(4.0);
+
+        let ComboboxSelection::Multiple(selected) = &self.selection else {
+            return response;
+        };
+        let mut removed = None;
+        for (index, value) in selected.iter().enumerate() {
+            let chip = Badge::new(self.label_for(labels, value)).variant(BadgeVariant::Secondary).removable(true).show(&mut chips);
+            if chip.removed {
+                removed = Some(index);
+            }
+        }
+        if selected.is_empty() {
+            chips.label(
+                egui::RichText::new(&self.placeholder)
+                    .size(theme.typography.small().size)
+                    .color(theme.colors.muted_foreground),
+            );
+        }
+
+        // Grow with the chips (applied next frame)
+        let needed = (chips.min_rect().height() + 16.0).max(min_height);
+        if (needed - height).abs() > 0.5 {
+            ui.ctx().data_mut(|d| d.insert_temp(height_id, needed));
+            ui.ctx().request_repaint();
+        }
+
+        self.draw_chevron(ui, theme, Pos2::new(rect.max.x - 16.0, rect.center().y));
+
+        let mut response = response;
+        if let Some(index) = removed {
+            if let ComboboxSelection::Multiple(selected) = &mut self.selection {
+                selected.remove(index);
+                response.mark_changed();
+            }
+        }
         response
     }
 
@@ -293,8 +913,12 @@ impl<'a> Combobox<'a> {
         );
 
         // Chevron down icon
-        let chevron_x = rect.max.x - 16.0;
-        let chevron_y = rect.center().y;
+        self.draw_chevron(ui, theme, Pos2::new(rect.max.x - 16.0, rect.center().y));
+    }
+
+    fn draw_chevron(&self, ui: &mut Ui, theme: &ShadcnTheme, center: Pos2) {
+        let chevron_x = center.x;
+        let chevron_y = center.y;
         let chevron_size = 4.0;
         let stroke = egui::Stroke::new(1.5, theme.colors.muted_foreground);
 
@@ -357,16 +981,50 @@ impl<'a> Combobox<'a> {
         ui.painter().line_segment([handle_start, handle_end], stroke);
     }
 
-    fn draw_option_item(&self, ui: &mut Ui, theme: &ShadcnTheme, label: &str, is_selected: bool) -> Response {
+    /// Spinner row while loading, or the error when `error` is set
+    fn draw_status_row(&self, ui: &mut Ui, theme: &ShadcnTheme, error: Option<&String>) {
+        ui.horizontal(|ui| {
+            ui.set_min_height(36.0);
+            ui.add_space(8.0);
+            match error {
+                None => {
+                    ui.add(Spinner::new().size(SpinnerSize::Small));
+                    ui.label(
+                        egui::RichText::new("Loading...")
+                            .size(13.0)
+                            .color(theme.colors.muted_foreground),
+                    );
+                }
+                Some(error) => {
+                    ui.label(
+                        egui::RichText::new(error)
+                            .size(13.0)
+                            .color(theme.colors.destructive),
+                    );
+                }
+            }
+        });
+    }
+
+    fn draw_option_item(
+        &self,
+        ui: &mut Ui,
+        theme: &ShadcnTheme,
+        label: &str,
+        is_selected: bool,
+        is_highlighted: bool,
+        enabled: bool,
+    ) -> Response {
         let height = 36.0; // Slightly smaller for dropdown items
         let width = ui.available_width();
-        let (rect, response) = ui.allocate_exact_size(Vec2::new(width, height), Sense::click());
+        let sense = if enabled { Sense::click() } else { Sense::hover() };
+        let (rect, response) = ui.allocate_exact_size(Vec2::new(width, height), sense);
 
         if ui.is_rect_visible(rect) {
             // Background - subtle hover like shadcn
             let bg_color = if is_selected {
                 Some(theme.colors.accent)
-            } else if response.hovered() {
+            } else if (response.hovered() && enabled) || is_highlighted {
                 Some(theme.colors.foreground.linear_multiply(0.08))
             } else {
                 None
@@ -403,6 +1061,8 @@ impl<'a> Combobox<'a> {
             let text_x = rect.min.x + 28.0;
             let text_color = if is_selected {
                 theme.colors.accent_foreground
+            } else if !enabled {
+                theme.colors.muted_foreground
             } else {
                 theme.colors.foreground
             };
@@ -416,7 +1076,7 @@ impl<'a> Combobox<'a> {
             );
         }
 
-        if response.hovered() {
+        if response.hovered() && enabled {
             ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
         }
 
@@ -438,4 +1098,213 @@ mod tests {
         assert_eq!(opt2.value, "val");
         assert_eq!(opt2.label, "Label");
     }
+
+    fn options() -> Vec<ComboboxOption> {
+        vec![
+            ComboboxOption::with_label("nostr", "Nostr"),
+            ComboboxOption::with_label("bitcoin", "Bitcoin"),
+            ComboboxOption::with_label("lightning", "Lightning"),
+        ]
+    }
+
+    #[test]
+    fn test_filter_options() {
+        let options = options();
+        assert_eq!(filter_options(&options, "").len(), 3);
+        let matches: Vec<_> = filter_options(&options, "TN").iter().map(|o| o.value.as_str()).collect();
+        assert_eq!(matches, ["lightning"]);
+        assert!(filter_options(&options, "zap").is_empty());
+    }
+
+    #[test]
+    fn test_static_options_filter_on_request() {
+        let options = options();
+        let mut source = StaticOptions::new(&options);
+        assert_eq!(source.options().len(), 3);
+        source.request("TN", 0);
+        assert_eq!(source.poll(), OptionStatus::Ready);
+        assert_eq!(source.options(), [options[2].clone()]);
+        // Labels resolve even for filtered-out options
+        assert_eq!(source.label_for("nostr"), Some("Nostr"));
+        assert!(!source.has_more());
+    }
+
+    /// A source whose requests complete when the test says so
+    type Replies = std::sync::Arc<std::sync::Mutex<Vec<(String, usize)>>>;
+    type Pending = std::sync::Arc<std::sync::Mutex<Option<Result<OptionPage, String>>>>;
+
+    fn manual_source(requests: Replies, reply: Pending) -> AsyncOptions<impl FnMut(&str, usize) -> OptionsFuture> {
+        AsyncOptions::new(move |search: &str, page: usize| {
+            requests.lock().unwrap().push((search.to_string(), page));
+            let reply = reply.clone();
+            Box::pin(std::future::poll_fn(move |_| match reply.lock().unwrap().take() {
+                Some(result) => Poll::Ready(result),
+                None => Poll::Pending,
+            })) as OptionsFuture
+        })
+    }
+
+    #[test]
+    fn test_async_options_pages_and_errors() {
+        let requests = Replies::default();
+        let reply = Pending::default();
+        let mut source = manual_source(requests.clone(), reply.clone());
+
+        source.request("n", 0);
+        assert_eq!(source.poll(), OptionStatus::Loading);
+        *reply.lock().unwrap() = Some(Ok(OptionPage { options: options()[..2].to_vec(), has_more: true }));
+        assert_eq!(source.poll(), OptionStatus::Ready);
+        assert!(source.has_more());
+
+        // Later pages append
+        source.request("n", 1);
+        *reply.lock().unwrap() = Some(Ok(OptionPage { options: options()[2..].to_vec(), has_more: false }));
+        assert_eq!(source.poll(), OptionStatus::Ready);
+        assert_eq!(source.options().len(), 3);
+        assert!(!source.has_more());
+
+        // A new search replaces the results
+        source.request("x", 0);
+        assert!(source.options().is_empty());
+        *reply.lock().unwrap() = Some(Err("relay offline".to_string()));
+        assert_eq!(source.poll(), OptionStatus::Failed("relay offline".to_string()));
+        assert_eq!(*requests.lock().unwrap(), [("n".to_string(), 0), ("n".to_string(), 1), ("x".to_string(), 0)]);
+    }
+
+    #[test]
+    fn test_source_queries_are_debounced() {
+        use crate::test_util::{click_events, context_with_motion, key_press, run_frame_with_events};
+        use crate::theme::MotionPreference;
+        use egui::Event;
+
+        let ctx = context_with_motion(MotionPreference::Full);
+        let requests = Replies::default();
+        let reply = Pending::default();
+        let mut source = manual_source(requests.clone(), reply.clone());
+        let mut author = None;
+        let mut frame = |time: f64, events: Vec<Event>, author: &mut Option<String>| {
+            let mut rect = egui::Rect::NOTHING;
+            run_frame_with_events(&ctx, time, events, |ui| {
+                rect = Combobox::new("author", &[], author).source(&mut source).show(ui).rect;
+            });
+            rect
+        };
+
+        let rect = frame(0.0, Vec::new(), &mut author);
+        // Opening queries right away
+        frame(0.1, click_events(rect.center()), &mut author);
+        assert_eq!(requests.lock().unwrap().len(), 1);
+
+        // Typing waits for the debounce
+        frame(0.2, vec![Event::Text("n".into())], &mut author);
+        frame(0.3, vec![Event::Text("o".into())], &mut author);
+        assert_eq!(requests.lock().unwrap().len(), 1);
+        frame(0.6, Vec::new(), &mut author);
+        assert_eq!(requests.lock().unwrap().last(), Some(&("no".to_string(), 0)));
+
+        *reply.lock().unwrap() = Some(Ok(OptionPage { options: options(), has_more: false }));
+        frame(0.7, Vec::new(), &mut author);
+        frame(0.8, vec![key_press(Key::ArrowDown, Modifiers::NONE), key_press(Key::Enter, Modifiers::NONE)], &mut author);
+        assert_eq!(author.as_deref(), Some("nostr"));
+    }
+
+    #[test]
+    fn test_fixed_options_stay_filtered() {
+        use crate::test_util::{click_events, context_with_motion, key_press, run_frame_with_events};
+        use crate::theme::MotionPreference;
+        use egui::Event;
+
+        let ctx = context_with_motion(MotionPreference::Full);
+        let options = options();
+        let mut network = None;
+        let frame = |time: f64, events: Vec<Event>, network: &mut Option<String>| {
+            let mut rect = egui::Rect::NOTHING;
+            run_frame_with_events(&ctx, time, events, |ui| {
+                rect = Combobox::new("network", &options, network).show(ui).rect;
+            });
+            rect
+        };
+
+        let rect = frame(0.0, Vec::new(), &mut network);
+        frame(0.1, click_events(rect.center()), &mut network);
+        frame(0.2, vec![Event::Text("light".into())], &mut network);
+        frame(0.3, Vec::new(), &mut network);
+        frame(0.4, Vec::new(), &mut network);
+        // Still a single match frames later, so Enter picks it
+        frame(0.5, vec![key_press(Key::Enter, Modifiers::NONE)], &mut network);
+        assert_eq!(network.as_deref(), Some("lightning"));
+    }
+
+    #[test]
+    fn test_create_candidate() {
+        let options = options();
+        let mut tags = vec!["zaps".to_string()];
+        let selection = ComboboxSelection::Multiple(&mut tags);
+        assert_eq!(create_candidate(&options, &selection, " plebs "), Some("plebs".to_string()));
+        // Existing options, labels and selected values aren't offered again
+        assert_eq!(create_candidate(&options, &selection, "bitcoin"), None);
+        assert_eq!(create_candidate(&options, &selection, "Nostr"), None);
+        assert_eq!(create_candidate(&options, &selection, "zaps"), None);
+        assert_eq!(create_candidate(&options, &selection, "  "), None);
+    }
+
+    #[test]
+    fn test_move_highlight() {
+        assert_eq!(move_highlight(None, 3, 1), Some(0));
+        assert_eq!(move_highlight(None, 3, -1), Some(2));
+        assert_eq!(move_highlight(Some(2), 3, 1), Some(0));
+        assert_eq!(move_highlight(Some(0), 3, -1), Some(2));
+        assert_eq!(move_highlight(Some(1), 0, 1), None);
+    }
+
+    #[test]
+    fn test_multiple_selection_toggles_and_respects_max() {
+        let mut tags = Vec::new();
+        let mut selection = ComboboxSelection::Multiple(&mut tags);
+        selection.activate("nostr", Some(2));
+        selection.activate("bitcoin", Some(2));
+        // Full: new values are refused, selected ones can still be removed
+        assert!(!selection.can_add("lightning", Some(2)));
+        selection.activate("lightning", Some(2));
+        selection.activate("nostr", Some(2));
+        assert_eq!(tags, ["bitcoin"]);
+
+        let mut selected = Some("nostr".to_string());
+        let mut selection = ComboboxSelection::Single(&mut selected);
+        selection.activate("bitcoin", Some(0));
+        assert_eq!(selected.as_deref(), Some("bitcoin"));
+    }
+
+    #[test]
+    fn test_keyboard_selects_highlighted_option() {
+        use crate::test_util::{click_events, context_with_motion, key_press, run_frame_with_events};
+        use crate::theme::MotionPreference;
+        use egui::Event;
+
+        let ctx = context_with_motion(MotionPreference::Full);
+        let options = options();
+        let mut tags = Vec::new();
+        let key = |key: Key| key_press(key, Modifiers::NONE);
+        let frame = |time: f64, events: Vec<Event>, tags: &mut Vec<String>| {
+            let mut rect = egui::Rect::NOTHING;
+            run_frame_with_events(&ctx, time, events, |ui| {
+                rect = Combobox::multiple("tags", &options, tags).show(ui).rect;
+            });
+            rect
+        };
+
+        let rect = frame(0.0, Vec::new(), &mut tags);
+        frame(0.1, click_events(rect.center()), &mut tags);
+        frame(0.2, vec![key(Key::ArrowDown), key(Key::ArrowDown)], &mut tags);
+        frame(0.3, vec![key(Key::Enter)], &mut tags);
+        assert_eq!(tags, ["bitcoin"]);
+
+        // Still open: pick another one
+        frame(0.4, vec![key(Key::ArrowUp), key(Key::Enter)], &mut tags);
+        assert_eq!(tags, ["bitcoin", "nostr"]);
+
+        // Backspace in the empty search removes the last chip
+        frame(0.5, vec![key(Key::Backspace)], &mut tags);
+        assert_eq!(tags, ["bitcoin"]);
+    }
 }
@@ -2,9 +2,16 @@
 //!
 //! A command palette / quick action menu (Cmd+K style).
 //!
+//! Item shortcuts are [`KeyboardShortcut`]s: they are rendered for the
+//! current platform and run their item even while the palette is closed.
+//! They are registered in the [`ShortcutRegistry`] under the palette's name,
+//! so they are listed in its help dialog.
+//!
 //! Reference: <https://ui.shadcn.com/docs/components/command>
 
-use egui::{Id, Ui, Sense, Vec2, Color32};
+use egui::{Id, KeyboardShortcut, Ui, Sense, Vec2};
+use crate::overlay::{OverlayKind, OverlayStack};
+use crate::shortcuts::{Shortcut, ShortcutRegistry, ShortcutScope};
 use crate::theme::ShadcnTheme;
 
 /// Command palette component
@@ -21,12 +28,12 @@ use crate::theme::ShadcnTheme;
 ///
 /// Command::new("cmd", &mut open, &mut search)
 ///     .group("Suggestions", |cmd| {
-///         cmd.item("Calendar", || println!("Open calendar"))
-///            .item("Search Emoji", || println!("Search emoji"))
+///         cmd.item("Calendar")
+///            .item("Search Emoji")
 ///     })
 ///     .group("Settings", |cmd| {
-///         cmd.item("Profile", || println!("Open profile"))
-///            .item("Settings", || println!("Open settings"))
+///         cmd.item_with_shortcut("Profile", KeyboardShortcut::new(Modifiers::COMMAND, Key::P))
+///            .item("Settings")
 ///     })
 ///     .show(ui);
 /// ```
@@ -34,6 +41,7 @@ pub struct Command<'a> {
     id: Id,
     open: &'a mut bool,
     search: &'a mut String,
+    name: String,
     placeholder: String,
     groups: Vec<CommandGroup>,
     width: f32,
@@ -49,7 +57,7 @@ struct CommandGroup {
 /// A single command item
 struct CommandItem {
     label: String,
-    shortcut: Option<String>,
+    shortcut: Option<KeyboardShortcut>,
     icon: Option<String>,
 }
 
@@ -60,6 +68,7 @@ impl<'a> Command<'a> {
             id: id.into(),
             open,
             search,
+            name: "Command palette".to_string(),
             placeholder: "Type a command or search...".to_string(),
             groups: Vec::new(),
             width: 500.0,
@@ -67,6 +76,14 @@ impl<'a> Command<'a> {
         }
     }
 
+    /// Set the name heading the item shortcuts in the shortcuts help dialog
+    ///
+    /// Give each palette its own name (default: "Command palette").
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
     /// Set the search placeholder text
     pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
         self.placeholder = placeholder.into();
@@ -98,12 +115,25 @@ impl<'a> Command<'a> {
 
     /// Show the command palette
     ///
-    /// Returns the index of the selected item as (group_index, item_index) if any
+    /// Returns the index of the selected item as (group_index, item_index) if
+    /// any, whether it was clicked or its shortcut was pressed.
+    ///
+    /// Item shortcuts are only checked here, so call this every frame, also
+    /// while the palette is closed; it draws nothing then.
     pub fn show(self, ui: &mut Ui) -> Option<(usize, usize)> {
+        if let Some(pressed) = self.consume_shortcuts(ui.ctx()) {
+            *self.open = false;
+            OverlayStack::remove(ui.ctx(), self.id);
+            return Some(pressed);
+        }
+
         if !*self.open {
+            OverlayStack::remove(ui.ctx(), self.id);
             return None;
         }
 
+        let overlay = OverlayStack::register(ui.ctx(), self.id, OverlayKind::Modal);
+
         let theme = ui.ctx().data(|d| {
             d.get_temp::<ShadcnTheme>(Id::new("shadcn_theme"))
                 .unwrap_or_else(ShadcnTheme::light)
@@ -114,12 +144,7 @@ impl<'a> Command<'a> {
         let mut selected_item = None;
 
         // Draw backdrop
-        let backdrop_layer = egui::LayerId::new(egui::Order::Middle, self.id.with("backdrop"));
-        ui.ctx().layer_painter(backdrop_layer).rect_filled(
-            screen_rect,
-            0.0,
-            Color32::from_black_alpha(100),
-        );
+        overlay.paint_backdrop(ui.ctx(), screen_rect, 100);
 
         // Calculate position (centered, near top)
         let dialog_pos = egui::pos2(
@@ -128,7 +153,7 @@ impl<'a> Command<'a> {
         );
 
         // Draw command palette
-        egui::Area::new(self.id.with("dialog"))
+        let area_response = egui::Area::new(self.id.with("dialog"))
             .order(egui::Order::Foreground)
             .fixed_pos(dialog_pos)
             .show(ui.ctx(), |ui| {
@@ -277,7 +302,7 @@ impl<'a> Command<'a> {
                                                     item_response.rect.center().y,
                                                 ),
                                                 egui::Align2::RIGHT_CENTER,
-                                                shortcut,
+                                                crate::shortcuts::format_shortcut(ui.ctx(), shortcut),
                                                 egui::FontId::proportional(theme.typography.small().size - 2.0),
                                                 theme.colors.muted_foreground,
                                             );
@@ -314,13 +339,37 @@ impl<'a> Command<'a> {
                 });
             });
 
-        // Handle escape key
-        if ui.ctx().input(|i| i.key_pressed(egui::Key::Escape)) {
+        overlay.raise(ui.ctx(), area_response.response.layer_id);
+
+        // Handle escape key (only when this is the top-most overlay)
+        if overlay.escape_pressed(ui.ctx()) {
             *self.open = false;
         }
 
+        if !*self.open {
+            OverlayStack::remove(ui.ctx(), self.id);
+        }
+
         selected_item
     }
+
+    fn consume_shortcuts(&self, ctx: &egui::Context) -> Option<(usize, usize)> {
+        let (indices, shortcuts): (Vec<_>, Vec<_>) = self.groups.iter()
+            .enumerate()
+            .flat_map(|(group_idx, group)| {
+                group.items.iter()
+                    .enumerate()
+                    .filter_map(move |(item_idx, item)| {
+                        let id = format!("{group_idx}.{item_idx}");
+                        let shortcut = Shortcut::new(id, item.label.clone(), item.shortcut?);
+                        Some(((group_idx, item_idx), shortcut))
+                    })
+            })
+            .unzip();
+        let scope = ShortcutScope::Menu(self.name.clone());
+        ShortcutRegistry::sync_menu(ctx, &scope, shortcuts);
+        ShortcutRegistry::dispatch_menu(ctx, &scope).map(|pressed| indices[pressed])
+    }
 }
 
 /// Builder for command group items
@@ -339,11 +388,11 @@ impl CommandGroupBuilder {
         self
     }
 
-    /// Add a command item with shortcut
-    pub fn item_with_shortcut(&mut self, label: impl Into<String>, shortcut: impl Into<String>) -> &mut Self {
+    /// Add a command item with a shortcut that selects it even while the palette is closed
+    pub fn item_with_shortcut(&mut self, label: impl Into<String>, shortcut: KeyboardShortcut) -> &mut Self {
         self.items.push(CommandItem {
             label: label.into(),
-            shortcut: Some(shortcut.into()),
+            shortcut: Some(shortcut),
             icon: None,
         });
         self
@@ -376,4 +425,30 @@ mod tests {
         assert_eq!(cmd.placeholder, "Search...");
         assert_eq!(cmd.width, 600.0);
     }
+
+    #[test]
+    fn test_shortcut_selects_item_while_closed() {
+        use crate::test_util::{context_with_motion, key_press, run_frame_with_events};
+        use crate::theme::MotionPreference;
+        use egui::{Key, Modifiers};
+
+        let ctx = context_with_motion(MotionPreference::Full);
+        let mut open = false;
+        let mut search = String::new();
+        let mut selected = None;
+        run_frame_with_events(&ctx, 0.0, vec![key_press(Key::B, Modifiers::COMMAND)], |ui| {
+            selected = Command::new("cmd", &mut open, &mut search)
+                .group("Settings", |cmd| {
+                    cmd.item_with_shortcut("Profile", KeyboardShortcut::new(Modifiers::COMMAND, Key::P))
+                        .item_with_shortcut("Billing", KeyboardShortcut::new(Modifiers::COMMAND, Key::B));
+                })
+                .show(ui);
+        });
+        assert_eq!(selected, Some((0, 1)));
+        assert!(!open);
+
+        let registry = ShortcutRegistry::load(&ctx);
+        let listed: Vec<(&str, &str)> = registry.shortcuts().iter().map(|s| (s.scope.name(), s.label.as_str())).collect();
+        assert_eq!(listed, vec![("Command palette", "Profile"), ("Command palette", "Billing")]);
+    }
 }
@@ -4,7 +4,9 @@
 //!
 //! Reference: <https://ui.shadcn.com/docs/components/context-menu>
 
-use egui::{Id, Response, Ui, Sense, Pos2};
+use egui::{Id, Response, Ui, Pos2, Rect};
+use crate::components::menu::{show_menu, MenuLayout, MenuModel};
+use crate::overlay::{OverlayKind, OverlayStack};
 use crate::theme::ShadcnTheme;
 
 /// Context Menu component for right-click menus
@@ -12,99 +14,37 @@ use crate::theme::ShadcnTheme;
 /// ## Example
 /// ```rust,ignore
 /// // Wrap content that should have a context menu
-/// let response = ContextMenu::new("my_context")
-///     .item("Copy", || println!("Copy"))
-///     .item("Paste", || println!("Paste"))
+/// let menu = MenuModel::new()
+///     .item(MenuItem::new("copy", "Copy"))
+///     .item(MenuItem::new("paste", "Paste"))
 ///     .separator()
-///     .item("Delete", || println!("Delete"))
-///     .show(ui, |ui| {
-///         ui.label("Right-click me!");
-///     });
+///     .item(MenuItem::new("delete", "Delete").destructive(true));
+///
+/// let response = ContextMenu::new("my_context", menu).show(ui, |ui| {
+///     ui.label("Right-click me!");
+/// });
+/// if let Some(action) = response.action {
+///     handle(action);
+/// }
 /// ```
-pub struct ContextMenu<'a> {
+pub struct ContextMenu<'a, A = &'static str> {
     id: &'a str,
-    items: Vec<ContextMenuItem>,
+    menu: MenuModel<'a, A>,
     width: f32,
 }
 
-/// A context menu item type
-enum ContextMenuItem {
-    Action {
-        label: String,
-        shortcut: Option<String>,
-        enabled: bool,
-        destructive: bool,
-    },
-    Separator,
-    Label(String),
-}
-
-impl<'a> ContextMenu<'a> {
-    /// Create a new context menu
-    pub fn new(id: &'a str) -> Self {
+impl<'a, A: Clone> ContextMenu<'a, A> {
+    /// Create a new context menu showing `menu`
+    ///
+    /// `id` also heads the menu's shortcuts in the shortcuts help dialog.
+    pub fn new(id: &'a str, menu: MenuModel<'a, A>) -> Self {
         Self {
             id,
-            items: Vec::new(),
+            menu,
             width: 180.0,
         }
     }
 
-    /// Add a menu item
-    pub fn item(mut self, label: impl Into<String>) -> Self {
-        self.items.push(ContextMenuItem::Action {
-            label: label.into(),
-            shortcut: None,
-            enabled: true,
-            destructive: false,
-        });
-        self
-    }
-
-    /// Add a menu item with keyboard shortcut
-    pub fn item_with_shortcut(mut self, label: impl Into<String>, shortcut: impl Into<String>) -> Self {
-        self.items.push(ContextMenuItem::Action {
-            label: label.into(),
-            shortcut: Some(shortcut.into()),
-            enabled: true,
-            destructive: false,
-        });
-        self
-    }
-
-    /// Add a destructive (red) menu item
-    pub fn destructive_item(mut self, label: impl Into<String>) -> Self {
-        self.items.push(ContextMenuItem::Action {
-            label: label.into(),
-            shortcut: None,
-            enabled: true,
-            destructive: true,
-        });
-        self
-    }
-
-    /// Add a disabled menu item
-    pub fn disabled_item(mut self, label: impl Into<String>) -> Self {
-        self.items.push(ContextMenuItem::Action {
-            label: label.into(),
-            shortcut: None,
-            enabled: false,
-            destructive: false,
-        });
-        self
-    }
-
-    /// Add a separator
-    pub fn separator(mut self) -> Self {
-        self.items.push(ContextMenuItem::Separator);
-        self
-    }
-
-    /// Add a label/header
-    pub fn label(mut self, text: impl Into<String>) -> Self {
-        self.items.push(ContextMenuItem::Label(text.into()));
-        self
-    }
-
     /// Set the menu width
     pub fn width(mut self, width: f32) -> Self {
         self.width = width;
@@ -113,12 +53,12 @@ impl<'a> ContextMenu<'a> {
 
     /// Show the context menu for content
     ///
-    /// Returns the response from the content area and the index of any clicked item.
+    /// Returns the response from the content area and the chosen action, if any.
     pub fn show<R>(
-        self,
+        mut self,
         ui: &mut Ui,
         content: impl FnOnce(&mut Ui) -> R,
-    ) -> ContextMenuResponse<R> {
+    ) -> ContextMenuResponse<R, A> {
         let theme = ui.ctx().data(|d| {
             d.get_temp::<ShadcnTheme>(Id::new("shadcn_theme"))
                 .unwrap_or_else(ShadcnTheme::light)
@@ -149,166 +89,40 @@ impl<'a> ContextMenu<'a> {
             }
         }
 
-        let mut clicked_item = None;
+        // Shortcuts work whether or not the menu is open
+        let mut action = self.menu.consume_shortcuts(ui.ctx(), self.id);
 
         // Show menu if open
+        let menu_id = id.with("menu");
         if let Some(pos) = menu_pos {
-            let menu_id = id.with("menu");
-
-            let area_response = egui::Area::new(menu_id)
-                .order(egui::Order::Foreground)
-                .fixed_pos(pos)
-                .show(ui.ctx(), |ui| {
-                    let frame = egui::Frame::NONE
-                        .fill(theme.colors.popover)
-                        .stroke(egui::Stroke::new(1.0, theme.colors.border))
-                        .corner_radius(theme.radii.md)
-                        .shadow(theme.shadows.md)
-                        .inner_margin(4.0);
-
-                    frame.show(ui, |ui| {
-                        ui.set_min_width(self.width);
-
-                        let mut action_index = 0;
-
-                        for item in &self.items {
-                            match item {
-                                ContextMenuItem::Action { label, shortcut, enabled, destructive } => {
-                                    let current_index = action_index;
-                                    action_index += 1;
-
-                                    let item_response = ui.allocate_response(
-                                        egui::vec2(ui.available_width(), 32.0),
-                                        if *enabled { Sense::click() } else { Sense::hover() },
-                                    );
-
-                                    if item_response.clicked() && *enabled {
-                                        clicked_item = Some(current_index);
-                                        // Close menu
-                                        ui.ctx().data_mut(|d| d.remove::<Pos2>(menu_state_id));
-                                    }
-
-                                    if ui.is_rect_visible(item_response.rect) {
-                                        let hovered = item_response.hovered() && *enabled;
-
-                                        // Hover background
-                                        if hovered {
-                                            ui.painter().rect_filled(
-                                                item_response.rect,
-                                                theme.radii.sm,
-                                                if *destructive {
-                                                    theme.colors.destructive.linear_multiply(0.1)
-                                                } else {
-                                                    theme.colors.accent
-                                                },
-                                            );
-                                        }
-
-                                        // Text color
-                                        let text_color = if *destructive {
-                                            if *enabled {
-                                                theme.colors.destructive
-                                            } else {
-                                                theme.colors.destructive.linear_multiply(0.5)
-                                            }
-                                        } else if *enabled {
-                                            if hovered {
-                                                theme.colors.accent_foreground
-                                            } else {
-                                                theme.colors.popover_foreground
-                                            }
-                                        } else {
-                                            theme.colors.popover_foreground.linear_multiply(0.5)
-                                        };
-
-                                        // Label
-                                        ui.painter().text(
-                                            egui::pos2(
-                                                item_response.rect.min.x + 8.0,
-                                                item_response.rect.center().y,
-                                            ),
-                                            egui::Align2::LEFT_CENTER,
-                                            label,
-                                            egui::FontId::proportional(theme.typography.small().size),
-                                            text_color,
-                                        );
-
-                                        // Shortcut
-                                        if let Some(shortcut) = shortcut {
-                                            ui.painter().text(
-                                                egui::pos2(
-                                                    item_response.rect.max.x - 8.0,
-                                                    item_response.rect.center().y,
-                                                ),
-                                                egui::Align2::RIGHT_CENTER,
-                                                shortcut,
-                                                egui::FontId::proportional(theme.typography.small().size - 1.0),
-                                                theme.colors.muted_foreground,
-                                            );
-                                        }
-                                    }
-                                }
-                                ContextMenuItem::Separator => {
-                                    let separator_rect = ui.allocate_space(egui::vec2(ui.available_width(), 9.0)).1;
-                                    ui.painter().line_segment(
-                                        [
-                                            egui::pos2(separator_rect.min.x + 4.0, separator_rect.center().y),
-                                            egui::pos2(separator_rect.max.x - 4.0, separator_rect.center().y),
-                                        ],
-                                        egui::Stroke::new(1.0, theme.colors.border),
-                                    );
-                                }
-                                ContextMenuItem::Label(text) => {
-                                    let label_response = ui.allocate_response(
-                                        egui::vec2(ui.available_width(), 24.0),
-                                        Sense::hover(),
-                                    );
-
-                                    if ui.is_rect_visible(label_response.rect) {
-                                        ui.painter().text(
-                                            egui::pos2(
-                                                label_response.rect.min.x + 8.0,
-                                                label_response.rect.center().y,
-                                            ),
-                                            egui::Align2::LEFT_CENTER,
-                                            text,
-                                            egui::FontId::proportional(theme.typography.small().size - 1.0),
-                                            theme.colors.muted_foreground,
-                                        );
-                                    }
-                                }
-                            }
-                        }
-                    });
-                });
-
-            // Close menu when clicking outside
-            if area_response.response.clicked_elsewhere() {
+            let layout = MenuLayout { width: self.width, row_height: 32.0 };
+            let output = show_menu(ui.ctx(), &theme, menu_id, &mut self.menu, pos, Rect::NOTHING, layout);
+            if output.action.is_some() || output.dismissed {
                 ui.ctx().data_mut(|d| d.remove::<Pos2>(menu_state_id));
             }
+            action = action.or(output.action);
+        }
 
-            // Close on escape
-            if ui.ctx().input(|i| i.key_pressed(egui::Key::Escape)) {
-                ui.ctx().data_mut(|d| d.remove::<Pos2>(menu_state_id));
-            }
+        if ui.ctx().data(|d| d.get_temp::<Pos2>(menu_state_id)).is_none() {
+            OverlayStack::remove(ui.ctx(), menu_id);
         }
 
         ContextMenuResponse {
             inner,
             response,
-            clicked_item,
+            action,
         }
     }
 }
 
 /// Response from showing a context menu
-pub struct ContextMenuResponse<R> {
+pub struct ContextMenuResponse<R, A = &'static str> {
     /// The return value from the content closure
     pub inner: R,
     /// The response from the content area
     pub response: Response,
-    /// Index of clicked menu item, if any (only counts Action items, not separators/labels)
-    pub clicked_item: Option<usize>,
+    /// Action chosen from the menu or by its shortcut this frame, if any
+    pub action: Option<A>,
 }
 
 /// Extension trait for adding context menus to responses
@@ -350,6 +164,7 @@ impl ContextMenuExt for Response {
             });
 
             let menu_id = id.with("menu");
+            let overlay = OverlayStack::register(ui.ctx(), menu_id, OverlayKind::Popover);
             let mut result = None;
 
             let area_response = egui::Area::new(menu_id)
@@ -368,14 +183,14 @@ impl ContextMenuExt for Response {
                     });
                 });
 
-            // Close menu when clicking outside
-            if area_response.response.clicked_elsewhere() {
-                ui.ctx().data_mut(|d| d.remove::<Pos2>(menu_state_id));
-            }
+            overlay.raise(ui.ctx(), area_response.response.layer_id);
 
-            // Close on escape
-            if ui.ctx().input(|i| i.key_pressed(egui::Key::Escape)) {
+            // Close menu on Escape or when clicking outside
+            if overlay.clicked_outside(ui.ctx(), area_response.response.rect)
+                || overlay.escape_pressed(ui.ctx())
+            {
                 ui.ctx().data_mut(|d| d.remove::<Pos2>(menu_state_id));
+                OverlayStack::remove(ui.ctx(), menu_id);
             }
 
             return result;
@@ -388,15 +203,16 @@ impl ContextMenuExt for Response {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::components::menu::MenuItem;
 
     #[test]
     fn test_context_menu_creation() {
-        let menu = ContextMenu::new("test")
-            .item("Copy")
+        let menu = ContextMenu::new("test", MenuModel::new()
+            .item(MenuItem::new("copy", "Copy"))
             .separator()
-            .item("Paste")
-            .destructive_item("Delete");
+            .item(MenuItem::new("paste", "Paste"))
+            .item(MenuItem::new("delete", "Delete").destructive(true)));
 
-        assert_eq!(menu.items.len(), 4);
+        assert_eq!(menu.menu.len(), 4);
     }
 }
@@ -1,30 +1,53 @@
 //! DatePicker component ported from shadcn/ui
 //!
-//! A date picker combining a button trigger with a calendar popover.
+//! A date picker combining a button trigger with a calendar popover, plus
+//! range ([`DateRangePicker`]) and date-and-time ([`DateTimePicker`]) variants.
 //!
 //! Reference: <https://ui.shadcn.com/docs/components/date-picker>
 
-use egui::{Id, Response, Ui, Sense, Vec2, Pos2};
+use chrono::{Datelike, Days, Months, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use egui::{Id, Pos2, Rect, Response, Sense, TextEdit, Ui, Vec2};
+use crate::overlay::{OverlayKind, OverlayStack};
 use crate::theme::ShadcnTheme;
-use crate::components::calendar::{Calendar, CalendarSelection};
+use crate::components::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::calendar::{Calendar, CalendarLocale, CalendarMode, CalendarSelection, EnglishLocale};
+use crate::components::input::input_border;
+
+const TRIGGER_HEIGHT: f32 = 36.0;
 
 /// DatePicker component for date selection
 ///
+/// With [`DatePicker::editable`] the trigger becomes a text field: typed dates
+/// are parsed leniently against the display format, and text that isn't a
+/// selectable date gets the error border until it is fixed.
+///
 /// ## Example
 /// ```rust,ignore
 /// let mut selected_date: Option<chrono::NaiveDate> = None;
 ///
 /// DatePicker::new("my_datepicker", &mut selected_date)
 ///     .placeholder("Pick a date")
+///     .locale(&EnglishLocale::GB)
+///     .editable(true)
 ///     .show(ui);
 /// ```
 pub struct DatePicker<'a> {
     id: Id,
     selected: &'a mut Option<chrono::NaiveDate>,
     placeholder: String,
-    format: String,
+    format: Option<String>,
     min_date: Option<chrono::NaiveDate>,
     max_date: Option<chrono::NaiveDate>,
+    locale: &'a dyn CalendarLocale,
+    show_week_numbers: bool,
+    editable: bool,
+}
+
+/// Text typed into an editable DatePicker that hasn't been committed yet
+#[derive(Clone, Default)]
+struct TypedDate {
+    text: String,
+    invalid: bool,
 }
 
 impl<'a> DatePicker<'a> {
@@ -34,9 +57,12 @@ impl<'a> DatePicker<'a> {
             id: Id::new(id),
             selected,
             placeholder: "Pick a date".to_string(),
-            format: "%B %d, %Y".to_string(),
+            format: None,
             min_date: None,
             max_date: None,
+            locale: &EnglishLocale::US,
+            show_week_numbers: false,
+            editable: false,
         }
     }
 
@@ -47,8 +73,30 @@ impl<'a> DatePicker<'a> {
     }
 
     /// Set the date format string (uses chrono format specifiers)
+    ///
+    /// Defaults to the locale's date format. Month and day names follow the locale.
     pub fn format(mut self, format: impl Into<String>) -> Self {
-        self.format = format.into();
+        self.format = Some(format.into());
+        self
+    }
+
+    /// Set the locale for the calendar and the displayed date
+    pub fn locale(mut self, locale: &'a dyn CalendarLocale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Show ISO week numbers in the calendar
+    pub fn show_week_numbers(mut self, show: bool) -> Self {
+        self.show_week_numbers = show;
+        self
+    }
+
+    /// Let the user type a date into the trigger (default: false)
+    ///
+    /// The calendar icon still opens the popover.
+    pub fn editable(mut self, editable: bool) -> Self {
+        self.editable = editable;
         self
     }
 
@@ -65,193 +113,843 @@ impl<'a> DatePicker<'a> {
     }
 
     /// Show the date picker
-    pub fn show(self, ui: &mut Ui) -> Response {
+    pub fn show(mut self, ui: &mut Ui) -> Response {
         let theme = ui.ctx().data(|d| {
             d.get_temp::<ShadcnTheme>(Id::new("shadcn_theme"))
                 .unwrap_or_else(ShadcnTheme::light)
         });
+        let format = self.format.clone().unwrap_or_else(|| self.locale.date_format().to_string());
 
-        // Use simple boolean state in memory (same pattern as Popover)
-        let open_id = self.id.with("open");
-        let is_open = ui.ctx().data(|d| d.get_temp::<bool>(open_id).unwrap_or(false));
-
-        // Trigger button
-        let button_text = match self.selected {
-            Some(date) => date.format(&self.format).to_string(),
-            None => self.placeholder.clone(),
+        let (anchor, mut response, toggled) = if self.editable {
+            self.typed_trigger(ui, &theme, &format)
+        } else {
+            let text = match self.selected {
+                Some(date) => self.locale.format_date(*date, &format),
+                None => self.placeholder.clone(),
+            };
+            let response = draw_trigger(ui, &theme, &text, self.selected.is_some(), 240.0);
+            let clicked = response.clicked();
+            (response.rect, response, clicked)
         };
 
-        let text_color = if self.selected.is_some() {
+        let mut changed = false;
+        picker_popover(ui, &theme, self.id, anchor, toggled, |ui, _| {
+            // Convert Option<NaiveDate> to CalendarSelection
+            let mut calendar_selection = match *self.selected {
+                Some(date) => CalendarSelection::Single(date),
+                None => CalendarSelection::None,
+            };
+
+            // View date state - stored in memory
+            let view_date_id = self.id.with("view_date");
+            let mut view_date = ui.ctx().data(|d| {
+                d.get_temp::<chrono::NaiveDate>(view_date_id)
+                    .unwrap_or_else(|| self.selected.unwrap_or_else(|| chrono::Local::now().date_naive()))
+            });
+
+            let mut calendar = Calendar::new(self.id.with("calendar"), &mut calendar_selection, &mut view_date)
+                .locale(self.locale)
+                .show_week_numbers(self.show_week_numbers);
+
+            if let Some(min) = self.min_date {
+                calendar = calendar.min_date(min);
+            }
+            if let Some(max) = self.max_date {
+                calendar = calendar.max_date(max);
+            }
+
+            calendar.show(ui);
+
+            // Save view date
+            ui.ctx().data_mut(|d| d.insert_temp(view_date_id, view_date));
+
+            // Update selected date from calendar selection, closing on selection
+            match calendar_selection {
+                CalendarSelection::Single(date) if *self.selected != Some(date) => {
+                    *self.selected = Some(date);
+                    // A picked date replaces whatever was typed
+                    ui.ctx().data_mut(|d| d.remove::<TypedDate>(self.id.with("typed")));
+                    changed = true;
+                    true
+                }
+                _ => false,
+            }
+        });
+
+        if changed {
+            response.mark_changed();
+        }
+        response
+    }
+
+    /// Draw the text field trigger, returning its rect, the text edit response
+    /// and whether the calendar icon was clicked
+    fn typed_trigger(&mut self, ui: &mut Ui, theme: &ShadcnTheme, format: &str) -> (Rect, Response, bool) {
+        let typed_id = self.id.with("typed");
+        let (rect, _) = ui.allocate_exact_size(Vec2::new(240.0, TRIGGER_HEIGHT), Sense::hover());
+        let icon_rect = Rect::from_min_size(rect.min, Vec2::new(36.0, rect.height()));
+        let toggle = ui.interact(icon_rect, self.id.with("toggle"), Sense::click());
+
+        // Typed text survives while focused or invalid; otherwise the field shows the selection
+        let mut typed = ui.ctx().data(|d| d.get_temp::<TypedDate>(typed_id)).unwrap_or_else(|| TypedDate {
+            text: self.selected.map(|date| self.locale.format_date(date, format)).unwrap_or_default(),
+            invalid: false,
+        });
+
+        ui.painter().rect_filled(rect, theme.radii.md, theme.colors.background);
+        let icon_color = if toggle.hovered() {
             theme.colors.foreground
         } else {
             theme.colors.muted_foreground
         };
+        draw_calendar_icon(ui.painter(), Pos2::new(rect.min.x + 12.0, rect.center().y), icon_color);
+        if toggle.hovered() {
+            ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
+        }
 
-        // Draw the trigger button (outline style with calendar icon)
-        let desired_width = 240.0;
-        let button_height = 36.0;
-        let (rect, response) = ui.allocate_exact_size(
-            Vec2::new(desired_width, button_height),
-            Sense::click(),
+        let text_rect = Rect::from_min_max(Pos2::new(rect.min.x + 30.0, rect.min.y), Pos2::new(rect.max.x - 8.0, rect.max.y));
+        let mut response = ui.put(
+            text_rect,
+            TextEdit::singleline(&mut typed.text)
+                .id(self.id.with("text"))
+                .hint_text(&self.placeholder)
+                .font(egui::FontId::proportional(theme.typography.small().size))
+                .frame(false)
+                .desired_width(text_rect.width())
+                .vertical_align(egui::Align::Center),
         );
 
-        if ui.is_rect_visible(rect) {
-            let is_hovered = response.hovered();
+        if response.changed() {
+            typed.invalid = false;
+            if typed.text.trim().is_empty() {
+                *self.selected = None;
+            } else {
+                match parse_date(&typed.text, format, self.locale) {
+                    Some(date) if self.in_range(date) => *self.selected = Some(date),
+                    _ => typed.invalid = true,
+                }
+            }
+            response.mark_changed();
+        }
+
+        ui.painter().rect_stroke(
+            rect,
+            theme.radii.md,
+            input_border(theme, typed.invalid, response.has_focus(), ui.rect_contains_pointer(rect)),
+            egui::StrokeKind::Inside,
+        );
 
-            // Background and border
-            let bg_color = if is_hovered {
-                theme.colors.accent
+        let keep_typed = response.has_focus() || typed.invalid;
+        ui.ctx().data_mut(|d| {
+            if keep_typed {
+                d.insert_temp(typed_id, typed);
             } else {
-                theme.colors.background
-            };
+                d.remove::<TypedDate>(typed_id);
+            }
+        });
 
-            ui.painter().rect(
-                rect,
-                theme.radii.md,
-                bg_color,
-                egui::Stroke::new(1.0, theme.colors.input),
-                egui::StrokeKind::Inside,
-            );
-
-            // Calendar icon on the left
-            let icon_x = rect.min.x + 12.0;
-            let icon_y = rect.center().y;
-            let icon_color = theme.colors.muted_foreground;
-            self.draw_calendar_icon(ui, Pos2::new(icon_x, icon_y), icon_color);
-
-            // Text
-            let text_x = rect.min.x + 36.0;
-            ui.painter().text(
-                Pos2::new(text_x, rect.center().y),
-                egui::Align2::LEFT_CENTER,
-                &button_text,
-                egui::FontId::proportional(theme.typography.small().size),
-                text_color,
-            );
-        }
+        (rect, response, toggle.clicked())
+    }
+
+    fn in_range(&self, date: NaiveDate) -> bool {
+        self.min_date.map_or(true, |min| date >= min) && self.max_date.map_or(true, |max| date <= max)
+    }
+}
+
+/// A named date range offered beside the calendar of a [`DateRangePicker`]
+///
+/// The relative presets are computed from the local date when constructed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DatePreset {
+    label: String,
+    start: NaiveDate,
+    end: NaiveDate,
+}
 
-        // Toggle on click
-        let mut new_open_state = is_open;
-        if response.clicked() {
-            new_open_state = !is_open;
+impl DatePreset {
+    /// A preset covering `start..=end` (the bounds may be given in either order)
+    pub fn new(label: impl Into<String>, start: NaiveDate, end: NaiveDate) -> Self {
+        Self {
+            label: label.into(),
+            start: start.min(end),
+            end: start.max(end),
         }
+    }
 
-        if response.hovered() {
-            ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
+    /// "Today"
+    pub fn today() -> Self {
+        Self::today_from(chrono::Local::now().date_naive())
+    }
+
+    /// "Last N days", ending today
+    pub fn last_days(days: u32) -> Self {
+        Self::last_days_from(chrono::Local::now().date_naive(), days)
+    }
+
+    /// "This month", the whole current calendar month
+    pub fn this_month() -> Self {
+        Self::this_month_from(chrono::Local::now().date_naive())
+    }
+
+    /// The "Today", "Last 7 days" and "This month" presets
+    pub fn defaults() -> Vec<Self> {
+        vec![Self::today(), Self::last_days(7), Self::this_month()]
+    }
+
+    /// The preset's label
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// The first and last day of the preset
+    pub fn range(&self) -> (NaiveDate, NaiveDate) {
+        (self.start, self.end)
+    }
+
+    /// The part of the preset within `min..=max`, or `None` if it lies outside
+    fn clamped(&self, min: Option<NaiveDate>, max: Option<NaiveDate>) -> Option<(NaiveDate, NaiveDate)> {
+        let start = clamp_date(self.start, min, None);
+        let end = clamp_date(self.end, None, max);
+        (start <= end).then_some((start, end))
+    }
+
+    fn today_from(today: NaiveDate) -> Self {
+        Self::new("Today", today, today)
+    }
+
+    fn last_days_from(today: NaiveDate, days: u32) -> Self {
+        let start = today - Days::new(u64::from(days.saturating_sub(1)));
+        Self::new(format!("Last {days} days"), start, today)
+    }
+
+    fn this_month_from(today: NaiveDate) -> Self {
+        let first = today.with_day(1).unwrap_or(today);
+        let last = first + Months::new(1) - Days::new(1);
+        Self::new("This month", first, last)
+    }
+}
+
+/// Date range picker built on the calendar's range mode
+///
+/// Shows two months by default. The popover closes once both ends are picked
+/// or a preset is chosen. Presets are cut to the min/max dates, and those
+/// entirely outside them are disabled.
+///
+/// ## Example
+/// ```rust,ignore
+/// let mut range: Option<(chrono::NaiveDate, chrono::NaiveDate)> = None;
+///
+/// DateRangePicker::new("report_range", &mut range)
+///     .presets(DatePreset::defaults())
+///     .show(ui);
+/// ```
+pub struct DateRangePicker<'a> {
+    id: Id,
+    selected: &'a mut Option<(NaiveDate, NaiveDate)>,
+    placeholder: String,
+    format: Option<String>,
+    min_date: Option<NaiveDate>,
+    max_date: Option<NaiveDate>,
+    locale: &'a dyn CalendarLocale,
+    number_of_months: usize,
+    presets: Vec<DatePreset>,
+}
+
+impl<'a> DateRangePicker<'a> {
+    /// Create a new date range picker
+    pub fn new(id: impl std::hash::Hash, selected: &'a mut Option<(NaiveDate, NaiveDate)>) -> Self {
+        Self {
+            id: Id::new(id),
+            selected,
+            placeholder: "Pick a date range".to_string(),
+            format: None,
+            min_date: None,
+            max_date: None,
+            locale: &EnglishLocale::US,
+            number_of_months: 2,
+            presets: Vec::new(),
         }
+    }
 
-        // Show popup if open
-        if new_open_state {
-            let area_id = self.id.with("area");
-            let area_response = egui::Area::new(area_id)
-                .order(egui::Order::Foreground)
-                .fixed_pos(rect.left_bottom() + egui::vec2(0.0, 4.0))
-                .show(ui.ctx(), |ui| {
-                    egui::Frame::NONE
-                        .fill(theme.colors.popover)
-                        .stroke(egui::Stroke::new(1.0, theme.colors.border))
-                        .corner_radius(theme.radii.lg)
-                        .shadow(theme.shadows.md)
-                        .inner_margin(egui::Margin::same(4))
-                        .show(ui, |ui| {
-                            // Convert Option<NaiveDate> to CalendarSelection
-                            let mut calendar_selection = match *self.selected {
-                                Some(date) => CalendarSelection::Single(date),
-                                None => CalendarSelection::None,
-                            };
-
-                            // View date state - stored in memory
-                            let view_date_id = self.id.with("view_date");
-                            let mut view_date = ui.ctx().data(|d| {
-                                d.get_temp::<chrono::NaiveDate>(view_date_id)
-                                    .unwrap_or_else(|| {
-                                        self.selected.unwrap_or_else(|| chrono::Local::now().date_naive())
-                                    })
-                            });
-
-                            let mut calendar = Calendar::new(
-                                self.id.with("calendar"),
-                                &mut calendar_selection,
-                                &mut view_date,
-                            );
-
-                            if let Some(min) = self.min_date {
-                                calendar = calendar.min_date(min);
-                            }
-                            if let Some(max) = self.max_date {
-                                calendar = calendar.max_date(max);
-                            }
+    /// Set the placeholder text when no range is selected
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
 
-                            calendar.show(ui);
-
-                            // Update selected date from calendar selection
-                            match calendar_selection {
-                                CalendarSelection::Single(date) => {
-                                    if *self.selected != Some(date) {
-                                        *self.selected = Some(date);
-                                        // Close popup on selection
-                                        new_open_state = false;
-                                    }
-                                }
-                                _ => {}
-                            }
+    /// Set the date format string used for both ends (defaults to the locale's)
+    pub fn format(mut self, format: impl Into<String>) -> Self {
+        self.format = Some(format.into());
+        self
+    }
+
+    /// Set the locale for the calendar and the displayed dates
+    pub fn locale(mut self, locale: &'a dyn CalendarLocale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Set how many months the calendar shows (default: 2)
+    pub fn number_of_months(mut self, months: usize) -> Self {
+        self.number_of_months = months.max(1);
+        self
+    }
 
-                            // Save view date
-                            ui.ctx().data_mut(|d| d.insert_temp(view_date_id, view_date));
-                        });
+    /// Offer preset ranges beside the calendar
+    pub fn presets(mut self, presets: Vec<DatePreset>) -> Self {
+        self.presets = presets;
+        self
+    }
+
+    /// Set minimum selectable date
+    pub fn min_date(mut self, date: NaiveDate) -> Self {
+        self.min_date = Some(date);
+        self
+    }
+
+    /// Set maximum selectable date
+    pub fn max_date(mut self, date: NaiveDate) -> Self {
+        self.max_date = Some(date);
+        self
+    }
+
+    /// Show the date range picker
+    pub fn show(self, ui: &mut Ui) -> Response {
+        let theme = ui.ctx().data(|d| {
+            d.get_temp::<ShadcnTheme>(Id::new("shadcn_theme"))
+                .unwrap_or_else(ShadcnTheme::light)
+        });
+        let format = self.format.clone().unwrap_or_else(|| self.locale.date_format().to_string());
+
+        let text = match self.selected {
+            Some((start, end)) => format!(
+                "{} – {}",
+                self.locale.format_date(*start, &format),
+                self.locale.format_date(*end, &format)
+            ),
+            None => self.placeholder.clone(),
+        };
+        let mut response = draw_trigger(ui, &theme, &text, self.selected.is_some(), 300.0);
+
+        // The calendar's half-picked range lives here until the second click
+        let pending_id = self.id.with("pending");
+        let view_date_id = self.id.with("view_date");
+        let mut changed = false;
+        picker_popover(ui, &theme, self.id, response.rect, response.clicked(), |ui, just_opened| {
+            let mut selection = ui
+                .ctx()
+                .data(|d| d.get_temp::<CalendarSelection>(pending_id))
+                .filter(|_| !just_opened)
+                .unwrap_or(match *self.selected {
+                    Some((start, end)) => CalendarSelection::Range(start, end),
+                    None => CalendarSelection::None,
                 });
+            let mut view_date = ui.ctx().data(|d| {
+                d.get_temp::<NaiveDate>(view_date_id)
+                    .unwrap_or_else(|| self.selected.map_or_else(|| chrono::Local::now().date_naive(), |(start, _)| start))
+            });
+
+            let mut close = false;
+            ui.horizontal_top(|ui| {
+                if !self.presets.is_empty() {
+                    ui.vertical(|ui| {
+                        ui.set_width(120.0);
+                        for preset in &self.presets {
+                            let range = preset.clamped(self.min_date, self.max_date);
+                            let button = Button::new(preset.label())
+                                .variant(ButtonVariant::Ghost)
+                                .size(ButtonSize::Small)
+                                .enabled(range.is_some());
+                            let clicked = ui.add_sized(Vec2::new(120.0, 32.0), button).clicked();
+                            if let Some((start, end)) = range.filter(|_| clicked) {
+                                *self.selected = Some((start, end));
+                                view_date = start;
+                                changed = true;
+                                close = true;
+                            }
+                        }
+                    });
+                    ui.separator();
+                }
 
-            // Close when clicking outside (but not on trigger)
-            // Only check if we were already open (not just opened this frame)
-            if is_open {
-                if area_response.response.clicked_elsewhere() && !response.clicked() {
-                    new_open_state = false;
+                let mut calendar = Calendar::new(self.id.with("calendar"), &mut selection, &mut view_date)
+                    .mode(CalendarMode::Range)
+                    .number_of_months(self.number_of_months)
+                    .locale(self.locale);
+                if let Some(min) = self.min_date {
+                    calendar = calendar.min_date(min);
+                }
+                if let Some(max) = self.max_date {
+                    calendar = calendar.max_date(max);
+                }
+                calendar.show(ui);
+            });
+
+            if let CalendarSelection::Range(start, end) = selection {
+                if !close && *self.selected != Some((start, end)) {
+                    *self.selected = Some((start, end));
+                    changed = true;
+                    close = true;
                 }
             }
+
+            ui.ctx().data_mut(|d| {
+                d.insert_temp(view_date_id, view_date);
+                if close {
+                    d.remove::<CalendarSelection>(pending_id);
+                } else {
+                    d.insert_temp(pending_id, selection);
+                }
+            });
+            close
+        });
+
+        if changed {
+            response.mark_changed();
         }
+        response
+    }
+}
 
-        // Save the open state for next frame
-        ui.ctx().data_mut(|d| d.insert_temp(open_id, new_open_state));
+/// Date and time picker: a calendar with hour and minute columns
+///
+/// Picking a day keeps the chosen time and picking a time keeps the chosen
+/// day (today, or the nearest allowed day, until one is picked), so the
+/// popover stays open until the user clicks outside or presses Escape.
+///
+/// ## Example
+/// ```rust,ignore
+/// let mut meeting: Option<chrono::NaiveDateTime> = None;
+///
+/// DateTimePicker::new("meeting", &mut meeting)
+///     .minute_step(15)
+///     .show(ui);
+/// ```
+pub struct DateTimePicker<'a> {
+    id: Id,
+    selected: &'a mut Option<NaiveDateTime>,
+    placeholder: String,
+    format: Option<String>,
+    min_date: Option<NaiveDate>,
+    max_date: Option<NaiveDate>,
+    locale: &'a dyn CalendarLocale,
+    minute_step: u32,
+}
+
+impl<'a> DateTimePicker<'a> {
+    /// Create a new date and time picker
+    pub fn new(id: impl std::hash::Hash, selected: &'a mut Option<NaiveDateTime>) -> Self {
+        Self {
+            id: Id::new(id),
+            selected,
+            placeholder: "Pick a date and time".to_string(),
+            format: None,
+            min_date: None,
+            max_date: None,
+            locale: &EnglishLocale::US,
+            minute_step: 5,
+        }
+    }
+
+    /// Set the placeholder text when nothing is selected
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Set the format string (defaults to the locale's date format followed by `%H:%M`)
+    pub fn format(mut self, format: impl Into<String>) -> Self {
+        self.format = Some(format.into());
+        self
+    }
+
+    /// Set the locale for the calendar and the displayed date
+    pub fn locale(mut self, locale: &'a dyn CalendarLocale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Set the spacing of the minute column in minutes (default: 5)
+    pub fn minute_step(mut self, step: u32) -> Self {
+        self.minute_step = step.clamp(1, 30);
+        self
+    }
+
+    /// Set minimum selectable date
+    pub fn min_date(mut self, date: NaiveDate) -> Self {
+        self.min_date = Some(date);
+        self
+    }
+
+    /// Set maximum selectable date
+    pub fn max_date(mut self, date: NaiveDate) -> Self {
+        self.max_date = Some(date);
+        self
+    }
+
+    /// Show the date and time picker
+    pub fn show(self, ui: &mut Ui) -> Response {
+        let theme = ui.ctx().data(|d| {
+            d.get_temp::<ShadcnTheme>(Id::new("shadcn_theme"))
+                .unwrap_or_else(ShadcnTheme::light)
+        });
+        let format = self
+            .format
+            .clone()
+            .unwrap_or_else(|| format!("{} %H:%M", self.locale.date_format()));
 
+        let text = match self.selected {
+            Some(datetime) => self.locale.format_datetime(*datetime, &format),
+            None => self.placeholder.clone(),
+        };
+        let mut response = draw_trigger(ui, &theme, &text, self.selected.is_some(), 280.0);
+
+        let view_date_id = self.id.with("view_date");
+        let mut changed = false;
+        picker_popover(ui, &theme, self.id, response.rect, response.clicked(), |ui, just_opened| {
+            let mut selection = match *self.selected {
+                Some(datetime) => CalendarSelection::Single(datetime.date()),
+                None => CalendarSelection::None,
+            };
+            let mut view_date = ui.ctx().data(|d| {
+                d.get_temp::<NaiveDate>(view_date_id).unwrap_or_else(|| {
+                    self.selected.map_or_else(|| chrono::Local::now().date_naive(), |datetime| datetime.date())
+                })
+            });
+
+            let mut picked = *self.selected;
+            ui.horizontal_top(|ui| {
+                let mut calendar = Calendar::new(self.id.with("calendar"), &mut selection, &mut view_date)
+                    .locale(self.locale);
+                if let Some(min) = self.min_date {
+                    calendar = calendar.min_date(min);
+                }
+                if let Some(max) = self.max_date {
+                    calendar = calendar.max_date(max);
+                }
+                calendar.show(ui);
+                if let CalendarSelection::Single(date) = selection {
+                    picked = Some(date.and_time(picked.map_or(NaiveTime::MIN, |datetime| datetime.time())));
+                }
+
+                ui.separator();
+                let day = clamp_date(
+                    picked.map_or_else(|| chrono::Local::now().date_naive(), |datetime| datetime.date()),
+                    self.min_date,
+                    self.max_date,
+                );
+                let hour = picked.map(|datetime| datetime.hour());
+                let minute = picked.map(|datetime| datetime.minute());
+                if let Some(hour) = time_column(ui, &theme, self.id.with("hours"), 0..24, hour, just_opened) {
+                    picked = day.and_hms_opt(hour, minute.unwrap_or(0), 0);
+                }
+                let minutes = (0..60).step_by(self.minute_step as usize);
+                if let Some(minute) = time_column(ui, &theme, self.id.with("minutes"), minutes, minute, just_opened) {
+                    picked = day.and_hms_opt(hour.unwrap_or(0), minute, 0);
+                }
+            });
+
+            if picked != *self.selected {
+                *self.selected = picked;
+                changed = true;
+            }
+            ui.ctx().data_mut(|d| d.insert_temp(view_date_id, view_date));
+            false
+        });
+
+        if changed {
+            response.mark_changed();
+        }
         response
     }
+}
 
-    fn draw_calendar_icon(&self, ui: &mut Ui, center: Pos2, color: egui::Color32) {
-        let stroke = egui::Stroke::new(1.2, color);
-        let size = 7.0;
+/// `date` moved inside the optional `min..=max` bounds
+fn clamp_date(date: NaiveDate, min: Option<NaiveDate>, max: Option<NaiveDate>) -> NaiveDate {
+    let date = min.map_or(date, |min| date.max(min));
+    max.map_or(date, |max| date.min(max))
+}
 
-        // Calendar outline (rounded rectangle)
-        let rect = egui::Rect::from_center_size(center, Vec2::splat(size * 2.0));
-        ui.painter().rect_stroke(rect, 2.0, stroke, egui::StrokeKind::Inside);
+/// A scrolling column of two-digit time values, returning the clicked one
+fn time_column(
+    ui: &mut Ui,
+    theme: &ShadcnTheme,
+    id: Id,
+    values: impl Iterator<Item = u32>,
+    current: Option<u32>,
+    scroll_to_current: bool,
+) -> Option<u32> {
+    let mut clicked = None;
+    egui::ScrollArea::vertical()
+        .id_salt(id)
+        .max_height(280.0)
+        .show(ui, |ui| {
+            ui.spacing_mut().item_spacing.y = 2.0;
+            for value in values {
+                let is_current = current == Some(value);
+                let (rect, response) = ui.allocate_exact_size(Vec2::new(44.0, 30.0), Sense::click());
+                let (fill, text_color) = if is_current {
+                    (theme.colors.primary, theme.colors.primary_foreground)
+                } else if response.hovered() {
+                    (theme.colors.accent, theme.colors.accent_foreground)
+                } else {
+                    (egui::Color32::TRANSPARENT, theme.colors.foreground)
+                };
+                ui.painter().rect_filled(rect, theme.radii.sm, fill);
+                ui.painter().text(
+                    rect.center(),
+                    egui::Align2::CENTER_CENTER,
+                    format!("{value:02}"),
+                    egui::FontId::proportional(theme.typography.small().size),
+                    text_color,
+                );
+                if is_current && scroll_to_current {
+                    ui.scroll_to_rect(rect, Some(egui::Align::Center));
+                }
+                if response.clicked() {
+                    clicked = Some(value);
+                }
+            }
+        });
+    clicked
+}
 
-        // Top bar (header area)
-        let top_y = rect.min.y + size * 0.5;
-        ui.painter().line_segment(
-            [Pos2::new(rect.min.x, top_y), Pos2::new(rect.max.x, top_y)],
-            stroke,
-        );
+/// Open state, overlay registration and popover frame shared by the pickers
+///
+/// `toggled` flips the open state. `content` is told whether the popover
+/// opened this frame and returns true to close it.
+fn picker_popover(
+    ui: &Ui,
+    theme: &ShadcnTheme,
+    id: Id,
+    anchor: Rect,
+    toggled: bool,
+    content: impl FnOnce(&mut Ui, bool) -> bool,
+) {
+    // Use simple boolean state in memory (same pattern as Popover)
+    let open_id = id.with("open");
+    let was_open = ui.ctx().data(|d| d.get_temp::<bool>(open_id).unwrap_or(false));
+    let mut open = was_open != toggled;
 
-        // Two small lines for calendar "rings" at top
-        let ring_y_top = rect.min.y - 2.0;
-        let ring_y_bottom = rect.min.y + 2.0;
-        let ring1_x = center.x - size * 0.4;
-        let ring2_x = center.x + size * 0.4;
+    if open {
+        let overlay = OverlayStack::register(ui.ctx(), id, OverlayKind::Popover);
+        let area_response = egui::Area::new(id.with("area"))
+            .order(egui::Order::Foreground)
+            .fixed_pos(anchor.left_bottom() + egui::vec2(0.0, 4.0))
+            .show(ui.ctx(), |ui| {
+                egui::Frame::NONE
+                    .fill(theme.colors.popover)
+                    .stroke(egui::Stroke::new(1.0, theme.colors.border))
+                    .corner_radius(theme.radii.lg)
+                    .shadow(theme.shadows.md)
+                    .inner_margin(egui::Margin::same(4))
+                    .show(ui, |ui| content(ui, !was_open))
+                    .inner
+            });
+        if area_response.inner {
+            open = false;
+        }
 
-        ui.painter().line_segment(
-            [Pos2::new(ring1_x, ring_y_top), Pos2::new(ring1_x, ring_y_bottom)],
-            stroke,
+        overlay.raise(ui.ctx(), area_response.response.layer_id);
+
+        // Close on Escape or when clicking outside (but not on trigger)
+        // Only check if we were already open (not just opened this frame)
+        if was_open {
+            let clicked_outside = !toggled && overlay.clicked_outside(ui.ctx(), area_response.response.rect);
+            if clicked_outside || overlay.escape_pressed(ui.ctx()) {
+                open = false;
+            }
+        }
+    }
+
+    // Save the open state for next frame
+    ui.ctx().data_mut(|d| d.insert_temp(open_id, open));
+    if !open {
+        OverlayStack::remove(ui.ctx(), id);
+    }
+}
+
+/// Draw an outline trigger button with a calendar icon and `text`
+fn draw_trigger(ui: &mut Ui, theme: &ShadcnTheme, text: &str, has_value: bool, width: f32) -> Response {
+    let (rect, response) = ui.allocate_exact_size(Vec2::new(width, TRIGGER_HEIGHT), Sense::click());
+
+    if ui.is_rect_visible(rect) {
+        // Background and border
+        let bg_color = if response.hovered() {
+            theme.colors.accent
+        } else {
+            theme.colors.background
+        };
+        ui.painter().rect(
+            rect,
+            theme.radii.md,
+            bg_color,
+            egui::Stroke::new(1.0, theme.colors.input),
+            egui::StrokeKind::Inside,
         );
-        ui.painter().line_segment(
-            [Pos2::new(ring2_x, ring_y_top), Pos2::new(ring2_x, ring_y_bottom)],
-            stroke,
+
+        // Calendar icon on the left
+        draw_calendar_icon(ui.painter(), Pos2::new(rect.min.x + 12.0, rect.center().y), theme.colors.muted_foreground);
+
+        let text_color = if has_value {
+            theme.colors.foreground
+        } else {
+            theme.colors.muted_foreground
+        };
+        ui.painter().text(
+            Pos2::new(rect.min.x + 36.0, rect.center().y),
+            egui::Align2::LEFT_CENTER,
+            text,
+            egui::FontId::proportional(theme.typography.small().size),
+            text_color,
         );
     }
+
+    if response.hovered() {
+        ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
+    }
+    response
+}
+
+fn draw_calendar_icon(painter: &egui::Painter, center: Pos2, color: egui::Color32) {
+    let stroke = egui::Stroke::new(1.2, color);
+    let size = 7.0;
+
+    // Calendar outline (rounded rectangle)
+    let rect = egui::Rect::from_center_size(center, Vec2::splat(size * 2.0));
+    painter.rect_stroke(rect, 2.0, stroke, egui::StrokeKind::Inside);
+
+    // Top bar (header area)
+    let top_y = rect.min.y + size * 0.5;
+    painter.line_segment([Pos2::new(rect.min.x, top_y), Pos2::new(rect.max.x, top_y)], stroke);
+
+    // Two small lines for calendar "rings" at top
+    let ring_y_top = rect.min.y - 2.0;
+    let ring_y_bottom = rect.min.y + 2.0;
+    let ring1_x = center.x - size * 0.4;
+    let ring2_x = center.x + size * 0.4;
+
+    painter.line_segment([Pos2::new(ring1_x, ring_y_top), Pos2::new(ring1_x, ring_y_bottom)], stroke);
+    painter.line_segment([Pos2::new(ring2_x, ring_y_top), Pos2::new(ring2_x, ring_y_bottom)], stroke);
+}
+
+/// Which date field a format specifier fills
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum DateField {
+    Day,
+    Month,
+    Year,
+}
+
+/// The order in which `format` lays out day, month and year
+fn field_order(format: &str) -> Vec<DateField> {
+    let mut order = Vec::new();
+    let mut push = |field: DateField| {
+        if !order.contains(&field) {
+            order.push(field);
+        }
+    };
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            continue;
+        }
+        // Skip chrono's padding modifiers (`%-d`, `%_m`, `%0e`)
+        let mut spec = chars.next();
+        if matches!(spec, Some('-' | '_' | '0')) {
+            spec = chars.next();
+        }
+        match spec {
+            Some('d' | 'e') => push(DateField::Day),
+            Some('m' | 'B' | 'b' | 'h') => push(DateField::Month),
+            Some('Y' | 'y') => push(DateField::Year),
+            Some('F') => [DateField::Year, DateField::Month, DateField::Day].into_iter().for_each(&mut push),
+            Some('D' | 'x') => [DateField::Month, DateField::Day, DateField::Year].into_iter().for_each(&mut push),
+            _ => {}
+        }
+    }
+    order
+}
+
+/// Parse a typed date leniently against a display `format`
+///
+/// Numbers are read in the format's field order whatever separates them,
+/// month names and abbreviations come from `locale`, weekday names and
+/// ordinal suffixes are ignored, and two-digit years mean 20xx. A leading
+/// four-digit year is always read as ISO year-month-day.
+fn parse_date(text: &str, format: &str, locale: &dyn CalendarLocale) -> Option<NaiveDate> {
+    let mut numbers: Vec<(u32, usize)> = Vec::new();
+    let mut named_month = None;
+
+    for token in text.split(|c: char| !c.is_alphanumeric()).filter(|token| !token.is_empty()) {
+        let digits = token.chars().take_while(char::is_ascii_digit).count();
+        if digits > 0 {
+            let suffix = token[digits..].to_lowercase();
+            if !matches!(suffix.as_str(), "" | "st" | "nd" | "rd" | "th") {
+                return None;
+            }
+            numbers.push((token[..digits].parse().ok()?, digits));
+        } else if let Some(month) = month_from_name(token, locale) {
+            if named_month.replace(month).is_some() {
+                return None;
+            }
+        } else if !is_weekday_name(token, locale) {
+            return None;
+        }
+    }
+
+    let order = match numbers.first() {
+        Some(&(_, 4)) if named_month.is_none() => vec![DateField::Year, DateField::Month, DateField::Day],
+        _ => field_order(format),
+    };
+
+    let (mut day, mut month, mut year) = (None, named_month, None);
+    let mut numbers = numbers.into_iter();
+    for field in order {
+        if field == DateField::Month && named_month.is_some() {
+            continue;
+        }
+        let Some((value, digits)) = numbers.next() else {
+            break;
+        };
+        match field {
+            DateField::Day => day = Some(value),
+            DateField::Month => month = Some(value),
+            DateField::Year => year = Some(if digits <= 2 { 2000 + value as i32 } else { value as i32 }),
+        }
+    }
+    if numbers.next().is_some() {
+        return None;
+    }
+
+    NaiveDate::from_ymd_opt(year?, month?, day?)
+}
+
+/// The month a (possibly abbreviated, at least three letters) name refers to
+fn month_from_name(token: &str, locale: &dyn CalendarLocale) -> Option<u32> {
+    let token = token.to_lowercase();
+    (1..=12).find(|&month| {
+        locale.month_short(month).to_lowercase() == token
+            || (token.chars().count() >= 3 && locale.month_name(month).to_lowercase().starts_with(&token))
+    })
+}
+
+fn is_weekday_name(token: &str, locale: &dyn CalendarLocale) -> bool {
+    let token = token.to_lowercase();
+    (0..7).map(chrono::Weekday::try_from).filter_map(Result::ok).any(|weekday| {
+        locale.weekday_short(weekday).to_lowercase() == token
+            || (token.chars().count() >= 3 && locale.weekday_name(weekday).to_lowercase().starts_with(&token))
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
     #[test]
     fn test_datepicker_creation() {
         let mut date: Option<chrono::NaiveDate> = None;
@@ -260,6 +958,103 @@ mod tests {
             .format("%Y-%m-%d");
 
         assert_eq!(picker.placeholder, "Select...");
-        assert_eq!(picker.format, "%Y-%m-%d");
+        assert_eq!(picker.format.as_deref(), Some("%Y-%m-%d"));
+    }
+
+    #[test]
+    fn test_parse_date_follows_format_order() {
+        let us = &EnglishLocale::US;
+        assert_eq!(parse_date("01/05/2025", "%m/%d/%Y", us), Some(date(2025, 1, 5)));
+        assert_eq!(parse_date("5.1.25", "%d.%m.%Y", us), Some(date(2025, 1, 5)));
+        assert_eq!(parse_date("1 5 2025", "%B %d, %Y", us), Some(date(2025, 1, 5)));
+    }
+
+    #[test]
+    fn test_parse_date_accepts_names_and_iso() {
+        let us = &EnglishLocale::US;
+        assert_eq!(parse_date("January 5, 2025", "%B %d, %Y", us), Some(date(2025, 1, 5)));
+        assert_eq!(parse_date("jan 5th 2025", "%B %d, %Y", us), Some(date(2025, 1, 5)));
+        assert_eq!(parse_date("Sunday, 5 Janu 2025", "%d %B %Y", us), Some(date(2025, 1, 5)));
+        assert_eq!(parse_date("2025-01-05", "%d/%m/%Y", us), Some(date(2025, 1, 5)));
+    }
+
+    #[test]
+    fn test_parse_date_rejects_bad_input() {
+        let us = &EnglishLocale::US;
+        assert_eq!(parse_date("", "%m/%d/%Y", us), None);
+        assert_eq!(parse_date("02/30/2025", "%m/%d/%Y", us), None);
+        assert_eq!(parse_date("01/05", "%m/%d/%Y", us), None);
+        assert_eq!(parse_date("01/05/2025/7", "%m/%d/%Y", us), None);
+        assert_eq!(parse_date("soon", "%m/%d/%Y", us), None);
+        assert_eq!(parse_date("Jan Feb 5 2025", "%B %d, %Y", us), None);
+    }
+
+    #[test]
+    fn test_presets() {
+        let today = date(2025, 2, 12);
+        assert_eq!(DatePreset::today_from(today).range(), (today, today));
+
+        let week = DatePreset::last_days_from(today, 7);
+        assert_eq!(week.label(), "Last 7 days");
+        assert_eq!(week.range(), (date(2025, 2, 6), today));
+
+        assert_eq!(DatePreset::this_month_from(today).range(), (date(2025, 2, 1), date(2025, 2, 28)));
+        assert_eq!(DatePreset::new("Span", today, date(2025, 1, 1)).range(), (date(2025, 1, 1), today));
+    }
+
+    #[test]
+    fn test_presets_respect_bounds() {
+        let today = date(2025, 2, 12);
+        let week = DatePreset::last_days_from(today, 7);
+        assert_eq!(week.clamped(None, None), Some((date(2025, 2, 6), today)));
+        assert_eq!(week.clamped(Some(date(2025, 2, 10)), None), Some((date(2025, 2, 10), today)));
+        assert_eq!(week.clamped(None, Some(date(2025, 2, 8))), Some((date(2025, 2, 6), date(2025, 2, 8))));
+        assert_eq!(week.clamped(Some(date(2025, 3, 1)), None), None);
+        assert_eq!(week.clamped(None, Some(date(2025, 1, 31))), None);
+    }
+
+    #[test]
+    fn test_clamp_date() {
+        let (min, max) = (Some(date(2025, 1, 10)), Some(date(2025, 1, 20)));
+        assert_eq!(clamp_date(date(2025, 1, 1), min, max), date(2025, 1, 10));
+        assert_eq!(clamp_date(date(2025, 1, 15), min, max), date(2025, 1, 15));
+        assert_eq!(clamp_date(date(2025, 2, 1), min, max), date(2025, 1, 20));
+        assert_eq!(clamp_date(date(2025, 2, 1), None, None), date(2025, 2, 1));
+    }
+
+    #[test]
+    fn test_editable_picker_parses_typed_text() {
+        use crate::test_util::{context_with_motion, run_frame, run_frame_with_events};
+        use crate::theme::MotionPreference;
+
+        let ctx = context_with_motion(MotionPreference::Full);
+        let mut selected = None;
+        let show = |ui: &mut Ui, selected: &mut Option<NaiveDate>| {
+            DatePicker::new("typed", selected).format("%d/%m/%Y").editable(true).show(ui)
+        };
+
+        run_frame(&ctx, 0.0, |ui| {
+            show(ui, &mut selected).request_focus();
+        });
+        run_frame_with_events(&ctx, 0.1, vec![egui::Event::Text("5/1/2025".into())], |ui| {
+            assert!(show(ui, &mut selected).changed());
+        });
+        assert_eq!(selected, Some(date(2025, 1, 5)));
+
+        // Invalid text keeps the last good date and flags the field
+        run_frame_with_events(&ctx, 0.2, vec![egui::Event::Text("x".into())], |ui| {
+            show(ui, &mut selected);
+        });
+        assert_eq!(selected, Some(date(2025, 1, 5)));
+        let typed = ctx.data(|d| d.get_temp::<TypedDate>(Id::new("typed").with("typed")));
+        assert!(typed.is_some_and(|typed| typed.invalid));
+    }
+
+    #[test]
+    fn test_field_order() {
+        use DateField::*;
+        assert_eq!(field_order("%B %d, %Y"), vec![Month, Day, Year]);
+        assert_eq!(field_order("%-d.%-m.%y"), vec![Day, Month, Year]);
+        assert_eq!(field_order("%F"), vec![Year, Month, Day]);
     }
 }
@@ -4,7 +4,8 @@
 //!
 //! Reference: <https://ui.shadcn.com/docs/components/dialog>
 
-use egui::{Color32, Frame, Id, Modal, Ui};
+use egui::{Color32, Frame, Id, Modal, ModalResponse, Ui};
+use crate::overlay::{Overlay, OverlayKind, OverlayStack};
 use crate::theme::ShadcnTheme;
 
 /// Dialog component for modal overlays
@@ -78,9 +79,12 @@ impl Dialog {
         content: impl FnOnce(&mut Ui) -> R,
     ) -> Option<R> {
         if !*open {
+            OverlayStack::remove(ctx, self.id);
             return None;
         }
 
+        let overlay = OverlayStack::register(ctx, self.id, OverlayKind::Modal);
+
         let theme = ctx.data(|d| {
             d.get_temp::<ShadcnTheme>(Id::new("shadcn_theme"))
                 .unwrap_or_else(ShadcnTheme::light)
@@ -94,8 +98,8 @@ impl Dialog {
             .shadow(theme.shadows.lg)
             .inner_margin(theme.spacing.lg);
 
-        // Semi-transparent backdrop (50% opacity like shadcn)
-        let backdrop_color = Color32::from_rgba_unmultiplied(0, 0, 0, 128);
+        // Semi-transparent backdrop (50% opacity like shadcn), dimmed less when nested
+        let backdrop_color = overlay.backdrop_color(128);
 
         let modal = Modal::new(self.id)
             .backdrop_color(backdrop_color)
@@ -164,8 +168,12 @@ impl Dialog {
         });
 
         // Handle closing
-        if modal_response.should_close() {
+        overlay.raise(ctx, modal_response.response.layer_id);
+
+        // Backdrop clicks and Escape only dismiss the top-most overlay
+        if modal_response.response.should_close() || dismissed(ctx, &overlay, &modal_response) {
             *open = false;
+            OverlayStack::remove(ctx, self.id);
         }
 
         Some(modal_response.inner)
@@ -180,9 +188,12 @@ impl Dialog {
         footer: impl FnOnce(&mut Ui),
     ) -> Option<R> {
         if !*open {
+            OverlayStack::remove(ctx, self.id);
             return None;
         }
 
+        let overlay = OverlayStack::register(ctx, self.id, OverlayKind::Modal);
+
         let theme = ctx.data(|d| {
             d.get_temp::<ShadcnTheme>(Id::new("shadcn_theme"))
                 .unwrap_or_else(ShadcnTheme::light)
@@ -195,7 +206,7 @@ impl Dialog {
             .shadow(theme.shadows.lg)
             .inner_margin(theme.spacing.lg);
 
-        let backdrop_color = Color32::from_rgba_unmultiplied(0, 0, 0, 128);
+        let backdrop_color = overlay.backdrop_color(128);
 
         let modal = Modal::new(self.id)
             .backdrop_color(backdrop_color)
@@ -264,14 +275,24 @@ impl Dialog {
             result
         });
 
-        if modal_response.should_close() {
+        overlay.raise(ctx, modal_response.response.layer_id);
+
+        // Backdrop clicks and Escape only dismiss the top-most overlay
+        if modal_response.response.should_close() || dismissed(ctx, &overlay, &modal_response) {
             *open = false;
+            OverlayStack::remove(ctx, self.id);
         }
 
         Some(modal_response.inner)
     }
 }
 
+/// Whether a modal was dismissed by a backdrop click or Escape while top-most
+fn dismissed<T>(ctx: &egui::Context, overlay: &Overlay, response: &ModalResponse<T>) -> bool {
+    (response.backdrop_response.clicked() && overlay.claim_click(ctx))
+        || overlay.escape_pressed(ctx)
+}
+
 /// Response from a confirmation dialog
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ConfirmResult {
@@ -293,12 +314,14 @@ pub fn confirm_dialog(
     title: &str,
     message: &str,
 ) -> ConfirmResult {
+    let id = id.into();
     if !*open {
+        OverlayStack::remove(ctx, id);
         return ConfirmResult::Pending;
     }
 
     let mut result = ConfirmResult::Pending;
-    let id = id.into();
+    let overlay = OverlayStack::register(ctx, id, OverlayKind::Modal);
 
     let theme = ctx.data(|d| {
         d.get_temp::<ShadcnTheme>(Id::new("shadcn_theme"))
@@ -312,7 +335,7 @@ pub fn confirm_dialog(
         .shadow(theme.shadows.lg)
         .inner_margin(theme.spacing.lg);
 
-    let backdrop_color = Color32::from_rgba_unmultiplied(0, 0, 0, 128);
+    let backdrop_color = overlay.backdrop_color(128);
 
     let modal = Modal::new(id)
         .backdrop_color(backdrop_color)
@@ -362,14 +385,17 @@ pub fn confirm_dialog(
         });
     });
 
+    overlay.raise(ctx, modal_response.response.layer_id);
+
     // Close on backdrop click or escape
-    if modal_response.should_close() {
+    if modal_response.response.should_close() || dismissed(ctx, &overlay, &modal_response) {
         result = ConfirmResult::Cancelled;
     }
 
     // Close dialog if user made a choice
     if result != ConfirmResult::Pending {
         *open = false;
+        OverlayStack::remove(ctx, id);
     }
 
     result
@@ -2,11 +2,14 @@
 //!
 //! A drawer panel that slides in from an edge with smooth animations,
 //! typically used for mobile navigation or additional content panels.
+//! Like vaul, it can be dragged by its handle or body, snaps to configurable
+//! points, and is dismissed by dragging or flicking it towards its edge.
 //!
 //! Reference: <https://ui.shadcn.com/docs/components/drawer>
 
-use egui::{Id, Ui, Color32, Rect, Pos2, Vec2, Sense};
+use egui::{Id, Ui, Rect, Pos2, Vec2, Sense};
 use crate::animation::SlideAnimation;
+use crate::overlay::{OverlayKind, OverlayStack};
 use crate::theme::ShadcnTheme;
 
 /// Side from which the drawer slides in
@@ -34,6 +37,7 @@ pub enum DrawerSide {
 /// Drawer::new("my_drawer", &mut drawer_open)
 ///     .side(DrawerSide::Bottom)
 ///     .title("Settings")
+///     .snap_points([0.25, 0.5, 1.0])
 ///     .show(ui, |ui| {
 ///         ui.label("Drawer content");
 ///     });
@@ -46,6 +50,22 @@ pub struct Drawer<'a> {
     description: Option<String>,
     show_handle: bool,
     size: Option<f32>,
+    snap_points: Vec<f32>,
+    default_snap: Option<usize>,
+}
+
+/// Release velocity (px/s) above which a drag counts as a flick
+const FLICK_VELOCITY: f32 = 400.0;
+
+/// Drag/snap state persisted between frames
+#[derive(Clone, Copy, Debug, Default)]
+struct DrawerDragState {
+    /// Index into the drawer's snap points
+    snap_index: usize,
+    /// Whether the user is currently dragging the drawer
+    dragging: bool,
+    /// Whether the drawer was open last frame
+    was_open: bool,
 }
 
 impl<'a> Drawer<'a> {
@@ -59,6 +79,8 @@ impl<'a> Drawer<'a> {
             description: None,
             show_handle: true,
             size: None,
+            snap_points: vec![1.0],
+            default_snap: None,
         }
     }
 
@@ -92,6 +114,33 @@ impl<'a> Drawer<'a> {
         self
     }
 
+    /// Set the snap points as fractions of the drawer size (e.g. `[0.25, 0.5, 1.0]`)
+    ///
+    /// Dragging the handle or body and releasing snaps the drawer to the nearest
+    /// point; a fast flick moves to the next point or dismisses the drawer.
+    /// Default: `[1.0]` (fully open only).
+    pub fn snap_points(mut self, points: impl Into<Vec<f32>>) -> Self {
+        let mut points: Vec<f32> = points
+            .into()
+            .into_iter()
+            .filter(|p| *p > 0.0)
+            .map(|p| p.min(1.0))
+            .collect();
+        points.sort_by(|a, b| a.total_cmp(b));
+        points.dedup();
+        if points.is_empty() {
+            points.push(1.0);
+        }
+        self.snap_points = points;
+        self
+    }
+
+    /// Set the snap point index the drawer opens at (default: the largest)
+    pub fn default_snap(mut self, index: usize) -> Self {
+        self.default_snap = Some(index);
+        self
+    }
+
     /// Show the drawer
     pub fn show<R>(self, ui: &mut Ui, content: impl FnOnce(&mut Ui) -> R) -> Option<R> {
         let theme = ui.ctx().data(|d| {
@@ -99,30 +148,48 @@ impl<'a> Drawer<'a> {
                 .unwrap_or_else(ShadcnTheme::light)
         });
 
-        // Load animation state
+        // Load animation and drag state
         let anim_id = self.id.with("anim");
+        let drag_id = self.id.with("drag");
         let mut anim = SlideAnimation::load(ui.ctx(), anim_id);
+        let mut drag: DrawerDragState = ui.ctx().data(|d| d.get_temp(drag_id).unwrap_or_default());
+
+        let largest_snap = self.snap_points.len() - 1;
+        if *self.open && !drag.was_open {
+            drag.snap_index = self.default_snap.unwrap_or(largest_snap).min(largest_snap);
+        }
+        drag.snap_index = drag.snap_index.min(largest_snap);
 
         // Determine target: 0.0 = fully visible, 1.0 = fully hidden
-        let target = if *self.open { 0.0 } else { 1.0 };
+        let target = if *self.open {
+            1.0 - self.snap_points[drag.snap_index]
+        } else {
+            1.0
+        };
 
-        // Start animation if we're not at target
-        if (anim.offset - target).abs() > 0.001 {
-            anim.opening = *self.open;
-            anim.animating = true;
+        // Follow the pointer while dragging, otherwise animate towards the target
+        if !drag.dragging {
+            if (anim.offset - target).abs() > 0.001 {
+                anim.opening = *self.open;
+                anim.animating = true;
+            }
+            anim.update_to(ui.ctx(), target);
         }
 
-        // Update animation
-        anim.update(ui.ctx());
-
         // Store animation state
         anim.store(ui.ctx(), anim_id);
 
         // Don't render if fully closed
         if !*self.open && anim.offset >= 0.999 {
+            OverlayStack::remove(ui.ctx(), self.id);
+            drag.was_open = false;
+            drag.dragging = false;
+            ui.ctx().data_mut(|d| d.insert_temp(drag_id, drag));
             return None;
         }
 
+        let overlay = OverlayStack::register(ui.ctx(), self.id, OverlayKind::Modal);
+
         #[allow(deprecated)]
         let screen_rect = ui.ctx().screen_rect();
         let mut result = None;
@@ -135,8 +202,16 @@ impl<'a> Drawer<'a> {
         let size = self.size.unwrap_or(default_size);
         let r = theme.radii.xl;
 
-        // Calculate the offset translation based on animation progress
-        let offset_amount = anim.offset;
+        // Calculate the offset translation based on animation progress.
+        // Without movement the drawer sits at its snap point and fades instead
+        // (direct dragging still moves it).
+        let (offset_amount, opacity) = if theme.motion.allows_movement() || drag.dragging {
+            (anim.offset, 1.0)
+        } else {
+            let rest = 1.0 - self.snap_points[drag.snap_index];
+            let hidden = ((anim.offset - rest) / (1.0 - rest)).clamp(0.0, 1.0);
+            (rest, 1.0 - hidden)
+        };
 
         let (drawer_rect, corner_radius) = match self.side {
             DrawerSide::Bottom => {
@@ -175,60 +250,38 @@ impl<'a> Drawer<'a> {
             }
         };
 
-        // Draw backdrop overlay with animated alpha
-        let backdrop_alpha = ((1.0 - offset_amount) * 128.0) as u8;
-        let backdrop_layer = egui::LayerId::new(egui::Order::Middle, self.id.with("backdrop_layer"));
-        ui.ctx().layer_painter(backdrop_layer).rect_filled(
-            screen_rect,
-            0.0,
-            Color32::from_black_alpha(backdrop_alpha),
-        );
-
-        // Check for clicks outside the drawer panel (only when mostly open)
-        if offset_amount < 0.3 {
-            let pointer_pos = ui.ctx().input(|i| i.pointer.latest_pos());
-            let primary_pressed = ui.ctx().input(|i| i.pointer.primary_pressed());
-
-            // Calculate the visible drawer rect (accounting for animation)
-            let visible_rect = match self.side {
-                DrawerSide::Bottom => Rect::from_min_size(
-                    Pos2::new(screen_rect.left(), screen_rect.bottom() - size * (1.0 - offset_amount)),
-                    Vec2::new(screen_rect.width(), size),
-                ),
-                DrawerSide::Top => Rect::from_min_size(
-                    screen_rect.left_top(),
-                    Vec2::new(screen_rect.width(), size * (1.0 - offset_amount)),
-                ),
-                DrawerSide::Left => Rect::from_min_size(
-                    screen_rect.left_top(),
-                    Vec2::new(size * (1.0 - offset_amount), screen_rect.height()),
-                ),
-                DrawerSide::Right => Rect::from_min_size(
-                    Pos2::new(screen_rect.right() - size * (1.0 - offset_amount), screen_rect.top()),
-                    Vec2::new(size, screen_rect.height()),
-                ),
-            };
+        // Backdrop opacity follows how far the drawer is pulled out
+        let max_snap = self.snap_points[largest_snap];
+        let progress = ((1.0 - anim.offset) / max_snap).clamp(0.0, 1.0);
+        let backdrop_alpha = (progress * 128.0) as u8;
+        overlay.paint_backdrop(ui.ctx(), screen_rect, backdrop_alpha);
 
-            let clicked_outside = primary_pressed
-                && pointer_pos.map(|p| !visible_rect.contains(p)).unwrap_or(false);
-
-            if clicked_outside {
-                *self.open = false;
-                // Re-store animation state to start closing immediately
-                anim.opening = false;
-                anim.animating = true;
-                anim.store(ui.ctx(), anim_id);
-                ui.ctx().request_repaint();
-            }
+        // Check for clicks outside the drawer panel (not on the frame it opened)
+        if drag.was_open && !drag.dragging && overlay.clicked_outside(ui.ctx(), drawer_rect) {
+            *self.open = false;
+            // Re-store animation state to start closing immediately
+            anim.opening = false;
+            anim.animating = true;
+            anim.store(ui.ctx(), anim_id);
+            ui.ctx().request_repaint();
         }
 
         // Draw the drawer panel
+        let mut drag_responses: Vec<egui::Response> = Vec::new();
         let drawer_id = self.id.with("panel");
-        egui::Area::new(drawer_id)
+        let area_response = egui::Area::new(drawer_id)
             .order(egui::Order::Foreground)
             .fixed_pos(drawer_rect.left_top())
+            .constrain(false) // slide in from off-screen
             .interactable(true)
             .show(ui.ctx(), |ui| {
+                ui.multiply_opacity(opacity);
+
+                // Dragging empty parts of the body moves the drawer too
+                // (registered first so content widgets take priority)
+                let body_response = ui.interact(drawer_rect, self.id.with("body_drag"), Sense::drag());
+                drag_responses.push(body_response);
+
                 let frame = egui::Frame::NONE
                     .fill(theme.colors.background)
                     .stroke(egui::Stroke::new(1.0, theme.colors.border))
@@ -241,19 +294,24 @@ impl<'a> Drawer<'a> {
                     ui.vertical(|ui| {
                         // Draw handle for bottom/top drawers
                         if self.show_handle && matches!(self.side, DrawerSide::Bottom | DrawerSide::Top) {
-                            ui.add_space(8.0);
                             ui.horizontal(|ui| {
                                 let handle_width = 48.0;
                                 let handle_height = 4.0;
+                                // Grab zone is larger than the visible pill
+                                let hit_size = Vec2::new(handle_width + 32.0, 20.0);
                                 let available_width = ui.available_width();
-                                ui.add_space((available_width - handle_width) / 2.0);
+                                ui.add_space((available_width - hit_size.x) / 2.0);
 
-                                let (handle_rect, handle_response) = ui.allocate_exact_size(
+                                let (hit_rect, handle_response) = ui.allocate_exact_size(
+                                    hit_size,
+                                    Sense::click_and_drag(),
+                                );
+                                let handle_rect = Rect::from_center_size(
+                                    hit_rect.center(),
                                     Vec2::new(handle_width, handle_height),
-                                    Sense::click(),
                                 );
 
-                                let handle_color = if handle_response.hovered() {
+                                let handle_color = if handle_response.hovered() || handle_response.dragged() {
                                     theme.colors.muted_foreground
                                 } else {
                                     theme.colors.border
@@ -269,11 +327,13 @@ impl<'a> Drawer<'a> {
                                     *self.open = false;
                                     ui.ctx().request_repaint();
                                 }
-                                if handle_response.hovered() {
-                                    ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
+                                if handle_response.dragged() {
+                                    ui.ctx().set_cursor_icon(egui::CursorIcon::Grabbing);
+                                } else if handle_response.hovered() {
+                                    ui.ctx().set_cursor_icon(egui::CursorIcon::Grab);
                                 }
+                                drag_responses.push(handle_response);
                             });
-                            ui.add_space(8.0);
                         }
 
                         // Content area with padding
@@ -313,8 +373,44 @@ impl<'a> Drawer<'a> {
                 });
             });
 
-        // Handle escape key
-        if ui.ctx().input(|i| i.key_pressed(egui::Key::Escape)) {
+        overlay.raise(ui.ctx(), area_response.response.layer_id);
+
+        // Drag tracking: positive values move towards the closed edge
+        let closing_dir = match self.side {
+            DrawerSide::Bottom => Vec2::new(0.0, 1.0),
+            DrawerSide::Top => Vec2::new(0.0, -1.0),
+            DrawerSide::Right => Vec2::new(1.0, 0.0),
+            DrawerSide::Left => Vec2::new(-1.0, 0.0),
+        };
+        let dragged = drag_responses.iter().any(|r| r.dragged());
+
+        if *self.open && dragged {
+            let delta: f32 = drag_responses.iter().map(|r| r.drag_delta().dot(closing_dir)).sum();
+            drag.dragging = true;
+            anim.animating = false;
+            anim.velocity = 0.0;
+            anim.offset = (anim.offset + delta / size).clamp(1.0 - max_snap, 1.0);
+            anim.store(ui.ctx(), anim_id);
+            ui.ctx().request_repaint();
+        } else if drag.dragging {
+            drag.dragging = false;
+            let velocity = ui.ctx().input(|i| i.pointer.velocity()).dot(closing_dir);
+            match resolve_release(1.0 - anim.offset, velocity, &self.snap_points) {
+                Some(index) => drag.snap_index = index,
+                None => *self.open = false,
+            }
+            // Hand the release velocity to the spring so the settle continues the gesture
+            anim.velocity = velocity / size;
+            anim.animating = true;
+            anim.store(ui.ctx(), anim_id);
+            ui.ctx().request_repaint();
+        }
+
+        drag.was_open = *self.open;
+        ui.ctx().data_mut(|d| d.insert_temp(drag_id, drag));
+
+        // Handle escape key (only when this is the top-most overlay)
+        if overlay.escape_pressed(ui.ctx()) {
             *self.open = false;
             ui.ctx().request_repaint();
         }
@@ -323,9 +419,43 @@ impl<'a> Drawer<'a> {
     }
 }
 
+/// Decide where a released drag settles
+///
+/// `visible` is the visible fraction of the drawer, `closing_velocity` the release
+/// velocity towards the closed edge (px/s). Returns the snap point index to settle
+/// at, or `None` to dismiss the drawer.
+fn resolve_release(visible: f32, closing_velocity: f32, snap_points: &[f32]) -> Option<usize> {
+    const EPSILON: f32 = 0.01;
+
+    if closing_velocity > FLICK_VELOCITY {
+        // Flick towards closed: next smaller snap point, or dismiss
+        return snap_points.iter().rposition(|p| *p < visible - EPSILON);
+    }
+
+    if closing_velocity < -FLICK_VELOCITY {
+        // Flick towards open: next larger snap point, or the largest
+        let next = snap_points.iter().position(|p| *p > visible + EPSILON);
+        return Some(next.unwrap_or(snap_points.len() - 1));
+    }
+
+    // Dismiss when dragged below half of the smallest snap point
+    if visible < snap_points[0] / 2.0 {
+        return None;
+    }
+
+    // Otherwise settle at the nearest snap point
+    snap_points
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| (*a - visible).abs().total_cmp(&(*b - visible).abs()))
+        .map(|(index, _)| index)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::{context_with_motion, run_frame};
+    use crate::theme::MotionPreference;
 
     #[test]
     fn test_drawer_creation() {
@@ -340,5 +470,76 @@ mod tests {
         assert_eq!(drawer.title, Some("Test".to_string()));
         assert!(!drawer.show_handle);
         assert_eq!(drawer.size, Some(400.0));
+        assert_eq!(drawer.snap_points, vec![1.0]);
+    }
+
+    #[test]
+    fn test_snap_points_are_normalized() {
+        let mut open = true;
+        let drawer = Drawer::new("test", &mut open).snap_points([1.0, 0.25, 0.5, 0.5, -1.0, 2.0]);
+        assert_eq!(drawer.snap_points, vec![0.25, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn test_release_snaps_to_nearest_point() {
+        let snaps = [0.25, 0.5, 1.0];
+        assert_eq!(resolve_release(0.55, 0.0, &snaps), Some(1));
+        assert_eq!(resolve_release(0.9, 0.0, &snaps), Some(2));
+        assert_eq!(resolve_release(0.2, 0.0, &snaps), Some(0));
+    }
+
+    #[test]
+    fn test_release_below_smallest_point_dismisses() {
+        assert_eq!(resolve_release(0.1, 0.0, &[0.25, 1.0]), None);
+        assert_eq!(resolve_release(0.3, 0.0, &[1.0]), None);
+    }
+
+    #[test]
+    fn test_flick_moves_one_snap_point() {
+        let snaps = [0.25, 0.5, 1.0];
+        // Flick closed from fully open goes to the middle point
+        assert_eq!(resolve_release(0.95, 1000.0, &snaps), Some(1));
+        // Flick closed from the smallest point dismisses
+        assert_eq!(resolve_release(0.25, 1000.0, &snaps), None);
+        // Flick open from the smallest point goes to the middle point
+        assert_eq!(resolve_release(0.26, -1000.0, &snaps), Some(1));
+        // Flick open at the top stays at the top
+        assert_eq!(resolve_release(1.0, -1000.0, &snaps), Some(2));
+    }
+
+    /// Panel top edge and slide offset one frame after opening a bottom drawer
+    fn first_open_frame(motion: MotionPreference) -> (f32, f32) {
+        let ctx = context_with_motion(motion);
+        let mut open = false;
+        run_frame(&ctx, 0.0, |ui| {
+            Drawer::new("drawer", &mut open).snap_points([0.5, 1.0]).default_snap(0).show(ui, |_| ());
+        });
+        open = true;
+        run_frame(&ctx, 1.0 / 60.0, |ui| {
+            Drawer::new("drawer", &mut open).snap_points([0.5, 1.0]).default_snap(0).show(ui, |_| ());
+        });
+
+        let id = Id::new("drawer");
+        let rect = ctx.memory(|m| m.area_rect(id.with("panel"))).unwrap();
+        (rect.top(), SlideAnimation::load(&ctx, id.with("anim")).offset)
+    }
+
+    #[test]
+    fn test_drawer_motion() {
+        // Half-open snap point of a 340px drawer on a 600px screen
+        let rest_top = 600.0 - 340.0 * 0.5;
+
+        // Full: slides up from the bottom edge
+        let (top, offset) = first_open_frame(MotionPreference::Full);
+        assert!(top > rest_top);
+        assert!(offset > 0.5 && offset < 1.0);
+
+        // Reduced: already at the snap point, fading in
+        let (top, offset) = first_open_frame(MotionPreference::Reduced);
+        assert_eq!(top, rest_top);
+        assert!(offset > 0.5 && offset < 1.0);
+
+        // None: at the snap point immediately
+        assert_eq!(first_open_frame(MotionPreference::None), (rest_top, 0.5));
     }
 }
@@ -4,54 +4,51 @@
 //!
 //! Reference: <https://ui.shadcn.com/docs/components/dropdown-menu>
 
-use egui::{Response, Ui, Sense, Popup};
+use egui::{Response, Ui, Sense};
+use crate::components::menu::{show_menu, MenuLayout, MenuModel};
+use crate::overlay::OverlayStack;
 use crate::theme::ShadcnTheme;
 
 /// Dropdown Menu component for action menus
 ///
 /// ## Example
 /// ```rust,ignore
-/// DropdownMenu::new("actions")
-///     .trigger(|ui| ui.button("Open Menu"))
-///     .item("Copy", || println!("Copy"))
-///     .item("Paste", || println!("Paste"))
+/// let menu = MenuModel::new()
+///     .item(MenuItem::new("copy", "Copy").shortcut(KeyboardShortcut::new(Modifiers::COMMAND, Key::C)))
+///     .item(MenuItem::new("paste", "Paste"))
 ///     .separator()
-///     .item("Delete", || println!("Delete"))
+///     .item(MenuItem::new("delete", "Delete").destructive(true));
+///
+/// let response = DropdownMenu::new("actions", menu)
+///     .trigger_text("Open Menu")
 ///     .show(ui);
+/// if let Some(action) = response.action {
+///     handle(action);
+/// }
 /// ```
-pub struct DropdownMenu<'a> {
+pub struct DropdownMenu<'a, A = &'static str> {
     id: &'a str,
-    items: Vec<MenuItem>,
+    menu: MenuModel<'a, A>,
     trigger_text: Option<String>,
     width: f32,
 }
 
-/// A menu item type
-enum MenuItem {
-    Action {
-        label: String,
-        shortcut: Option<String>,
-        enabled: bool,
-        destructive: bool,
-    },
-    Separator,
-    Label(String),
-}
-
 /// Result of showing a dropdown menu
-pub struct DropdownMenuResponse {
+pub struct DropdownMenuResponse<A = &'static str> {
     /// The response from the trigger button
     pub trigger_response: Response,
-    /// Index of clicked item, if any
-    pub clicked_item: Option<usize>,
+    /// Action chosen from the menu or by its shortcut this frame, if any
+    pub action: Option<A>,
 }
 
-impl<'a> DropdownMenu<'a> {
-    /// Create a new dropdown menu
-    pub fn new(id: &'a str) -> Self {
+impl<'a, A: Clone> DropdownMenu<'a, A> {
+    /// Create a new dropdown menu showing `menu`
+    ///
+    /// `id` also heads the menu's shortcuts in the shortcuts help dialog.
+    pub fn new(id: &'a str, menu: MenuModel<'a, A>) -> Self {
         Self {
             id,
-            items: Vec::new(),
+            menu,
             trigger_text: None,
             width: 160.0,
         }
@@ -63,51 +60,6 @@ impl<'a> DropdownMenu<'a> {
         self
     }
 
-    /// Add a menu item
-    pub fn item(mut self, label: impl Into<String>) -> Self {
-        self.items.push(MenuItem::Action {
-            label: label.into(),
-            shortcut: None,
-            enabled: true,
-            destructive: false,
-        });
-        self
-    }
-
-    /// Add a menu item with keyboard shortcut
-    pub fn item_with_shortcut(mut self, label: impl Into<String>, shortcut: impl Into<String>) -> Self {
-        self.items.push(MenuItem::Action {
-            label: label.into(),
-            shortcut: Some(shortcut.into()),
-            enabled: true,
-            destructive: false,
-        });
-        self
-    }
-
-    /// Add a destructive (red) menu item
-    pub fn destructive_item(mut self, label: impl Into<String>) -> Self {
-        self.items.push(MenuItem::Action {
-            label: label.into(),
-            shortcut: None,
-            enabled: true,
-            destructive: true,
-        });
-        self
-    }
-
-    /// Add a separator
-    pub fn separator(mut self) -> Self {
-        self.items.push(MenuItem::Separator);
-        self
-    }
-
-    /// Add a label/header
-    pub fn label(mut self, text: impl Into<String>) -> Self {
-        self.items.push(MenuItem::Label(text.into()));
-        self
-    }
-
     /// Set the menu width
     pub fn width(mut self, width: f32) -> Self {
         self.width = width;
@@ -115,7 +67,7 @@ impl<'a> DropdownMenu<'a> {
     }
 
     /// Show the dropdown menu
-    pub fn show(self, ui: &mut Ui) -> DropdownMenuResponse {
+    pub fn show(mut self, ui: &mut Ui) -> DropdownMenuResponse<A> {
         let theme = ui.ctx().data(|d| {
             d.get_temp::<ShadcnTheme>(egui::Id::new("shadcn_theme"))
                 .unwrap_or_else(ShadcnTheme::light)
@@ -123,7 +75,10 @@ impl<'a> DropdownMenu<'a> {
 
         let id = egui::Id::new(self.id);
         let popup_id = id.with("popup");
-        let is_open = Popup::is_id_open(ui.ctx(), popup_id);
+        // Use simple boolean state in memory (same pattern as Popover)
+        let open_id = id.with("open");
+        let is_open = ui.ctx().data(|d| d.get_temp::<bool>(open_id).unwrap_or(false));
+        let mut open = is_open;
 
         // Render trigger button
         let trigger_response = if let Some(text) = &self.trigger_text {
@@ -132,7 +87,7 @@ impl<'a> DropdownMenu<'a> {
                     .min_size(egui::vec2(0.0, 44.0)) // Apple HIG touch target
             );
             if response.clicked() {
-                Popup::toggle_id(ui.ctx(), popup_id);
+                open = !open;
             }
             response
         } else {
@@ -143,7 +98,7 @@ impl<'a> DropdownMenu<'a> {
             );
 
             if response.clicked() {
-                Popup::toggle_id(ui.ctx(), popup_id);
+                open = !open;
             }
 
             if ui.is_rect_visible(response.rect) {
@@ -186,144 +141,31 @@ impl<'a> DropdownMenu<'a> {
             response
         };
 
-        let mut clicked_item = None;
-        let mut action_index = 0;
+        // Shortcuts work whether or not the menu is open
+        let mut action = self.menu.consume_shortcuts(ui.ctx(), self.id);
 
         // Show popup menu
         if is_open {
-            let popup_response = egui::Area::new(popup_id)
-                .order(egui::Order::Foreground)
-                .fixed_pos(trigger_response.rect.left_bottom() + egui::vec2(0.0, 4.0))
-                .show(ui.ctx(), |ui| {
-                    let frame = egui::Frame::NONE
-                        .fill(theme.colors.popover)
-                        .stroke(egui::Stroke::new(1.0, theme.colors.border))
-                        .corner_radius(theme.radii.md)
-                        .shadow(theme.shadows.md)
-                        .inner_margin(4.0);
-
-                    frame.show(ui, |ui| {
-                        ui.set_min_width(self.width);
-
-                        for item in &self.items {
-                            match item {
-                                MenuItem::Action { label, shortcut, enabled, destructive } => {
-                                    let current_index = action_index;
-                                    action_index += 1;
-
-                                    let item_response = ui.allocate_response(
-                                        egui::vec2(ui.available_width(), 36.0),
-                                        if *enabled { Sense::click() } else { Sense::hover() },
-                                    );
-
-                                    if item_response.clicked() && *enabled {
-                                        clicked_item = Some(current_index);
-                                        Popup::close_id(ui.ctx(), popup_id);
-                                    }
-
-                                    if ui.is_rect_visible(item_response.rect) {
-                                        let hovered = item_response.hovered() && *enabled;
-
-                                        // Hover background
-                                        if hovered {
-                                            ui.painter().rect_filled(
-                                                item_response.rect,
-                                                theme.radii.sm,
-                                                if *destructive {
-                                                    theme.colors.destructive.linear_multiply(0.1)
-                                                } else {
-                                                    theme.colors.accent
-                                                },
-                                            );
-                                        }
-
-                                        // Text color
-                                        let text_color = if *destructive {
-                                            if *enabled {
-                                                theme.colors.destructive
-                                            } else {
-                                                theme.colors.destructive.linear_multiply(0.5)
-                                            }
-                                        } else if *enabled {
-                                            if hovered {
-                                                theme.colors.accent_foreground
-                                            } else {
-                                                theme.colors.popover_foreground
-                                            }
-                                        } else {
-                                            theme.colors.popover_foreground.linear_multiply(0.5)
-                                        };
-
-                                        // Label
-                                        ui.painter().text(
-                                            egui::pos2(
-                                                item_response.rect.min.x + 8.0,
-                                                item_response.rect.center().y,
-                                            ),
-                                            egui::Align2::LEFT_CENTER,
-                                            label,
-                                            egui::FontId::proportional(theme.typography.body().size),
-                                            text_color,
-                                        );
-
-                                        // Shortcut
-                                        if let Some(shortcut) = shortcut {
-                                            ui.painter().text(
-                                                egui::pos2(
-                                                    item_response.rect.max.x - 8.0,
-                                                    item_response.rect.center().y,
-                                                ),
-                                                egui::Align2::RIGHT_CENTER,
-                                                shortcut,
-                                                egui::FontId::proportional(theme.typography.small().size),
-                                                theme.colors.foreground.linear_multiply(0.5),
-                                            );
-                                        }
-                                    }
-                                }
-                                MenuItem::Separator => {
-                                    let separator_rect = ui.allocate_space(egui::vec2(ui.available_width(), 9.0)).1;
-                                    ui.painter().line_segment(
-                                        [
-                                            egui::pos2(separator_rect.min.x + 4.0, separator_rect.center().y),
-                                            egui::pos2(separator_rect.max.x - 4.0, separator_rect.center().y),
-                                        ],
-                                        egui::Stroke::new(1.0, theme.colors.border),
-                                    );
-                                }
-                                MenuItem::Label(text) => {
-                                    let label_response = ui.allocate_response(
-                                        egui::vec2(ui.available_width(), 28.0),
-                                        Sense::hover(),
-                                    );
-
-                                    if ui.is_rect_visible(label_response.rect) {
-                                        ui.painter().text(
-                                            egui::pos2(
-                                                label_response.rect.min.x + 8.0,
-                                                label_response.rect.center().y,
-                                            ),
-                                            egui::Align2::LEFT_CENTER,
-                                            text,
-                                            egui::FontId::proportional(theme.typography.small().size),
-                                            theme.colors.foreground.linear_multiply(0.7),
-                                        );
-                                    }
-                                }
-                            }
-                        }
-                    });
-                });
-
-            // Close popup when clicking outside
-            if popup_response.response.clicked_elsewhere() {
-                Popup::close_id(ui.ctx(), popup_id);
+            let layout = MenuLayout { width: self.width, row_height: 36.0 };
+            let output = show_menu(
+                ui.ctx(), &theme, popup_id, &mut self.menu,
+                trigger_response.rect.left_bottom() + egui::vec2(0.0, 4.0),
+                trigger_response.rect, layout,
+            );
+            if output.action.is_some() || output.dismissed {
+                open = false;
             }
+            action = action.or(output.action);
+        }
+
+        ui.ctx().data_mut(|d| d.insert_temp(open_id, open));
+        if !open {
+            OverlayStack::remove(ui.ctx(), popup_id);
         }
 
         DropdownMenuResponse {
             trigger_response,
-            clicked_item,
+            action,
         }
     }
 }
@@ -331,13 +173,31 @@ impl<'a> DropdownMenu<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::components::menu::MenuItem;
+    use crate::test_util::{context_with_motion, key_press, run_frame_with_events};
+    use crate::theme::MotionPreference;
+    use egui::{Key, KeyboardShortcut, Modifiers};
 
     #[test]
     fn test_dropdown_menu_creation() {
-        let menu = DropdownMenu::new("test")
-            .item("Option 1")
+        let menu = DropdownMenu::new("test", MenuModel::new()
+            .item(MenuItem::new("one", "Option 1"))
             .separator()
-            .item("Option 2");
-        assert_eq!(menu.items.len(), 3);
+            .item(MenuItem::new("two", "Option 2")));
+        assert_eq!(menu.menu.len(), 3);
+    }
+
+    #[test]
+    fn test_shortcut_fires_while_closed() {
+        let ctx = context_with_motion(MotionPreference::Full);
+        let mut action = None;
+        run_frame_with_events(&ctx, 0.0, vec![key_press(Key::D, Modifiers::COMMAND)], |ui| {
+            let menu = MenuModel::new()
+                .item(MenuItem::new("copy", "Copy"))
+                .item(MenuItem::new("duplicate", "Duplicate").shortcut(KeyboardShortcut::new(Modifiers::COMMAND, Key::D)));
+            action = DropdownMenu::new("actions", menu).show(ui).action;
+        });
+        assert_eq!(action, Some("duplicate"));
+        assert_eq!(ctx.data(|d| d.get_temp::<bool>(egui::Id::new("actions").with("open"))), Some(false));
     }
 }
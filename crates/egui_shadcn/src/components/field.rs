@@ -5,6 +5,8 @@
 //! Reference: <https://ui.shadcn.com/docs/components/form>
 
 use egui::Ui;
+use crate::components::form::FormState;
+use crate::components::spinner::{Spinner, SpinnerSize};
 use crate::theme::ShadcnTheme;
 
 /// Field wrapper for form inputs
@@ -33,6 +35,7 @@ pub struct Field<'a> {
     description: Option<String>,
     error: Option<String>,
     required: bool,
+    pending: bool,
 }
 
 impl<'a> Field<'a> {
@@ -44,6 +47,7 @@ impl<'a> Field<'a> {
             description: None,
             error: None,
             required: false,
+            pending: false,
         }
     }
 
@@ -65,6 +69,25 @@ impl<'a> Field<'a> {
         self
     }
 
+    /// Show a spinner while an async validation is running
+    pub fn pending(mut self, pending: bool) -> Self {
+        self.pending = pending;
+        self
+    }
+
+    /// Take the error message and pending state from a [`FormState`] field
+    /// named like this field's id
+    ///
+    /// The error only shows once the field is touched. An explicit
+    /// [`Field::error`] takes precedence.
+    pub fn form(mut self, form: &FormState) -> Self {
+        if self.error.is_none() {
+            self.error = form.get_error(self.id).map(str::to_owned);
+        }
+        self.pending |= form.is_pending(self.id);
+        self
+    }
+
     /// Mark the field as required (shows asterisk)
     pub fn required(mut self, required: bool) -> Self {
         self.required = required;
@@ -136,6 +159,16 @@ impl<'a> Field<'a> {
                             .color(theme.colors.destructive)
                     );
                 });
+            } else if self.pending {
+                ui.add_space(4.0);
+                ui.horizontal(|ui| {
+                    ui.add(Spinner::new().size(SpinnerSize::Small));
+                    ui.label(
+                        egui::RichText::new("Checking...")
+                            .size(theme.typography.small().size - 2.0)
+                            .color(theme.colors.muted_foreground)
+                    );
+                });
             }
 
             FieldResponse {
@@ -196,4 +229,19 @@ mod tests {
         assert!(field.required);
         assert!(field.error.is_some());
     }
+
+    #[test]
+    fn test_field_error_from_form() {
+        let mut form = FormState::new();
+        form.add_field("email", crate::components::form::validators::required);
+
+        assert!(Field::new("email").form(&form).error.is_none());
+
+        form.validate_all();
+        let field = Field::new("email").form(&form);
+        assert_eq!(field.error.as_deref(), Some("This field is required"));
+
+        let field = Field::new("email").error(Some("Taken")).form(&form);
+        assert_eq!(field.error.as_deref(), Some("Taken"));
+    }
 }
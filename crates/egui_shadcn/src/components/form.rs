@@ -4,41 +4,307 @@
 //!
 //! Reference: <https://ui.shadcn.com/docs/components/form>
 
-use std::collections::HashMap;
+use std::any::Any;
+use std::fmt;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Poll, Wake, Waker};
+use std::time::Duration;
 
 /// Form state manager for handling validation
 ///
+/// Fields are declared with a value type (`String`, `bool`, numbers,
+/// `NaiveDate`, your own enums, ...) and accessed through the typed
+/// [`FieldKey`] returned on declaration. Field validators see their own value;
+/// form validators see the whole form, so cross-field rules can be expressed.
+/// The form also tracks touched, dirty and submitting state.
+///
 /// ## Example
 /// ```rust,ignore
 /// let mut form = FormState::new();
+/// let password = form.add("password", String::new());
+/// let confirm = form.add("confirm", String::new());
+/// let start = form.add("start", today);
+/// let end = form.add("end", today);
+///
+/// form.add_validator(password, |v: &String| validators::min_length(8)(v));
+/// form.add_form_validator(move |form| {
+///     if form.get(password) != form.get(confirm) {
+///         return Err(FieldError::new("confirm", "Passwords don't match"));
+///     }
+///     Ok(())
+/// });
+/// form.add_form_validator(move |form| match (form.get(start), form.get(end)) {
+///     (Some(start), Some(end)) if end < start => Err(FieldError::new("end", "End date must be after start date")),
+///     _ => Ok(()),
+/// });
 ///
-/// // Add validators
-/// form.add_field("email", |v| {
-///     if v.is_empty() {
-///         Err("Email is required")
-///     } else if !v.contains('@') {
-///         Err("Invalid email format")
-///     } else {
-///         Ok(())
+/// // Edit through the typed key, then let `Field` render the error
+/// Field::new("confirm").label("Confirm password").form(&form).show(ui, |ui| {
+///     if shadcn_input(ui, form.get_mut(confirm).unwrap(), "").changed() {
+///         form.touch("confirm");
+///         form.validate_field("confirm");
 ///     }
 /// });
 ///
 /// // Validate on submit
-/// if form.validate_all() {
-///     // Form is valid, proceed
+/// if form.begin_submit() {
+///     // Form is valid, proceed, then call `form.finish_submit()`
 /// }
 /// ```
+///
+/// String fields can also be declared with a single validator over `&str`:
+/// ```rust,ignore
+/// form.add_field("email", validators::email);
+/// ```
+///
+/// ## Async validation
+/// Checks that need the network (is this NIP-05 handle real, is the username
+/// taken) run as futures once the value has been stable for a debounce
+/// period. Call [`FormState::poll`] every frame to drive them; `Field` shows
+/// a spinner while a check is pending.
+/// ```rust,ignore
+/// form.set_async_validator(handle, Duration::from_millis(400), |handle: &String| {
+///     let handle = handle.clone();
+///     Box::pin(async move { lookup_nip05(&handle).await })
+/// });
+///
+/// form.poll(ui.ctx());
+/// ```
 pub struct FormState {
-    fields: HashMap<String, FieldState>,
-    validators: HashMap<String, Box<dyn Fn(&str) -> Result<(), String>>>,
+    fields: Vec<FieldState>,
+    form_validators: Vec<FormValidator>,
+    executor: Box<dyn ValidationExecutor>,
+    submitting: bool,
+}
+
+/// Validator over the whole form
+type FormValidator = Box<dyn Fn(&FormState) -> Result<(), FieldError>>;
+
+/// Type-erased validator over one field's value
+type ValueValidator = Box<dyn Fn(&dyn Any) -> Result<(), String>>;
+
+/// Seconds between polls of a running validation
+///
+/// Tasks have no way to wake the UI, so they are polled at this interval
+/// rather than every frame.
+const TASK_POLL_INTERVAL: f64 = 0.1;
+
+/// Type-erased async validator over one field's value, `None` to skip
+type AsyncValueValidator = Box<dyn Fn(&dyn Any) -> Option<ValidationFuture>>;
+
+/// A boxed future resolving to a validation result
+pub type ValidationFuture = Pin<Box<dyn Future<Output = Result<(), String>> + Send>>;
+
+/// Runs async validations
+///
+/// The default [`PollExecutor`] needs no runtime. Implement this to hand
+/// validations to an existing runtime (e.g. spawn onto tokio and return a
+/// task wrapping the join handle).
+pub trait ValidationExecutor {
+    /// Start running a validation
+    fn spawn(&mut self, future: ValidationFuture) -> Box<dyn ValidationTask>;
+}
+
+/// An in-flight async validation
+pub trait ValidationTask {
+    /// Check for the result without blocking
+    fn poll(&mut self) -> Option<Result<(), String>>;
+
+    /// The result is no longer needed (the value changed)
+    fn cancel(&mut self) {}
+}
+
+/// Executor that polls validation futures on the UI thread
+///
+/// Futures are polled once per [`FormState::poll`] with a no-op waker, so
+/// they should complete by checking shared state (a channel, a cache filled
+/// by a background thread) rather than relying on wake-ups.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PollExecutor;
+
+impl ValidationExecutor for PollExecutor {
+    fn spawn(&mut self, future: ValidationFuture) -> Box<dyn ValidationTask> {
+        Box::new(PolledTask { future: Some(future) })
+    }
+}
+
+struct PolledTask {
+    future: Option<ValidationFuture>,
+}
+
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+/// Poll `future` once with a no-op waker
+pub(crate) fn poll_now<T>(future: &mut Pin<Box<dyn Future<Output = T> + Send>>) -> Poll<T> {
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut cx = std::task::Context::from_waker(&waker);
+    future.as_mut().poll(&mut cx)
+}
+
+impl ValidationTask for PolledTask {
+    fn poll(&mut self) -> Option<Result<(), String>> {
+        let future = self.future.as_mut()?;
+        match poll_now(future) {
+            Poll::Ready(result) => {
+                self.future = None;
+                Some(result)
+            }
+            Poll::Pending => None,
+        }
+    }
+
+    fn cancel(&mut self) {
+        self.future = None;
+    }
+}
+
+/// Type-erased async validator over one field's value
+struct AsyncValidator {
+    debounce: f64,
+    validate: AsyncValueValidator,
+}
+
+/// An async validation waiting for its debounce or its result
+struct PendingValidation {
+    /// Value being validated
+    value: Box<dyn FormValue>,
+    /// When the debounce started (set on the first poll)
+    since: Option<f64>,
+    task: Option<Box<dyn ValidationTask>>,
 }
 
 struct FieldState {
-    value: String,
+    name: String,
+    value: Box<dyn FormValue>,
+    initial: Box<dyn FormValue>,
+    validators: Vec<ValueValidator>,
+    async_validator: Option<AsyncValidator>,
+    pending: Option<PendingValidation>,
+    /// Last async result and the value it was computed for
+    checked: Option<(Box<dyn FormValue>, Option<String>)>,
     error: Option<String>,
     touched: bool,
 }
 
+impl FieldState {
+    fn cancel_pending(&mut self) {
+        if let Some(mut task) = self.pending.take().and_then(|p| p.task) {
+            task.cancel();
+        }
+    }
+
+    /// Async part of validation; returns the known async error, if any
+    ///
+    /// Reuses the last result when the value hasn't changed, keeps an
+    /// in-flight check for the same value, and otherwise cancels the stale
+    /// check and schedules a new one.
+    fn check_async(&mut self) -> Option<String> {
+        self.async_validator.as_ref()?;
+        if let Some((value, error)) = &self.checked {
+            if value.eq_value(self.value.as_ref()) {
+                let error = error.clone();
+                self.cancel_pending();
+                return error;
+            }
+        }
+        if self.pending.as_ref().is_some_and(|p| p.value.eq_value(self.value.as_ref())) {
+            return None;
+        }
+        self.cancel_pending();
+        self.pending = Some(PendingValidation {
+            value: self.value.clone_box(),
+            since: None,
+            task: None,
+        });
+        None
+    }
+}
+
+/// A value that can be stored in a [`FormState`] field
+///
+/// Implemented for every `Clone + PartialEq + 'static` type.
+pub trait FormValue: Any {
+    /// Upcast for downcasting to the concrete type
+    fn as_any(&self) -> &dyn Any;
+    /// Mutable upcast for downcasting to the concrete type
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+    /// Clone into a new box
+    fn clone_box(&self) -> Box<dyn FormValue>;
+    /// Compare with another value of possibly different type
+    fn eq_value(&self, other: &dyn FormValue) -> bool;
+}
+
+impl<T: Any + Clone + PartialEq> FormValue for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn FormValue> {
+        Box::new(self.clone())
+    }
+
+    fn eq_value(&self, other: &dyn FormValue) -> bool {
+        other.as_any().downcast_ref::<T>() == Some(self)
+    }
+}
+
+/// Typed handle to a form field, returned by [`FormState::add`]
+pub struct FieldKey<T> {
+    name: &'static str,
+    _type: PhantomData<fn() -> T>,
+}
+
+impl<T> FieldKey<T> {
+    /// Field name (also used as the `Field` id)
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl<T> Clone for FieldKey<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for FieldKey<T> {}
+
+impl<T> fmt::Debug for FieldKey<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FieldKey").field(&self.name).finish()
+    }
+}
+
+/// An error reported by a form validator for one field
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    /// Name of the field the error belongs to
+    pub field: String,
+    /// Message shown under the field
+    pub message: String,
+}
+
+impl FieldError {
+    /// Create an error for `field`
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
 impl Default for FormState {
     fn default() -> Self {
         Self::new()
@@ -49,57 +315,177 @@ impl FormState {
     /// Create a new form state
     pub fn new() -> Self {
         Self {
-            fields: HashMap::new(),
-            validators: HashMap::new(),
+            fields: Vec::new(),
+            form_validators: Vec::new(),
+            executor: Box::new(PollExecutor),
+            submitting: false,
+        }
+    }
+
+    /// Run async validations on a custom executor
+    pub fn with_executor(mut self, executor: impl ValidationExecutor + 'static) -> Self {
+        self.executor = Box::new(executor);
+        self
+    }
+
+    /// Declare a typed field with its initial value
+    ///
+    /// Re-declaring an existing name replaces the field.
+    pub fn add<T: FormValue + Clone>(&mut self, name: &'static str, initial: T) -> FieldKey<T> {
+        self.insert_field(name.to_owned(), Box::new(initial.clone()), Box::new(initial));
+        FieldKey {
+            name,
+            _type: PhantomData,
         }
     }
 
-    /// Add a field with a validator
+    /// Add a text field with a validator
     pub fn add_field<F>(&mut self, name: impl Into<String>, validator: F)
     where
         F: Fn(&str) -> Result<(), String> + 'static,
     {
         let name = name.into();
-        self.fields.insert(name.clone(), FieldState {
-            value: String::new(),
+        self.insert_field(name.clone(), Box::new(String::new()), Box::new(String::new()));
+        if let Some(field) = self.field_mut(&name) {
+            field.validators.push(Box::new(move |value| match value.downcast_ref::<String>() {
+                Some(value) => validator(value),
+                None => Ok(()),
+            }));
+        }
+    }
+
+    fn insert_field(&mut self, name: String, value: Box<dyn FormValue>, initial: Box<dyn FormValue>) {
+        let field = FieldState {
+            name,
+            value,
+            initial,
+            validators: Vec::new(),
+            async_validator: None,
+            pending: None,
+            checked: None,
             error: None,
             touched: false,
-        });
-        self.validators.insert(name, Box::new(validator));
+        };
+        match self.fields.iter_mut().find(|f| f.name == field.name) {
+            Some(existing) => *existing = field,
+            None => self.fields.push(field),
+        }
+    }
+
+    fn field(&self, name: &str) -> Option<&FieldState> {
+        self.fields.iter().find(|f| f.name == name)
+    }
+
+    fn field_mut(&mut self, name: &str) -> Option<&mut FieldState> {
+        self.fields.iter_mut().find(|f| f.name == name)
+    }
+
+    /// Add a validator for a typed field
+    pub fn add_validator<T: FormValue>(
+        &mut self,
+        key: FieldKey<T>,
+        validator: impl Fn(&T) -> Result<(), String> + 'static,
+    ) {
+        if let Some(field) = self.field_mut(key.name) {
+            field.validators.push(Box::new(move |value| match value.downcast_ref::<T>() {
+                Some(value) => validator(value),
+                None => Ok(()),
+            }));
+        }
+    }
+
+    /// Set the async validator for a typed field, replacing any previous one
+    ///
+    /// It runs only when the synchronous validators pass, after the value has
+    /// been unchanged for `debounce`.
+    pub fn set_async_validator<T: FormValue>(
+        &mut self,
+        key: FieldKey<T>,
+        debounce: Duration,
+        validator: impl Fn(&T) -> ValidationFuture + 'static,
+    ) {
+        if let Some(field) = self.field_mut(key.name) {
+            field.cancel_pending();
+            field.checked = None;
+            field.async_validator = Some(AsyncValidator {
+                debounce: debounce.as_secs_f64(),
+                validate: Box::new(move |value| value.downcast_ref::<T>().map(&validator)),
+            });
+        }
+    }
+
+    /// Add a validator that sees the whole form (e.g. "passwords match")
+    ///
+    /// The returned error is shown on the field it names.
+    pub fn add_form_validator(&mut self, validator: impl Fn(&FormState) -> Result<(), FieldError> + 'static) {
+        self.form_validators.push(Box::new(validator));
+    }
+
+    /// Typed value of a field
+    pub fn get<T: FormValue>(&self, key: FieldKey<T>) -> Option<&T> {
+        self.field(key.name).and_then(|f| f.value.as_any().downcast_ref())
+    }
+
+    /// Mutable typed value of a field
+    pub fn get_mut<T: FormValue>(&mut self, key: FieldKey<T>) -> Option<&mut T> {
+        self.field_mut(key.name).and_then(|f| f.value.as_any_mut().downcast_mut())
     }
 
-    /// Get mutable reference to a field value
+    /// Set a typed value, mark the field touched and re-validate it
+    pub fn set<T: FormValue>(&mut self, key: FieldKey<T>, value: T) {
+        if let Some(field) = self.field_mut(key.name) {
+            field.value = Box::new(value);
+            field.touched = true;
+        }
+        self.validate_field(key.name);
+    }
+
+    /// Get mutable reference to a text field value
     pub fn get_value_mut(&mut self, name: &str) -> Option<&mut String> {
-        self.fields.get_mut(name).map(|f| &mut f.value)
+        self.field_mut(name).and_then(|f| f.value.as_any_mut().downcast_mut())
     }
 
-    /// Get field value
+    /// Get text field value
     pub fn get_value(&self, name: &str) -> Option<&str> {
-        self.fields.get(name).map(|f| f.value.as_str())
+        self.field(name)
+            .and_then(|f| f.value.as_any().downcast_ref::<String>())
+            .map(String::as_str)
     }
 
-    /// Set field value
+    /// Set text field value
     pub fn set_value(&mut self, name: &str, value: impl Into<String>) {
-        if let Some(field) = self.fields.get_mut(name) {
-            field.value = value.into();
+        if let Some(field) = self.get_value_mut(name) {
+            *field = value.into();
         }
     }
 
     /// Mark field as touched (user has interacted)
     pub fn touch(&mut self, name: &str) {
-        if let Some(field) = self.fields.get_mut(name) {
+        if let Some(field) = self.field_mut(name) {
             field.touched = true;
         }
     }
 
     /// Check if field has been touched
     pub fn is_touched(&self, name: &str) -> bool {
-        self.fields.get(name).map(|f| f.touched).unwrap_or(false)
+        self.field(name).map(|f| f.touched).unwrap_or(false)
+    }
+
+    /// Check if a field's value differs from its initial value
+    pub fn is_field_dirty(&self, name: &str) -> bool {
+        self.field(name)
+            .map(|f| !f.value.eq_value(f.initial.as_ref()))
+            .unwrap_or(false)
+    }
+
+    /// Check if any field differs from its initial value
+    pub fn is_dirty(&self) -> bool {
+        self.fields.iter().any(|f| !f.value.eq_value(f.initial.as_ref()))
     }
 
     /// Get field error (only if touched)
     pub fn get_error(&self, name: &str) -> Option<&str> {
-        self.fields.get(name).and_then(|f| {
+        self.field(name).and_then(|f| {
             if f.touched {
                 f.error.as_deref()
             } else {
@@ -110,63 +496,232 @@ impl FormState {
 
     /// Get field error regardless of touched state
     pub fn get_error_always(&self, name: &str) -> Option<&str> {
-        self.fields.get(name).and_then(|f| f.error.as_deref())
+        self.field(name).and_then(|f| f.error.as_deref())
+    }
+
+    /// Set or clear a field error directly (e.g. from a server response)
+    pub fn set_error(&mut self, name: &str, error: Option<impl Into<String>>) {
+        if let Some(field) = self.field_mut(name) {
+            field.error = error.map(Into::into);
+        }
+    }
+
+    /// All current errors as `(field, message)`, in declaration order
+    pub fn errors(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.fields
+            .iter()
+            .filter_map(|f| f.error.as_deref().map(|e| (f.name.as_str(), e)))
+    }
+
+    /// Run a field's own validators, returning the first error
+    fn field_error(field: &FieldState) -> Option<String> {
+        field
+            .validators
+            .iter()
+            .find_map(|validator| validator(field.value.as_any()).err())
+    }
+
+    /// Errors from form validators, keyed by field
+    fn form_errors(&self) -> Vec<FieldError> {
+        self.form_validators
+            .iter()
+            .filter_map(|validator| validator(self).err())
+            .collect()
     }
 
     /// Validate a single field
+    ///
+    /// Runs the field's validators and any form validators that report on it.
     pub fn validate_field(&mut self, name: &str) -> bool {
-        let value = self.fields.get(name).map(|f| f.value.clone());
-        let validator = self.validators.get(name);
-
-        if let (Some(value), Some(validator)) = (value, validator) {
-            let result = validator(&value);
-            if let Some(field) = self.fields.get_mut(name) {
-                field.error = result.err();
-                return field.error.is_none();
+        let Some(index) = self.fields.iter().position(|f| f.name == name) else {
+            return true;
+        };
+        let error = Self::field_error(&self.fields[index]).or_else(|| {
+            self.form_errors()
+                .into_iter()
+                .find(|e| e.field == name)
+                .map(|e| e.message)
+        });
+        let field = &mut self.fields[index];
+        field.error = match error {
+            Some(error) => {
+                field.cancel_pending();
+                Some(error)
             }
-        }
-        true
+            None => field.check_async(),
+        };
+        field.error.is_none() && field.pending.is_none()
     }
 
     /// Validate all fields and mark them as touched
     pub fn validate_all(&mut self) -> bool {
-        let names: Vec<String> = self.fields.keys().cloned().collect();
-        let mut all_valid = true;
+        for field in &mut self.fields {
+            field.touched = true;
+            field.error = Self::field_error(field);
+        }
 
-        for name in names {
-            self.touch(&name);
-            if !self.validate_field(&name) {
-                all_valid = false;
+        // Form validators only fill in fields without an error of their own
+        for error in self.form_errors() {
+            if let Some(field) = self.field_mut(&error.field) {
+                field.error.get_or_insert(error.message);
+            }
+        }
+
+        for field in &mut self.fields {
+            if field.error.is_some() {
+                field.cancel_pending();
+            } else {
+                field.error = field.check_async();
             }
         }
 
-        all_valid
+        self.is_valid()
     }
 
     /// Check if form is valid (without triggering validation)
+    ///
+    /// A form with pending async validations is not valid yet.
     pub fn is_valid(&self) -> bool {
-        self.fields.values().all(|f| f.error.is_none())
+        self.fields.iter().all(|f| f.error.is_none() && f.pending.is_none())
+    }
+
+    /// Whether an async validation for the field is pending
+    pub fn is_pending(&self, name: &str) -> bool {
+        self.field(name).is_some_and(|f| f.pending.is_some())
+    }
+
+    /// Whether any async validation is pending
+    pub fn is_validating(&self) -> bool {
+        self.fields.iter().any(|f| f.pending.is_some())
+    }
+
+    /// Drive async validations; call once per frame
+    ///
+    /// Starts validations whose debounce has elapsed, collects finished
+    /// results and schedules a repaint for the next debounce deadline, or
+    /// every 100ms while a validation is running.
+    pub fn poll(&mut self, ctx: &egui::Context) {
+        let now = ctx.input(|i| i.time);
+        if let Some(delay) = self.poll_at(now) {
+            ctx.request_repaint_after(Duration::from_secs_f64(delay));
+        }
+    }
+
+    /// Drive async validations at time `now` (seconds)
+    ///
+    /// Returns how long until the next poll is needed, if anything is pending.
+    fn poll_at(&mut self, now: f64) -> Option<f64> {
+        let mut next: Option<f64> = None;
+        for field in &mut self.fields {
+            let Some(mut pending) = field.pending.take() else {
+                continue;
+            };
+            let Some(validator) = &field.async_validator else {
+                continue;
+            };
+
+            if pending.task.is_none() {
+                let since = *pending.since.get_or_insert(now);
+                let remaining = since + validator.debounce - now;
+                if remaining > 0.0 {
+                    next = Some(next.map_or(remaining, |next| next.min(remaining)));
+                    field.pending = Some(pending);
+                    continue;
+                }
+                match (validator.validate)(pending.value.as_any()) {
+                    Some(future) => pending.task = Some(self.executor.spawn(future)),
+                    None => continue,
+                }
+            }
+
+            match pending.task.as_mut().and_then(|task| task.poll()) {
+                Some(result) => {
+                    let error = result.err();
+                    // A result for an outdated value is cached but not shown
+                    if pending.value.eq_value(field.value.as_ref()) {
+                        field.error = error.clone();
+                    }
+                    field.checked = Some((pending.value, error));
+                }
+                None => {
+                    next = Some(next.map_or(TASK_POLL_INTERVAL, |next| next.min(TASK_POLL_INTERVAL)));
+                    field.pending = Some(pending);
+                }
+            }
+        }
+        next
+    }
+
+    /// Validate everything and, if valid, enter the submitting state
+    ///
+    /// Returns `false` (without submitting) if validation fails, async
+    /// validations are still pending or a submit is already in progress. Call [`FormState::finish_submit`] when done.
+    pub fn begin_submit(&mut self) -> bool {
+        if self.submitting || !self.validate_all() {
+            return false;
+        }
+        self.submitting = true;
+        true
+    }
+
+    /// Leave the submitting state
+    ///
+    /// On success the current values become the new initial values, so the
+    /// form is no longer dirty.
+    pub fn finish_submit(&mut self, success: bool) {
+        self.submitting = false;
+        if success {
+            for field in &mut self.fields {
+                field.initial = field.value.clone_box();
+            }
+        }
+    }
+
+    /// Whether a submit is in progress
+    pub fn is_submitting(&self) -> bool {
+        self.submitting
     }
 
     /// Reset all fields to initial state
     pub fn reset(&mut self) {
-        for field in self.fields.values_mut() {
-            field.value.clear();
+        for field in &mut self.fields {
+            field.cancel_pending();
+            field.value = field.initial.clone_box();
             field.error = None;
             field.touched = false;
         }
+        self.submitting = false;
     }
 
     /// Reset a single field
     pub fn reset_field(&mut self, name: &str) {
-        if let Some(field) = self.fields.get_mut(name) {
-            field.value.clear();
+        if let Some(field) = self.field_mut(name) {
+            field.cancel_pending();
+            field.value = field.initial.clone_box();
             field.error = None;
             field.touched = false;
         }
     }
 }
 
+/// Build a [`validators::pattern`] validator from a regex literal
+///
+/// The expression is checked at compile time and compiled once on first use.
+/// Requires the `regex` feature.
+///
+/// ## Example
+/// ```rust,ignore
+/// form.add_field("username", egui_shadcn::pattern!("^[a-z0-9_]{3,16}$", "3-16 lowercase letters, digits or _"));
+/// ```
+#[cfg(feature = "regex")]
+#[macro_export]
+macro_rules! pattern {
+    ($regex:literal, $message:expr) => {{
+        use $crate::__private::lazy_regex;
+        $crate::components::form::validators::pattern(lazy_regex::regex!($regex), $message)
+    }};
+}
+
 /// Common validation functions
 pub mod validators {
     /// Validate that a string is not empty
@@ -212,16 +767,99 @@ pub mod validators {
         }
     }
 
-    /// Validate that value matches a pattern
-    pub fn pattern(_regex_str: &'static str, message: &'static str) -> impl Fn(&str) -> Result<(), String> {
+    /// Validate that value matches a regular expression
+    ///
+    /// Prefer the [`pattern!`](crate::pattern) macro, which checks the
+    /// expression at compile time. Empty values pass; combine with
+    /// [`required`] for required fields.
+    #[cfg(feature = "regex")]
+    pub fn pattern(regex: &regex::Regex, message: impl Into<String>) -> impl Fn(&str) -> Result<(), String> {
+        let regex = regex.clone();
+        let message = message.into();
+        move |value: &str| {
+            if value.is_empty() || regex.is_match(value) {
+                Ok(())
+            } else {
+                Err(message.clone())
+            }
+        }
+    }
+
+    /// Validate an `http` or `https` URL with a host
+    pub fn url(value: &str) -> Result<(), String> {
+        if value.is_empty() {
+            return Ok(());
+        }
+        let rest = value
+            .strip_prefix("https://")
+            .or_else(|| value.strip_prefix("http://"));
+        let host = rest
+            .map(|rest| rest.split(['/', '?', '#']).next().unwrap_or(""))
+            .map(|authority| authority.rsplit_once('@').map_or(authority, |(_, host)| host));
+        match host {
+            Some(host) if is_host(host.split(':').next().unwrap_or("")) && !value.contains(char::is_whitespace) => Ok(()),
+            _ => Err("Please enter a valid URL".to_string()),
+        }
+    }
+
+    /// Validate a bech32 nostr public key (`npub1...`)
+    pub fn npub(value: &str) -> Result<(), String> {
+        nostr_bech32(value, "npub", "Please enter a valid npub")
+    }
+
+    /// Validate a bech32 nostr secret key (`nsec1...`)
+    pub fn nsec(value: &str) -> Result<(), String> {
+        nostr_bech32(value, "nsec", "Please enter a valid nsec")
+    }
+
+    /// Validate a bech32 nostr note id (`note1...`)
+    pub fn note(value: &str) -> Result<(), String> {
+        nostr_bech32(value, "note", "Please enter a valid note id")
+    }
+
+    /// Validate a 32-byte hex public key (64 hex characters)
+    pub fn hex_pubkey(value: &str) -> Result<(), String> {
+        if value.is_empty() || (value.len() == 64 && value.bytes().all(|b| b.is_ascii_hexdigit())) {
+            Ok(())
+        } else {
+            Err("Please enter a 64-character hex key".to_string())
+        }
+    }
+
+    /// Validate a NIP-05 identifier (`name@example.com`)
+    pub fn nip05(value: &str) -> Result<(), String> {
+        if value.is_empty() {
+            return Ok(());
+        }
+        let valid = value.split_once('@').is_some_and(|(name, domain)| {
+            !name.is_empty()
+                && name
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.'))
+                && is_host(domain)
+                && domain.contains('.')
+        });
+        if valid {
+            Ok(())
+        } else {
+            Err("Please enter an identifier like name@example.com".to_string())
+        }
+    }
+
+    /// Validate a number within `min..=max`
+    pub fn range<T>(min: T, max: T) -> impl Fn(&str) -> Result<(), String>
+    where
+        T: std::str::FromStr + PartialOrd + std::fmt::Display,
+    {
         move |value: &str| {
-            // Simple pattern matching without regex crate
-            // For full regex support, users should implement custom validators
-            if value.is_empty() {
+            if value.trim().is_empty() {
                 return Ok(());
             }
-            // This is a simplified check - for production use a proper regex crate
-            Err(message.to_string())
+            match value.trim().parse::<T>() {
+                Ok(number) if number >= min && number <= max => Ok(()),
+                Ok(_) => Err(format!("Must be between {} and {}", min, max)),
+                Err(_) => Err("Please enter a number".to_string()),
+            }
         }
     }
 
@@ -236,6 +874,92 @@ pub mod validators {
             v2(value)
         }
     }
+
+    /// Host name: dot-separated labels of letters, digits and hyphens
+    fn is_host(host: &str) -> bool {
+        !host.is_empty()
+            && host.split('.').all(|label| {
+                !label.is_empty()
+                    && !label.starts_with('-')
+                    && !label.ends_with('-')
+                    && label.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+            })
+    }
+
+    fn nostr_bech32(value: &str, hrp: &str, message: &str) -> Result<(), String> {
+        if value.is_empty() {
+            return Ok(());
+        }
+        match bech32::decode(value) {
+            Some((prefix, data)) if prefix == hrp && data.len() == 32 => Ok(()),
+            _ => Err(message.to_string()),
+        }
+    }
+
+    /// Minimal bech32 (BIP-173) decoding for nostr entities (NIP-19)
+    mod bech32 {
+        const CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+        const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+        fn polymod(values: impl Iterator<Item = u8>) -> u32 {
+            let mut checksum = 1u32;
+            for value in values {
+                let top = checksum >> 25;
+                checksum = ((checksum & 0x1ff_ffff) << 5) ^ value as u32;
+                for (i, generator) in GENERATOR.iter().enumerate() {
+                    if (top >> i) & 1 == 1 {
+                        checksum ^= generator;
+                    }
+                }
+            }
+            checksum
+        }
+
+        /// Decode into the human-readable prefix and payload bytes
+        ///
+        /// Returns `None` for mixed case, unknown characters, a bad checksum
+        /// or non-zero padding.
+        pub(super) fn decode(value: &str) -> Option<(String, Vec<u8>)> {
+            if value.bytes().any(|b| b.is_ascii_lowercase()) && value.bytes().any(|b| b.is_ascii_uppercase()) {
+                return None;
+            }
+            let value = value.to_ascii_lowercase();
+            let (hrp, data) = value.rsplit_once('1')?;
+            if hrp.is_empty() || data.len() < 6 || !hrp.bytes().all(|b| (33..=126).contains(&b)) {
+                return None;
+            }
+            let data = data
+                .bytes()
+                .map(|b| CHARSET.iter().position(|&c| c == b).map(|p| p as u8))
+                .collect::<Option<Vec<u8>>>()?;
+
+            let expanded = hrp
+                .bytes()
+                .map(|b| b >> 5)
+                .chain([0])
+                .chain(hrp.bytes().map(|b| b & 31))
+                .chain(data.iter().copied());
+            if polymod(expanded) != 1 {
+                return None;
+            }
+
+            // 5-bit groups -> bytes
+            let mut bytes = Vec::new();
+            let (mut acc, mut bits) = (0u32, 0u32);
+            for &group in &data[..data.len() - 6] {
+                acc = (acc << 5) | group as u32;
+                bits += 5;
+                if bits >= 8 {
+                    bits -= 8;
+                    bytes.push((acc >> bits) as u8);
+                }
+            }
+            if bits >= 5 || (acc & ((1 << bits) - 1)) != 0 {
+                return None;
+            }
+            Some((hrp.to_string(), bytes))
+        }
+    }
 }
 
 #[cfg(test)]
@@ -268,4 +992,313 @@ mod tests {
         assert!(min_5("hi").is_err());
         assert!(min_5("hello").is_ok());
     }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_pattern() {
+        let username = crate::pattern!("^[a-z0-9_]{3,16}$", "Invalid username");
+        assert!(username("").is_ok());
+        assert!(username("jack_21").is_ok());
+        assert_eq!(username("Jack"), Err("Invalid username".to_string()));
+
+        let regex = regex::Regex::new("^#[0-9a-f]{6}$").unwrap();
+        let color = validators::pattern(&regex, "Invalid color");
+        assert!(color("#a1b2c3").is_ok());
+        assert!(color("a1b2c3").is_err());
+    }
+
+    #[test]
+    fn test_url() {
+        assert!(validators::url("").is_ok());
+        assert!(validators::url("https://example.com").is_ok());
+        assert!(validators::url("http://localhost:8080/path?q=1#top").is_ok());
+        assert!(validators::url("https://user@sub.example.org").is_ok());
+
+        assert!(validators::url("example.com").is_err());
+        assert!(validators::url("ftp://example.com").is_err());
+        assert!(validators::url("https://").is_err());
+        assert!(validators::url("https://exa mple.com").is_err());
+        assert!(validators::url("https://-bad.com").is_err());
+    }
+
+    const NPUB: &str = "npub10elfcs4fr0l0r8af98jlmgdh9c8tcxjvz9qkw038js35mp4dma8qzvjptg";
+    const NSEC: &str = "nsec1vl029mgpspedva04g90vltkh6fvh240zqtv9k0t9af8935ke9laqsnlfe5";
+    const NOTE: &str = "note1vl029mgpspedva04g90vltkh6fvh240zqtv9k0t9af8935ke9laqd0h4xf";
+
+    #[test]
+    fn test_nostr_bech32() {
+        assert!(validators::npub("").is_ok());
+        assert!(validators::npub(NPUB).is_ok());
+        assert!(validators::npub(&NPUB.to_uppercase()).is_ok());
+        assert!(validators::nsec(NSEC).is_ok());
+        assert!(validators::note(NOTE).is_ok());
+
+        // Wrong prefix
+        assert!(validators::npub(NSEC).is_err());
+        assert!(validators::nsec(NPUB).is_err());
+        assert!(validators::note(NSEC).is_err());
+
+        // Bad checksum, mixed case, invalid character
+        let mut typo = NPUB.to_string();
+        typo.replace_range(10..11, "q");
+        assert!(validators::npub(&typo).is_err());
+        assert!(validators::npub(&NPUB.replacen('n', "N", 1)).is_err());
+        assert!(validators::npub(&NPUB.replacen('q', "b", 1)).is_err());
+
+        // Valid checksum but only 31 bytes of payload
+        assert!(validators::npub("npub1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqkxnxjx").is_err());
+    }
+
+    #[test]
+    fn test_hex_pubkey() {
+        let key = "7e7e9c42a91bfef19fa929e5fda1b72e0ebc1a4c1141673e2794234d86addf4e";
+        assert!(validators::hex_pubkey("").is_ok());
+        assert!(validators::hex_pubkey(key).is_ok());
+        assert!(validators::hex_pubkey(&key.to_uppercase()).is_ok());
+        assert!(validators::hex_pubkey(&key[1..]).is_err());
+        assert!(validators::hex_pubkey(&key.replacen('e', "g", 1)).is_err());
+        assert!(validators::hex_pubkey(NPUB).is_err());
+    }
+
+    #[test]
+    fn test_nip05() {
+        assert!(validators::nip05("").is_ok());
+        assert!(validators::nip05("bob@example.com").is_ok());
+        assert!(validators::nip05("_@bob.example.com").is_ok());
+        assert!(validators::nip05("jack.d-o_e@sub.domain.io").is_ok());
+
+        assert!(validators::nip05("bob").is_err());
+        assert!(validators::nip05("@example.com").is_err());
+        assert!(validators::nip05("bob@localhost").is_err());
+        assert!(validators::nip05("bob smith@example.com").is_err());
+        assert!(validators::nip05("bob@exa_mple.com").is_err());
+    }
+
+    #[test]
+    fn test_range() {
+        let percent = validators::range(0.0, 100.0);
+        assert!(percent("").is_ok());
+        assert!(percent("0").is_ok());
+        assert!(percent(" 42.5 ").is_ok());
+        assert_eq!(percent("100.1"), Err("Must be between 0 and 100".to_string()));
+        assert_eq!(percent("abc"), Err("Please enter a number".to_string()));
+
+        let port = validators::range(1_u16, 65535);
+        assert!(port("443").is_ok());
+        assert!(port("0").is_err());
+        assert!(port("70000").is_err());
+    }
+
+    #[test]
+    fn test_typed_fields() {
+        let mut form = FormState::new();
+        let terms = form.add("terms", false);
+        let age = form.add("age", 0_u32);
+        form.add_validator(terms, |accepted| if *accepted { Ok(()) } else { Err("Accept the terms".into()) });
+        form.add_validator(age, |age| if *age >= 18 { Ok(()) } else { Err("Must be 18 or older".into()) });
+
+        assert!(!form.validate_all());
+        assert_eq!(form.get_error("terms"), Some("Accept the terms"));
+
+        *form.get_mut(terms).unwrap() = true;
+        form.set(age, 30);
+        assert_eq!(form.get(age), Some(&30));
+        assert!(form.validate_all());
+
+        // Text accessors don't see non-string fields
+        assert!(form.get_value("age").is_none());
+    }
+
+    #[test]
+    fn test_password_confirmation() {
+        let mut form = FormState::new();
+        let password = form.add("password", String::new());
+        let confirm = form.add("confirm", String::new());
+        form.add_form_validator(move |form| {
+            if form.get(password) != form.get(confirm) {
+                return Err(FieldError::new("confirm", "Passwords don't match"));
+            }
+            Ok(())
+        });
+
+        form.set(password, "hunter22".to_string());
+        form.set(confirm, "hunter2".to_string());
+        assert_eq!(form.get_error("confirm"), Some("Passwords don't match"));
+        assert!(form.get_error("password").is_none());
+
+        form.set(confirm, "hunter22".to_string());
+        assert!(form.get_error("confirm").is_none());
+        assert!(form.validate_all());
+    }
+
+    #[test]
+    fn test_date_range() {
+        use chrono::NaiveDate;
+
+        let day = |d| NaiveDate::from_ymd_opt(2024, 5, d).unwrap();
+        let mut form = FormState::new();
+        let start = form.add("start", day(10));
+        let end = form.add("end", day(10));
+        form.add_form_validator(move |form| match (form.get(start), form.get(end)) {
+            (Some(start), Some(end)) if end < start => Err(FieldError::new("end", "End date must be after start date")),
+            _ => Ok(()),
+        });
+
+        form.set(end, day(9));
+        assert!(!form.validate_all());
+        assert_eq!(form.errors().collect::<Vec<_>>(), vec![("end", "End date must be after start date")]);
+
+        form.set(end, day(12));
+        assert!(form.validate_all());
+    }
+
+    #[test]
+    fn test_dirty_and_submit() {
+        let mut form = FormState::new();
+        let name = form.add("name", String::from("Ada"));
+        form.add_validator(name, |v: &String| validators::required(v));
+        assert!(!form.is_dirty());
+
+        form.set(name, String::new());
+        assert!(form.is_dirty());
+        assert!(form.is_field_dirty("name"));
+        assert!(!form.begin_submit());
+        assert!(!form.is_submitting());
+
+        form.set(name, String::from("Grace"));
+        assert!(form.begin_submit());
+        assert!(form.is_submitting());
+        assert!(!form.begin_submit());
+
+        form.finish_submit(true);
+        assert!(!form.is_submitting());
+        assert!(!form.is_dirty());
+
+        form.set(name, String::from("Linus"));
+        form.reset();
+        assert_eq!(form.get(name).map(String::as_str), Some("Grace"));
+        assert!(!form.is_touched("name"));
+    }
+
+    /// Where a test fills in the async check's result
+    type AnswerSlot = Arc<std::sync::Mutex<Option<Result<(), String>>>>;
+
+    /// Future that resolves once `answer` is filled in
+    struct Answer(AnswerSlot);
+
+    impl Future for Answer {
+        type Output = Result<(), String>;
+
+        fn poll(self: Pin<&mut Self>, _cx: &mut std::task::Context<'_>) -> Poll<Self::Output> {
+            match self.0.lock().unwrap().take() {
+                Some(result) => Poll::Ready(result),
+                None => Poll::Pending,
+            }
+        }
+    }
+
+    /// A form with a username field whose async check returns `answer`,
+    /// recording every value it was started for
+    fn username_form() -> (FormState, FieldKey<String>, AnswerSlot, Arc<std::sync::Mutex<Vec<String>>>) {
+        let answer = Arc::new(std::sync::Mutex::new(None));
+        let started = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut form = FormState::new();
+        let username = form.add("username", String::new());
+        form.add_validator(username, |v: &String| validators::required(v));
+        let (a, st) = (answer.clone(), started.clone());
+        form.set_async_validator(username, Duration::from_millis(300), move |name: &String| {
+            st.lock().unwrap().push(name.clone());
+            Box::pin(Answer(a.clone()))
+        });
+        (form, username, answer, started)
+    }
+
+    #[test]
+    fn test_async_validation_debounces() {
+        let (mut form, username, answer, started) = username_form();
+
+        form.set(username, "jack".to_string());
+        assert!(form.is_pending("username"));
+        assert!(!form.is_valid());
+
+        // Debounce: nothing starts until 300ms have passed
+        assert_eq!(form.poll_at(0.0), Some(0.3));
+        assert!(form.poll_at(0.2).is_some());
+        assert!(started.lock().unwrap().is_empty());
+
+        // Running checks are polled at an interval, not every frame
+        assert_eq!(form.poll_at(0.31), Some(TASK_POLL_INTERVAL));
+        assert_eq!(*started.lock().unwrap(), vec!["jack"]);
+
+        *answer.lock().unwrap() = Some(Err("Username is taken".into()));
+        assert_eq!(form.poll_at(0.4), None);
+        assert!(!form.is_pending("username"));
+        assert_eq!(form.get_error("username"), Some("Username is taken"));
+
+        // Re-validating the same value reuses the result
+        assert!(!form.validate_all());
+        assert!(!form.is_validating());
+        assert_eq!(started.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_async_validation_cancels_stale() {
+        let (mut form, username, answer, started) = username_form();
+
+        form.set(username, "jac".to_string());
+        form.poll_at(0.0);
+        form.poll_at(0.5);
+        assert_eq!(*started.lock().unwrap(), vec!["jac"]);
+
+        // Typing again drops the running check and restarts the debounce
+        form.set(username, "jack".to_string());
+        *answer.lock().unwrap() = Some(Err("Username is taken".into()));
+        form.poll_at(0.6);
+        assert!(form.get_error("username").is_none());
+        assert!(form.is_pending("username"));
+
+        *answer.lock().unwrap() = Some(Ok(()));
+        form.poll_at(1.0);
+        assert_eq!(*started.lock().unwrap(), vec!["jac", "jack"]);
+        assert!(form.is_valid());
+        assert!(form.begin_submit());
+    }
+
+    #[test]
+    fn test_async_validation_skipped_on_sync_error() {
+        let (mut form, username, _answer, started) = username_form();
+
+        form.set(username, "jack".to_string());
+        form.set(username, String::new());
+        assert!(!form.is_pending("username"));
+        assert_eq!(form.get_error("username"), Some("This field is required"));
+        assert_eq!(form.poll_at(1.0), None);
+        assert!(started.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_custom_executor() {
+        struct Rejecting;
+
+        struct Rejected;
+
+        impl ValidationTask for Rejected {
+            fn poll(&mut self) -> Option<Result<(), String>> {
+                Some(Err("Offline".into()))
+            }
+        }
+
+        impl ValidationExecutor for Rejecting {
+            fn spawn(&mut self, _future: ValidationFuture) -> Box<dyn ValidationTask> {
+                Box::new(Rejected)
+            }
+        }
+
+        let mut form = FormState::new().with_executor(Rejecting);
+        let handle = form.add("nip05", String::new());
+        form.set_async_validator(handle, Duration::ZERO, |_: &String| Box::pin(async { Ok(()) }));
+        form.set(handle, "bob@example.com".to_string());
+        form.poll_at(0.0);
+        assert_eq!(form.get_error("nip05"), Some("Offline"));
+    }
 }
@@ -27,6 +27,22 @@ pub fn shadcn_input(ui: &mut Ui, text: &mut String, placeholder: &str) -> Respon
 ///
 /// When `has_error` is true, the border will be red/destructive colored.
 pub fn shadcn_input_with_error(ui: &mut Ui, text: &mut String, placeholder: &str, has_error: bool) -> Response {
+    input_frame(ui, has_error, |ui| {
+        let text_edit = TextEdit::singleline(text)
+            .hint_text(placeholder)
+            .frame(false)  // Disable default frame, we provide our own
+            .desired_width(ui.available_width())
+            .vertical_align(egui::Align::Center);
+
+        ui.add(text_edit)
+    })
+}
+
+/// Paint the shadcn input frame around single-line content
+///
+/// `content` is laid out in the padded interior and returns the response
+/// whose focus drives the ring border (usually the `TextEdit`).
+pub(crate) fn input_frame(ui: &mut Ui, has_error: bool, content: impl FnOnce(&mut Ui) -> Response) -> Response {
     let theme = ui.ctx().data(|d| {
             d.get_temp::<ShadcnTheme>(egui::Id::new("shadcn_theme"))
                 .unwrap_or_else(ShadcnTheme::light)
@@ -40,27 +56,27 @@ pub fn shadcn_input_with_error(ui: &mut Ui, text: &mut String, placeholder: &str
     // Draw background
     ui.painter().rect_filled(rect, theme.radii.md, theme.colors.background);
 
-    // We'll detect focus after adding the text edit
-    let mut response: Option<Response> = None;
-
-    // Draw input inside the rect
-    ui.scope_builder(
-        egui::UiBuilder::new().max_rect(rect.shrink2(egui::vec2(12.0, 8.0))),
-        |ui| {
-            let text_edit = TextEdit::singleline(text)
-                .hint_text(placeholder)
-                .frame(false)  // Disable default frame, we provide our own
-                .desired_width(ui.available_width())
-                .vertical_align(egui::Align::Center);
+    // Draw content inside the rect
+    let response = ui
+        .scope_builder(
+            egui::UiBuilder::new().max_rect(rect.shrink2(egui::vec2(12.0, 8.0))),
+            content,
+        )
+        .inner;
+    let has_focus = response.has_focus();
 
-            response = Some(ui.add(text_edit));
-        },
+    ui.painter().rect_stroke(
+        rect,
+        theme.radii.md,
+        input_border(&theme, has_error, has_focus, is_hovered),
+        egui::StrokeKind::Inside,
     );
 
-    let response = response.unwrap();
-    let has_focus = response.has_focus();
+    response
+}
 
-    // Determine border color based on state
+/// Border stroke of a single-line input in the given state
+pub(crate) fn input_border(theme: &ShadcnTheme, has_error: bool, has_focus: bool, is_hovered: bool) -> egui::Stroke {
     // Error state takes priority - show destructive color
     let border_color = if has_error {
         theme.colors.destructive
@@ -73,16 +89,9 @@ pub fn shadcn_input_with_error(ui: &mut Ui, text: &mut String, placeholder: &str
         theme.colors.border
     };
 
-    // Draw border (thicker when focused or error for emphasis)
+    // Thicker when focused or error for emphasis
     let border_width = if has_focus || has_error { 2.0 } else { 1.0 };
-    ui.painter().rect_stroke(
-        rect,
-        theme.radii.md,
-        egui::Stroke::new(border_width, border_color),
-        egui::StrokeKind::Inside,
-    );
-
-    response
+    egui::Stroke::new(border_width, border_color)
 }
 
 /// Styled multi-line text area
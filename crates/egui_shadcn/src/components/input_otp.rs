@@ -0,0 +1,444 @@
+//! InputOTP component ported from shadcn/ui
+//!
+//! One-time password input with a slot per character, for verification codes
+//! and two-factor authentication.
+//!
+//! Reference: <https://ui.shadcn.com/docs/components/input-otp>
+
+use egui::{Event, EventFilter, Id, Key, Modifiers, Rect, Response, Sense, Ui, Vec2};
+use crate::animation::motion;
+use crate::components::input::input_border;
+use crate::theme::ShadcnTheme;
+
+/// Size of each slot (44px touch target, matching `shadcn_input`)
+const SLOT_SIZE: f32 = 44.0;
+
+/// Width reserved for a separator between groups
+const SEPARATOR_WIDTH: f32 = 24.0;
+
+/// Caret blink period in seconds
+const CARET_BLINK: f64 = 1.0;
+
+/// Editing rules shared by keyboard, paste and IME input
+#[derive(Debug, Clone, Copy)]
+struct OtpEditor {
+    length: usize,
+    alphanumeric: bool,
+}
+
+impl OtpEditor {
+    fn accepts(&self, c: char) -> bool {
+        if self.alphanumeric {
+            c.is_ascii_alphanumeric()
+        } else {
+            c.is_ascii_digit()
+        }
+    }
+
+    /// Last slot the caret may sit on: the first empty slot, or the last slot when full
+    fn max_cursor(&self, value: &str) -> usize {
+        value.chars().count().min(self.length.saturating_sub(1))
+    }
+
+    /// Type characters at the caret, advancing after each accepted one
+    ///
+    /// Characters after the one that fills the last slot are dropped.
+    fn type_text(&self, value: &mut String, cursor: &mut usize, text: &str) {
+        let mut chars: Vec<char> = value.chars().collect();
+        for c in text.chars().filter(|c| self.accepts(*c)) {
+            if *cursor < chars.len() {
+                chars[*cursor] = c;
+            } else {
+                chars.push(c);
+            }
+            if *cursor + 1 >= self.length {
+                break;
+            }
+            *cursor += 1;
+        }
+        *value = chars.into_iter().collect();
+    }
+
+    /// Clear the caret's slot, or move back and clear the previous one if empty
+    fn backspace(&self, value: &mut String, cursor: &mut usize) {
+        let mut chars: Vec<char> = value.chars().collect();
+        if *cursor < chars.len() {
+            chars.remove(*cursor);
+        } else if *cursor > 0 {
+            *cursor -= 1;
+            chars.remove(*cursor);
+        }
+        *value = chars.into_iter().collect();
+    }
+
+    /// Paste text: a whole code replaces the value, a fragment is typed at the caret
+    fn paste(&self, value: &mut String, cursor: &mut usize, text: &str) {
+        let code: String = text.chars().filter(|c| self.accepts(*c)).collect();
+        if code.chars().count() >= self.length {
+            *value = code.chars().take(self.length).collect();
+            *cursor = self.max_cursor(value);
+        } else {
+            self.type_text(value, cursor, &code);
+        }
+    }
+}
+
+/// Response from showing an [`InputOTP`]
+pub struct InputOTPResponse {
+    /// Response of the whole slot row
+    pub response: Response,
+    /// Whether the last slot was filled this frame
+    pub completed: bool,
+}
+
+/// Callback receiving the code once every slot is filled
+type CompleteCallback<'a> = Box<dyn FnOnce(&str) + 'a>;
+
+/// One-time password input with separate character slots
+///
+/// Typing fills the active slot and advances to the next; Backspace clears
+/// and moves back; Arrow Left/Right move between filled slots. Pasting a
+/// whole code fills every slot.
+///
+/// ## Example
+/// ```rust,ignore
+/// let mut code = String::new();
+/// InputOTP::new(&mut code, 6)
+///     .groups(&[3, 3])
+///     .on_complete(|code| verify(code))
+///     .show(ui);
+/// ```
+pub struct InputOTP<'a> {
+    value: &'a mut String,
+    length: usize,
+    groups: Vec<usize>,
+    alphanumeric: bool,
+    has_error: bool,
+    id_salt: Option<Id>,
+    on_complete: Option<CompleteCallback<'a>>,
+}
+
+impl<'a> InputOTP<'a> {
+    /// Create an input with `length` slots bound to `value`
+    pub fn new(value: &'a mut String, length: usize) -> Self {
+        Self {
+            value,
+            length: length.max(1),
+            groups: Vec::new(),
+            alphanumeric: false,
+            has_error: false,
+            id_salt: None,
+            on_complete: None,
+        }
+    }
+
+    /// Split the slots into groups with a separator between them
+    ///
+    /// E.g. `&[3, 3]` for `123-456`. Sizes beyond `length` are ignored.
+    pub fn groups(mut self, sizes: &[usize]) -> Self {
+        self.groups = sizes.to_vec();
+        self
+    }
+
+    /// Accept letters as well as digits
+    pub fn alphanumeric(mut self, alphanumeric: bool) -> Self {
+        self.alphanumeric = alphanumeric;
+        self
+    }
+
+    /// Show the error border
+    pub fn error(mut self, has_error: bool) -> Self {
+        self.has_error = has_error;
+        self
+    }
+
+    /// Set a stable ID salt (needed when the layout around the input changes)
+    pub fn id_salt(mut self, salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(salt));
+        self
+    }
+
+    /// Called with the code when the last slot is filled
+    pub fn on_complete(mut self, callback: impl FnOnce(&str) + 'a) -> Self {
+        self.on_complete = Some(Box::new(callback));
+        self
+    }
+
+    /// Indices of slots that are followed by a separator
+    fn separators(&self) -> Vec<usize> {
+        let mut end = 0;
+        self.groups
+            .iter()
+            .filter_map(|size| {
+                end += size;
+                (end < self.length).then(|| end - 1)
+            })
+            .collect()
+    }
+
+    /// Show the input
+    pub fn show(self, ui: &mut Ui) -> InputOTPResponse {
+        let theme = ui.ctx().data(|d| {
+            d.get_temp::<ShadcnTheme>(egui::Id::new("shadcn_theme"))
+                .unwrap_or_else(ShadcnTheme::light)
+        });
+        let editor = OtpEditor { length: self.length, alphanumeric: self.alphanumeric };
+        let id = match self.id_salt {
+            Some(salt) => ui.make_persistent_id(salt),
+            None => ui.next_auto_id(),
+        };
+        let separators = self.separators();
+
+        // Normalize values set from outside
+        let mut value: String = self.value.chars().filter(|c| editor.accepts(*c)).take(self.length).collect();
+        let was_complete = value.chars().count() == self.length;
+
+        let width = self.length as f32 * SLOT_SIZE + separators.len() as f32 * SEPARATOR_WIDTH;
+        let (rect, _) = ui.allocate_exact_size(Vec2::new(width, SLOT_SIZE), Sense::hover());
+        let response = ui.interact(rect, id, Sense::click());
+
+        // Slot rects, left to right
+        let mut slots = Vec::with_capacity(self.length);
+        let mut x = rect.left();
+        for i in 0..self.length {
+            slots.push(Rect::from_min_size(egui::pos2(x, rect.top()), Vec2::splat(SLOT_SIZE)));
+            x += SLOT_SIZE;
+            if separators.contains(&i) {
+                x += SEPARATOR_WIDTH;
+            }
+        }
+
+        let cursor_id = id.with("cursor");
+        let mut cursor = ui
+            .data(|d| d.get_temp::<usize>(cursor_id))
+            .unwrap_or(value.chars().count())
+            .min(editor.max_cursor(&value));
+
+        if response.clicked() {
+            response.request_focus();
+            if let Some(pos) = response.interact_pointer_pos() {
+                if let Some(clicked) = slots.iter().position(|slot| slot.contains(pos)) {
+                    cursor = clicked.min(editor.max_cursor(&value));
+                }
+            }
+        }
+
+        let has_focus = response.has_focus();
+        if has_focus {
+            ui.memory_mut(|m| {
+                m.set_focus_lock_filter(id, EventFilter { horizontal_arrows: true, ..Default::default() })
+            });
+
+            let events = ui.input(|i| i.events.clone());
+            for event in events {
+                match event {
+                    Event::Text(text) | Event::Ime(egui::ImeEvent::Commit(text)) => {
+                        editor.type_text(&mut value, &mut cursor, &text);
+                    }
+                    Event::Paste(text) => editor.paste(&mut value, &mut cursor, &text),
+                    Event::Key { key: Key::Backspace, pressed: true, .. } => {
+                        editor.backspace(&mut value, &mut cursor);
+                    }
+                    Event::Key { key: Key::ArrowLeft, pressed: true, .. } => {
+                        cursor = cursor.saturating_sub(1);
+                    }
+                    Event::Key { key: Key::ArrowRight, pressed: true, .. } => {
+                        cursor = (cursor + 1).min(editor.max_cursor(&value));
+                    }
+                    _ => {}
+                }
+            }
+            ui.input_mut(|i| {
+                i.consume_key(Modifiers::NONE, Key::ArrowLeft);
+                i.consume_key(Modifiers::NONE, Key::ArrowRight);
+            });
+
+            // Ask for the on-screen keyboard on touch devices
+            let caret = slots[cursor];
+            ui.ctx().output_mut(|o| {
+                o.ime = Some(egui::output::IMEOutput { rect, cursor_rect: caret });
+            });
+        }
+        ui.data_mut(|d| d.insert_temp(cursor_id, cursor));
+
+        let mut response = response;
+        if value != *self.value {
+            *self.value = value;
+            response.mark_changed();
+        }
+        let completed = !was_complete && self.value.chars().count() == self.length;
+        if completed {
+            if let Some(on_complete) = self.on_complete {
+                on_complete(self.value.as_str());
+            }
+        }
+
+        // Paint
+        if ui.is_rect_visible(rect) {
+            let painter = ui.painter();
+            let radius = theme.radii.md;
+            let hovered = response.hovered();
+            let border = input_border(&theme, self.has_error, false, hovered);
+            let chars: Vec<char> = self.value.chars().collect();
+
+            for (i, slot) in slots.iter().enumerate() {
+                let group_start = i == 0 || separators.contains(&(i - 1));
+                let group_end = i + 1 == self.length || separators.contains(&i);
+                let corners = egui::CornerRadius {
+                    nw: if group_start { radius } else { 0 },
+                    sw: if group_start { radius } else { 0 },
+                    ne: if group_end { radius } else { 0 },
+                    se: if group_end { radius } else { 0 },
+                };
+                // Neighbouring slots share a border
+                let slot_rect = if group_start { *slot } else { slot.with_min_x(slot.left() - border.width) };
+                painter.rect_filled(slot_rect, corners, theme.colors.background);
+                painter.rect_stroke(slot_rect, corners, border, egui::StrokeKind::Inside);
+
+                if let Some(c) = chars.get(i) {
+                    painter.text(
+                        slot.center(),
+                        egui::Align2::CENTER_CENTER,
+                        c,
+                        egui::FontId::proportional(theme.typography.body().size),
+                        theme.colors.foreground,
+                    );
+                }
+
+                if has_focus && i == cursor {
+                    theme.draw_focus_ring(painter, slot_rect, corners, true);
+
+                    // Blinking caret in the empty active slot
+                    if chars.get(i).is_none() {
+                        let time = ui.input(|i| i.time);
+                        let loops = motion(ui.ctx()).allows_loops();
+                        if !loops || time % CARET_BLINK < CARET_BLINK / 2.0 {
+                            let caret = Rect::from_center_size(slot.center(), Vec2::new(1.0, 16.0));
+                            painter.rect_filled(caret, 0.0, theme.colors.foreground);
+                        }
+                        if loops {
+                            let until_toggle = CARET_BLINK / 2.0 - time % (CARET_BLINK / 2.0);
+                            ui.ctx().request_repaint_after(std::time::Duration::from_secs_f64(until_toggle));
+                        }
+                    }
+                }
+
+                if separators.contains(&i) {
+                    let center = egui::pos2(slot.right() + SEPARATOR_WIDTH / 2.0, slot.center().y);
+                    painter.line_segment(
+                        [center - Vec2::new(4.0, 0.0), center + Vec2::new(4.0, 0.0)],
+                        egui::Stroke::new(1.5, theme.colors.muted_foreground),
+                    );
+                }
+            }
+        }
+
+        InputOTPResponse { response, completed }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{context_with_motion, key_press, run_frame_with_events};
+    use crate::theme::MotionPreference;
+
+    const DIGITS: OtpEditor = OtpEditor { length: 6, alphanumeric: false };
+
+    #[test]
+    fn test_typing_advances() {
+        let (mut value, mut cursor) = (String::new(), 0);
+        DIGITS.type_text(&mut value, &mut cursor, "12a3");
+        assert_eq!(value, "123");
+        assert_eq!(cursor, 3);
+
+        // Filling the last slot keeps the caret on it
+        DIGITS.type_text(&mut value, &mut cursor, "4567");
+        assert_eq!(value, "123456");
+        assert_eq!(cursor, 5);
+
+        // Typing over a filled slot replaces it
+        let mut cursor = 1;
+        DIGITS.type_text(&mut value, &mut cursor, "9");
+        assert_eq!(value, "193456");
+        assert_eq!(cursor, 2);
+    }
+
+    #[test]
+    fn test_backspace_moves_back() {
+        let (mut value, mut cursor) = ("123".to_string(), 3);
+        DIGITS.backspace(&mut value, &mut cursor);
+        assert_eq!((value.as_str(), cursor), ("12", 2));
+
+        // On a filled slot, clear it and stay
+        let (mut value, mut cursor) = ("123456".to_string(), 5);
+        DIGITS.backspace(&mut value, &mut cursor);
+        assert_eq!((value.as_str(), cursor), ("12345", 5));
+        DIGITS.backspace(&mut value, &mut cursor);
+        assert_eq!((value.as_str(), cursor), ("1234", 4));
+
+        let (mut value, mut cursor) = (String::new(), 0);
+        DIGITS.backspace(&mut value, &mut cursor);
+        assert_eq!((value.as_str(), cursor), ("", 0));
+    }
+
+    #[test]
+    fn test_paste_fills_slots() {
+        let (mut value, mut cursor) = ("12".to_string(), 2);
+        DIGITS.paste(&mut value, &mut cursor, "987-654");
+        assert_eq!(value, "987654");
+        assert_eq!(cursor, 5);
+
+        // A fragment is typed at the caret
+        let (mut value, mut cursor) = ("12".to_string(), 2);
+        DIGITS.paste(&mut value, &mut cursor, "34");
+        assert_eq!(value, "1234");
+
+        let letters = OtpEditor { length: 4, alphanumeric: true };
+        let (mut value, mut cursor) = (String::new(), 0);
+        letters.paste(&mut value, &mut cursor, "ab 12");
+        assert_eq!(value, "ab12");
+    }
+
+    #[test]
+    fn test_separators() {
+        let mut value = String::new();
+        let otp = InputOTP::new(&mut value, 6).groups(&[3, 3]);
+        assert_eq!(otp.separators(), vec![2]);
+        let otp = otp.groups(&[2, 2, 2, 5]);
+        assert_eq!(otp.separators(), vec![1, 3]);
+    }
+
+    #[test]
+    fn test_keyboard_entry_and_completion() {
+        let ctx = context_with_motion(MotionPreference::Full);
+        let mut value = String::new();
+        let mut completed_with = None;
+        let frame = |time: f64, events: Vec<Event>, value: &mut String, completed_with: &mut Option<String>| {
+            let mut id = None;
+            run_frame_with_events(&ctx, time, events, |ui| {
+                let response = InputOTP::new(value, 4)
+                    .id_salt("otp")
+                    .on_complete(|code| *completed_with = Some(code.to_string()))
+                    .show(ui);
+                id = Some(response.response.id);
+            });
+            id.unwrap()
+        };
+
+        let id = frame(0.0, Vec::new(), &mut value, &mut completed_with);
+        ctx.memory_mut(|m| m.request_focus(id));
+        frame(0.1, Vec::new(), &mut value, &mut completed_with);
+
+        frame(0.2, vec![Event::Text("1".into()), Event::Text("2".into())], &mut value, &mut completed_with);
+        assert_eq!(value, "12");
+        assert!(completed_with.is_none());
+
+        frame(0.3, vec![key_press(Key::Backspace, Modifiers::NONE)], &mut value, &mut completed_with);
+        assert_eq!(value, "1");
+
+        frame(0.4, vec![Event::Paste("4821".into())], &mut value, &mut completed_with);
+        assert_eq!(value, "4821");
+        assert_eq!(completed_with.as_deref(), Some("4821"));
+    }
+}
@@ -1,10 +1,12 @@
 //! Kbd component ported from shadcn/ui
 //!
-//! Displays keyboard shortcuts in a styled format.
+//! Displays keyboard shortcuts in a styled format. [`Kbd::shortcut`] renders
+//! a [`KeyboardShortcut`] for the current platform (⌘ on Mac, Ctrl elsewhere).
 //!
 //! Reference: <https://ui.shadcn.com/docs/components/kbd>
 
-use egui::{Response, Ui, Widget};
+use egui::{KeyboardShortcut, Response, Ui, Widget};
+use crate::shortcuts::{shortcut_keys, ShortcutPlatform};
 use crate::theme::ShadcnTheme;
 
 /// Kbd component for displaying keyboard shortcuts
@@ -13,16 +15,31 @@ use crate::theme::ShadcnTheme;
 /// ```rust,ignore
 /// ui.add(Kbd::new("Ctrl"));
 /// ui.add(Kbd::new("K"));
+///
+/// // One key cap per key: "⌘ K" on Mac, "Ctrl K" elsewhere
+/// ui.add(Kbd::shortcut(KeyboardShortcut::new(Modifiers::COMMAND, Key::K)));
 /// ```
 pub struct Kbd {
-    text: String,
+    content: KbdContent,
+}
+
+enum KbdContent {
+    Text(String),
+    Shortcut(KeyboardShortcut),
 }
 
 impl Kbd {
     /// Create a new keyboard shortcut display
     pub fn new(text: impl Into<String>) -> Self {
         Self {
-            text: text.into(),
+            content: KbdContent::Text(text.into()),
+        }
+    }
+
+    /// Display `shortcut` as a row of key caps, spelled for the current platform
+    pub fn shortcut(shortcut: KeyboardShortcut) -> Self {
+        Self {
+            content: KbdContent::Shortcut(shortcut),
         }
     }
 }
@@ -34,24 +51,39 @@ impl Widget for Kbd {
                 .unwrap_or_else(ShadcnTheme::light)
         });
 
-        // shadcn kbd style: subtle background with clear border
-        let bg_color = theme.colors.muted;
-        let border_color = theme.colors.foreground.linear_multiply(0.2);
-
-        let frame = egui::Frame::NONE
-            .fill(bg_color)
-            .stroke(egui::Stroke::new(1.0, border_color))
-            .inner_margin(5.0) // Compact padding
-            .corner_radius(4.0) // Small, consistent radius
-            .shadow(theme.shadows.xs2); // Subtle bottom shadow for "key" effect
-
-        frame.show(ui, |ui| {
-            ui.label(
-                egui::RichText::new(&self.text)
-                    .size(12.0) // Slightly smaller for compact look
-                    .family(egui::FontFamily::Monospace)
-                    .color(theme.colors.foreground.linear_multiply(0.8)),
-            );
-        }).response
+        match self.content {
+            KbdContent::Text(text) => key_cap(ui, &theme, &text),
+            KbdContent::Shortcut(shortcut) => {
+                let keys = shortcut_keys(&shortcut, ShortcutPlatform::of(ui.ctx()));
+                ui.horizontal(|ui| {
+                    ui.spacing_mut().item_spacing.x = 4.0;
+                    for key in &keys {
+                        key_cap(ui, &theme, key);
+                    }
+                }).response
+            }
+        }
     }
 }
+
+fn key_cap(ui: &mut Ui, theme: &ShadcnTheme, text: &str) -> Response {
+    // shadcn kbd style: subtle background with clear border
+    let bg_color = theme.colors.muted;
+    let border_color = theme.colors.foreground.linear_multiply(0.2);
+
+    let frame = egui::Frame::NONE
+        .fill(bg_color)
+        .stroke(egui::Stroke::new(1.0, border_color))
+        .inner_margin(5.0) // Compact padding
+        .corner_radius(4.0) // Small, consistent radius
+        .shadow(theme.shadows.xs2); // Subtle bottom shadow for "key" effect
+
+    frame.show(ui, |ui| {
+        ui.label(
+            egui::RichText::new(text)
+                .size(12.0) // Slightly smaller for compact look
+                .family(egui::FontFamily::Monospace)
+                .color(theme.colors.foreground.linear_multiply(0.8)),
+        );
+    }).response
+}
@@ -0,0 +1,565 @@
+//! Masked input component
+//!
+//! A single-line input that formats its value as you type: phone numbers,
+//! dates, currency amounts, fixed-length hex strings or any custom pattern.
+//! The bound string holds the raw value (only the typed characters); the
+//! formatted text is derived from it.
+//!
+//! Reference: <https://ui.shadcn.com/docs/components/input>
+
+use egui::text::{CCursor, CCursorRange};
+use egui::{Id, Response, TextEdit, Ui};
+use crate::components::input::input_frame;
+
+/// One position in a pattern mask
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Slot {
+    /// Inserted automatically, never part of the raw value
+    Literal(char),
+    /// `#`: 0-9
+    Digit,
+    /// `H`: 0-9, a-f, A-F
+    Hex,
+    /// `A`: a letter
+    Letter,
+    /// `*`: a letter or digit
+    Alphanumeric,
+}
+
+impl Slot {
+    fn parse(c: char) -> Self {
+        match c {
+            '#' => Slot::Digit,
+            'H' => Slot::Hex,
+            'A' => Slot::Letter,
+            '*' => Slot::Alphanumeric,
+            c => Slot::Literal(c),
+        }
+    }
+
+    fn accepts(self, c: char) -> bool {
+        match self {
+            Slot::Literal(_) => false,
+            Slot::Digit => c.is_ascii_digit(),
+            Slot::Hex => c.is_ascii_hexdigit(),
+            Slot::Letter => c.is_alphabetic(),
+            Slot::Alphanumeric => c.is_alphanumeric(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum MaskKind {
+    Pattern(Vec<Slot>),
+    Currency { symbol: String, decimals: usize },
+}
+
+/// Describes how a [`MaskedInput`] formats its raw value
+///
+/// Pattern masks use `#` for a digit, `H` for a hex digit, `A` for a letter
+/// and `*` for a letter or digit; every other character is a literal that is
+/// inserted as you type.
+///
+/// ## Example
+/// ```rust,ignore
+/// let phone = InputMask::phone();                // (###) ###-####
+/// let date = InputMask::date();                  // YYYY-MM-DD
+/// let price = InputMask::currency("$");          // $1,234.56
+/// let key = InputMask::hex(64);                  // 64 hex digits
+/// let plate = InputMask::pattern("AAA-####");    // custom
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputMask {
+    kind: MaskKind,
+    placeholder: String,
+}
+
+/// Result of applying an edit to a masked value
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct MaskedEdit {
+    raw: String,
+    formatted: String,
+    /// Cursor position in `formatted`, in characters
+    cursor: usize,
+}
+
+impl InputMask {
+    /// Mask from a pattern such as `(###) ###-####`
+    ///
+    /// The placeholder shows the pattern with `_` for each input position.
+    pub fn pattern(pattern: &str) -> Self {
+        let slots: Vec<Slot> = pattern.chars().map(Slot::parse).collect();
+        let placeholder = slots
+            .iter()
+            .map(|slot| match slot {
+                Slot::Literal(c) => *c,
+                _ => '_',
+            })
+            .collect();
+        Self {
+            kind: MaskKind::Pattern(slots),
+            placeholder,
+        }
+    }
+
+    /// US phone number: `(###) ###-####`
+    pub fn phone() -> Self {
+        Self::pattern("(###) ###-####")
+    }
+
+    /// ISO date: `YYYY-MM-DD`
+    pub fn date() -> Self {
+        Self::pattern("####-##-##").with_placeholder("YYYY-MM-DD")
+    }
+
+    /// Fixed-length hex string (e.g. 64 for a public key)
+    pub fn hex(len: usize) -> Self {
+        Self::pattern(&"H".repeat(len))
+    }
+
+    /// Thousands-separated amount with two decimals, e.g. `$1,234.56`
+    ///
+    /// `symbol` is prefixed to the amount and must not contain digits or `.`.
+    pub fn currency(symbol: impl Into<String>) -> Self {
+        let symbol = symbol.into();
+        Self {
+            placeholder: format!("{}0.00", symbol),
+            kind: MaskKind::Currency { symbol, decimals: 2 },
+        }
+    }
+
+    /// Set the number of decimals for a currency mask (no effect on patterns)
+    pub fn decimals(mut self, decimals: usize) -> Self {
+        if let MaskKind::Currency { decimals: d, .. } = &mut self.kind {
+            *d = decimals;
+        }
+        self
+    }
+
+    /// Replace the placeholder shown while the input is empty
+    pub fn with_placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Placeholder shown while the input is empty
+    pub fn placeholder(&self) -> &str {
+        &self.placeholder
+    }
+
+    /// Extract the raw value from typed, pasted or formatted text
+    ///
+    /// Characters that don't fit the mask are dropped, and input beyond the
+    /// mask's length is ignored.
+    pub fn extract(&self, text: &str) -> String {
+        match &self.kind {
+            MaskKind::Pattern(slots) => {
+                let mut raw = String::new();
+                let mut pos = 0;
+                for c in text.chars() {
+                    // A literal typed where the mask expects it is formatting, not input
+                    if slots.get(pos) == Some(&Slot::Literal(c)) {
+                        pos += 1;
+                        continue;
+                    }
+                    while let Some(Slot::Literal(_)) = slots.get(pos) {
+                        pos += 1;
+                    }
+                    match slots.get(pos) {
+                        Some(slot) if slot.accepts(c) => {
+                            raw.push(c);
+                            pos += 1;
+                        }
+                        Some(_) => {}
+                        None => break,
+                    }
+                }
+                raw
+            }
+            MaskKind::Currency { decimals, .. } => {
+                let mut raw = String::new();
+                let mut fraction: Option<usize> = None;
+                for c in text.chars() {
+                    match (c, fraction) {
+                        ('0'..='9', None) => raw.push(c),
+                        ('0'..='9', Some(len)) if len < *decimals => {
+                            raw.push(c);
+                            fraction = Some(len + 1);
+                        }
+                        // Without decimals the fraction is dropped, not merged into the integer
+                        ('.', None) => {
+                            if *decimals > 0 {
+                                if raw.is_empty() {
+                                    raw.push('0');
+                                }
+                                raw.push('.');
+                            }
+                            fraction = Some(0);
+                        }
+                        _ => {}
+                    }
+                }
+                // Drop leading zeros ("007" -> "7", but keep "0.5")
+                let leading = raw
+                    .chars()
+                    .zip(raw.chars().skip(1))
+                    .take_while(|(c, next)| *c == '0' && next.is_ascii_digit())
+                    .count();
+                raw.split_off(leading)
+            }
+        }
+    }
+
+    /// Format a raw value for display
+    pub fn format(&self, raw: &str) -> String {
+        match &self.kind {
+            MaskKind::Pattern(slots) => {
+                let mut chars = raw.chars().peekable();
+                let mut formatted = String::new();
+                for slot in slots {
+                    if chars.peek().is_none() {
+                        break;
+                    }
+                    match slot {
+                        Slot::Literal(c) => formatted.push(*c),
+                        _ => formatted.extend(chars.next()),
+                    }
+                }
+                formatted
+            }
+            MaskKind::Currency { symbol, .. } => {
+                if raw.is_empty() {
+                    return String::new();
+                }
+                let (int, fraction) = match raw.split_once('.') {
+                    Some((int, fraction)) => (int, Some(fraction)),
+                    None => (raw, None),
+                };
+                let mut formatted = symbol.clone();
+                for (i, c) in int.chars().enumerate() {
+                    if i > 0 && (int.len() - i) % 3 == 0 {
+                        formatted.push(',');
+                    }
+                    formatted.push(c);
+                }
+                if let Some(fraction) = fraction {
+                    formatted.push('.');
+                    formatted.push_str(fraction);
+                }
+                formatted
+            }
+        }
+    }
+
+    /// Whether the raw value fills the mask
+    ///
+    /// Pattern masks are complete when every input position is filled;
+    /// currency masks when an amount has been entered.
+    pub fn is_complete(&self, raw: &str) -> bool {
+        match &self.kind {
+            MaskKind::Pattern(slots) => {
+                let positions = slots.iter().filter(|s| !matches!(s, Slot::Literal(_))).count();
+                raw.chars().count() == positions
+            }
+            MaskKind::Currency { .. } => !raw.is_empty(),
+        }
+    }
+
+    /// Character indices in `formatted` that hold raw characters
+    fn raw_positions(&self, formatted: &str) -> Vec<usize> {
+        match &self.kind {
+            MaskKind::Pattern(slots) => slots
+                .iter()
+                .zip(formatted.chars())
+                .enumerate()
+                .filter(|(_, (slot, _))| !matches!(slot, Slot::Literal(_)))
+                .map(|(i, _)| i)
+                .collect(),
+            MaskKind::Currency { symbol, .. } => formatted
+                .chars()
+                .enumerate()
+                .skip(symbol.chars().count())
+                .filter(|(_, c)| c.is_ascii_digit() || *c == '.')
+                .map(|(i, _)| i)
+                .collect(),
+        }
+    }
+
+    /// Apply a text edit and work out where the cursor goes
+    ///
+    /// `edited` is the text after the edit with the cursor at character index
+    /// `cursor`. The cursor keeps the same number of raw characters before
+    /// it, so it skips over literals the mask inserts or removes.
+    fn apply_edit(&self, old_raw: &str, old_formatted: &str, edited: &str, cursor: usize) -> MaskedEdit {
+        let mut raw = self.extract(edited);
+        let prefix: String = edited.chars().take(cursor).collect();
+        let mut before = self.extract(&prefix).chars().count().min(raw.chars().count());
+
+        // Deleting only a literal deletes the raw character in front of it
+        if raw == old_raw && edited.chars().count() < old_formatted.chars().count() && before > 0 {
+            let (index, _) = raw.char_indices().nth(before - 1).unwrap_or_default();
+            raw.remove(index);
+            raw = self.extract(&raw);
+            before -= 1;
+        }
+
+        let formatted = self.format(&raw);
+        let positions = self.raw_positions(&formatted);
+        let cursor = match before {
+            0 => positions.first().copied().unwrap_or(0),
+            n => positions.get(n - 1).map_or(formatted.chars().count(), |p| p + 1),
+        };
+        MaskedEdit { raw, formatted, cursor }
+    }
+}
+
+/// Response from showing a [`MaskedInput`]
+pub struct MaskedInputResponse {
+    /// Response of the underlying text edit
+    pub response: Response,
+    /// The formatted text as displayed
+    pub formatted: String,
+    /// Whether the raw value fills the mask
+    pub complete: bool,
+}
+
+/// Single-line input that formats its value as you type
+///
+/// The bound string holds the raw value: `5551234567` for a phone number,
+/// `20240115` for a date, `1234.5` for an amount. The cursor stays next to
+/// the character you typed as separators are inserted.
+///
+/// ## Example
+/// ```rust,ignore
+/// let mut phone = String::new();
+/// let response = MaskedInput::new(&mut phone, InputMask::phone()).show(ui);
+/// if response.complete {
+///     // phone == "5551234567", response.formatted == "(555) 123-4567"
+/// }
+/// ```
+pub struct MaskedInput<'a> {
+    raw: &'a mut String,
+    mask: InputMask,
+    placeholder: Option<String>,
+    has_error: bool,
+    id_salt: Option<Id>,
+}
+
+impl<'a> MaskedInput<'a> {
+    /// Create a masked input bound to a raw value
+    pub fn new(raw: &'a mut String, mask: InputMask) -> Self {
+        Self {
+            raw,
+            mask,
+            placeholder: None,
+            has_error: false,
+            id_salt: None,
+        }
+    }
+
+    /// Override the mask's placeholder
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Show the error border
+    pub fn error(mut self, has_error: bool) -> Self {
+        self.has_error = has_error;
+        self
+    }
+
+    /// Set a stable ID salt (needed when the layout around the input changes)
+    pub fn id_salt(mut self, salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(salt));
+        self
+    }
+
+    /// Show the input
+    pub fn show(self, ui: &mut Ui) -> MaskedInputResponse {
+        let Self { raw, mask, placeholder, has_error, id_salt } = self;
+        let id = match id_salt {
+            Some(salt) => ui.make_persistent_id(salt),
+            None => ui.next_auto_id(),
+        };
+
+        // Normalize values set from outside
+        let clean = mask.extract(raw);
+        if *raw != clean {
+            *raw = clean;
+        }
+        let old_formatted = mask.format(raw);
+        let mut text = old_formatted.clone();
+        let placeholder = placeholder.unwrap_or_else(|| mask.placeholder().to_owned());
+
+        let response = input_frame(ui, has_error, |ui| {
+            let output = TextEdit::singleline(&mut text)
+                .id(id)
+                .hint_text(placeholder)
+                .frame(false)
+                .desired_width(ui.available_width())
+                .vertical_align(egui::Align::Center)
+                .show(ui);
+
+            if output.response.changed() {
+                let cursor = output
+                    .cursor_range
+                    .map_or(text.chars().count(), |range| range.primary.index);
+                let edit = mask.apply_edit(raw, &old_formatted, &text, cursor);
+                *raw = edit.raw;
+                text = edit.formatted;
+
+                let mut state = output.state;
+                state
+                    .cursor
+                    .set_char_range(Some(CCursorRange::one(CCursor::new(edit.cursor))));
+                state.store(ui.ctx(), id);
+                // The edited text was already drawn this frame
+                ui.ctx().request_repaint();
+            }
+            output.response
+        });
+
+        MaskedInputResponse {
+            response,
+            complete: mask.is_complete(raw),
+            formatted: text,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Type `input` at `cursor` into the formatted value of `raw`
+    fn type_at(mask: &InputMask, raw: &str, cursor: usize, input: &str) -> MaskedEdit {
+        let formatted = mask.format(raw);
+        let mut edited: Vec<char> = formatted.chars().collect();
+        let inserted: Vec<char> = input.chars().collect();
+        edited.splice(cursor..cursor, inserted.iter().copied());
+        let edited: String = edited.into_iter().collect();
+        mask.apply_edit(raw, &formatted, &edited, cursor + inserted.len())
+    }
+
+    /// Delete the character before `cursor` in the formatted value of `raw`
+    fn backspace_at(mask: &InputMask, raw: &str, cursor: usize) -> MaskedEdit {
+        let formatted = mask.format(raw);
+        let mut edited: Vec<char> = formatted.chars().collect();
+        edited.remove(cursor - 1);
+        let edited: String = edited.into_iter().collect();
+        mask.apply_edit(raw, &formatted, &edited, cursor - 1)
+    }
+
+    #[test]
+    fn test_phone_formatting() {
+        let mask = InputMask::phone();
+        assert_eq!(mask.format(""), "");
+        assert_eq!(mask.format("5"), "(5");
+        assert_eq!(mask.format("5551"), "(555) 1");
+        assert_eq!(mask.format("5551234567"), "(555) 123-4567");
+        assert_eq!(mask.extract("(555) 123-4567"), "5551234567");
+        assert_eq!(mask.extract("555.123.4567 ext 9"), "5551234567");
+        assert!(mask.is_complete("5551234567"));
+        assert!(!mask.is_complete("555123"));
+        assert_eq!(mask.placeholder(), "(___) ___-____");
+    }
+
+    #[test]
+    fn test_typing_skips_inserted_literals() {
+        let mask = InputMask::phone();
+        let mut raw = String::new();
+        let mut cursor = 0;
+        for digit in "5551234".chars() {
+            let edit = type_at(&mask, &raw, cursor, &digit.to_string());
+            raw = edit.raw;
+            cursor = edit.cursor;
+        }
+        assert_eq!(raw, "5551234");
+        assert_eq!(mask.format(&raw), "(555) 123-4");
+        assert_eq!(cursor, 11);
+    }
+
+    #[test]
+    fn test_cursor_stays_after_mid_insert() {
+        let mask = InputMask::phone();
+        // (555) 1|23 -> type 9 -> (555) 19|2-3
+        let edit = type_at(&mask, "555123", 7, "9");
+        assert_eq!(edit.formatted, "(555) 192-3");
+        assert_eq!(edit.cursor, 8);
+
+        // Typing just before a literal lands after it
+        let edit = type_at(&mask, "555", 4, "1");
+        assert_eq!(edit.formatted, "(555) 1");
+        assert_eq!(edit.cursor, 7);
+    }
+
+    #[test]
+    fn test_backspace_over_literal_deletes_digit() {
+        let mask = InputMask::phone();
+        // (555) |123: deleting the space removes the last area code digit
+        let edit = backspace_at(&mask, "555123", 6);
+        assert_eq!(edit.raw, "55123");
+        assert_eq!(edit.formatted, "(551) 23");
+        assert_eq!(edit.cursor, 3);
+
+        let edit = backspace_at(&mask, "555123", 8);
+        assert_eq!(edit.raw, "55513");
+        assert_eq!(edit.cursor, 7);
+    }
+
+    #[test]
+    fn test_date_mask() {
+        let mask = InputMask::date();
+        assert_eq!(mask.placeholder(), "YYYY-MM-DD");
+        assert_eq!(mask.format("202401"), "2024-01");
+        assert_eq!(mask.extract("2024/01/15"), "20240115");
+        assert_eq!(mask.format("20240115"), "2024-01-15");
+        assert!(mask.is_complete("20240115"));
+
+        let edit = type_at(&mask, "2024", 4, "0");
+        assert_eq!(edit.formatted, "2024-0");
+        assert_eq!(edit.cursor, 6);
+    }
+
+    #[test]
+    fn test_hex_mask() {
+        let mask = InputMask::hex(8);
+        assert_eq!(mask.extract("DEADbeefcafe"), "DEADbeef");
+        assert_eq!(mask.extract("xyz12"), "12");
+        assert!(mask.is_complete("deadbeef"));
+        assert!(!mask.is_complete("dead"));
+    }
+
+    #[test]
+    fn test_currency_mask() {
+        let mask = InputMask::currency("$");
+        assert_eq!(mask.format(""), "");
+        assert_eq!(mask.format("1234567.5"), "$1,234,567.5");
+        assert_eq!(mask.format("123"), "$123");
+        assert_eq!(mask.extract("$1,234.567"), "1234.56");
+        assert_eq!(mask.extract("007"), "7");
+        assert_eq!(mask.extract(".5"), "0.5");
+        assert_eq!(mask.extract("1.2.3"), "1.23");
+        assert_eq!(InputMask::currency("$").decimals(0).extract("12.50"), "12");
+
+        // $1,23|4 -> type 5 -> $12,35|4
+        let edit = type_at(&mask, "1234", 5, "5");
+        assert_eq!(edit.raw, "12354");
+        assert_eq!(edit.formatted, "$12,354");
+        assert_eq!(edit.cursor, 6);
+
+        // $1,|234: deleting the separator deletes the 1
+        let edit = backspace_at(&mask, "1234", 3);
+        assert_eq!(edit.formatted, "$234");
+        assert_eq!(edit.cursor, 1);
+    }
+
+    #[test]
+    fn test_custom_pattern_with_digit_literal() {
+        let mask = InputMask::pattern("+1 ###");
+        assert_eq!(mask.extract("+1 555"), "555");
+        assert_eq!(mask.extract("555"), "555");
+        assert_eq!(mask.format("555"), "+1 555");
+    }
+}
@@ -0,0 +1,691 @@
+//! Menu model shared by Menubar, DropdownMenu and ContextMenu
+//!
+//! A [`MenuModel`] describes a menu once: actions with stable IDs, checkbox
+//! and radio items bound to the caller's state, nested submenus, separators
+//! and labels. The menu components render it and report the chosen action,
+//! and item shortcuts fire their action even while the menu is closed. Item
+//! shortcuts go through the [`ShortcutRegistry`], so they are listed in its
+//! help dialog.
+
+use egui::{Context, Id, KeyboardShortcut, LayerId, Pos2, Rect, Response, Sense, Ui, Vec2};
+use crate::components::tooltip::TooltipExt;
+use crate::overlay::{OverlayKind, OverlayStack};
+use crate::shortcuts::{Shortcut, ShortcutRegistry, ShortcutScope};
+use crate::theme::ShadcnTheme;
+
+/// A menu entry that triggers an action
+///
+/// ## Example
+/// ```rust,ignore
+/// MenuItem::new("save", "Save")
+///     .shortcut(KeyboardShortcut::new(Modifiers::COMMAND, Key::S))
+///     .disabled("Nothing to save");
+/// ```
+pub struct MenuItem<A> {
+    action: A,
+    label: String,
+    shortcut: Option<KeyboardShortcut>,
+    disabled: Option<String>,
+    destructive: bool,
+}
+
+impl<A> MenuItem<A> {
+    /// Create an item reporting `action` when chosen
+    pub fn new(action: A, label: impl Into<String>) -> Self {
+        Self {
+            action,
+            label: label.into(),
+            shortcut: None,
+            disabled: None,
+            destructive: false,
+        }
+    }
+
+    /// Set the keyboard shortcut, shown in the menu and active while it is closed
+    pub fn shortcut(mut self, shortcut: KeyboardShortcut) -> Self {
+        self.shortcut = Some(shortcut);
+        self
+    }
+
+    /// Disable the item; `reason` is shown in a tooltip when it is hovered
+    pub fn disabled(mut self, reason: impl Into<String>) -> Self {
+        self.disabled = Some(reason.into());
+        self
+    }
+
+    /// Style the item as destructive (red)
+    pub fn destructive(mut self, destructive: bool) -> Self {
+        self.destructive = destructive;
+        self
+    }
+
+    /// The action reported when the item is chosen
+    pub fn action(&self) -> &A {
+        &self.action
+    }
+
+    /// The item's label
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    fn enabled(&self) -> bool {
+        self.disabled.is_none()
+    }
+}
+
+/// The items of a menu
+///
+/// Built every frame like the components rendering it; checkbox and radio
+/// items borrow the state they toggle.
+///
+/// ## Example
+/// ```rust,ignore
+/// let menu = MenuModel::new()
+///     .item(MenuItem::new("new", "New").shortcut(KeyboardShortcut::new(Modifiers::COMMAND, Key::N)))
+///     .submenu("Open Recent", MenuModel::new()
+///         .item(MenuItem::new("recent_1", "notes.md")))
+///     .separator()
+///     .checkbox(MenuItem::new("word_wrap", "Word Wrap"), &mut self.word_wrap)
+///     .radio_group(&mut self.sort, [
+///         (MenuItem::new("sort_name", "Sort by Name"), Sort::Name),
+///         (MenuItem::new("sort_date", "Sort by Date"), Sort::Date),
+///     ]);
+/// ```
+pub struct MenuModel<'a, A = &'static str> {
+    entries: Vec<MenuEntry<'a, A>>,
+}
+
+enum MenuEntry<'a, A> {
+    Item(MenuItem<A>),
+    Checkbox(MenuItem<A>, &'a mut bool),
+    RadioGroup(Vec<MenuItem<A>>, Box<dyn RadioBinding + 'a>),
+    Submenu(String, MenuModel<'a, A>),
+    Separator,
+    Label(String),
+}
+
+/// Selection state of a radio group, erased over the value type
+trait RadioBinding {
+    fn is_selected(&self, index: usize) -> bool;
+    fn select(&mut self, index: usize);
+}
+
+struct RadioValues<'a, V> {
+    selected: &'a mut V,
+    values: Vec<V>,
+}
+
+impl<V: Clone + PartialEq> RadioBinding for RadioValues<'_, V> {
+    fn is_selected(&self, index: usize) -> bool {
+        self.values.get(index) == Some(&*self.selected)
+    }
+
+    fn select(&mut self, index: usize) {
+        if let Some(value) = self.values.get(index) {
+            *self.selected = value.clone();
+        }
+    }
+}
+
+impl<A> Default for MenuModel<'_, A> {
+    fn default() -> Self {
+        Self { entries: Vec::new() }
+    }
+}
+
+impl<'a, A: Clone> MenuModel<'a, A> {
+    /// Create an empty menu
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an action item
+    pub fn item(mut self, item: MenuItem<A>) -> Self {
+        self.entries.push(MenuEntry::Item(item));
+        self
+    }
+
+    /// Add an item that toggles `checked` when chosen
+    pub fn checkbox(mut self, item: MenuItem<A>, checked: &'a mut bool) -> Self {
+        self.entries.push(MenuEntry::Checkbox(item, checked));
+        self
+    }
+
+    /// Add a group of items that set `selected` to their value when chosen
+    pub fn radio_group<V: Clone + PartialEq + 'a>(
+        mut self,
+        selected: &'a mut V,
+        items: impl IntoIterator<Item = (MenuItem<A>, V)>,
+    ) -> Self {
+        let (items, values): (Vec<_>, Vec<_>) = items.into_iter().unzip();
+        self.entries.push(MenuEntry::RadioGroup(items, Box::new(RadioValues { selected, values })));
+        self
+    }
+
+    /// Add a nested menu, opened by hovering it or pressing the Right arrow
+    pub fn submenu(mut self, label: impl Into<String>, menu: MenuModel<'a, A>) -> Self {
+        self.entries.push(MenuEntry::Submenu(label.into(), menu));
+        self
+    }
+
+    /// Add a separator
+    pub fn separator(mut self) -> Self {
+        self.entries.push(MenuEntry::Separator);
+        self
+    }
+
+    /// Add a label/header
+    pub fn label(mut self, text: impl Into<String>) -> Self {
+        self.entries.push(MenuEntry::Label(text.into()));
+        self
+    }
+
+    /// Number of top-level entries, including separators and labels
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the menu has no entries
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Run the action whose shortcut was pressed this frame, if any
+    ///
+    /// The enabled items' shortcuts are registered in the [`ShortcutRegistry`]
+    /// under [`ShortcutScope::Menu`]`(name)`, which should be unique to the
+    /// menu. Keys the focused scope or a global shortcut also binds are left
+    /// to them (see [`ShortcutRegistry::conflicts`]).
+    ///
+    /// Checkbox and radio items update their state. Menu components call this
+    /// every frame, so shortcuts work whether or not the menu is open.
+    pub fn consume_shortcuts(&mut self, ctx: &Context, name: &str) -> Option<A> {
+        let scope = ShortcutScope::Menu(name.to_owned());
+        let mut shortcuts = Vec::new();
+        self.collect_shortcuts("", &mut shortcuts);
+        let keys: Vec<KeyboardShortcut> = shortcuts.iter().map(|s| s.keys).collect();
+        ShortcutRegistry::sync_menu(ctx, &scope, shortcuts);
+        let pressed = ShortcutRegistry::dispatch_menu(ctx, &scope)?;
+        self.trigger_shortcut(&keys[pressed])
+    }
+
+    /// Collect the enabled items' shortcuts, identified by their position in
+    /// the menu (entry indices joined by dots, e.g. `"3.1"`) below `path`
+    fn collect_shortcuts(&self, path: &str, out: &mut Vec<Shortcut>) {
+        let shortcut = |item: &MenuItem<A>, id: String| {
+            let keys = item.shortcut.filter(|_| item.enabled())?;
+            Some(Shortcut::new(id, item.label.clone(), keys))
+        };
+        for (index, entry) in self.entries.iter().enumerate() {
+            let id = format!("{path}{index}");
+            match entry {
+                MenuEntry::Item(item) | MenuEntry::Checkbox(item, _) => out.extend(shortcut(item, id)),
+                MenuEntry::RadioGroup(items, _) => out.extend(
+                    items.iter().enumerate().filter_map(|(i, item)| shortcut(item, format!("{id}.{i}"))),
+                ),
+                MenuEntry::Submenu(_, menu) => menu.collect_shortcuts(&format!("{id}."), out),
+                MenuEntry::Separator | MenuEntry::Label(_) => {}
+            }
+        }
+    }
+
+    fn trigger_shortcut(&mut self, shortcut: &KeyboardShortcut) -> Option<A> {
+        let matches = |item: &MenuItem<A>| item.enabled() && item.shortcut.as_ref() == Some(shortcut);
+        for entry in &mut self.entries {
+            let action = match entry {
+                MenuEntry::Item(item) if matches(item) => Some(item.action.clone()),
+                MenuEntry::Checkbox(item, checked) if matches(item) => {
+                    **checked = !**checked;
+                    Some(item.action.clone())
+                }
+                MenuEntry::RadioGroup(items, binding) => items.iter().position(matches).map(|index| {
+                    binding.select(index);
+                    items[index].action.clone()
+                }),
+                MenuEntry::Submenu(_, menu) => menu.trigger_shortcut(shortcut),
+                _ => None,
+            };
+            if action.is_some() {
+                return action;
+            }
+        }
+        None
+    }
+}
+
+/// Size of the rows of a menu popup
+#[derive(Clone, Copy)]
+pub(crate) struct MenuLayout {
+    pub width: f32,
+    pub row_height: f32,
+}
+
+/// Result of showing an open menu for a frame
+pub(crate) struct MenuOutput<A> {
+    /// Action chosen this frame; the menu should close
+    pub action: Option<A>,
+    /// Escape or a click outside dismissed the menu
+    pub dismissed: bool,
+}
+
+/// Show an open menu at `pos`, with its open submenus beside it
+///
+/// The menu registers as one overlay under `id`. Clicks inside `anchor` (the
+/// trigger) are left to the caller rather than dismissing the menu.
+pub(crate) fn show_menu<A: Clone>(
+    ctx: &Context,
+    theme: &ShadcnTheme,
+    id: Id,
+    menu: &mut MenuModel<'_, A>,
+    pos: Pos2,
+    anchor: Rect,
+    layout: MenuLayout,
+) -> MenuOutput<A> {
+    let overlay = OverlayStack::register(ctx, id, OverlayKind::Popover);
+    let path_id = id.with("submenus");
+    let mut pass = MenuPass {
+        ctx: ctx.clone(),
+        theme,
+        layout,
+        path: ctx.data(|d| d.get_temp::<Vec<usize>>(path_id).unwrap_or_default()),
+        parent_rows: Vec::new(),
+        panels: Vec::new(),
+        focus_first: None,
+        focus_row: None,
+        action: None,
+    };
+    pass.panel(menu, 0, id.with("panel"), pos);
+
+    for (_, layer) in &pass.panels {
+        overlay.raise(ctx, *layer);
+    }
+
+    let mut dismissed = false;
+    if overlay.escape_pressed(ctx) {
+        // Escape closes the innermost submenu first
+        if let Some(parent_row) = pass.parent_rows.get(pass.path.len().wrapping_sub(1)).copied() {
+            pass.path.pop();
+            ctx.memory_mut(|m| m.request_focus(parent_row));
+        } else {
+            dismissed = true;
+        }
+    }
+    let in_menu = ctx.input(|i| {
+        i.pointer.interact_pos().is_some_and(|p| {
+            anchor.contains(p) || pass.panels.iter().skip(1).any(|(rect, _)| rect.contains(p))
+        })
+    });
+    let root = pass.panels.first().map_or(Rect::NOTHING, |(rect, _)| *rect);
+    if !in_menu && overlay.clicked_outside(ctx, root) {
+        dismissed = true;
+    }
+
+    if pass.action.is_some() || dismissed {
+        ctx.data_mut(|d| d.remove::<Vec<usize>>(path_id));
+        OverlayStack::remove(ctx, id);
+    } else {
+        ctx.data_mut(|d| d.insert_temp(path_id, pass.path));
+    }
+    MenuOutput { action: pass.action, dismissed }
+}
+
+/// How a row marks its kind at the edges
+#[derive(Clone, Copy, PartialEq)]
+enum RowMark {
+    None,
+    Check(bool),
+    Radio(bool),
+    /// Chevron; highlighted while the submenu is open
+    Submenu(bool),
+}
+
+/// State of one frame of an open menu, threaded through its panels
+struct MenuPass<'t, A> {
+    ctx: Context,
+    theme: &'t ShadcnTheme,
+    layout: MenuLayout,
+    /// Entry index of the open submenu at each depth
+    path: Vec<usize>,
+    /// Row id of each open submenu, for returning focus to it
+    parent_rows: Vec<Id>,
+    panels: Vec<(Rect, LayerId)>,
+    /// Depth whose first enabled row should take focus
+    focus_first: Option<usize>,
+    /// Row to focus once the panels are drawn
+    focus_row: Option<Id>,
+    action: Option<A>,
+}
+
+impl<A: Clone> MenuPass<'_, A> {
+    fn panel(&mut self, menu: &mut MenuModel<'_, A>, depth: usize, id: Id, pos: Pos2) {
+        let theme = self.theme;
+        let checkable = menu
+            .entries
+            .iter()
+            .any(|entry| matches!(entry, MenuEntry::Checkbox(..) | MenuEntry::RadioGroup(..)));
+        let mut open_row = None;
+
+        let area = egui::Area::new(id)
+            .order(egui::Order::Foreground)
+            .fixed_pos(pos)
+            .show(&self.ctx.clone(), |ui| {
+                egui::Frame::NONE
+                    .fill(theme.colors.popover)
+                    .stroke(egui::Stroke::new(1.0, theme.colors.border))
+                    .corner_radius(theme.radii.md)
+                    .shadow(theme.shadows.md)
+                    .inner_margin(4.0)
+                    .show(ui, |ui| {
+                        ui.set_min_width(self.layout.width);
+                        ui.spacing_mut().item_spacing.y = 0.0;
+                        for (index, entry) in menu.entries.iter_mut().enumerate() {
+                            let row_id = id.with(("row", index));
+                            match entry {
+                                MenuEntry::Item(item) => {
+                                    if self.row(ui, row_id, item, RowMark::None, checkable, depth).clicked() {
+                                        self.action = Some(item.action.clone());
+                                    }
+                                }
+                                MenuEntry::Checkbox(item, checked) => {
+                                    if self.row(ui, row_id, item, RowMark::Check(**checked), checkable, depth).clicked() {
+                                        **checked = !**checked;
+                                        self.action = Some(item.action.clone());
+                                    }
+                                }
+                                MenuEntry::RadioGroup(items, binding) => {
+                                    for (option, item) in items.iter().enumerate() {
+                                        let mark = RowMark::Radio(binding.is_selected(option));
+                                        if self.row(ui, row_id.with(option), item, mark, checkable, depth).clicked() {
+                                            binding.select(option);
+                                            self.action = Some(item.action.clone());
+                                        }
+                                    }
+                                }
+                                MenuEntry::Submenu(label, _) => {
+                                    let item = MenuItem::new((), label.as_str());
+                                    let is_open = self.path.get(depth) == Some(&index);
+                                    let response = self.row(ui, row_id, &item, RowMark::Submenu(is_open), checkable, depth);
+                                    let opened_by_key = response.has_focus()
+                                        && ui.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowRight));
+                                    if response.hovered() || response.clicked() || opened_by_key {
+                                        self.path.truncate(depth);
+                                        self.path.push(index);
+                                    }
+                                    if opened_by_key {
+                                        self.focus_first = Some(depth + 1);
+                                        ui.memory_mut(|m| m.move_focus(egui::FocusDirection::None));
+                                    }
+                                    if self.path.get(depth) == Some(&index) {
+                                        open_row = Some((response.rect, row_id));
+                                    }
+                                }
+                                MenuEntry::Separator => {
+                                    let separator_rect = ui.allocate_space(Vec2::new(ui.available_width(), 9.0)).1;
+                                    ui.painter().line_segment(
+                                        [
+                                            Pos2::new(separator_rect.min.x + 4.0, separator_rect.center().y),
+                                            Pos2::new(separator_rect.max.x - 4.0, separator_rect.center().y),
+                                        ],
+                                        egui::Stroke::new(1.0, theme.colors.border),
+                                    );
+                                }
+                                MenuEntry::Label(text) => {
+                                    let label_response = ui.allocate_response(Vec2::new(ui.available_width(), 28.0), Sense::hover());
+                                    if ui.is_rect_visible(label_response.rect) {
+                                        let indent = if checkable { 28.0 } else { 8.0 };
+                                        ui.painter().text(
+                                            Pos2::new(label_response.rect.min.x + indent, label_response.rect.center().y),
+                                            egui::Align2::LEFT_CENTER,
+                                            text,
+                                            egui::FontId::proportional(theme.typography.small().size - 1.0),
+                                            theme.colors.muted_foreground,
+                                        );
+                                    }
+                                }
+                            }
+                        }
+                    });
+            });
+        self.panels.push((area.response.rect, area.response.layer_id));
+
+        // Draw the open submenu beside its row
+        let Some(&open) = self.path.get(depth) else { return };
+        match (menu.entries.get_mut(open), open_row) {
+            (Some(MenuEntry::Submenu(_, submenu)), Some((row_rect, row_id))) => {
+                self.parent_rows.truncate(depth);
+                self.parent_rows.push(row_id);
+                let pos = Pos2::new(area.response.rect.right() + 2.0, row_rect.top() - 4.0);
+                self.panel(submenu, depth + 1, id.with(("submenu", open)), pos);
+            }
+            _ => self.path.truncate(depth),
+        }
+
+        if depth == 0 {
+            if let Some(row) = self.focus_row.take() {
+                self.ctx.memory_mut(|m| m.request_focus(row));
+            }
+        }
+    }
+
+    fn row<T>(&mut self, ui: &mut Ui, id: Id, item: &MenuItem<T>, mark: RowMark, checkable: bool, depth: usize) -> Response {
+        let theme = self.theme;
+        let enabled = item.enabled();
+        let (rect, _) = ui.allocate_exact_size(Vec2::new(ui.available_width(), self.layout.row_height), Sense::hover());
+        let mut response = ui.interact(rect, id, if enabled { Sense::click() } else { Sense::hover() });
+
+        if enabled && self.focus_first == Some(depth) {
+            self.focus_first = None;
+            self.focus_row = Some(id);
+        }
+
+        // Leaving a submenu row for another row closes the submenu
+        if response.hovered() && !matches!(mark, RowMark::Submenu(_)) && self.path.len() > depth {
+            self.path.truncate(depth);
+        }
+
+        if response.has_focus() {
+            ui.memory_mut(|m| {
+                m.set_focus_lock_filter(
+                    id,
+                    egui::EventFilter { horizontal_arrows: true, ..Default::default() },
+                )
+            });
+            // Left arrow returns to the parent menu
+            if depth > 0 && ui.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowLeft)) {
+                self.path.truncate(depth - 1);
+                self.focus_row = self.parent_rows.get(depth - 1).copied();
+                ui.memory_mut(|m| m.move_focus(egui::FocusDirection::None));
+            }
+        }
+
+        if ui.is_rect_visible(rect) {
+            let open = mark == RowMark::Submenu(true);
+            let highlighted = enabled && (response.hovered() || response.has_focus() || open);
+            if highlighted {
+                let fill = if item.destructive {
+                    theme.colors.destructive.linear_multiply(0.1)
+                } else {
+                    theme.colors.accent
+                };
+                ui.painter().rect_filled(rect, theme.radii.sm, fill);
+            }
+
+            let text_color = match (item.destructive, enabled) {
+                (true, true) => theme.colors.destructive,
+                (true, false) => theme.colors.destructive.linear_multiply(0.5),
+                (false, true) if highlighted => theme.colors.accent_foreground,
+                (false, true) => theme.colors.popover_foreground,
+                (false, false) => theme.colors.popover_foreground.linear_multiply(0.5),
+            };
+
+            let indent = if checkable { 28.0 } else { 8.0 };
+            ui.painter().text(
+                Pos2::new(rect.min.x + indent, rect.center().y),
+                egui::Align2::LEFT_CENTER,
+                &item.label,
+                egui::FontId::proportional(theme.typography.small().size),
+                text_color,
+            );
+
+            let mark_center = Pos2::new(rect.min.x + 14.0, rect.center().y);
+            match mark {
+                RowMark::Check(true) => {
+                    let stroke = egui::Stroke::new(1.5, text_color);
+                    ui.painter().line_segment([mark_center + Vec2::new(-4.0, 0.0), mark_center + Vec2::new(-1.0, 3.0)], stroke);
+                    ui.painter().line_segment([mark_center + Vec2::new(-1.0, 3.0), mark_center + Vec2::new(4.0, -3.0)], stroke);
+                }
+                RowMark::Radio(true) => {
+                    ui.painter().circle_filled(mark_center, 3.0, text_color);
+                }
+                RowMark::Submenu(_) => {
+                    let c = Pos2::new(rect.max.x - 12.0, rect.center().y);
+                    let stroke = egui::Stroke::new(1.5, text_color);
+                    ui.painter().line_segment([c + Vec2::new(-2.0, -4.0), c + Vec2::new(2.0, 0.0)], stroke);
+                    ui.painter().line_segment([c + Vec2::new(2.0, 0.0), c + Vec2::new(-2.0, 4.0)], stroke);
+                }
+                RowMark::None | RowMark::Check(false) | RowMark::Radio(false) => {}
+            }
+
+            if let Some(shortcut) = &item.shortcut {
+                ui.painter().text(
+                    Pos2::new(rect.max.x - 8.0, rect.center().y),
+                    egui::Align2::RIGHT_CENTER,
+                    crate::shortcuts::format_shortcut(ui.ctx(), shortcut),
+                    egui::FontId::proportional(theme.typography.small().size - 1.0),
+                    theme.colors.muted_foreground,
+                );
+            }
+        }
+
+        if let Some(reason) = &item.disabled {
+            response = response.shadcn_tooltip(reason.as_str());
+        }
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{context_with_motion, key_press as key, run_frame_with_events};
+    use crate::theme::MotionPreference;
+    use egui::{Event, Key, Modifiers};
+
+    fn undo_redo<'a>(wrap: &'a mut bool) -> MenuModel<'a> {
+        MenuModel::new()
+            .item(MenuItem::new("undo", "Undo").shortcut(KeyboardShortcut::new(Modifiers::COMMAND, Key::Z)))
+            .item(MenuItem::new("redo", "Redo").shortcut(KeyboardShortcut::new(Modifiers::COMMAND | Modifiers::SHIFT, Key::Z)))
+            .item(MenuItem::new("save", "Save").shortcut(KeyboardShortcut::new(Modifiers::COMMAND, Key::S)).disabled("Nothing to save"))
+            .submenu("View", MenuModel::new()
+                .checkbox(MenuItem::new("wrap", "Word Wrap").shortcut(KeyboardShortcut::new(Modifiers::ALT, Key::Z)), wrap))
+    }
+
+    #[test]
+    fn test_shortcuts_fire_most_specific_action() {
+        let ctx = context_with_motion(MotionPreference::Full);
+        let mut wrap = false;
+        let fired = |events: Vec<Event>, wrap: &mut bool| {
+            let mut action = None;
+            run_frame_with_events(&ctx, 0.0, events, |_| {
+                action = undo_redo(wrap).consume_shortcuts(&ctx, "Edit");
+            });
+            action
+        };
+
+        assert_eq!(fired(vec![key(Key::Z, Modifiers::COMMAND)], &mut wrap), Some("undo"));
+        assert_eq!(fired(vec![key(Key::Z, Modifiers::COMMAND | Modifiers::SHIFT)], &mut wrap), Some("redo"));
+        // Disabled items don't fire
+        assert_eq!(fired(vec![key(Key::S, Modifiers::COMMAND)], &mut wrap), None);
+        // Shortcuts in submenus toggle their checkbox
+        assert_eq!(fired(vec![key(Key::Z, Modifiers::ALT)], &mut wrap), Some("wrap"));
+        assert!(wrap);
+
+        // The enabled items are listed in the registry under the menu's name
+        let registry = ShortcutRegistry::load(&ctx);
+        let listed: Vec<(&str, &str, &str)> = registry
+            .shortcuts()
+            .iter()
+            .map(|s| (s.scope.name(), s.action.as_str(), s.label.as_str()))
+            .collect();
+        assert_eq!(listed, vec![("Edit", "0", "Undo"), ("Edit", "1", "Redo"), ("Edit", "3.0", "Word Wrap")]);
+    }
+
+    #[test]
+    fn test_app_shortcuts_take_precedence() {
+        let ctx = context_with_motion(MotionPreference::Full);
+        let undo = KeyboardShortcut::new(Modifiers::COMMAND, Key::Z);
+        ShortcutRegistry::register(&ctx, Shortcut::new("app_undo", "Undo", undo)).unwrap();
+
+        let mut wrap = false;
+        let (mut menu_action, mut app_action) = (None, None);
+        run_frame_with_events(&ctx, 0.0, vec![key(Key::Z, Modifiers::COMMAND)], |_| {
+            menu_action = undo_redo(&mut wrap).consume_shortcuts(&ctx, "Edit");
+            app_action = ShortcutRegistry::dispatch(&ctx);
+        });
+        assert_eq!(menu_action, None);
+        assert_eq!(app_action.as_deref(), Some("app_undo"));
+
+        let conflicts = ShortcutRegistry::load(&ctx).conflicts();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(
+            conflicts[0].to_string(),
+            "Ctrl+Z is already bound to \"app_undo\" in scope \"Global\", cannot bind it to \"0\""
+        );
+    }
+
+    #[test]
+    fn test_radio_group_binding() {
+        #[derive(Clone, PartialEq, Debug)]
+        enum Sort {
+            Name,
+            Date,
+        }
+        let mut sort = Sort::Name;
+        let mut menu = MenuModel::new().radio_group(&mut sort, [
+            (MenuItem::new("by_name", "Name"), Sort::Name),
+            (MenuItem::new("by_date", "Date").shortcut(KeyboardShortcut::new(Modifiers::COMMAND, Key::D)), Sort::Date),
+        ]);
+        assert_eq!(menu.len(), 1);
+
+        let ctx = context_with_motion(MotionPreference::Full);
+        let mut action = None;
+        run_frame_with_events(&ctx, 0.0, vec![key(Key::D, Modifiers::COMMAND)], |_| {
+            action = menu.consume_shortcuts(&ctx, "Sort");
+        });
+        assert_eq!(action, Some("by_date"));
+        drop(menu);
+        assert_eq!(sort, Sort::Date);
+    }
+
+    #[test]
+    fn test_submenu_opens_with_right_arrow() {
+        let ctx = context_with_motion(MotionPreference::Full);
+        let mut wrap = false;
+        let id = Id::new("menu");
+        let layout = MenuLayout { width: 180.0, row_height: 32.0 };
+        let frame = |time: f64, events: Vec<Event>, wrap: &mut bool| {
+            let mut action = None;
+            run_frame_with_events(&ctx, time, events, |ui| {
+                let theme = ShadcnTheme::light();
+                let mut menu = undo_redo(wrap);
+                action = show_menu(ui.ctx(), &theme, id, &mut menu, Pos2::new(10.0, 10.0), Rect::NOTHING, layout).action;
+            });
+            action
+        };
+
+        frame(0.0, Vec::new(), &mut wrap);
+        let view_row = id.with("panel").with(("row", 3));
+        ctx.memory_mut(|m| m.request_focus(view_row));
+        frame(0.1, Vec::new(), &mut wrap);
+        frame(0.2, vec![key(Key::ArrowRight, Modifiers::NONE)], &mut wrap);
+        assert_eq!(ctx.data(|d| d.get_temp::<Vec<usize>>(id.with("submenus"))), Some(vec![3]));
+
+        // Focus moved into the submenu; Enter toggles the checkbox
+        let wrap_row = id.with("panel").with(("submenu", 3)).with(("row", 0));
+        frame(0.3, Vec::new(), &mut wrap);
+        assert!(ctx.memory(|m| m.has_focus(wrap_row)));
+        assert_eq!(frame(0.4, vec![key(Key::Enter, Modifiers::NONE)], &mut wrap), Some("wrap"));
+        assert!(wrap);
+    }
+}
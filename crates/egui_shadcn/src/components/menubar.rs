@@ -5,49 +5,41 @@
 //! Reference: <https://ui.shadcn.com/docs/components/menubar>
 
 use egui::{Id, Ui, Sense, Vec2, Pos2};
+use crate::components::menu::{show_menu, MenuLayout, MenuModel};
 use crate::theme::ShadcnTheme;
 
 /// Menubar component for application menus
 ///
+/// Each menu is a [`MenuModel`]; the response reports the chosen action, and
+/// item shortcuts work while the menus are closed.
+///
 /// ## Example
 /// ```rust,ignore
-/// Menubar::new("app_menu")
-///     .menu("File", |menu| {
-///         menu.item("New", || println!("New"))
-///             .item_with_shortcut("Open", "Ctrl+O", || println!("Open"))
-///             .separator()
-///             .item("Exit", || println!("Exit"));
-///     })
-///     .menu("Edit", |menu| {
-///         menu.item_with_shortcut("Undo", "Ctrl+Z", || println!("Undo"))
-///             .item_with_shortcut("Redo", "Ctrl+Y", || println!("Redo"));
-///     })
+/// let response = Menubar::new("app_menu")
+///     .menu("File", MenuModel::new()
+///         .item(MenuItem::new("new", "New"))
+///         .item(MenuItem::new("open", "Open").shortcut(KeyboardShortcut::new(Modifiers::COMMAND, Key::O)))
+///         .separator()
+///         .item(MenuItem::new("exit", "Exit")))
+///     .menu("Edit", MenuModel::new()
+///         .item(MenuItem::new("undo", "Undo").shortcut(KeyboardShortcut::new(Modifiers::COMMAND, Key::Z))))
 ///     .show(ui);
+///
+/// if let Some(action) = response.action {
+///     handle(action);
+/// }
 /// ```
-pub struct Menubar<'a> {
+pub struct Menubar<'a, A = &'static str> {
     id: &'a str,
-    menus: Vec<MenubarMenu>,
+    menus: Vec<MenubarMenu<'a, A>>,
 }
 
-struct MenubarMenu {
+struct MenubarMenu<'a, A> {
     label: String,
-    items: Vec<MenubarItem>,
-}
-
-enum MenubarItem {
-    Action {
-        label: String,
-        shortcut: Option<String>,
-        enabled: bool,
-    },
-    Separator,
-    Submenu {
-        label: String,
-        items: Vec<MenubarItem>,
-    },
+    model: MenuModel<'a, A>,
 }
 
-impl<'a> Menubar<'a> {
+impl<'a, A: Clone> Menubar<'a, A> {
     /// Create a new menubar
     pub fn new(id: &'a str) -> Self {
         Self {
@@ -57,18 +49,18 @@ impl<'a> Menubar<'a> {
     }
 
     /// Add a menu to the menubar
-    pub fn menu(mut self, label: impl Into<String>, build: impl FnOnce(&mut MenuBuilder)) -> Self {
-        let mut builder = MenuBuilder { items: Vec::new() };
-        build(&mut builder);
+    ///
+    /// `label` also heads the menu's shortcuts in the shortcuts help dialog.
+    pub fn menu(mut self, label: impl Into<String>, model: MenuModel<'a, A>) -> Self {
         self.menus.push(MenubarMenu {
             label: label.into(),
-            items: builder.items,
+            model,
         });
         self
     }
 
     /// Show the menubar
-    pub fn show(self, ui: &mut Ui) -> MenubarResponse {
+    pub fn show(mut self, ui: &mut Ui) -> MenubarResponse<A> {
         let theme = ui.ctx().data(|d| {
             d.get_temp::<ShadcnTheme>(Id::new("shadcn_theme"))
                 .unwrap_or_else(ShadcnTheme::light)
@@ -80,11 +72,17 @@ impl<'a> Menubar<'a> {
         // Get currently open menu index
         let open_menu: Option<usize> = ui.ctx().data(|d| d.get_temp(open_menu_id));
 
-        let mut clicked_item: Option<(usize, usize)> = None;
+        // Shortcuts work whether or not a menu is open; every menu registers
+        // its shortcuts, so don't stop at the first one that fires
+        let mut action = None;
+        for menu in &mut self.menus {
+            let fired = menu.model.consume_shortcuts(ui.ctx(), &menu.label);
+            action = action.or(fired);
+        }
         let mut new_open_menu = open_menu;
 
         // Menubar container - subtle rounded border like shadcn
-        let menubar_response = egui::Frame::NONE
+        let bar = egui::Frame::NONE
             .fill(theme.colors.background)
             .stroke(egui::Stroke::new(1.0, theme.colors.border))
             .corner_radius(theme.radii.md)
@@ -93,6 +91,7 @@ impl<'a> Menubar<'a> {
                 ui.horizontal(|ui| {
                     ui.spacing_mut().item_spacing.x = 0.0;
 
+                    let mut triggers = Vec::with_capacity(self.menus.len());
                     for (menu_idx, menu) in self.menus.iter().enumerate() {
                         let is_open = open_menu == Some(menu_idx);
 
@@ -107,24 +106,29 @@ impl<'a> Menubar<'a> {
                         } else if button_response.hovered() && open_menu.is_some() && !is_open {
                             new_open_menu = Some(menu_idx);
                         }
-
-                        // Show dropdown if open
-                        if is_open {
-                            let dropdown_pos = Pos2::new(
-                                button_response.rect.min.x,
-                                button_response.rect.max.y + 2.0,
-                            );
-
-                            if let Some(item_idx) = self.show_dropdown(
-                                ui, &theme, id, menu_idx, &menu.items, dropdown_pos
-                            ) {
-                                clicked_item = Some((menu_idx, item_idx));
-                                new_open_menu = None;
-                            }
-                        }
+                        triggers.push(button_response.rect);
                     }
-                });
+                    triggers
+                })
+                .inner
             });
+        let triggers = bar.inner;
+
+        // Show dropdown if open
+        if let Some(menu_idx) = new_open_menu {
+            let trigger = triggers[menu_idx];
+            let dropdown_pos = Pos2::new(trigger.min.x, trigger.max.y + 2.0);
+            let layout = MenuLayout { width: 180.0, row_height: 32.0 };
+            // Clicks on the other triggers switch menus rather than dismissing
+            let output = show_menu(
+                ui.ctx(), &theme, id.with(("menu", menu_idx)), &mut self.menus[menu_idx].model,
+                dropdown_pos, bar.response.rect, layout,
+            );
+            if output.action.is_some() || output.dismissed {
+                new_open_menu = None;
+            }
+            action = action.or(output.action);
+        }
 
         // Update open menu state
         if new_open_menu != open_menu {
@@ -136,26 +140,13 @@ impl<'a> Menubar<'a> {
                 }
             });
         }
-
-        // Close menu when clicking outside
-        if open_menu.is_some() {
-            let clicked_outside = ui.input(|i| {
-                i.pointer.any_click() &&
-                !menubar_response.response.rect.contains(
-                    i.pointer.interact_pos().unwrap_or(Pos2::ZERO)
-                )
-            });
-            if clicked_outside {
-                ui.ctx().data_mut(|d| d.remove::<usize>(open_menu_id));
+        for menu_idx in 0..self.menus.len() {
+            if new_open_menu != Some(menu_idx) {
+                crate::overlay::OverlayStack::remove(ui.ctx(), id.with(("menu", menu_idx)));
             }
         }
 
-        // Close on escape
-        if ui.input(|i| i.key_pressed(egui::Key::Escape)) {
-            ui.ctx().data_mut(|d| d.remove::<usize>(open_menu_id));
-        }
-
-        MenubarResponse { clicked_item }
+        MenubarResponse { action }
     }
 
     fn draw_menu_trigger(
@@ -201,180 +192,28 @@ impl<'a> Menubar<'a> {
 
         response
     }
-
-    fn show_dropdown(
-        &self,
-        ui: &mut Ui,
-        theme: &ShadcnTheme,
-        id: Id,
-        menu_idx: usize,
-        items: &[MenubarItem],
-        pos: Pos2,
-    ) -> Option<usize> {
-        let mut clicked_item = None;
-        let dropdown_id = id.with(("dropdown", menu_idx));
-
-        egui::Area::new(dropdown_id)
-            .order(egui::Order::Foreground)
-            .fixed_pos(pos)
-            .show(ui.ctx(), |ui| {
-                let frame = egui::Frame::NONE
-                    .fill(theme.colors.popover)
-                    .stroke(egui::Stroke::new(1.0, theme.colors.border))
-                    .corner_radius(theme.radii.md)
-                    .shadow(theme.shadows.md)
-                    .inner_margin(4.0);
-
-                frame.show(ui, |ui| {
-                    ui.set_min_width(180.0);
-
-                    let mut action_idx = 0;
-                    for item in items {
-                        match item {
-                            MenubarItem::Action { label, shortcut, enabled } => {
-                                let current_idx = action_idx;
-                                action_idx += 1;
-
-                                let item_response = ui.allocate_response(
-                                    Vec2::new(ui.available_width(), 32.0),
-                                    if *enabled { Sense::click() } else { Sense::hover() },
-                                );
-
-                                if item_response.clicked() && *enabled {
-                                    clicked_item = Some(current_idx);
-                                }
-
-                                if ui.is_rect_visible(item_response.rect) {
-                                    let hovered = item_response.hovered() && *enabled;
-
-                                    if hovered {
-                                        ui.painter().rect_filled(
-                                            item_response.rect,
-                                            theme.radii.sm,
-                                            theme.colors.accent,
-                                        );
-                                    }
-
-                                    let text_color = if *enabled {
-                                        if hovered {
-                                            theme.colors.accent_foreground
-                                        } else {
-                                            theme.colors.popover_foreground
-                                        }
-                                    } else {
-                                        theme.colors.muted_foreground
-                                    };
-
-                                    // Label
-                                    ui.painter().text(
-                                        Pos2::new(
-                                            item_response.rect.min.x + 8.0,
-                                            item_response.rect.center().y,
-                                        ),
-                                        egui::Align2::LEFT_CENTER,
-                                        label,
-                                        egui::FontId::proportional(theme.typography.small().size),
-                                        text_color,
-                                    );
-
-                                    // Shortcut
-                                    if let Some(shortcut) = shortcut {
-                                        ui.painter().text(
-                                            Pos2::new(
-                                                item_response.rect.max.x - 8.0,
-                                                item_response.rect.center().y,
-                                            ),
-                                            egui::Align2::RIGHT_CENTER,
-                                            shortcut,
-                                            egui::FontId::proportional(theme.typography.small().size - 2.0),
-                                            theme.colors.muted_foreground,
-                                        );
-                                    }
-                                }
-                            }
-                            MenubarItem::Separator => {
-                                let sep_rect = ui.allocate_space(Vec2::new(ui.available_width(), 9.0)).1;
-                                ui.painter().line_segment(
-                                    [
-                                        Pos2::new(sep_rect.min.x + 4.0, sep_rect.center().y),
-                                        Pos2::new(sep_rect.max.x - 4.0, sep_rect.center().y),
-                                    ],
-                                    egui::Stroke::new(1.0, theme.colors.border),
-                                );
-                            }
-                            MenubarItem::Submenu { .. } => {
-                                // TODO: Implement submenus if needed
-                            }
-                        }
-                    }
-                });
-            });
-
-        clicked_item
-    }
-}
-
-/// Builder for menu items
-pub struct MenuBuilder {
-    items: Vec<MenubarItem>,
-}
-
-impl MenuBuilder {
-    /// Add a menu item
-    pub fn item(&mut self, label: impl Into<String>) -> &mut Self {
-        self.items.push(MenubarItem::Action {
-            label: label.into(),
-            shortcut: None,
-            enabled: true,
-        });
-        self
-    }
-
-    /// Add a menu item with keyboard shortcut
-    pub fn item_with_shortcut(&mut self, label: impl Into<String>, shortcut: impl Into<String>) -> &mut Self {
-        self.items.push(MenubarItem::Action {
-            label: label.into(),
-            shortcut: Some(shortcut.into()),
-            enabled: true,
-        });
-        self
-    }
-
-    /// Add a disabled menu item
-    pub fn disabled_item(&mut self, label: impl Into<String>) -> &mut Self {
-        self.items.push(MenubarItem::Action {
-            label: label.into(),
-            shortcut: None,
-            enabled: false,
-        });
-        self
-    }
-
-    /// Add a separator
-    pub fn separator(&mut self) -> &mut Self {
-        self.items.push(MenubarItem::Separator);
-        self
-    }
 }
 
 /// Response from showing a menubar
-pub struct MenubarResponse {
-    /// The clicked item as (menu_index, item_index), if any
-    pub clicked_item: Option<(usize, usize)>,
+pub struct MenubarResponse<A = &'static str> {
+    /// Action chosen from a menu or by its shortcut this frame, if any
+    pub action: Option<A>,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::components::menu::MenuItem;
 
     #[test]
     fn test_menubar_creation() {
         let menubar = Menubar::new("test")
-            .menu("File", |m| {
-                m.item("New").separator().item("Exit");
-            });
+            .menu("File", MenuModel::new()
+                .item(MenuItem::new("new", "New"))
+                .separator()
+                .item(MenuItem::new("exit", "Exit")));
 
         assert_eq!(menubar.menus.len(), 1);
-        assert_eq!(menubar.menus[0].items.len(), 3);
+        assert_eq!(menubar.menus[0].model.len(), 3);
     }
 }
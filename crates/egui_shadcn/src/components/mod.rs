@@ -30,6 +30,9 @@ pub mod spinner;
 
 // Phase 3: Form Components
 pub mod input;
+pub mod masked_input;
+pub mod input_otp;
+pub mod number_input;
 pub mod checkbox;
 pub mod switch;
 pub mod slider;
@@ -40,6 +43,7 @@ pub mod toggle;
 pub mod toggle_group;
 pub mod radio;
 pub mod select;
+pub mod menu;
 pub mod dropdown_menu;
 pub mod combobox;
 
@@ -71,6 +75,7 @@ pub mod date_picker;
 pub mod carousel;
 pub mod chart;
 pub mod resizable;
+pub mod sortable_list;
 
 // Phase 7: Navigation & Forms
 pub mod menubar;
@@ -79,8 +84,8 @@ pub mod navigation_menu;
 pub mod field;
 pub mod form;
 
-pub use badge::{Badge, BadgeVariant};
-pub use avatar::{Avatar, AvatarSize};
+pub use badge::{Badge, BadgeResponse, BadgeVariant};
+pub use avatar::{avatar_color, Avatar, AvatarGroup, AvatarShape, AvatarSize, AvatarStatus};
 pub use card::{Card, card_title, card_description};
 pub use alert::{Alert, AlertVariant};
 pub use skeleton::Skeleton;
@@ -89,6 +94,9 @@ pub use button::{Button, ButtonVariant, ButtonSize};
 pub use spinner::{Spinner, SpinnerSize};
 
 pub use input::{shadcn_input, shadcn_input_with_error, shadcn_textarea, form_label, form_helper};
+pub use masked_input::{InputMask, MaskedInput, MaskedInputResponse};
+pub use number_input::NumberInput;
+pub use input_otp::{InputOTP, InputOTPResponse};
 pub use checkbox::Checkbox;
 pub use switch::Switch;
 pub use slider::Slider;
@@ -98,15 +106,19 @@ pub use textarea::Textarea;
 pub use toggle::{Toggle, ToggleVariant, ToggleSize};
 pub use toggle_group::{ToggleGroup, ToggleGroupType, ToggleGroupVariant};
 pub use radio::{RadioGroup, RadioButton};
-pub use select::Select;
+pub use select::{Select, SelectGroupBuilder, SelectOption};
+pub use menu::{MenuItem, MenuModel};
 pub use dropdown_menu::{DropdownMenu, DropdownMenuResponse};
-pub use combobox::{Combobox, ComboboxOption};
+pub use combobox::{
+    AsyncOptions, Combobox, ComboboxOption, OptionPage, OptionSource, OptionStatus, OptionsFuture,
+    StaticOptions,
+};
 
 pub use separator::{Separator, SeparatorOrientation};
-pub use tabs::Tabs;
+pub use tabs::{Tabs, TabsOrientation, TabsOverflow};
 pub use collapsible::{Collapsible, collapsible_trigger};
 pub use accordion::{Accordion, AccordionType};
-pub use breadcrumb::{Breadcrumb, BreadcrumbSeparator};
+pub use breadcrumb::{Breadcrumb, BreadcrumbResponse, BreadcrumbSeparator};
 
 pub use dialog::{Dialog, confirm_dialog, ConfirmResult};
 pub use tooltip::{Tooltip, TooltipExt, shadcn_tooltip_for};
@@ -122,14 +134,18 @@ pub use pagination::Pagination;
 pub use aspect_ratio::{AspectRatio, AspectRatioPreset, AspectRatioResponse};
 pub use table::{Table, TableBody, TableResponse, simple_table};
 pub use command::{Command, CommandGroupBuilder};
-pub use calendar::{Calendar, CalendarMode, CalendarSelection};
-pub use date_picker::DatePicker;
+pub use calendar::{Calendar, CalendarLocale, CalendarMode, CalendarSelection, DayDecoration, EnglishLocale};
+pub use date_picker::{DatePicker, DatePreset, DateRangePicker, DateTimePicker};
 pub use carousel::{Carousel, CarouselOrientation};
 pub use chart::{Chart, ChartType, DataPoint};
-pub use resizable::{ResizablePanelGroup, ResizableDirection};
+pub use resizable::{ResizablePanelGroup, ResizableDirection, ResizablePanel, PanelLayout, ParsePanelLayoutError};
+pub use sortable_list::{SortableList, SortableListResponse};
 
-pub use menubar::{Menubar, MenuBuilder, MenubarResponse};
-pub use sidebar::{Sidebar, SidebarBuilder, SidebarResponse};
+pub use menubar::{Menubar, MenubarResponse};
+pub use sidebar::{Sidebar, SidebarBuilder, SidebarEvent, SidebarNode, SidebarResponse};
 pub use navigation_menu::{NavigationMenu, NavDropdownBuilder, NavigationMenuResponse};
 pub use field::{Field, FieldResponse, labeled_input, required_input};
-pub use form::{FormState, validators};
+pub use form::{
+    FieldError, FieldKey, FormState, FormValue, PollExecutor, ValidationExecutor, ValidationFuture,
+    ValidationTask, validators,
+};
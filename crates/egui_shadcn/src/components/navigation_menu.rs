@@ -5,6 +5,7 @@
 //! Reference: <https://ui.shadcn.com/docs/components/navigation-menu>
 
 use egui::{Id, Ui, Sense, Vec2, Pos2};
+use crate::overlay::{OverlayKind, OverlayStack};
 use crate::theme::ShadcnTheme;
 
 /// Navigation Menu component for website navigation
@@ -143,9 +144,8 @@ impl<'a> NavigationMenu<'a> {
             });
         }
 
-        // Close on escape
-        if ui.input(|i| i.key_pressed(egui::Key::Escape)) {
-            ui.ctx().data_mut(|d| d.remove::<usize>(open_dropdown_id));
+        if ui.ctx().data(|d| d.get_temp::<usize>(open_dropdown_id)).is_none() {
+            OverlayStack::remove(ui.ctx(), id.with("dropdown"));
         }
 
         NavigationMenuResponse { clicked_item }
@@ -201,6 +201,7 @@ impl<'a> NavigationMenu<'a> {
     ) -> Option<usize> {
         let mut clicked_idx = None;
         let dropdown_id = id.with(("dropdown_content", item_idx));
+        let overlay = OverlayStack::register(ui.ctx(), id.with("dropdown"), OverlayKind::Popover);
 
         let area_response = egui::Area::new(dropdown_id)
             .order(egui::Order::Foreground)
@@ -277,8 +278,12 @@ impl<'a> NavigationMenu<'a> {
                 });
             });
 
-        // Close when clicking outside
-        if area_response.response.clicked_elsewhere() {
+        overlay.raise(ui.ctx(), area_response.response.layer_id);
+
+        // Close on Escape or when clicking outside
+        if overlay.clicked_outside(ui.ctx(), area_response.response.rect)
+            || overlay.escape_pressed(ui.ctx())
+        {
             ui.ctx().data_mut(|d| d.remove::<usize>(id.with("open_dropdown")));
         }
 
@@ -0,0 +1,445 @@
+//! Number input component
+//!
+//! A numeric text input with stepper buttons, min/max clamping, a step size
+//! and keyboard and mouse wheel stepping.
+//!
+//! Reference: <https://ui.shadcn.com/docs/components/input>
+
+use egui::emath::Numeric;
+use egui::{Id, Key, Modifiers, Response, Sense, TextEdit, Ui, Vec2, Widget};
+use crate::components::input::input_frame;
+use crate::theme::ShadcnTheme;
+
+/// Width of each stepper button
+const STEPPER_WIDTH: f32 = 28.0;
+
+/// Most decimals shown for floats without a fixed number of decimals
+const MAX_DECIMALS: usize = 6;
+
+/// Number input with stepper buttons
+///
+/// Works with any numeric type (`i32`, `u8`, `f32`, `f64`, ...). Typed values
+/// are clamped to the range as you type and re-formatted when the input loses
+/// focus. While focused, Arrow Up/Down and the mouse wheel step the value.
+///
+/// ## Example
+/// ```rust,ignore
+/// let mut quantity = 1_u32;
+/// ui.add(NumberInput::new(&mut quantity).range(1..=99));
+///
+/// let mut zap = 0.5_f64;
+/// ui.add(NumberInput::new(&mut zap).range(0.0..=10.0).step(0.25));
+/// ```
+pub struct NumberInput<'a, N: Numeric> {
+    value: &'a mut N,
+    min: f64,
+    max: f64,
+    origin: f64,
+    step: f64,
+    decimals: Option<usize>,
+    has_error: bool,
+    id_salt: Option<Id>,
+}
+
+impl<'a, N: Numeric> NumberInput<'a, N> {
+    /// Create a number input bound to `value`
+    pub fn new(value: &'a mut N) -> Self {
+        Self {
+            value,
+            min: N::MIN.to_f64(),
+            max: N::MAX.to_f64(),
+            origin: 0.0,
+            step: 1.0,
+            decimals: None,
+            has_error: false,
+            id_salt: None,
+        }
+    }
+
+    /// Allowed range (inclusive)
+    ///
+    /// Reversed bounds are swapped and NaN bounds are ignored. Stepping snaps to
+    /// the step grid starting at the lower bound.
+    pub fn range(mut self, range: std::ops::RangeInclusive<N>) -> Self {
+        let start = Some(range.start().to_f64()).filter(|bound| !bound.is_nan());
+        let end = Some(range.end().to_f64()).filter(|bound| !bound.is_nan());
+        let (low, high) = match (start, end) {
+            (Some(start), Some(end)) if start > end => (Some(end), Some(start)),
+            bounds => bounds,
+        };
+        if let Some(low) = low {
+            self.min = low;
+            self.origin = low;
+        }
+        if let Some(high) = high {
+            self.max = high;
+        }
+        self
+    }
+
+    /// Amount added or removed per step (default 1)
+    pub fn step(mut self, step: f64) -> Self {
+        if step > 0.0 {
+            self.step = step;
+        }
+        self
+    }
+
+    /// Number of decimals shown, rounding typed values to match (default: integers
+    /// show none, floats as many as `step` has plus any extra the value has)
+    pub fn decimals(mut self, decimals: usize) -> Self {
+        self.decimals = Some(decimals);
+        self
+    }
+
+    /// Show the error border
+    pub fn error(mut self, has_error: bool) -> Self {
+        self.has_error = has_error;
+        self
+    }
+
+    /// Set a stable ID salt (needed when the layout around the input changes)
+    pub fn id_salt(mut self, salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(salt));
+        self
+    }
+
+    /// Decimals every value is rounded to, if fixed
+    fn fixed_decimals(&self) -> Option<usize> {
+        match self.decimals {
+            Some(decimals) => Some(decimals),
+            None if N::INTEGRAL => Some(0),
+            None => None,
+        }
+    }
+}
+
+/// Decimals needed to show multiples of `step` exactly (at most 6)
+fn step_decimals(step: f64) -> usize {
+    let text = format!("{}", step);
+    text.split_once('.').map_or(0, |(_, fraction)| fraction.len().min(MAX_DECIMALS))
+}
+
+/// Format `value` with `fixed` decimals, or otherwise with at least `min_decimals`
+/// and as many more as it needs (at most 6)
+fn format_value(value: f64, fixed: Option<usize>, min_decimals: usize) -> String {
+    if let Some(decimals) = fixed {
+        return format!("{:.*}", decimals, value);
+    }
+    let mut text = format!("{:.*}", MAX_DECIMALS.max(min_decimals), value);
+    if let Some(dot) = text.find('.') {
+        let keep = dot + 1 + min_decimals;
+        while text.len() > keep && text.ends_with('0') {
+            text.pop();
+        }
+        if text.ends_with('.') {
+            text.pop();
+        }
+    }
+    text
+}
+
+/// Clamp `value` to `min..=max`, rounding it to `decimals` if given
+fn constrain(value: f64, min: f64, max: f64, decimals: Option<usize>) -> f64 {
+    let rounded = match decimals {
+        Some(decimals) => {
+            let scale = 10f64.powi(decimals as i32);
+            (value * scale).round() / scale
+        }
+        None => value,
+    };
+    rounded.clamp(min, max)
+}
+
+/// Parse typed text, rejecting "nan" and "inf" which would skip the clamp
+fn parse_typed(text: &str) -> Option<f64> {
+    text.trim().parse::<f64>().ok().filter(|value| value.is_finite())
+}
+
+/// Move `value` by `steps` steps, snapping to the step grid from `origin`
+fn stepped(value: f64, steps: f64, step: f64, origin: f64, min: f64, max: f64, decimals: usize) -> f64 {
+    let target = value + steps * step;
+    let snapped = origin + ((target - origin) / step).round() * step;
+    constrain(snapped, min, max, Some(decimals))
+}
+
+impl<'a, N: Numeric> Widget for NumberInput<'a, N> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let theme = ui.ctx().data(|d| {
+            d.get_temp::<ShadcnTheme>(egui::Id::new("shadcn_theme"))
+                .unwrap_or_else(ShadcnTheme::light)
+        });
+        let fixed = self.fixed_decimals();
+        let step_decimals = step_decimals(self.step);
+        let (min, max, origin, step) = (self.min, self.max, self.origin, self.step);
+        let id = match self.id_salt {
+            Some(salt) => ui.make_persistent_id(salt),
+            None => ui.next_auto_id(),
+        };
+        let text_id = id.with("text");
+
+        let original = self.value.to_f64();
+        let mut value = original;
+        let format = |value: f64| format_value(value, fixed, step_decimals);
+
+        // Keep the typed text while editing, show the formatted value otherwise
+        let editing = ui.memory(|m| m.has_focus(text_id));
+        let mut text = if editing {
+            ui.data(|d| d.get_temp::<String>(text_id)).unwrap_or_else(|| format(value))
+        } else {
+            format(value)
+        };
+        let mut steps = 0.0;
+
+        let response = input_frame(ui, self.has_error, |ui| {
+            ui.horizontal_centered(|ui| {
+                ui.spacing_mut().item_spacing.x = 2.0;
+                let text_width = (ui.available_width() - 2.0 * (STEPPER_WIDTH + 2.0)).max(0.0);
+                let text_response = ui.add(
+                    TextEdit::singleline(&mut text)
+                        .id(text_id)
+                        .frame(false)
+                        .desired_width(text_width)
+                        .vertical_align(egui::Align::Center),
+                );
+
+                for (label, delta, enabled) in [("−", -1.0, value > min), ("+", 1.0, value < max)] {
+                    let (rect, button) = ui.allocate_exact_size(
+                        Vec2::new(STEPPER_WIDTH, ui.available_height()),
+                        if enabled { Sense::click() } else { Sense::hover() },
+                    );
+                    if button.hovered() && enabled {
+                        ui.painter().rect_filled(rect, theme.radii.sm, theme.colors.accent);
+                    }
+                    let color = if enabled {
+                        theme.colors.foreground
+                    } else {
+                        theme.colors.muted_foreground.linear_multiply(0.5)
+                    };
+                    ui.painter().text(
+                        rect.center(),
+                        egui::Align2::CENTER_CENTER,
+                        label,
+                        egui::FontId::proportional(theme.typography.body().size),
+                        color,
+                    );
+                    if button.clicked() {
+                        steps += delta;
+                    }
+                }
+
+                text_response
+            })
+            .inner
+        });
+
+        if response.changed() {
+            if let Some(typed) = parse_typed(&text) {
+                value = constrain(typed, min, max, fixed);
+            }
+        }
+
+        if response.has_focus() {
+            ui.input_mut(|i| {
+                if i.consume_key(Modifiers::NONE, Key::ArrowUp) {
+                    steps += 1.0;
+                }
+                if i.consume_key(Modifiers::NONE, Key::ArrowDown) {
+                    steps -= 1.0;
+                }
+            });
+            if ui.rect_contains_pointer(response.rect.expand2(Vec2::new(12.0 + 2.0 * STEPPER_WIDTH, 8.0))) {
+                let wheel = ui.input(|i| i.raw_scroll_delta.y);
+                if wheel != 0.0 {
+                    steps += wheel.signum() as f64;
+                    // Don't scroll the surrounding area as well
+                    ui.input_mut(|i| i.smooth_scroll_delta = Vec2::ZERO);
+                }
+            }
+        }
+
+        if steps != 0.0 {
+            value = stepped(value, steps, step, origin, min, max, fixed.unwrap_or(step_decimals));
+            text = format(value);
+        }
+
+        if response.has_focus() {
+            ui.data_mut(|d| d.insert_temp(text_id, text));
+        } else {
+            ui.data_mut(|d| d.remove::<String>(text_id));
+        }
+
+        let mut response = response;
+        if value != original {
+            *self.value = N::from_f64(value);
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{context_with_motion, key_press, run_frame, run_frame_with_events};
+    use crate::theme::MotionPreference;
+    use egui::{Event, MouseWheelUnit};
+
+    #[test]
+    fn test_step_decimals() {
+        assert_eq!(step_decimals(1.0), 0);
+        assert_eq!(step_decimals(0.1), 1);
+        assert_eq!(step_decimals(0.25), 2);
+    }
+
+    #[test]
+    fn test_stepped() {
+        assert_eq!(stepped(5.0, 1.0, 1.0, 0.0, 0.0, 10.0, 0), 6.0);
+        assert_eq!(stepped(10.0, 1.0, 1.0, 0.0, 0.0, 10.0, 0), 10.0);
+        assert_eq!(stepped(0.0, -1.0, 1.0, 0.0, 0.0, 10.0, 0), 0.0);
+
+        // No float drift
+        let mut value = 0.0;
+        for _ in 0..3 {
+            value = stepped(value, 1.0, 0.1, 0.0, 0.0, 1.0, 1);
+        }
+        assert_eq!(value, 0.3);
+
+        // Off-grid values snap to the step grid
+        assert_eq!(stepped(0.3, 1.0, 0.25, 0.0, 0.0, 10.0, 2), 0.5);
+        assert_eq!(stepped(3.0, 1.0, 5.0, 1.0, 1.0, 100.0, 0), 6.0);
+    }
+
+    #[test]
+    fn test_constrain() {
+        assert_eq!(constrain(15.0, 0.0, 10.0, Some(0)), 10.0);
+        assert_eq!(constrain(-3.0, 0.0, 10.0, Some(0)), 0.0);
+        assert_eq!(constrain(1.2345, 0.0, 10.0, Some(2)), 1.23);
+        assert_eq!(constrain(1.2345, 0.0, 10.0, None), 1.2345);
+    }
+
+    #[test]
+    fn test_format_value() {
+        assert_eq!(format_value(2.5, Some(0), 0), "2");
+        assert_eq!(format_value(2.5, None, 0), "2.5");
+        assert_eq!(format_value(3.0, None, 0), "3");
+        assert_eq!(format_value(2.5, None, 2), "2.50");
+        assert_eq!(format_value(0.1 + 0.2, None, 1), "0.3");
+    }
+
+    #[test]
+    fn test_range_bounds() {
+        let reversed = || std::ops::RangeInclusive::new(10, 0);
+        let mut value = 5_i32;
+        let input = NumberInput::new(&mut value).range(reversed());
+        assert_eq!((input.min, input.max), (0.0, 10.0));
+
+        let mut value = 5.0_f64;
+        let input = NumberInput::new(&mut value).range(f64::NAN..=10.0);
+        assert_eq!((input.min, input.max), (<f64 as Numeric>::MIN, 10.0));
+
+        // Reversed bounds don't panic when clamping
+        let ctx = context_with_motion(MotionPreference::Full);
+        let mut value = 15_i32;
+        run_frame(&ctx, 0.0, |ui| {
+            ui.add(NumberInput::new(&mut value).range(reversed()));
+        });
+    }
+
+    #[test]
+    fn test_parse_typed() {
+        assert_eq!(parse_typed(" 2.5 "), Some(2.5));
+        assert_eq!(parse_typed("-3"), Some(-3.0));
+        assert_eq!(parse_typed("abc"), None);
+        assert_eq!(parse_typed("nan"), None);
+        assert_eq!(parse_typed("inf"), None);
+        assert_eq!(parse_typed("-infinity"), None);
+    }
+
+    #[test]
+    fn test_wheel_steps_when_focused() {
+        let ctx = context_with_motion(MotionPreference::Full);
+        let mut value = 5_i32;
+        let frame = |time: f64, events: Vec<Event>, value: &mut i32| {
+            let mut input = None;
+            run_frame_with_events(&ctx, time, events, |ui| {
+                let response = ui.add(NumberInput::new(&mut *value).range(0..=10).id_salt("qty"));
+                input = Some((response.id, response.rect));
+            });
+            input.unwrap()
+        };
+        let (id, rect) = frame(0.0, Vec::new(), &mut value);
+        let wheel = |y: f32| {
+            vec![
+                Event::PointerMoved(rect.center()),
+                Event::MouseWheel { unit: MouseWheelUnit::Line, delta: Vec2::new(0.0, y), modifiers: Modifiers::NONE },
+            ]
+        };
+
+        // Not focused: the wheel scrolls the page instead
+        frame(0.1, wheel(1.0), &mut value);
+        assert_eq!(value, 5);
+
+        ctx.memory_mut(|m| m.request_focus(id));
+        frame(0.2, wheel(1.0), &mut value);
+        assert_eq!(value, 6);
+        frame(0.3, wheel(-1.0), &mut value);
+        frame(0.4, wheel(-1.0), &mut value);
+        assert_eq!(value, 4);
+
+        // Clamped at the bounds
+        for i in 0..10 {
+            frame(0.5 + i as f64 * 0.1, wheel(1.0), &mut value);
+        }
+        assert_eq!(value, 10);
+    }
+
+    #[test]
+    fn test_unranged_f32_steps_from_zero() {
+        let ctx = context_with_motion(MotionPreference::Full);
+        let mut value = 0.5_f32;
+        let frame = |time: f64, events: Vec<Event>, value: &mut f32| {
+            let mut id = None;
+            run_frame_with_events(&ctx, time, events, |ui| {
+                id = Some(ui.add(NumberInput::new(&mut *value).step(0.25).id_salt("ratio")).id);
+            });
+            id.unwrap()
+        };
+        let id = frame(0.0, Vec::new(), &mut value);
+        ctx.memory_mut(|m| m.request_focus(id));
+        frame(0.1, vec![key_press(Key::ArrowUp, Modifiers::NONE)], &mut value);
+        assert_eq!(value, 0.75);
+        frame(0.2, vec![key_press(Key::ArrowDown, Modifiers::NONE)], &mut value);
+        frame(0.3, vec![key_press(Key::ArrowDown, Modifiers::NONE)], &mut value);
+        assert_eq!(value, 0.25);
+    }
+
+    #[test]
+    fn test_typed_float_keeps_precision() {
+        let ctx = context_with_motion(MotionPreference::Full);
+        let mut value = 0.0_f64;
+        let frame = |time: f64, events: Vec<Event>, value: &mut f64| {
+            let mut id = None;
+            run_frame_with_events(&ctx, time, events, |ui| {
+                id = Some(ui.add(NumberInput::new(&mut *value).id_salt("amount")).id);
+            });
+            id.unwrap()
+        };
+        let id = frame(0.0, Vec::new(), &mut value);
+        ctx.memory_mut(|m| m.request_focus(id));
+        frame(0.1, vec![Event::Text("2.5".into())], &mut value);
+        assert_eq!(value, 2.5);
+    }
+
+    #[test]
+    fn test_value_unchanged_without_input() {
+        let ctx = context_with_motion(MotionPreference::Full);
+        let mut value = 2.5_f64;
+        let mut changed = false;
+        run_frame(&ctx, 0.0, |ui| {
+            changed = ui.add(NumberInput::new(&mut value).step(0.5)).changed();
+        });
+        assert!(!changed);
+        assert_eq!(value, 2.5);
+    }
+}
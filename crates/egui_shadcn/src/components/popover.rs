@@ -6,6 +6,7 @@
 //! Reference: <https://ui.shadcn.com/docs/components/popover>
 
 use egui::{Id, Response, Ui};
+use crate::overlay::{OverlayKind, OverlayStack};
 use crate::theme::ShadcnTheme;
 
 /// Popover component for displaying rich content
@@ -73,9 +74,12 @@ impl Popover {
         if !new_open_state {
             // Save state and return early
             ui.ctx().data_mut(|d| d.insert_temp(open_id, new_open_state));
+            OverlayStack::remove(ui.ctx(), self.id);
             return None;
         }
 
+        let overlay = OverlayStack::register(ui.ctx(), self.id, OverlayKind::Popover);
+
         let theme = ui.ctx().data(|d| {
             d.get_temp::<ShadcnTheme>(Id::new("shadcn_theme"))
                 .unwrap_or_else(ShadcnTheme::light)
@@ -108,16 +112,23 @@ impl Popover {
                 });
             });
 
-        // Close when clicking outside (but not on the trigger itself)
+        overlay.raise(ui.ctx(), area_response.response.layer_id);
+
+        // Close on Escape or when clicking outside (but not on the trigger itself)
         // Only check if we were already open (not just opened this frame)
         if is_open {
-            if area_response.response.clicked_elsewhere() && !trigger.clicked() {
+            let clicked_outside = !trigger.clicked()
+                && overlay.clicked_outside(ui.ctx(), area_response.response.rect);
+            if clicked_outside || overlay.escape_pressed(ui.ctx()) {
                 new_open_state = false;
             }
         }
 
         // Save the open state for next frame
         ui.ctx().data_mut(|d| d.insert_temp(open_id, new_open_state));
+        if !new_open_state {
+            OverlayStack::remove(ui.ctx(), self.id);
+        }
 
         result
     }
@@ -7,6 +7,12 @@
 use egui::{Response, Ui, Widget};
 use crate::theme::ShadcnTheme;
 
+/// Fraction of the track covered by the indeterminate segment
+const INDETERMINATE_WIDTH: f32 = 0.3;
+
+/// Seconds for the indeterminate segment to sweep across the track
+const INDETERMINATE_PERIOD: f64 = 1.5;
+
 /// Progress bar widget matching shadcn/ui design
 ///
 /// ## Example
@@ -39,6 +45,8 @@ impl Progress {
     }
 
     /// Set indeterminate mode (animated loading)
+    ///
+    /// The bar sweeps across the track; with reduced motion it stays still.
     pub fn indeterminate(mut self, indeterminate: bool) -> Self {
         self.indeterminate = indeterminate;
         self
@@ -68,24 +76,42 @@ impl Widget for Progress {
             );
 
             // Draw progress fill
-            let fill_width = if self.indeterminate {
-                // Animated indeterminate mode (simplified - no animation in this version)
-                desired_width * 0.3
-            } else {
-                desired_width * self.value
-            };
+            if self.indeterminate {
+                let segment_width = desired_width * INDETERMINATE_WIDTH;
+                let start = if theme.motion.allows_loops() {
+                    // Sweep from off the left edge to off the right edge
+                    let time = ui.input(|i| i.time);
+                    let cycle = (time % INDETERMINATE_PERIOD) as f32 / INDETERMINATE_PERIOD as f32;
+                    ui.ctx().request_repaint();
+                    cycle * (desired_width + segment_width) - segment_width
+                } else {
+                    0.0
+                };
 
-            if fill_width > 0.0 {
                 let fill_rect = egui::Rect::from_min_size(
-                    rect.min,
-                    egui::vec2(fill_width, self.height),
+                    egui::pos2(rect.min.x + start, rect.min.y),
+                    egui::vec2(segment_width, self.height),
                 );
-
-                painter.rect_filled(
+                painter.with_clip_rect(rect).rect_filled(
                     fill_rect,
                     theme.radii.progress(),
-                    theme.colors.primary, // Purple fill
+                    theme.colors.primary,
                 );
+            } else {
+                let fill_width = desired_width * self.value;
+
+                if fill_width > 0.0 {
+                    let fill_rect = egui::Rect::from_min_size(
+                        rect.min,
+                        egui::vec2(fill_width, self.height),
+                    );
+
+                    painter.rect_filled(
+                        fill_rect,
+                        theme.radii.progress(),
+                        theme.colors.primary, // Purple fill
+                    );
+                }
             }
         }
 
@@ -96,6 +122,8 @@ impl Widget for Progress {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::looping_frames;
+    use crate::theme::MotionPreference;
 
     #[test]
     fn test_progress_creation() {
@@ -112,4 +140,14 @@ mod tests {
         let progress = Progress::new(-0.5);
         assert_eq!(progress.value, 0.0);
     }
+
+    #[test]
+    fn test_indeterminate_motion() {
+        let bar = |ui: &mut egui::Ui| {
+            ui.add(Progress::new(0.0).indeterminate(true));
+        };
+        assert_eq!(looping_frames(MotionPreference::Full, bar), (true, true));
+        assert_eq!(looping_frames(MotionPreference::Reduced, bar), (false, false));
+        assert_eq!(looping_frames(MotionPreference::None, bar), (false, false));
+    }
 }
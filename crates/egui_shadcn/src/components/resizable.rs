@@ -4,9 +4,18 @@
 //!
 //! Reference: <https://ui.shadcn.com/docs/components/resizable>
 
-use egui::{Id, Response, Ui, Sense, Vec2, Pos2, Rect};
+use std::fmt;
+use std::str::FromStr;
+
+use egui::{Id, Key, Modifiers, Pos2, Rect, Response, Sense, Ui, UiBuilder, Vec2};
 use crate::theme::ShadcnTheme;
 
+/// Pixels a focused handle moves per arrow key press
+const KEYBOARD_STEP: f32 = 10.0;
+
+/// Pixels a focused handle moves per arrow key press while holding Shift
+const KEYBOARD_STEP_LARGE: f32 = 50.0;
+
 /// Resizable panel direction
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ResizableDirection {
@@ -17,8 +26,386 @@ pub enum ResizableDirection {
     Vertical,
 }
 
+/// Size constraints for one panel of a [`ResizablePanelGroup`]
+///
+/// Minimum, maximum and collapsed sizes are in pixels; the default size is a
+/// fraction of the group.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResizablePanel {
+    default_size: Option<f32>,
+    min_size: Option<f32>,
+    max_size: f32,
+    collapsible: bool,
+    collapsed_size: f32,
+}
+
+impl Default for ResizablePanel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ResizablePanel {
+    /// A panel using the group's minimum size and no maximum
+    pub fn new() -> Self {
+        Self {
+            default_size: None,
+            min_size: None,
+            max_size: f32::INFINITY,
+            collapsible: false,
+            collapsed_size: 0.0,
+        }
+    }
+
+    /// Initial share of the group (0.0 to 1.0)
+    ///
+    /// Panels without a default split the remaining space equally.
+    pub fn default_size(mut self, fraction: f32) -> Self {
+        self.default_size = Some(fraction.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Minimum size in pixels (overrides the group's `min_size`)
+    pub fn min_size(mut self, min_size: f32) -> Self {
+        self.min_size = Some(min_size.max(0.0));
+        self
+    }
+
+    /// Maximum size in pixels
+    pub fn max_size(mut self, max_size: f32) -> Self {
+        self.max_size = max_size.max(0.0);
+        self
+    }
+
+    /// Allow dragging the panel past its minimum to collapse it
+    pub fn collapsible(mut self, collapsible: bool) -> Self {
+        self.collapsible = collapsible;
+        self
+    }
+
+    /// Size in pixels of the panel while collapsed (default 0)
+    pub fn collapsed_size(mut self, collapsed_size: f32) -> Self {
+        self.collapsed_size = collapsed_size.max(0.0);
+        self
+    }
+}
+
+/// Error returned when parsing a [`PanelLayout`] from a string
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePanelLayoutError(String);
+
+impl fmt::Display for ParsePanelLayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid panel size: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParsePanelLayoutError {}
+
+/// Sizes of the panels in a [`ResizablePanelGroup`]
+///
+/// Each panel has a weight; expanded panels share the space left after
+/// handles and collapsed panels in proportion to their weights. A collapsed
+/// panel keeps its weight so expanding it restores its previous size.
+///
+/// Layouts round-trip through strings (e.g. `"0.25,0.5,0.25c"`, where `c`
+/// marks a collapsed panel) so they can be saved across sessions:
+///
+/// ```rust,ignore
+/// storage.set_string("deck_layout", layout.to_string());
+/// let layout: PanelLayout = storage.get_string("deck_layout")
+///     .and_then(|s| s.parse().ok())
+///     .unwrap_or_default();
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PanelLayout {
+    weights: Vec<f32>,
+    collapsed: Vec<bool>,
+}
+
+impl PanelLayout {
+    /// Create a layout from panel sizes (normalized to sum to 1.0)
+    pub fn new(sizes: impl Into<Vec<f32>>) -> Self {
+        let weights: Vec<f32> = sizes.into().into_iter().map(|w| w.max(0.0)).collect();
+        let collapsed = vec![false; weights.len()];
+        let mut layout = Self { weights, collapsed };
+        layout.normalize();
+        layout
+    }
+
+    /// `count` panels of equal size
+    pub fn equal(count: usize) -> Self {
+        Self::new(vec![1.0; count])
+    }
+
+    /// Default layout for a set of panels
+    ///
+    /// Panels with a `default_size` get it; the rest split what is left equally.
+    pub fn from_panels(panels: &[ResizablePanel]) -> Self {
+        let claimed: f32 = panels.iter().filter_map(|p| p.default_size).sum();
+        let unsized_count = panels.iter().filter(|p| p.default_size.is_none()).count();
+        let share = if unsized_count > 0 { (1.0 - claimed).max(0.0) / unsized_count as f32 } else { 0.0 };
+        Self::new(
+            panels
+                .iter()
+                .map(|p| p.default_size.unwrap_or(share))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Number of panels
+    pub fn len(&self) -> usize {
+        self.weights.len()
+    }
+
+    /// Whether the layout has no panels
+    pub fn is_empty(&self) -> bool {
+        self.weights.is_empty()
+    }
+
+    /// Share of the expanded space taken by `panel` (0.0 if collapsed)
+    pub fn size(&self, panel: usize) -> f32 {
+        if self.is_collapsed(panel) {
+            return 0.0;
+        }
+        let total = self.expanded_weight();
+        match self.weights.get(panel) {
+            Some(&weight) if total > 0.0 => weight / total,
+            _ => 0.0,
+        }
+    }
+
+    /// Shares of all panels (see [`PanelLayout::size`])
+    pub fn sizes(&self) -> Vec<f32> {
+        (0..self.len()).map(|i| self.size(i)).collect()
+    }
+
+    /// Whether `panel` is collapsed
+    pub fn is_collapsed(&self, panel: usize) -> bool {
+        self.collapsed.get(panel).copied().unwrap_or(false)
+    }
+
+    /// Collapse `panel`, giving its space to the other panels
+    pub fn collapse(&mut self, panel: usize) {
+        if let Some(collapsed) = self.collapsed.get_mut(panel) {
+            *collapsed = true;
+        }
+    }
+
+    /// Expand a collapsed `panel` back to its previous size
+    pub fn expand(&mut self, panel: usize) {
+        if let Some(collapsed) = self.collapsed.get_mut(panel) {
+            *collapsed = false;
+        }
+        self.normalize();
+    }
+
+    fn expanded_weight(&self) -> f32 {
+        self.weights
+            .iter()
+            .zip(&self.collapsed)
+            .filter(|(_, &collapsed)| !collapsed)
+            .map(|(w, _)| w)
+            .sum()
+    }
+
+    /// Scale weights so expanded panels sum to 1.0
+    fn normalize(&mut self) {
+        let total = self.expanded_weight();
+        if total > 0.0 {
+            for w in &mut self.weights {
+                *w /= total;
+            }
+        } else {
+            let expanded = self.collapsed.iter().filter(|c| !**c).count().max(1);
+            for (w, collapsed) in self.weights.iter_mut().zip(&self.collapsed) {
+                if !collapsed {
+                    *w = 1.0 / expanded as f32;
+                }
+            }
+        }
+    }
+
+    /// Pixel sizes of the panels for `space` pixels (excluding handles)
+    fn to_pixels(&self, limits: &[Limits], space: f32) -> Vec<f32> {
+        let collapsed_space: f32 = (0..self.len())
+            .filter(|&i| self.is_collapsed(i))
+            .map(|i| limits[i].collapsed)
+            .sum();
+        let expanded_space = (space - collapsed_space).max(0.0);
+        (0..self.len())
+            .map(|i| {
+                if self.is_collapsed(i) {
+                    limits[i].collapsed
+                } else {
+                    self.size(i) * expanded_space
+                }
+            })
+            .collect()
+    }
+
+    /// Update from pixel sizes, keeping collapsed panels' previous weights
+    fn set_pixels(&mut self, pixels: &[f32], collapsed: &[bool]) {
+        let expanded: f32 = pixels
+            .iter()
+            .zip(collapsed)
+            .filter(|(_, &c)| !c)
+            .map(|(px, _)| px)
+            .sum();
+        for (i, (&px, &is_collapsed)) in pixels.iter().zip(collapsed).enumerate() {
+            if !is_collapsed && expanded > 0.0 {
+                self.weights[i] = px / expanded;
+            }
+            self.collapsed[i] = is_collapsed;
+        }
+    }
+}
+
+impl fmt::Display for PanelLayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (weight, collapsed)) in self.weights.iter().zip(&self.collapsed).enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{weight:.4}")?;
+            if *collapsed {
+                f.write_str("c")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for PanelLayout {
+    type Err = ParsePanelLayoutError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut layout = Self::default();
+        for part in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let (number, collapsed) = match part.strip_suffix('c') {
+                Some(number) => (number, true),
+                None => (part, false),
+            };
+            let weight: f32 = number
+                .parse()
+                .ok()
+                .filter(|w: &f32| w.is_finite() && *w >= 0.0)
+                .ok_or_else(|| ParsePanelLayoutError(part.to_owned()))?;
+            layout.weights.push(weight);
+            layout.collapsed.push(collapsed);
+        }
+        Ok(layout)
+    }
+}
+
+/// Resolved pixel constraints for one panel
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Limits {
+    min: f32,
+    max: f32,
+    collapsible: bool,
+    collapsed: f32,
+}
+
+/// Move handle `handle` (between panels `handle` and `handle + 1`) by `delta` pixels
+///
+/// The panel on the side the handle moves away from grows up to its maximum;
+/// panels on the other side shrink nearest-first down to their minimums. A
+/// collapsible panel collapses once pushed halfway from its minimum towards its
+/// collapsed size, and a collapsed panel re-opens at its minimum once dragged
+/// halfway there.
+fn drag_sizes(
+    start: &[f32],
+    start_collapsed: &[bool],
+    limits: &[Limits],
+    handle: usize,
+    delta: f32,
+) -> (Vec<f32>, Vec<bool>) {
+    let mut sizes = start.to_vec();
+    let mut collapsed = start_collapsed.to_vec();
+    let unchanged = (sizes.clone(), collapsed.clone());
+    if delta == 0.0 || handle + 1 >= sizes.len() {
+        return unchanged;
+    }
+
+    let (grow, shrink): (usize, Vec<usize>) = if delta > 0.0 {
+        (handle, (handle + 1..sizes.len()).collect())
+    } else {
+        (handle + 1, (0..=handle).rev().collect())
+    };
+
+    let mut want = delta.abs();
+    let opening = collapsed[grow];
+    let to_min = (limits[grow].min - sizes[grow]).max(0.0);
+    if opening {
+        if want < to_min / 2.0 {
+            return unchanged;
+        }
+        want = want.max(to_min);
+    }
+    let room = (limits[grow].max - sizes[grow]).max(0.0);
+    want = want.min(room);
+
+    let mut taken = 0.0;
+    for &j in &shrink {
+        let need = want - taken;
+        if need <= 0.0 {
+            break;
+        }
+        if collapsed[j] {
+            continue;
+        }
+        let available = (sizes[j] - limits[j].min).max(0.0);
+        let collapse_gain = sizes[j] - limits[j].collapsed;
+        let collapse_threshold = available + (limits[j].min - limits[j].collapsed) / 2.0;
+        if need <= available {
+            sizes[j] -= need;
+            taken += need;
+        } else if limits[j].collapsible && need > collapse_threshold && taken + collapse_gain <= room {
+            sizes[j] = limits[j].collapsed;
+            collapsed[j] = true;
+            taken += collapse_gain;
+        } else {
+            sizes[j] -= available;
+            taken += available;
+        }
+    }
+
+    if opening && taken < to_min {
+        return unchanged;
+    }
+    sizes[grow] += taken;
+    if taken > 0.0 {
+        collapsed[grow] = false;
+    }
+    (sizes, collapsed)
+}
+
+/// Drag in progress on one handle
+#[derive(Debug, Clone)]
+struct HandleDrag {
+    handle: usize,
+    start: Vec<f32>,
+    start_collapsed: Vec<bool>,
+    delta: f32,
+}
+
+/// Where the group reads and writes its sizes
+enum LayoutTarget<'a> {
+    /// Two panels sized by the first panel's ratio
+    Split(&'a mut f32),
+    /// Any number of panels
+    Layout(&'a mut PanelLayout),
+}
+
 /// A resizable panel group for split pane layouts
 ///
+/// Holds any number of panels separated by drag handles. Handles can also be
+/// focused and moved with the arrow keys (Shift for larger steps), and
+/// double-clicked to reset the two panels around them to their default sizes.
+/// Groups nest: show another group inside a panel for mixed layouts.
+///
 /// ## Example
 /// ```rust,ignore
 /// let mut split = 0.5; // 50% split
@@ -33,10 +420,21 @@ pub enum ResizableDirection {
 ///             _ => {}
 ///         }
 ///     });
+///
+/// // N columns with constraints
+/// let mut layout = PanelLayout::default();
+/// ResizablePanelGroup::with_layout("deck", &mut layout)
+///     .panel(ResizablePanel::new().default_size(0.2).collapsible(true).collapsed_size(48.0))
+///     .panel(ResizablePanel::new().min_size(240.0))
+///     .panel(ResizablePanel::new().min_size(240.0).max_size(600.0))
+///     .show(ui, |ui, column| {
+///         ui.label(format!("Column {column}"));
+///     });
 /// ```
 pub struct ResizablePanelGroup<'a> {
     id: Id,
-    split: &'a mut f32,
+    target: LayoutTarget<'a>,
+    panels: Vec<ResizablePanel>,
     direction: ResizableDirection,
     min_size: f32,
     handle_size: f32,
@@ -49,9 +447,22 @@ impl<'a> ResizablePanelGroup<'a> {
     /// - `id`: Unique identifier
     /// - `split`: Ratio of first panel (0.0 to 1.0)
     pub fn new(id: impl std::hash::Hash, split: &'a mut f32) -> Self {
+        Self::from_target(Id::new(id), LayoutTarget::Split(split))
+    }
+
+    /// Create a group of any number of panels backed by a [`PanelLayout`]
+    ///
+    /// If the layout's panel count doesn't match the configured panels (e.g. a
+    /// fresh `PanelLayout::default()`), it is reset to the panels' defaults.
+    pub fn with_layout(id: impl std::hash::Hash, layout: &'a mut PanelLayout) -> Self {
+        Self::from_target(Id::new(id), LayoutTarget::Layout(layout))
+    }
+
+    fn from_target(id: Id, target: LayoutTarget<'a>) -> Self {
         Self {
-            id: Id::new(id),
-            split,
+            id,
+            target,
+            panels: Vec::new(),
             direction: ResizableDirection::Horizontal,
             min_size: 50.0,
             handle_size: 8.0,
@@ -59,6 +470,18 @@ impl<'a> ResizablePanelGroup<'a> {
         }
     }
 
+    /// Add a panel with its constraints
+    pub fn panel(mut self, panel: ResizablePanel) -> Self {
+        self.panels.push(panel);
+        self
+    }
+
+    /// Add several panels
+    pub fn panels(mut self, panels: impl IntoIterator<Item = ResizablePanel>) -> Self {
+        self.panels.extend(panels);
+        self
+    }
+
     /// Set the panel direction
     pub fn direction(mut self, direction: ResizableDirection) -> Self {
         self.direction = direction;
@@ -85,10 +508,9 @@ impl<'a> ResizablePanelGroup<'a> {
 
     /// Show the resizable panels with a content builder
     ///
-    /// The content builder receives `(ui, panel_index)` where:
-    /// - `panel_index = 0` for the first panel
-    /// - `panel_index = 1` for the second panel
-    pub fn show<F>(self, ui: &mut Ui, mut content: F) -> Response
+    /// The content builder receives `(ui, panel_index)` where
+    /// `panel_index = 0` is the first (left or top) panel.
+    pub fn show<F>(mut self, ui: &mut Ui, mut content: F) -> Response
     where
         F: FnMut(&mut Ui, usize),
     {
@@ -97,195 +519,248 @@ impl<'a> ResizablePanelGroup<'a> {
                 .unwrap_or_else(ShadcnTheme::light)
         });
 
-        let available = ui.available_size();
-        let (response, handle_response) = match self.direction {
-            ResizableDirection::Horizontal => {
-                self.show_horizontal(ui, &theme, available, &mut content)
-            }
-            ResizableDirection::Vertical => {
-                self.show_vertical(ui, &theme, available, &mut content)
-            }
+        // Resolve the layout and per-panel constraints
+        let panel_count = match &self.target {
+            LayoutTarget::Split(_) => 2,
+            LayoutTarget::Layout(layout) if self.panels.is_empty() => layout.len(),
+            LayoutTarget::Layout(_) => self.panels.len(),
         };
+        while self.panels.len() < panel_count {
+            self.panels.push(ResizablePanel::new());
+        }
+        let limits: Vec<Limits> = self
+            .panels
+            .iter()
+            .map(|p| {
+                let min = p.min_size.unwrap_or(self.min_size);
+                Limits {
+                    min,
+                    max: p.max_size.max(min),
+                    collapsible: p.collapsible,
+                    collapsed: p.collapsed_size.min(min),
+                }
+            })
+            .collect();
+        let mut layout = match &self.target {
+            LayoutTarget::Split(split) => PanelLayout::new([**split, 1.0 - **split]),
+            LayoutTarget::Layout(layout) if layout.len() == panel_count => (*layout).clone(),
+            LayoutTarget::Layout(_) => PanelLayout::from_panels(&self.panels),
+        };
+        let original = layout.clone();
+
+        let horizontal = self.direction == ResizableDirection::Horizontal;
+        let main = |v: Vec2| if horizontal { v.x } else { v.y };
+
+        let (group_rect, mut response) = ui.allocate_exact_size(ui.available_size(), Sense::hover());
+        let handle_count = panel_count.saturating_sub(1);
+        let space = (main(group_rect.size()) - self.handle_size * handle_count as f32).max(0.0);
+        let mut pixels = layout.to_pixels(&limits, space);
+
+        // Handles first, so their interaction is resolved before laying out panels
+        let drag_id = self.id.with("drag");
+        let mut drag: Option<HandleDrag> = ui.ctx().data(|d| d.get_temp(drag_id));
+        let handle_rects = self.handle_rects(group_rect, &pixels);
+        let mut handle_responses = Vec::with_capacity(handle_count);
+        for (i, handle_rect) in handle_rects.iter().enumerate() {
+            let handle = ui.interact(*handle_rect, self.id.with(("handle", i)), Sense::click_and_drag());
+
+            if handle.drag_started() {
+                handle.request_focus();
+                drag = Some(HandleDrag {
+                    handle: i,
+                    start: pixels.clone(),
+                    start_collapsed: layout.collapsed.clone(),
+                    delta: 0.0,
+                });
+            }
 
-        // Handle dragging
-        if let Some(handle) = handle_response {
             if handle.dragged() {
-                if let Some(pointer_pos) = ui.ctx().pointer_interact_pos() {
-                    let (total_size, pos_component) = match self.direction {
-                        ResizableDirection::Horizontal => (available.x, pointer_pos.x - handle.rect.center().x + available.x * *self.split),
-                        ResizableDirection::Vertical => (available.y, pointer_pos.y - handle.rect.center().y + available.y * *self.split),
-                    };
-
-                    // Calculate new split ratio
-                    let new_split = pos_component / total_size;
-
-                    // Clamp to min sizes
-                    let min_ratio = self.min_size / total_size;
-                    *self.split = new_split.clamp(min_ratio, 1.0 - min_ratio);
+                if let Some(drag) = drag.as_mut().filter(|d| d.handle == i) {
+                    drag.delta += main(handle.drag_delta());
+                    let (new_pixels, collapsed) =
+                        drag_sizes(&drag.start, &drag.start_collapsed, &limits, i, drag.delta);
+                    layout.set_pixels(&new_pixels, &collapsed);
+                }
+            } else if handle.drag_stopped() {
+                drag = None;
+            }
+
+            // Double-click resets the two panels around the handle
+            if handle.double_clicked() {
+                let defaults = PanelLayout::from_panels(&self.panels);
+                let combined = pixels[i] + pixels[i + 1];
+                let (a, b) = (defaults.weights[i], defaults.weights[i + 1]);
+                let first = if a + b > 0.0 { combined * a / (a + b) } else { combined / 2.0 };
+                let mut reset = pixels.clone();
+                reset[i] = first;
+                reset[i + 1] = combined - first;
+                let mut collapsed = layout.collapsed.clone();
+                collapsed[i] = false;
+                collapsed[i + 1] = false;
+                layout.set_pixels(&reset, &collapsed);
+            }
+
+            // Arrow keys move a focused handle; Enter toggles a collapsible neighbour
+            if handle.has_focus() {
+                ui.memory_mut(|m| {
+                    m.set_focus_lock_filter(
+                        handle.id,
+                        egui::EventFilter {
+                            horizontal_arrows: horizontal,
+                            vertical_arrows: !horizontal,
+                            ..Default::default()
+                        },
+                    )
+                });
+                let (back, forward) = if horizontal {
+                    (Key::ArrowLeft, Key::ArrowRight)
+                } else {
+                    (Key::ArrowUp, Key::ArrowDown)
+                };
+                let mut delta = 0.0;
+                let mut toggle = false;
+                ui.input_mut(|input| {
+                    let step = if input.modifiers.shift { KEYBOARD_STEP_LARGE } else { KEYBOARD_STEP };
+                    // Extra Shift is ignored when matching, so this covers both step sizes
+                    if input.consume_key(Modifiers::NONE, back) {
+                        delta -= step;
+                    }
+                    if input.consume_key(Modifiers::NONE, forward) {
+                        delta += step;
+                    }
+                    toggle = input.consume_key(Modifiers::NONE, Key::Enter);
+                });
+                if delta != 0.0 {
+                    let (new_pixels, collapsed) = drag_sizes(&pixels, &layout.collapsed, &limits, i, delta);
+                    layout.set_pixels(&new_pixels, &collapsed);
+                }
+                if toggle {
+                    if let Some(panel) = [i, i + 1].into_iter().find(|&p| limits[p].collapsible) {
+                        if layout.is_collapsed(panel) {
+                            layout.expand(panel);
+                        } else {
+                            layout.collapse(panel);
+                        }
+                    }
                 }
             }
 
-            // Update cursor
             if handle.hovered() || handle.dragged() {
-                let cursor = match self.direction {
-                    ResizableDirection::Horizontal => egui::CursorIcon::ResizeHorizontal,
-                    ResizableDirection::Vertical => egui::CursorIcon::ResizeVertical,
+                let cursor = if horizontal {
+                    egui::CursorIcon::ResizeHorizontal
+                } else {
+                    egui::CursorIcon::ResizeVertical
                 };
                 ui.ctx().set_cursor_icon(cursor);
             }
+
+            handle_responses.push(handle);
         }
+        ui.ctx().data_mut(|d| match drag {
+            Some(drag) => d.insert_temp(drag_id, drag),
+            None => d.remove::<HandleDrag>(drag_id),
+        });
 
-        response
-    }
+        // Lay out panels with the (possibly updated) sizes
+        if layout != original {
+            pixels = layout.to_pixels(&limits, space);
+            response.mark_changed();
+        }
+        let mut offset = 0.0;
+        for (i, size) in pixels.iter().enumerate() {
+            let panel_rect = if horizontal {
+                Rect::from_min_size(group_rect.min + Vec2::new(offset, 0.0), Vec2::new(*size, group_rect.height()))
+            } else {
+                Rect::from_min_size(group_rect.min + Vec2::new(0.0, offset), Vec2::new(group_rect.width(), *size))
+            };
+            offset += size + self.handle_size;
+
+            let mut panel_ui = ui.new_child(UiBuilder::new().max_rect(panel_rect).id_salt(("panel", i)));
+            panel_ui.set_clip_rect(panel_rect.intersect(ui.clip_rect()));
+            content(&mut panel_ui, i);
+        }
 
-    fn show_horizontal<F>(
-        &self,
-        ui: &mut Ui,
-        theme: &ShadcnTheme,
-        available: Vec2,
-        content: &mut F,
-    ) -> (Response, Option<Response>)
-    where
-        F: FnMut(&mut Ui, usize),
-    {
-        let first_width = (available.x - self.handle_size) * *self.split;
-        let second_width = available.x - first_width - self.handle_size;
+        // Handles are painted over the panels
+        if self.show_handle {
+            for (handle_rect, handle) in self.handle_rects(group_rect, &pixels).iter().zip(&handle_responses) {
+                self.draw_handle(ui, &theme, *handle_rect, handle);
+            }
+        }
 
-        let mut handle_response = None;
+        match &mut self.target {
+            LayoutTarget::Split(split) => **split = layout.size(0),
+            LayoutTarget::Layout(target) => **target = layout,
+        }
 
-        let response = ui.horizontal(|ui| {
-            ui.spacing_mut().item_spacing.x = 0.0;
+        response
+    }
 
-            // First panel
-            ui.allocate_ui(Vec2::new(first_width, available.y), |ui| {
-                egui::Frame::NONE
-                    .show(ui, |ui| {
-                        content(ui, 0);
-                    });
+    /// Rects of the handles between panels of the given pixel sizes
+    fn handle_rects(&self, group_rect: Rect, pixels: &[f32]) -> Vec<Rect> {
+        let mut offset = 0.0;
+        let mut rects = Vec::new();
+        for size in pixels.iter().take(pixels.len().saturating_sub(1)) {
+            offset += size;
+            rects.push(match self.direction {
+                ResizableDirection::Horizontal => Rect::from_min_size(
+                    group_rect.min + Vec2::new(offset, 0.0),
+                    Vec2::new(self.handle_size, group_rect.height()),
+                ),
+                ResizableDirection::Vertical => Rect::from_min_size(
+                    group_rect.min + Vec2::new(0.0, offset),
+                    Vec2::new(group_rect.width(), self.handle_size),
+                ),
             });
+            offset += self.handle_size;
+        }
+        rects
+    }
 
-            // Handle
-            let (handle_rect, handle_resp) = ui.allocate_exact_size(
-                Vec2::new(self.handle_size, available.y),
-                Sense::drag(),
-            );
+    fn draw_handle(&self, ui: &Ui, theme: &ShadcnTheme, handle_rect: Rect, handle: &Response) {
+        if !ui.is_rect_visible(handle_rect) {
+            return;
+        }
 
-            if ui.is_rect_visible(handle_rect) && self.show_handle {
-                let is_active = handle_resp.hovered() || handle_resp.dragged();
-                let handle_color = if is_active {
-                    theme.colors.primary
-                } else {
-                    theme.colors.border
-                };
+        let is_active = handle.hovered() || handle.dragged() || handle.has_focus();
+        let handle_color = if is_active {
+            theme.colors.primary
+        } else {
+            theme.colors.border
+        };
+        let center = handle_rect.center();
 
+        match self.direction {
+            ResizableDirection::Horizontal => {
                 // Draw handle line
-                let center_x = handle_rect.center().x;
                 ui.painter().line_segment(
                     [
-                        Pos2::new(center_x, handle_rect.min.y + 4.0),
-                        Pos2::new(center_x, handle_rect.max.y - 4.0),
+                        Pos2::new(center.x, handle_rect.min.y + 4.0),
+                        Pos2::new(center.x, handle_rect.max.y - 4.0),
                     ],
                     egui::Stroke::new(2.0, handle_color),
                 );
 
                 // Draw grip dots
-                let grip_y = handle_rect.center().y;
                 for offset in [-8.0, 0.0, 8.0] {
-                    ui.painter().circle_filled(
-                        Pos2::new(center_x, grip_y + offset),
-                        2.0,
-                        handle_color,
-                    );
+                    ui.painter().circle_filled(Pos2::new(center.x, center.y + offset), 2.0, handle_color);
                 }
             }
-
-            handle_response = Some(handle_resp);
-
-            // Second panel
-            ui.allocate_ui(Vec2::new(second_width, available.y), |ui| {
-                egui::Frame::NONE
-                    .show(ui, |ui| {
-                        content(ui, 1);
-                    });
-            });
-        });
-
-        (response.response, handle_response)
-    }
-
-    fn show_vertical<F>(
-        &self,
-        ui: &mut Ui,
-        theme: &ShadcnTheme,
-        available: Vec2,
-        content: &mut F,
-    ) -> (Response, Option<Response>)
-    where
-        F: FnMut(&mut Ui, usize),
-    {
-        let first_height = (available.y - self.handle_size) * *self.split;
-        let second_height = available.y - first_height - self.handle_size;
-
-        let mut handle_response = None;
-
-        let response = ui.vertical(|ui| {
-            ui.spacing_mut().item_spacing.y = 0.0;
-
-            // First panel
-            ui.allocate_ui(Vec2::new(available.x, first_height), |ui| {
-                egui::Frame::NONE
-                    .show(ui, |ui| {
-                        content(ui, 0);
-                    });
-            });
-
-            // Handle
-            let (handle_rect, handle_resp) = ui.allocate_exact_size(
-                Vec2::new(available.x, self.handle_size),
-                Sense::drag(),
-            );
-
-            if ui.is_rect_visible(handle_rect) && self.show_handle {
-                let is_active = handle_resp.hovered() || handle_resp.dragged();
-                let handle_color = if is_active {
-                    theme.colors.primary
-                } else {
-                    theme.colors.border
-                };
-
+            ResizableDirection::Vertical => {
                 // Draw handle line
-                let center_y = handle_rect.center().y;
                 ui.painter().line_segment(
                     [
-                        Pos2::new(handle_rect.min.x + 4.0, center_y),
-                        Pos2::new(handle_rect.max.x - 4.0, center_y),
+                        Pos2::new(handle_rect.min.x + 4.0, center.y),
+                        Pos2::new(handle_rect.max.x - 4.0, center.y),
                     ],
                     egui::Stroke::new(2.0, handle_color),
                 );
 
                 // Draw grip dots
-                let grip_x = handle_rect.center().x;
                 for offset in [-8.0, 0.0, 8.0] {
-                    ui.painter().circle_filled(
-                        Pos2::new(grip_x + offset, center_y),
-                        2.0,
-                        handle_color,
-                    );
+                    ui.painter().circle_filled(Pos2::new(center.x + offset, center.y), 2.0, handle_color);
                 }
             }
-
-            handle_response = Some(handle_resp);
-
-            // Second panel
-            ui.allocate_ui(Vec2::new(available.x, second_height), |ui| {
-                egui::Frame::NONE
-                    .show(ui, |ui| {
-                        content(ui, 1);
-                    });
-            });
-        });
-
-        (response.response, handle_response)
+        }
     }
 }
 
@@ -293,6 +768,10 @@ impl<'a> ResizablePanelGroup<'a> {
 mod tests {
     use super::*;
 
+    fn limits(min: f32) -> Limits {
+        Limits { min, max: f32::INFINITY, collapsible: false, collapsed: 0.0 }
+    }
+
     #[test]
     fn test_resizable_creation() {
         let mut split = 0.5;
@@ -303,4 +782,97 @@ mod tests {
         assert_eq!(panels.direction, ResizableDirection::Vertical);
         assert_eq!(panels.min_size, 100.0);
     }
+
+    #[test]
+    fn test_layout_from_panels() {
+        let layout = PanelLayout::from_panels(&[
+            ResizablePanel::new().default_size(0.5),
+            ResizablePanel::new(),
+            ResizablePanel::new(),
+        ]);
+        assert_eq!(layout.sizes(), vec![0.5, 0.25, 0.25]);
+    }
+
+    #[test]
+    fn test_collapse_and_expand_restore_size() {
+        let mut layout = PanelLayout::new([0.2, 0.4, 0.4]);
+        layout.collapse(0);
+        assert!(layout.is_collapsed(0));
+        assert_eq!(layout.size(0), 0.0);
+        assert_eq!(layout.size(1), 0.5);
+
+        layout.expand(0);
+        assert!((layout.size(0) - 0.2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_layout_round_trips_through_string() {
+        let mut layout = PanelLayout::new([0.25, 0.5, 0.25]);
+        layout.collapse(2);
+        let text = layout.to_string();
+        assert_eq!(text, "0.2500,0.5000,0.2500c");
+        assert_eq!(text.parse::<PanelLayout>().unwrap(), layout);
+
+        assert!("0.5,abc".parse::<PanelLayout>().is_err());
+        assert!("-1,2".parse::<PanelLayout>().is_err());
+    }
+
+    #[test]
+    fn test_drag_shrinks_neighbours_nearest_first() {
+        let limits = [limits(50.0); 3];
+        let (sizes, _) = drag_sizes(&[100.0, 100.0, 100.0], &[false; 3], &limits, 0, 80.0);
+        // Middle panel gives 50px down to its minimum, the last panel the rest
+        assert_eq!(sizes, vec![180.0, 50.0, 70.0]);
+    }
+
+    #[test]
+    fn test_drag_respects_max_size() {
+        let mut limits = [limits(50.0); 2];
+        limits[0].max = 120.0;
+        let (sizes, _) = drag_sizes(&[100.0, 100.0], &[false; 2], &limits, 0, 80.0);
+        assert_eq!(sizes, vec![120.0, 80.0]);
+    }
+
+    #[test]
+    fn test_drag_collapses_past_threshold() {
+        let mut limits = [limits(100.0); 2];
+        limits[1].collapsible = true;
+        limits[1].collapsed = 20.0;
+
+        // Pushed 30px past the minimum: not yet halfway to collapsed (40px)
+        let (sizes, collapsed) = drag_sizes(&[200.0, 200.0], &[false; 2], &limits, 0, 130.0);
+        assert_eq!(sizes, vec![300.0, 100.0]);
+        assert!(!collapsed[1]);
+
+        // Pushed 50px past: snaps to the collapsed size
+        let (sizes, collapsed) = drag_sizes(&[200.0, 200.0], &[false; 2], &limits, 0, 150.0);
+        assert_eq!(sizes, vec![380.0, 20.0]);
+        assert!(collapsed[1]);
+    }
+
+    #[test]
+    fn test_drag_reopens_collapsed_panel() {
+        let mut limits = [limits(100.0); 2];
+        limits[0].collapsible = true;
+        limits[0].collapsed = 20.0;
+        let start = [20.0, 380.0];
+
+        // Less than halfway to the minimum stays collapsed
+        let (sizes, collapsed) = drag_sizes(&start, &[true, false], &limits, 0, 30.0);
+        assert_eq!(sizes, start.to_vec());
+        assert!(collapsed[0]);
+
+        // Past halfway jumps open at the minimum
+        let (sizes, collapsed) = drag_sizes(&start, &[true, false], &limits, 0, 50.0);
+        assert_eq!(sizes, vec![100.0, 300.0]);
+        assert!(!collapsed[0]);
+    }
+
+    #[test]
+    fn test_pixels_account_for_collapsed_panels() {
+        let mut layout = PanelLayout::new([0.5, 0.5]);
+        layout.collapse(0);
+        let limits = [Limits { collapsed: 40.0, ..limits(100.0) }, limits(100.0)];
+        assert_eq!(layout.to_pixels(&limits, 400.0), vec![40.0, 360.0]);
+    }
 }
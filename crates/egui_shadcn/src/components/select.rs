@@ -4,11 +4,24 @@
 //!
 //! Reference: <https://ui.shadcn.com/docs/components/select>
 
-use egui::{Response, Ui, Sense, Id};
+use egui::{Event, Id, Key, Modifiers, Response, Sense, Ui};
+use crate::overlay::{OverlayKind, OverlayStack};
 use crate::theme::ShadcnTheme;
 
+/// Typed characters further apart than this start a new typeahead search
+const TYPEAHEAD_TIMEOUT: f64 = 1.0;
+
 /// Select component for dropdown selection
 ///
+/// Binds to any `T: PartialEq + Clone`; options carry the value they select.
+/// Options can be grouped under labels, have an icon and a description, and
+/// be disabled.
+///
+/// Keyboard (native-like): with the trigger focused, Up/Down change the value
+/// without opening, Space/Enter open the list, and typing jumps to the next
+/// option starting with the typed text. While open, Up/Down move the
+/// highlight, Space/Enter select it and Escape closes.
+///
 /// ## Example
 /// ```rust,ignore
 /// let mut selected = 0;
@@ -18,29 +31,152 @@ use crate::theme::ShadcnTheme;
 ///     .option("Banana")
 ///     .option("Cherry")
 ///     .show(ui);
+///
+/// #[derive(Clone, PartialEq)]
+/// enum Relay { Damus, Nos, Primal }
+///
+/// let mut relay = Relay::Damus;
+/// Select::new("relay", &mut relay)
+///     .group("Public", |g| {
+///         g.item(Relay::Damus, "relay.damus.io");
+///         g.item_with(SelectOption::new(Relay::Nos, "nos.lol").description("Fast, free"));
+///     })
+///     .group("Paid", |g| {
+///         g.item_with(SelectOption::new(Relay::Primal, "relay.primal.net").enabled(false));
+///     })
+///     .show(ui);
 /// ```
-pub struct Select<'a> {
+pub struct Select<'a, T: PartialEq + Clone = usize> {
     id: &'a str,
-    selected: &'a mut usize,
-    options: Vec<SelectOption>,
+    selected: &'a mut T,
+    groups: Vec<SelectGroup<T>>,
     placeholder: String,
     width: f32,
     enabled: bool,
 }
 
-/// Configuration for a select option
-struct SelectOption {
+/// A run of options, optionally under a label
+///
+/// Groups after the first are preceded by a separator.
+struct SelectGroup<T> {
+    label: Option<String>,
+    options: Vec<SelectOption<T>>,
+}
+
+/// A select option
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelectOption<T> {
+    value: T,
     label: String,
+    description: Option<String>,
+    icon: Option<String>,
     enabled: bool,
 }
 
-impl<'a> Select<'a> {
-    /// Create a new select bound to a selection index
-    pub fn new(id: &'a str, selected: &'a mut usize) -> Self {
+impl<T> SelectOption<T> {
+    /// Create an option selecting `value`
+    pub fn new(value: T, label: impl Into<String>) -> Self {
+        Self {
+            value,
+            label: label.into(),
+            description: None,
+            icon: None,
+            enabled: true,
+        }
+    }
+
+    /// Show an icon (emoji or icon-font glyph) before the label
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Show a muted description under the label
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set whether the option can be selected
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+/// Builder for the options in a select group
+pub struct SelectGroupBuilder<T> {
+    options: Vec<SelectOption<T>>,
+}
+
+impl<T> SelectGroupBuilder<T> {
+    /// Add an option selecting `value`
+    pub fn item(&mut self, value: T, label: impl Into<String>) -> &mut Self {
+        self.options.push(SelectOption::new(value, label));
+        self
+    }
+
+    /// Add a configured option
+    pub fn item_with(&mut self, option: SelectOption<T>) -> &mut Self {
+        self.options.push(option);
+        self
+    }
+}
+
+/// Typeahead buffer (kept in temp data)
+#[derive(Debug, Clone, Default)]
+struct Typeahead {
+    text: String,
+    last: f64,
+}
+
+/// Next enabled option in direction `delta`, staying put at either end
+fn step_enabled(enabled: &[bool], current: Option<usize>, delta: isize) -> Option<usize> {
+    let mut candidates: Box<dyn Iterator<Item = usize>> = match (current, delta < 0) {
+        (Some(current), false) => Box::new(current + 1..enabled.len()),
+        (Some(current), true) => Box::new((0..current).rev()),
+        (None, false) => Box::new(0..enabled.len()),
+        (None, true) => Box::new((0..enabled.len()).rev()),
+    };
+    candidates.find(|&i| enabled[i]).or(current)
+}
+
+/// Option the typed `prefix` jumps to, searching from `current`
+///
+/// Repeating one character cycles through the options starting with it;
+/// a longer prefix keeps the current option while it still matches.
+fn typeahead_match(labels: &[(&str, bool)], current: Option<usize>, prefix: &str) -> Option<usize> {
+    let prefix = prefix.to_lowercase();
+    let first = prefix.chars().next()?;
+    let cycling = prefix.chars().all(|c| c == first);
+    let (needle, offset) = if cycling {
+        (first.to_string(), 1)
+    } else {
+        (prefix, 0)
+    };
+    let start = current.map_or(0, |current| current + offset);
+    (0..labels.len())
+        .map(|i| (start + i) % labels.len())
+        .find(|&i| labels[i].1 && labels[i].0.to_lowercase().starts_with(&needle))
+}
+
+impl<'a> Select<'a, usize> {
+    /// Add an option selected by its index
+    pub fn option(self, label: impl Into<String>) -> Self {
+        let index = self.groups.iter().map(|g| g.options.len()).sum();
+        self.item(index, label)
+    }
+}
+
+impl<'a, T: PartialEq + Clone> Select<'a, T> {
+    /// Create a new select bound to `selected`
+    ///
+    /// Shows the placeholder while `selected` matches no option.
+    pub fn new(id: &'a str, selected: &'a mut T) -> Self {
         Self {
             id,
             selected,
-            options: Vec::new(),
+            groups: Vec::new(),
             placeholder: "Select...".to_string(),
             width: 180.0,
             enabled: true,
@@ -53,11 +189,39 @@ impl<'a> Select<'a> {
         self
     }
 
-    /// Add an option
-    pub fn option(mut self, label: impl Into<String>) -> Self {
-        self.options.push(SelectOption {
-            label: label.into(),
-            enabled: true,
+    /// Add an option selecting `value` (to the last group or separator section)
+    pub fn item(self, value: T, label: impl Into<String>) -> Self {
+        self.item_with(SelectOption::new(value, label))
+    }
+
+    /// Add a configured option (icon, description, disabled)
+    pub fn item_with(mut self, option: SelectOption<T>) -> Self {
+        match self.groups.last_mut() {
+            Some(group) => group.options.push(option),
+            None => self.groups.push(SelectGroup {
+                label: None,
+                options: vec![option],
+            }),
+        }
+        self
+    }
+
+    /// Add a labelled group of options
+    pub fn group(mut self, label: impl Into<String>, options: impl FnOnce(&mut SelectGroupBuilder<T>)) -> Self {
+        let mut builder = SelectGroupBuilder { options: Vec::new() };
+        options(&mut builder);
+        self.groups.push(SelectGroup {
+            label: Some(label.into()),
+            options: builder.options,
+        });
+        self
+    }
+
+    /// Draw a separator; following options start a new unlabelled group
+    pub fn separator(mut self) -> Self {
+        self.groups.push(SelectGroup {
+            label: None,
+            options: Vec::new(),
         });
         self
     }
@@ -87,24 +251,112 @@ impl<'a> Select<'a> {
 
         // Use simple boolean state in memory instead of Popup API
         let open_id = id.with("open");
+        let highlight_id = id.with("highlight");
+        let typeahead_id = id.with("typeahead");
         let is_open = ui.ctx().data(|d| d.get_temp::<bool>(open_id).unwrap_or(false));
 
+        // All options in display order
+        let options: Vec<&SelectOption<T>> = self.groups.iter().flat_map(|g| &g.options).collect();
+        let enabled: Vec<bool> = options.iter().map(|o| self.enabled && o.enabled).collect();
+        let current = options.iter().position(|o| o.value == *self.selected);
+        let mut highlight = ui
+            .ctx()
+            .data(|d| d.get_temp::<usize>(highlight_id))
+            .filter(|h| *h < options.len())
+            .or(current);
+
         let sense = if self.enabled {
             Sense::click()
         } else {
             Sense::hover()
         };
 
+        // Typeahead buffer, if the user is still typing
+        let now = ui.input(|i| i.time);
+        let mut buffer = ui
+            .ctx()
+            .data(|d| d.get_temp::<Typeahead>(typeahead_id))
+            .filter(|t| now - t.last < TYPEAHEAD_TIMEOUT)
+            .unwrap_or_default();
+
+        // egui turns Space on the focused trigger into a click. While a
+        // search is being typed it is part of the text ("new york"), so drop
+        // the key before the trigger is created.
+        let trigger_id = id.with("trigger");
+        if !buffer.text.is_empty() && ui.memory(|m| m.has_focus(trigger_id)) {
+            ui.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Space));
+        }
+
         // Allocate trigger button
-        let (response, painter) = ui.allocate_painter(
-            egui::vec2(self.width, touch_target),
-            sense,
-        );
+        let (rect, _) =
+            ui.allocate_exact_size(egui::vec2(self.width, touch_target), Sense::hover());
+        let mut response = ui.interact(rect, trigger_id, sense);
+        let painter = ui.painter_at(rect);
 
         // Toggle on click
         let mut new_open_state = is_open;
         if response.clicked() && self.enabled {
             new_open_state = !is_open;
+            response.request_focus();
+        }
+
+        // Keyboard: act on the value while closed, on the highlight while open
+        let mut chosen = None;
+        if self.enabled && (response.has_focus() || is_open) {
+            // Space confirms only outside a search
+            let (steps, confirm, typed) = ui.input_mut(|i| {
+                let up = i.count_and_consume_key(Modifiers::NONE, Key::ArrowUp) as isize;
+                let down = i.count_and_consume_key(Modifiers::NONE, Key::ArrowDown) as isize;
+                let confirm = i.consume_key(Modifiers::NONE, Key::Enter)
+                    | (buffer.text.is_empty() && i.consume_key(Modifiers::NONE, Key::Space));
+                let typed: String = i
+                    .events
+                    .iter()
+                    .filter_map(|e| match e {
+                        Event::Text(text) => Some(text.as_str()),
+                        _ => None,
+                    })
+                    .collect();
+                (down - up, confirm, typed)
+            });
+
+            let target = if is_open { highlight } else { current };
+            let mut moved = target;
+            for _ in 0..steps.unsigned_abs() {
+                moved = step_enabled(&enabled, moved, steps.signum());
+            }
+
+            // A leading space is the Space key, not a search
+            let typed = if buffer.text.is_empty() { typed.trim_start() } else { typed.as_str() };
+            if !typed.is_empty() {
+                buffer.text.push_str(typed);
+                buffer.last = now;
+                let labels: Vec<(&str, bool)> = options
+                    .iter()
+                    .zip(&enabled)
+                    .map(|(o, enabled)| (o.label.as_str(), *enabled))
+                    .collect();
+                if let Some(found) = typeahead_match(&labels, moved, &buffer.text) {
+                    moved = Some(found);
+                }
+                ui.ctx().data_mut(|d| d.insert_temp(typeahead_id, buffer));
+            }
+
+            if is_open {
+                highlight = moved;
+                if confirm {
+                    chosen = highlight.filter(|h| enabled[*h]);
+                    new_open_state = false;
+                }
+            } else {
+                if moved != current {
+                    chosen = moved;
+                }
+                if confirm {
+                    new_open_state = true;
+                    highlight = current;
+                }
+            }
         }
 
         // Draw trigger
@@ -132,19 +384,20 @@ impl<'a> Select<'a> {
                 egui::StrokeKind::Inside,
             );
 
-            // Draw focus ring when open or hovered
-            if hovered || is_open {
+            // Draw focus ring when open, hovered or focused
+            if hovered || is_open || response.has_focus() {
                 theme.draw_focus_ring(&painter, rect, rounding, true);
             }
 
             // Text (selected value or placeholder)
-            let text = if *self.selected < self.options.len() {
-                self.options[*self.selected].label.clone()
-            } else {
-                self.placeholder.clone()
+            let shown = chosen.or(current).map(|i| options[i]);
+            let text = match shown {
+                Some(SelectOption { icon: Some(icon), label, .. }) => format!("{}  {}", icon, label),
+                Some(option) => option.label.clone(),
+                None => self.placeholder.clone(),
             };
 
-            let text_color = if *self.selected < self.options.len() {
+            let text_color = if shown.is_some() {
                 if self.enabled {
                     theme.colors.foreground
                 } else {
@@ -159,7 +412,7 @@ impl<'a> Select<'a> {
                 rect.max - egui::vec2(chevron_width + 8.0, 0.0),
             );
 
-            painter.text(
+            painter.with_clip_rect(text_rect).text(
                 egui::pos2(text_rect.min.x, rect.center().y),
                 egui::Align2::LEFT_CENTER,
                 text,
@@ -186,6 +439,7 @@ impl<'a> Select<'a> {
 
         // Show popup using new_open_state (which reflects the click toggle)
         if new_open_state {
+            let overlay = OverlayStack::register(ui.ctx(), id, OverlayKind::Popover);
             let popup_area_id = id.with("area");
             let area_response = egui::Area::new(popup_area_id)
                 .order(egui::Order::Foreground)
@@ -200,94 +454,81 @@ impl<'a> Select<'a> {
 
                     frame.show(ui, |ui| {
                         ui.set_min_width(self.width - 8.0);
-
-                        for (idx, option) in self.options.iter().enumerate() {
-                            let is_selected = idx == *self.selected;
-                            let item_enabled = self.enabled && option.enabled;
-
-                            // 44px minimum for Apple HIG touch target
-                            let item_response = ui.allocate_response(
-                                egui::vec2(ui.available_width(), 44.0),
-                                if item_enabled { Sense::click() } else { Sense::hover() },
-                            );
-
-                            if item_response.clicked() && item_enabled {
-                                *self.selected = idx;
-                                new_open_state = false; // Close on selection
+                        ui.spacing_mut().item_spacing.y = 0.0;
+
+                        let mut idx = 0;
+                        for (group_index, group) in self.groups.iter().enumerate() {
+                            if group_index > 0 {
+                                ui.add_space(4.0);
+                                let (rect, _) = ui.allocate_exact_size(egui::vec2(ui.available_width(), 1.0), Sense::hover());
+                                ui.painter().rect_filled(rect, 0.0, theme.colors.border);
+                                ui.add_space(4.0);
                             }
-
-                            if ui.is_rect_visible(item_response.rect) {
-                                let hovered = item_response.hovered() && item_enabled;
-
-                                // Hover background
-                                if hovered {
-                                    ui.painter().rect_filled(
-                                        item_response.rect,
-                                        theme.radii.sm,
-                                        theme.colors.accent,
-                                    );
-                                }
-
-                                // Text
-                                let text_color = if item_enabled {
-                                    if hovered {
-                                        theme.colors.accent_foreground
-                                    } else {
-                                        theme.colors.popover_foreground
-                                    }
-                                } else {
-                                    theme.colors.popover_foreground.linear_multiply(0.5)
-                                };
-
+                            if let Some(label) = &group.label {
+                                let (rect, _) = ui.allocate_exact_size(egui::vec2(ui.available_width(), 28.0), Sense::hover());
                                 ui.painter().text(
-                                    egui::pos2(
-                                        item_response.rect.min.x + 8.0,
-                                        item_response.rect.center().y,
-                                    ),
+                                    egui::pos2(rect.min.x + 8.0, rect.center().y),
                                     egui::Align2::LEFT_CENTER,
-                                    &option.label,
-                                    egui::FontId::proportional(theme.typography.body().size),
-                                    text_color,
+                                    label,
+                                    egui::FontId::proportional(theme.typography.small().size),
+                                    theme.colors.muted_foreground,
+                                );
+                            }
+
+                            for option in &group.options {
+                                let item_response = self.draw_option(
+                                    ui,
+                                    &theme,
+                                    option,
+                                    idx == current.unwrap_or(usize::MAX),
+                                    highlight == Some(idx),
+                                    enabled[idx],
                                 );
 
-                                // Check mark for selected
-                                if is_selected {
-                                    let check_pos = egui::pos2(
-                                        item_response.rect.max.x - 20.0,
-                                        item_response.rect.center().y,
-                                    );
-                                    let check_size = 5.0;
-
-                                    let p1 = check_pos + egui::vec2(-check_size * 0.5, 0.0);
-                                    let p2 = check_pos + egui::vec2(-check_size * 0.1, check_size * 0.4);
-                                    let p3 = check_pos + egui::vec2(check_size * 0.5, -check_size * 0.4);
-
-                                    ui.painter().line_segment(
-                                        [p1, p2],
-                                        egui::Stroke::new(2.0, text_color),
-                                    );
-                                    ui.painter().line_segment(
-                                        [p2, p3],
-                                        egui::Stroke::new(2.0, text_color),
-                                    );
+                                if item_response.clicked() && enabled[idx] {
+                                    chosen = Some(idx);
+                                    new_open_state = false; // Close on selection
+                                }
+                                if item_response.hovered() && enabled[idx] {
+                                    highlight = Some(idx);
                                 }
+                                idx += 1;
                             }
                         }
                     });
                 });
 
-            // Close popup when clicking outside (but not on the trigger itself)
+            overlay.raise(ui.ctx(), area_response.response.layer_id);
+
+            // Close popup on Escape or when clicking outside (but not on the trigger itself)
             // Only check if we didn't just open this frame
             if is_open {
-                // Use clicked_elsewhere which is more reliable
-                if area_response.response.clicked_elsewhere() && !response.clicked() {
+                let clicked_outside = !response.clicked()
+                    && overlay.clicked_outside(ui.ctx(), area_response.response.rect);
+                if clicked_outside || overlay.escape_pressed(ui.ctx()) {
                     new_open_state = false;
                 }
             }
         }
 
+        if let Some(index) = chosen {
+            if options[index].value != *self.selected {
+                *self.selected = options[index].value.clone();
+                response.mark_changed();
+            }
+        }
+
         // Save the open state for next frame
-        ui.ctx().data_mut(|d| d.insert_temp(open_id, new_open_state));
+        ui.ctx().data_mut(|d| {
+            d.insert_temp(open_id, new_open_state);
+            match highlight.filter(|_| new_open_state) {
+                Some(highlight) => d.insert_temp(highlight_id, highlight),
+                None => d.remove::<usize>(highlight_id),
+            }
+        });
+        if !new_open_state {
+            OverlayStack::remove(ui.ctx(), id);
+        }
 
         if !self.enabled {
             response.on_disabled_hover_text("Disabled")
@@ -295,11 +536,112 @@ impl<'a> Select<'a> {
             response
         }
     }
+
+    /// Draw one option row: icon, label, description and check mark
+    fn draw_option(
+        &self,
+        ui: &mut Ui,
+        theme: &ShadcnTheme,
+        option: &SelectOption<T>,
+        is_selected: bool,
+        is_highlighted: bool,
+        item_enabled: bool,
+    ) -> Response {
+        // 44px minimum for Apple HIG touch target, taller with a description
+        let height = if option.description.is_some() { 52.0 } else { 44.0 };
+        let item_response = ui.allocate_response(
+            egui::vec2(ui.available_width(), height),
+            if item_enabled { Sense::click() } else { Sense::hover() },
+        );
+
+        if ui.is_rect_visible(item_response.rect) {
+            let rect = item_response.rect;
+            let active = is_highlighted && item_enabled;
+
+            // Hover / keyboard highlight background
+            if active {
+                ui.painter().rect_filled(rect, theme.radii.sm, theme.colors.accent);
+            }
+
+            // Text
+            let text_color = if item_enabled {
+                if active {
+                    theme.colors.accent_foreground
+                } else {
+                    theme.colors.popover_foreground
+                }
+            } else {
+                theme.colors.popover_foreground.linear_multiply(0.5)
+            };
+            let muted = if item_enabled {
+                theme.colors.muted_foreground
+            } else {
+                theme.colors.muted_foreground.linear_multiply(0.5)
+            };
+
+            let mut text_x = rect.min.x + 8.0;
+            if let Some(icon) = &option.icon {
+                ui.painter().text(
+                    egui::pos2(text_x, rect.center().y),
+                    egui::Align2::LEFT_CENTER,
+                    icon,
+                    egui::FontId::proportional(theme.typography.body().size),
+                    text_color,
+                );
+                text_x += 24.0;
+            }
+
+            match &option.description {
+                Some(description) => {
+                    ui.painter().text(
+                        egui::pos2(text_x, rect.center().y - 1.0),
+                        egui::Align2::LEFT_BOTTOM,
+                        &option.label,
+                        egui::FontId::proportional(theme.typography.body().size),
+                        text_color,
+                    );
+                    ui.painter().text(
+                        egui::pos2(text_x, rect.center().y + 1.0),
+                        egui::Align2::LEFT_TOP,
+                        description,
+                        egui::FontId::proportional(theme.typography.small().size),
+                        muted,
+                    );
+                }
+                None => {
+                    ui.painter().text(
+                        egui::pos2(text_x, rect.center().y),
+                        egui::Align2::LEFT_CENTER,
+                        &option.label,
+                        egui::FontId::proportional(theme.typography.body().size),
+                        text_color,
+                    );
+                }
+            }
+
+            // Check mark for selected
+            if is_selected {
+                let check_pos = egui::pos2(rect.max.x - 20.0, rect.center().y);
+                let check_size = 5.0;
+
+                let p1 = check_pos + egui::vec2(-check_size * 0.5, 0.0);
+                let p2 = check_pos + egui::vec2(-check_size * 0.1, check_size * 0.4);
+                let p3 = check_pos + egui::vec2(check_size * 0.5, -check_size * 0.4);
+
+                ui.painter().line_segment([p1, p2], egui::Stroke::new(2.0, text_color));
+                ui.painter().line_segment([p2, p3], egui::Stroke::new(2.0, text_color));
+            }
+        }
+
+        item_response
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::{context_with_motion, key_press, run_frame_with_events};
+    use crate::theme::MotionPreference;
 
     #[test]
     fn test_select_creation() {
@@ -307,6 +649,134 @@ mod tests {
         let select = Select::new("test", &mut selected)
             .option("Option 1")
             .option("Option 2");
-        assert_eq!(select.options.len(), 2);
+        assert_eq!(select.groups.iter().map(|g| g.options.len()).sum::<usize>(), 2);
+    }
+
+    #[test]
+    fn test_groups_and_separators() {
+        let mut selected = "b";
+        let select = Select::new("grouped", &mut selected)
+            .item("a", "A")
+            .separator()
+            .group("Letters", |g| {
+                g.item("b", "B").item_with(SelectOption::new("c", "C").enabled(false).icon("✓"));
+            });
+        assert_eq!(select.groups.len(), 3);
+        assert_eq!(select.groups[2].label.as_deref(), Some("Letters"));
+        assert!(!select.groups[2].options[1].enabled);
+        // Index options keep counting across groups
+        let mut index = 0;
+        let select = Select::new("indexed", &mut index).option("x").separator().option("y");
+        assert_eq!(select.groups[1].options[0].value, 1);
+    }
+
+    #[test]
+    fn test_step_enabled_skips_disabled() {
+        let enabled = [true, false, true, true];
+        assert_eq!(step_enabled(&enabled, Some(0), 1), Some(2));
+        assert_eq!(step_enabled(&enabled, Some(2), -1), Some(0));
+        // No wrap at the ends
+        assert_eq!(step_enabled(&enabled, Some(3), 1), Some(3));
+        assert_eq!(step_enabled(&enabled, None, 1), Some(0));
+        assert_eq!(step_enabled(&enabled, None, -1), Some(3));
+    }
+
+    #[test]
+    fn test_typeahead_match() {
+        let labels = [("Apple", true), ("Avocado", true), ("Banana", true), ("Apricot", false), ("Blueberry", true)];
+        assert_eq!(typeahead_match(&labels, None, "b"), Some(2));
+        // Repeating a letter cycles, skipping disabled options
+        assert_eq!(typeahead_match(&labels, Some(0), "a"), Some(1));
+        assert_eq!(typeahead_match(&labels, Some(1), "aa"), Some(0));
+        // A longer prefix stays on a still-matching option
+        assert_eq!(typeahead_match(&labels, Some(2), "ba"), Some(2));
+        assert_eq!(typeahead_match(&labels, Some(2), "bl"), Some(4));
+        assert_eq!(typeahead_match(&labels, Some(0), "apr"), None);
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Fruit {
+        Apple,
+        Banana,
+        Cherry,
+        Durian,
+    }
+
+    #[test]
+    fn test_keyboard_changes_value_without_opening() {
+        let ctx = context_with_motion(MotionPreference::Full);
+        let mut fruit = Fruit::Apple;
+        let frame = |time: f64, events: Vec<Event>, fruit: &mut Fruit| {
+            let mut response = None;
+            run_frame_with_events(&ctx, time, events, |ui| {
+                let r = Select::new("fruit", fruit)
+                    .item(Fruit::Apple, "Apple")
+                    .item_with(SelectOption::new(Fruit::Banana, "Banana").enabled(false))
+                    .item(Fruit::Cherry, "Cherry")
+                    .item(Fruit::Durian, "Durian")
+                    .show(ui);
+                response = Some((r.id, r.changed()));
+            });
+            response.unwrap()
+        };
+        let key = |key: Key| key_press(key, Modifiers::NONE);
+        let is_open = |ctx: &egui::Context| ctx.data(|d| d.get_temp::<bool>(Id::new("fruit").with("open"))).unwrap_or(false);
+
+        let (id, _) = frame(0.0, Vec::new(), &mut fruit);
+        ctx.memory_mut(|m| m.request_focus(id));
+        frame(0.1, Vec::new(), &mut fruit);
+
+        let (_, changed) = frame(0.2, vec![key(Key::ArrowDown)], &mut fruit);
+        assert!(changed);
+        assert_eq!(fruit, Fruit::Cherry);
+        assert!(!is_open(&ctx));
+
+        frame(0.3, vec![Event::Text("a".into())], &mut fruit);
+        assert_eq!(fruit, Fruit::Apple);
+        frame(2.0, vec![Event::Text("d".into())], &mut fruit);
+        assert_eq!(fruit, Fruit::Durian);
+
+        // Enter opens, ArrowUp + Enter picks while open
+        frame(3.0, vec![key(Key::Enter)], &mut fruit);
+        assert!(is_open(&ctx));
+        frame(3.1, vec![key(Key::ArrowUp), key(Key::Enter)], &mut fruit);
+        assert_eq!(fruit, Fruit::Cherry);
+        assert!(!is_open(&ctx));
+    }
+
+    #[test]
+    fn test_typeahead_continues_past_spaces() {
+        let ctx = context_with_motion(MotionPreference::Full);
+        let mut city = "Newark";
+        let frame = |time: f64, events: Vec<Event>, city: &mut &'static str| {
+            let mut id = None;
+            run_frame_with_events(&ctx, time, events, |ui| {
+                let response = Select::new("city", city)
+                    .item("Newark", "Newark")
+                    .item("New Jersey", "New Jersey")
+                    .item("New York", "New York")
+                    .show(ui);
+                id = Some(response.id);
+            });
+            id.unwrap()
+        };
+        let is_open = |ctx: &egui::Context| ctx.data(|d| d.get_temp::<bool>(Id::new("city").with("open"))).unwrap_or(false);
+        // Space arrives as a key press and as text
+        let space = || vec![key_press(Key::Space, Modifiers::NONE), Event::Text(" ".into())];
+
+        let id = frame(0.0, Vec::new(), &mut city);
+        ctx.memory_mut(|m| m.request_focus(id));
+        frame(0.1, Vec::new(), &mut city);
+
+        frame(0.2, vec![Event::Text("new".into())], &mut city);
+        frame(0.3, space(), &mut city);
+        assert!(!is_open(&ctx));
+        frame(0.4, vec![Event::Text("y".into())], &mut city);
+        assert_eq!(city, "New York");
+        assert!(!is_open(&ctx));
+
+        // Once the search times out, Space opens the list again
+        frame(3.0, space(), &mut city);
+        assert!(is_open(&ctx));
     }
 }
@@ -4,8 +4,9 @@
 //!
 //! Reference: <https://ui.shadcn.com/docs/components/sheet>
 
-use egui::{Id, Ui, Sense, Color32, Rect, Pos2, Vec2};
+use egui::{Id, Ui, Sense, Rect, Pos2, Vec2};
 use crate::animation::SlideAnimation;
+use crate::overlay::{OverlayKind, OverlayStack};
 use crate::theme::ShadcnTheme;
 
 /// Side from which the sheet slides in
@@ -120,9 +121,12 @@ impl<'a> Sheet<'a> {
 
         // Don't render if fully closed
         if !*self.open && anim.offset >= 0.999 {
+            OverlayStack::remove(ui.ctx(), self.id);
             return None;
         }
 
+        let overlay = OverlayStack::register(ui.ctx(), self.id, OverlayKind::Modal);
+
         #[allow(deprecated)]
         let screen_rect = ui.ctx().screen_rect();
         let mut result = None;
@@ -136,7 +140,8 @@ impl<'a> Sheet<'a> {
         let sheet_height = self.height.unwrap_or(default_height);
 
         // Calculate the offset translation based on animation progress
-        let offset_amount = anim.offset;
+        // (with reduced motion the sheet stays in place and fades instead)
+        let (offset_amount, opacity) = theme.motion.slide_and_fade(anim.offset);
 
         let (sheet_rect, corner_radius, translate) = match self.side {
             SheetSide::Right => {
@@ -180,19 +185,11 @@ impl<'a> Sheet<'a> {
         };
 
         // Draw backdrop overlay with animated alpha
-        let backdrop_alpha = ((1.0 - offset_amount) * 128.0) as u8;
-        let backdrop_layer = egui::LayerId::new(egui::Order::Middle, self.id.with("backdrop_layer"));
-        ui.ctx().layer_painter(backdrop_layer).rect_filled(
-            screen_rect,
-            0.0,
-            Color32::from_black_alpha(backdrop_alpha),
-        );
+        let backdrop_alpha = ((1.0 - anim.offset) * 128.0) as u8;
+        overlay.paint_backdrop(ui.ctx(), screen_rect, backdrop_alpha);
 
         // Check for clicks outside the sheet panel (only when mostly open)
-        if offset_amount < 0.3 {
-            let pointer_pos = ui.ctx().input(|i| i.pointer.latest_pos());
-            let primary_pressed = ui.ctx().input(|i| i.pointer.primary_pressed());
-
+        if anim.offset < 0.3 {
             // Calculate the visible sheet rect (accounting for animation)
             let visible_rect = match self.side {
                 SheetSide::Right => Rect::from_min_size(
@@ -213,10 +210,7 @@ impl<'a> Sheet<'a> {
                 ),
             };
 
-            let clicked_outside = primary_pressed
-                && pointer_pos.map(|p| !visible_rect.contains(p)).unwrap_or(false);
-
-            if clicked_outside {
+            if overlay.clicked_outside(ui.ctx(), visible_rect) {
                 *self.open = false;
                 // Re-store animation state to start closing immediately
                 anim.opening = false;
@@ -228,11 +222,14 @@ impl<'a> Sheet<'a> {
 
         // Draw the sheet panel
         let sheet_id = self.id.with("panel");
-        egui::Area::new(sheet_id)
+        let area_response = egui::Area::new(sheet_id)
             .order(egui::Order::Foreground)
             .fixed_pos(sheet_rect.left_top())
+            .constrain(false) // slide in from off-screen
             .interactable(true)
             .show(ui.ctx(), |ui| {
+                ui.multiply_opacity(opacity);
+
                 let frame = egui::Frame::NONE
                     .fill(theme.colors.background)
                     .stroke(egui::Stroke::new(1.0, theme.colors.border))
@@ -315,8 +312,10 @@ impl<'a> Sheet<'a> {
                 });
             });
 
-        // Handle escape key to close
-        if ui.ctx().input(|i| i.key_pressed(egui::Key::Escape)) {
+        overlay.raise(ui.ctx(), area_response.response.layer_id);
+
+        // Handle escape key to close (only when this is the top-most overlay)
+        if overlay.escape_pressed(ui.ctx()) {
             *self.open = false;
             ui.ctx().request_repaint();
         }
@@ -328,6 +327,8 @@ impl<'a> Sheet<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::{context_with_motion, run_frame};
+    use crate::theme::MotionPreference;
 
     #[test]
     fn test_sheet_creation() {
@@ -343,4 +344,37 @@ mod tests {
         assert_eq!(sheet.description, Some("Test desc".to_string()));
         assert_eq!(sheet.width, Some(500.0));
     }
+
+    /// Panel left edge and slide offset one frame after opening a right sheet
+    fn first_open_frame(motion: MotionPreference) -> (f32, f32) {
+        let ctx = context_with_motion(motion);
+        let mut open = false;
+        run_frame(&ctx, 0.0, |ui| {
+            Sheet::new("sheet", &mut open).show(ui, |_| ());
+        });
+        open = true;
+        run_frame(&ctx, 1.0 / 60.0, |ui| {
+            Sheet::new("sheet", &mut open).show(ui, |_| ());
+        });
+
+        let id = Id::new("sheet");
+        let rect = ctx.memory(|m| m.area_rect(id.with("panel"))).unwrap();
+        (rect.left(), SlideAnimation::load(&ctx, id.with("anim")).offset)
+    }
+
+    #[test]
+    fn test_sheet_motion() {
+        // Full: slides in from the right edge
+        let (left, offset) = first_open_frame(MotionPreference::Full);
+        assert!(left > 400.0);
+        assert!(offset > 0.0 && offset < 1.0);
+
+        // Reduced: already in place, fading in
+        let (left, offset) = first_open_frame(MotionPreference::Reduced);
+        assert_eq!(left, 400.0);
+        assert!(offset > 0.0 && offset < 1.0);
+
+        // None: fully open immediately
+        assert_eq!(first_open_frame(MotionPreference::None), (400.0, 0.0));
+    }
 }
@@ -1,77 +1,288 @@
 //! Sidebar component ported from shadcn/ui
 //!
-//! A collapsible side navigation component.
+//! A collapsible side navigation component built from a tree of
+//! [`SidebarNode`]s with stable IDs.
 //!
 //! Reference: <https://ui.shadcn.com/docs/components/sidebar>
 
+use std::hash::Hash;
 use egui::{Id, Ui, Sense, Vec2, Pos2};
+use crate::dnd::{DragAxis, DragList, Reorder};
 use crate::theme::ShadcnTheme;
 
 /// Sidebar component for side navigation
 ///
+/// Build it from [`SidebarNode`]s keyed by your own ID type with
+/// [`Sidebar::tree`]. The sidebar keeps the selection in the bound
+/// `Option<K>`, persists which sections are expanded (with
+/// `insert_persisted`, so it survives restarts when egui persistence is on)
+/// and reports what happened as [`SidebarEvent`]s.
+///
 /// ## Example
 /// ```rust,ignore
-/// let mut sidebar_open = true;
+/// #[derive(Clone, PartialEq, Hash)]
+/// enum Page { Inbox, Drafts, Project(u32) }
 ///
-/// Sidebar::new("main_sidebar", &mut sidebar_open)
-///     .width(240.0)
-///     .collapsible(true)
+/// let response = Sidebar::tree("main_sidebar", &mut sidebar_open, &mut page)
 ///     .header(|ui| {
 ///         ui.label("My App");
 ///     })
-///     .item("Dashboard", "D", true, || println!("Dashboard"))
-///     .item("Settings", "S", false, || println!("Settings"))
+///     .node(SidebarNode::item(Page::Inbox, "Inbox").icon("I").badge(unread))
+///     .node(SidebarNode::item(Page::Drafts, "Drafts").icon("D"))
 ///     .separator()
-///     .item("Logout", "L", false, || println!("Logout"))
+///     .node(
+///         SidebarNode::item(Page::Project(0), "Projects")
+///             .icon("P")
+///             .child(SidebarNode::item(Page::Project(1), "Website").action("Rename").action("Delete")),
+///     )
 ///     .show(ui);
+///
+/// for event in response.events {
+///     if let SidebarEvent::MenuAction { node, action: 1 } = event {
+///         delete_project(node);
+///     }
+/// }
 /// ```
-pub struct Sidebar<'a> {
-    id: &'a str,
+///
+/// [`Sidebar::new`] keeps the older index-based builders (`item`, `menu`,
+/// `project_item`, ...), numbering items in the order they are added.
+pub struct Sidebar<'a, K = usize> {
+    id: Id,
     open: &'a mut bool,
+    selected: Option<&'a mut Option<K>>,
     width: f32,
     collapsed_width: f32,
     collapsible: bool,
-    items: Vec<SidebarItem>,
+    items: Vec<SidebarEntry<K>>,
+    next_index: usize,
     header: Option<Box<dyn FnOnce(&mut Ui) + 'a>>,
     footer: Option<Box<dyn FnOnce(&mut Ui) + 'a>>,
 }
 
-enum SidebarItem {
-    Link {
-        label: String,
-        icon: Option<String>,
-        active: bool,
-    },
-    /// Link with a context menu (three dots) - for project items
-    LinkWithMenu {
-        label: String,
-        icon: Option<String>,
-        active: bool,
-        menu_items: Vec<String>,
-    },
+enum SidebarEntry<K> {
+    Node(SidebarNode<K>),
     Separator,
-    Section {
-        label: String,
-        items: Vec<SidebarItem>,
+}
+
+/// A node in the sidebar tree
+///
+/// A node with children is a collapsible section: clicking it toggles the
+/// children instead of selecting it. A [`SidebarNode::group`] is a labelled
+/// group whose children are always shown.
+#[derive(Clone, Debug)]
+pub struct SidebarNode<K> {
+    id: K,
+    label: String,
+    icon: Option<String>,
+    badge: Option<u32>,
+    actions: Vec<String>,
+    children: Vec<SidebarNode<K>>,
+    group: bool,
+    expanded: bool,
+    reorderable: bool,
+    active: bool,
+}
+
+impl<K> SidebarNode<K> {
+    /// A navigation item (or a collapsible section once it has children)
+    pub fn item(id: K, label: impl Into<String>) -> Self {
+        Self {
+            id,
+            label: label.into(),
+            icon: None,
+            badge: None,
+            actions: Vec::new(),
+            children: Vec::new(),
+            group: false,
+            expanded: false,
+            reorderable: false,
+            active: false,
+        }
+    }
+
+    /// A labelled group whose children are always shown
+    pub fn group(id: K, label: impl Into<String>) -> Self {
+        Self {
+            group: true,
+            ..Self::item(id, label)
+        }
+    }
+
+    /// Set the icon shown before the label (and alone when the sidebar is collapsed)
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Show a trailing count badge, e.g. unread items (0 hides it)
+    pub fn badge(mut self, count: u32) -> Self {
+        self.badge = (count > 0).then_some(count);
+        self
+    }
+
+    /// Add an entry to the node's "..." menu
+    ///
+    /// Picking it reports [`SidebarEvent::MenuAction`] with the entry's index.
+    pub fn action(mut self, label: impl Into<String>) -> Self {
+        self.actions.push(label.into());
+        self
+    }
+
+    /// Add a child node
+    pub fn child(mut self, child: SidebarNode<K>) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Add several child nodes
+    pub fn children(mut self, children: impl IntoIterator<Item = SidebarNode<K>>) -> Self {
+        self.children.extend(children);
+        self
+    }
+
+    /// Whether the section starts expanded before the user toggles it (default: false)
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    /// Let the user drag the children (or move them with Space and the arrow
+    /// keys) to reorder them, reported as [`SidebarEvent::Reorder`]
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    /// The node's ID
+    pub fn id(&self) -> &K {
+        &self.id
+    }
+
+    /// The node's label
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Highlight regardless of the bound selection (index-based API)
+    fn active(mut self, active: bool) -> Self {
+        self.active = active;
+        self
+    }
+}
+
+/// Something the user did in the sidebar this frame
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SidebarEvent<K> {
+    /// A navigation item was clicked
+    Selected(K),
+    /// An entry of a node's "..." menu was picked; `action` indexes the node's actions
+    MenuAction {
+        /// The node whose menu was used
+        node: K,
+        /// Index of the action in the order it was added
+        action: usize,
     },
-    /// Collapsible menu item with sub-items (like Playground > History, Starred, Settings)
-    Collapsible {
-        label: String,
-        icon: Option<String>,
-        items: Vec<SidebarItem>,
+    /// A child of a [reorderable](SidebarNode::reorderable) node was moved
+    ///
+    /// Apply it to your children with [`Reorder::apply`] for the next frame.
+    Reorder {
+        /// The node whose children were reordered
+        parent: K,
+        /// Index of the child before the move
+        from: usize,
+        /// Index of the child after the move
+        to: usize,
+    },
+    /// A collapsible section was expanded or collapsed
+    Toggled {
+        /// The section node
+        node: K,
+        /// Whether it is now expanded
+        expanded: bool,
     },
 }
 
-impl<'a> Sidebar<'a> {
-    /// Create a new sidebar
+impl<'a> Sidebar<'a, usize> {
+    /// Create a sidebar using the index-based item builders
     pub fn new(id: &'a str, open: &'a mut bool) -> Self {
+        Self::with_selection(id, open, None)
+    }
+
+    /// Add a navigation item
+    pub fn item(mut self, label: impl Into<String>, icon: impl Into<String>, active: bool) -> Self {
+        let node = SidebarNode::item(self.take_index(), label).icon(icon).active(active);
+        self.items.push(SidebarEntry::Node(node));
+        self
+    }
+
+    /// Add a navigation item without icon
+    pub fn item_no_icon(mut self, label: impl Into<String>, active: bool) -> Self {
+        let node = SidebarNode::item(self.take_index(), label).active(active);
+        self.items.push(SidebarEntry::Node(node));
+        self
+    }
+
+    /// Add a project item with context menu (three dots)
+    /// Menu items will appear when the three dots are clicked
+    pub fn project_item(mut self, label: impl Into<String>, icon: impl Into<String>, active: bool, menu_items: Vec<&str>) -> Self {
+        let node = menu_items
+            .into_iter()
+            .fold(SidebarNode::item(self.take_index(), label).icon(icon).active(active), SidebarNode::action);
+        self.items.push(SidebarEntry::Node(node));
+        self
+    }
+
+    /// Add a section with label
+    pub fn section(mut self, label: impl Into<String>, build: impl FnOnce(&mut SidebarBuilder)) -> Self {
+        let (header, children) = self.build_children(build);
+        self.items.push(SidebarEntry::Node(SidebarNode::group(header, label).children(children)));
+        self
+    }
+
+    /// Add a collapsible menu item with sub-items (like Playground > History, Starred, Settings)
+    pub fn menu(mut self, label: impl Into<String>, icon: impl Into<String>, build: impl FnOnce(&mut SidebarBuilder)) -> Self {
+        let (header, children) = self.build_children(build);
+        self.items.push(SidebarEntry::Node(SidebarNode::item(header, label).icon(icon).children(children)));
+        self
+    }
+
+    fn take_index(&mut self) -> usize {
+        self.next_index += 1;
+        self.next_index - 1
+    }
+
+    /// Run a section builder, returning an ID for the header and the children
+    ///
+    /// Headers are numbered down from `usize::MAX` so items keep their
+    /// indices in insertion order.
+    fn build_children(&mut self, build: impl FnOnce(&mut SidebarBuilder)) -> (usize, Vec<SidebarNode<usize>>) {
+        let header = usize::MAX - self.items.len();
+        let mut builder = SidebarBuilder {
+            items: Vec::new(),
+            next_index: self.next_index,
+        };
+        build(&mut builder);
+        self.next_index = builder.next_index;
+        (header, builder.items)
+    }
+}
+
+impl<'a, K: Clone + PartialEq + Hash> Sidebar<'a, K> {
+    /// Create a sidebar built from [`SidebarNode`]s, selecting into `selected`
+    pub fn tree(id: &'a str, open: &'a mut bool, selected: &'a mut Option<K>) -> Self {
+        Self::with_selection(id, open, Some(selected))
+    }
+
+    fn with_selection(id: &'a str, open: &'a mut bool, selected: Option<&'a mut Option<K>>) -> Self {
         Self {
-            id,
+            id: Id::new(id),
             open,
+            selected,
             width: 240.0,
             collapsed_width: 60.0,
             collapsible: true,
             items: Vec::new(),
+            next_index: 0,
             header: None,
             footer: None,
         }
@@ -107,69 +318,26 @@ impl<'a> Sidebar<'a> {
         self
     }
 
-    /// Add a navigation item
-    pub fn item(mut self, label: impl Into<String>, icon: impl Into<String>, active: bool) -> Self {
-        self.items.push(SidebarItem::Link {
-            label: label.into(),
-            icon: Some(icon.into()),
-            active,
-        });
+    /// Add a top-level node
+    pub fn node(mut self, node: SidebarNode<K>) -> Self {
+        self.items.push(SidebarEntry::Node(node));
         self
     }
 
-    /// Add a navigation item without icon
-    pub fn item_no_icon(mut self, label: impl Into<String>, active: bool) -> Self {
-        self.items.push(SidebarItem::Link {
-            label: label.into(),
-            icon: None,
-            active,
-        });
-        self
-    }
-
-    /// Add a project item with context menu (three dots)
-    /// Menu items will appear when the three dots are clicked
-    pub fn project_item(mut self, label: impl Into<String>, icon: impl Into<String>, active: bool, menu_items: Vec<&str>) -> Self {
-        self.items.push(SidebarItem::LinkWithMenu {
-            label: label.into(),
-            icon: Some(icon.into()),
-            active,
-            menu_items: menu_items.into_iter().map(|s| s.to_string()).collect(),
-        });
+    /// Add several top-level nodes
+    pub fn nodes(mut self, nodes: impl IntoIterator<Item = SidebarNode<K>>) -> Self {
+        self.items.extend(nodes.into_iter().map(SidebarEntry::Node));
         self
     }
 
     /// Add a separator
     pub fn separator(mut self) -> Self {
-        self.items.push(SidebarItem::Separator);
-        self
-    }
-
-    /// Add a section with label
-    pub fn section(mut self, label: impl Into<String>, build: impl FnOnce(&mut SidebarBuilder)) -> Self {
-        let mut builder = SidebarBuilder { items: Vec::new() };
-        build(&mut builder);
-        self.items.push(SidebarItem::Section {
-            label: label.into(),
-            items: builder.items,
-        });
-        self
-    }
-
-    /// Add a collapsible menu item with sub-items (like Playground > History, Starred, Settings)
-    pub fn menu(mut self, label: impl Into<String>, icon: impl Into<String>, build: impl FnOnce(&mut SidebarBuilder)) -> Self {
-        let mut builder = SidebarBuilder { items: Vec::new() };
-        build(&mut builder);
-        self.items.push(SidebarItem::Collapsible {
-            label: label.into(),
-            icon: Some(icon.into()),
-            items: builder.items,
-        });
+        self.items.push(SidebarEntry::Separator);
         self
     }
 
     /// Show the sidebar
-    pub fn show(self, ui: &mut Ui) -> SidebarResponse {
+    pub fn show(self, ui: &mut Ui) -> SidebarResponse<K> {
         let theme = ui.ctx().data(|d| {
             d.get_temp::<ShadcnTheme>(Id::new("shadcn_theme"))
                 .unwrap_or_else(ShadcnTheme::light)
@@ -183,8 +351,15 @@ impl<'a> Sidebar<'a> {
         let footer = self.footer;
         let items = self.items;
         let collapsible = self.collapsible;
-
-        let mut clicked_item: Option<usize> = None;
+        let selected = self.selected;
+
+        let mut tree = TreeUi {
+            theme: &theme,
+            id: self.id,
+            selected: selected.as_deref().and_then(Option::as_ref),
+            is_collapsed,
+            events: Vec::new(),
+        };
         let mut toggle_clicked = false;
 
         egui::Frame::NONE
@@ -230,32 +405,12 @@ impl<'a> Sidebar<'a> {
 
                     // Navigation items
                     ui.add_space(4.0);
-                    let mut item_idx = 0;
                     for item in &items {
                         match item {
-                            SidebarItem::Link { label, icon, active } => {
-                                let current_idx = item_idx;
-                                item_idx += 1;
-
-                                let response = draw_nav_item(
-                                    ui, &theme, label, icon.as_deref(), *active, is_collapsed
-                                );
-                                if response.clicked() {
-                                    clicked_item = Some(current_idx);
-                                }
+                            SidebarEntry::Node(node) => {
+                                tree.show_node(ui, node, 0, false);
                             }
-                            SidebarItem::LinkWithMenu { label, icon, active, menu_items } => {
-                                let current_idx = item_idx;
-                                item_idx += 1;
-
-                                let response = draw_nav_item_with_menu(
-                                    ui, &theme, label, icon.as_deref(), *active, is_collapsed, menu_items, current_idx
-                                );
-                                if response.clicked() {
-                                    clicked_item = Some(current_idx);
-                                }
-                            }
-                            SidebarItem::Separator => {
+                            SidebarEntry::Separator => {
                                 ui.add_space(8.0);
                                 let sep_rect = ui.available_rect_before_wrap();
                                 ui.painter().line_segment(
@@ -267,66 +422,6 @@ impl<'a> Sidebar<'a> {
                                 );
                                 ui.add_space(8.0);
                             }
-                            SidebarItem::Section { label, items: sub_items } => {
-                                if !is_collapsed {
-                                    ui.add_space(12.0);
-                                    ui.horizontal(|ui| {
-                                        ui.add_space(16.0);
-                                        ui.label(
-                                            egui::RichText::new(label)
-                                                .size(theme.typography.small().size - 2.0)
-                                                .color(theme.colors.sidebar_foreground.linear_multiply(0.6))
-                                        );
-                                    });
-                                    ui.add_space(4.0);
-                                }
-
-                                for sub_item in sub_items {
-                                    if let SidebarItem::Link { label, icon, active } = sub_item {
-                                        let current_idx = item_idx;
-                                        item_idx += 1;
-
-                                        let response = draw_nav_item(
-                                            ui, &theme, label, icon.as_deref(), *active, is_collapsed
-                                        );
-                                        if response.clicked() {
-                                            clicked_item = Some(current_idx);
-                                        }
-                                    }
-                                }
-                            }
-                            SidebarItem::Collapsible { label, icon, items: sub_items } => {
-                                // Get/set expanded state from egui temp storage
-                                let expanded_id = Id::new(("sidebar_expanded", label.as_str()));
-                                let is_expanded = ui.ctx().data(|d| d.get_temp::<bool>(expanded_id).unwrap_or(false));
-
-                                // Draw the collapsible header item with chevron
-                                let response = draw_collapsible_item(
-                                    ui, &theme, label, icon.as_deref(), is_expanded, is_collapsed
-                                );
-
-                                if response.clicked() {
-                                    // Toggle expanded state
-                                    ui.ctx().data_mut(|d| d.insert_temp(expanded_id, !is_expanded));
-                                }
-
-                                // Show sub-items if expanded and not collapsed
-                                if is_expanded && !is_collapsed {
-                                    for sub_item in sub_items {
-                                        if let SidebarItem::Link { label, icon, active } = sub_item {
-                                            let current_idx = item_idx;
-                                            item_idx += 1;
-
-                                            let response = draw_sub_item(
-                                                ui, &theme, label, *active
-                                            );
-                                            if response.clicked() {
-                                                clicked_item = Some(current_idx);
-                                            }
-                                        }
-                                    }
-                                }
-                            }
                         }
                     }
 
@@ -353,467 +448,443 @@ impl<'a> Sidebar<'a> {
                 });
             });
 
+        let events = tree.events;
+
         // Toggle the sidebar open state when toggle button was clicked
         if toggle_clicked {
             *self.open = !*self.open;
         }
 
+        let clicked_item = events.iter().rev().find_map(|event| match event {
+            SidebarEvent::Selected(node) => Some(node.clone()),
+            _ => None,
+        });
+        if let (Some(selected), Some(clicked)) = (selected, &clicked_item) {
+            *selected = Some(clicked.clone());
+        }
+
         SidebarResponse {
             clicked_item,
             toggle_clicked,
+            events,
         }
     }
 }
 
-fn draw_toggle_button(ui: &mut Ui, theme: &ShadcnTheme, _is_collapsed: bool) -> egui::Response {
-    let size = Vec2::splat(32.0);
-    let (rect, response) = ui.allocate_exact_size(size, Sense::click());
+/// Per-frame state while drawing the node tree
+struct TreeUi<'t, K> {
+    theme: &'t ShadcnTheme,
+    id: Id,
+    selected: Option<&'t K>,
+    is_collapsed: bool,
+    events: Vec<SidebarEvent<K>>,
+}
 
-    if ui.is_rect_visible(rect) {
-        let hovered = response.hovered();
+impl<K: Clone + PartialEq + Hash> TreeUi<'_, K> {
+    /// Draw `node` and its visible children, returning the row response
+    /// (`None` for groups, which have no row of their own)
+    fn show_node(&mut self, ui: &mut Ui, node: &SidebarNode<K>, depth: usize, draggable: bool) -> Option<egui::Response> {
+        let node_id = self.id.with(("node", &node.id));
+
+        if node.group {
+            if !self.is_collapsed {
+                ui.add_space(12.0);
+                ui.horizontal(|ui| {
+                    ui.add_space(16.0 + indent(depth));
+                    ui.label(
+                        egui::RichText::new(&node.label)
+                            .size(self.theme.typography.small().size - 2.0)
+                            .color(self.theme.colors.sidebar_foreground.linear_multiply(0.6))
+                    );
+                });
+                ui.add_space(4.0);
+            }
+            self.show_children(ui, node, node_id, depth);
+            return None;
+        }
 
-        if hovered {
-            ui.painter().rect_filled(rect, theme.radii.sm, theme.colors.sidebar_accent);
+        let has_children = !node.children.is_empty();
+        let expanded = has_children.then(|| is_expanded(ui.ctx(), node_id, node.expanded));
+        let state = RowState {
+            depth,
+            active: node.active || self.selected == Some(&node.id),
+            expanded,
+            is_collapsed: self.is_collapsed,
+            draggable,
+        };
+
+        let (response, action) = draw_row(ui, self.theme, node, &state, node_id);
+        if let Some(action) = action {
+            self.events.push(SidebarEvent::MenuAction { node: node.id.clone(), action });
+        }
+        if response.clicked() {
+            match expanded {
+                Some(expanded) => {
+                    set_expanded(ui.ctx(), node_id, !expanded);
+                    self.events.push(SidebarEvent::Toggled { node: node.id.clone(), expanded: !expanded });
+                }
+                None => self.events.push(SidebarEvent::Selected(node.id.clone())),
+            }
         }
 
-        // Draw panel icon - shadcn style (rectangle with sidebar indicator on left)
-        let icon_size = 16.0;
-        let icon_rect = egui::Rect::from_center_size(rect.center(), Vec2::splat(icon_size));
-        let stroke = egui::Stroke::new(1.5, theme.colors.sidebar_foreground);
+        // Show sub-items if expanded and not collapsed
+        if expanded == Some(true) && !self.is_collapsed {
+            self.show_children(ui, node, node_id, depth + 1);
+        }
+        Some(response)
+    }
 
-        // Outer rectangle (panel) with rounded corners
-        ui.painter().rect_stroke(icon_rect, 2.0, stroke, egui::StrokeKind::Inside);
+    fn show_children(&mut self, ui: &mut Ui, node: &SidebarNode<K>, node_id: Id, depth: usize) {
+        if !node.reorderable {
+            for child in &node.children {
+                self.show_node(ui, child, depth, false);
+            }
+            return;
+        }
 
-        // Vertical line on left side (sidebar indicator) - slightly inset
-        let line_x = icon_rect.min.x + icon_size * 0.33;
-        ui.painter().line_segment(
-            [
-                Pos2::new(line_x, icon_rect.min.y + 3.0),
-                Pos2::new(line_x, icon_rect.max.y - 3.0),
-            ],
-            stroke,
-        );
+        let mut list = DragList::new(ui.ctx(), node_id, DragAxis::Vertical);
+        for (index, child) in node.children.iter().enumerate() {
+            // Before the row, so picking up or dropping doesn't click it
+            list.keys(ui, index, row_id(self.id.with(("node", &child.id))));
+            let block = ui.scope(|ui| {
+                if list.dragged() == Some(index) {
+                    ui.multiply_opacity(0.4);
+                }
+                self.show_node(ui, child, depth, true)
+            });
+            if let Some(row) = block.inner {
+                list.item(ui, index, &row, block.response.rect);
+            }
+        }
+        if let Some(Reorder { from, to }) = list.finish(ui) {
+            self.events.push(SidebarEvent::Reorder { parent: node.id.clone(), from, to });
+        }
     }
-
-    response
 }
 
-fn draw_nav_item(
-    ui: &mut Ui,
-    theme: &ShadcnTheme,
-    label: &str,
-    icon: Option<&str>,
-    active: bool,
-    is_collapsed: bool,
-) -> egui::Response {
-    let height = 36.0; // Slightly smaller for lighter feel
-    let width = ui.available_width() - 16.0;
-
-    ui.horizontal(|ui| {
-        ui.add_space(8.0);
-
-        let (rect, response) = ui.allocate_exact_size(
-            Vec2::new(width, height),
-            Sense::click(),
-        );
-
-        if ui.is_rect_visible(rect) {
-            let hovered = response.hovered();
-
-            // Background - lighter styling like shadcn
-            let bg_color = if active {
-                // Active: subtle accent background
-                theme.colors.sidebar_accent
-            } else if hovered {
-                // Hover: visible but not heavy
-                theme.colors.foreground.linear_multiply(0.08)
-            } else {
-                egui::Color32::TRANSPARENT
-            };
-
-            ui.painter().rect_filled(rect, theme.radii.sm, bg_color);
+/// Id of a node's row, known before the row is drawn
+fn row_id(node_id: Id) -> Id {
+    node_id.with("row")
+}
 
-            // Subtle left border indicator for active item
-            if active {
-                let indicator_rect = egui::Rect::from_min_size(
-                    rect.min,
-                    egui::vec2(3.0, rect.height()),
-                );
-                ui.painter().rect_filled(indicator_rect, 1.5, theme.colors.primary);
-            }
+/// Whether a section is expanded, falling back to its default
+fn is_expanded(ctx: &egui::Context, node_id: Id, default: bool) -> bool {
+    ctx.data_mut(|d| d.get_persisted::<bool>(node_id.with("expanded")))
+        .unwrap_or(default)
+}
 
-            // Icon
-            if let Some(icon) = icon {
-                ui.painter().text(
-                    Pos2::new(rect.min.x + 12.0, rect.center().y),
-                    egui::Align2::LEFT_CENTER,
-                    icon,
-                    egui::FontId::proportional(16.0),
-                    if active {
-                        theme.colors.sidebar_accent_foreground
-                    } else {
-                        theme.colors.sidebar_foreground
-                    },
-                );
-            }
+fn set_expanded(ctx: &egui::Context, node_id: Id, expanded: bool) {
+    ctx.data_mut(|d| d.insert_persisted(node_id.with("expanded"), expanded));
+}
 
-            // Label (only when expanded)
-            if !is_collapsed {
-                let text_x = if icon.is_some() { rect.min.x + 36.0 } else { rect.min.x + 12.0 };
-                ui.painter().text(
-                    Pos2::new(text_x, rect.center().y),
-                    egui::Align2::LEFT_CENTER,
-                    label,
-                    egui::FontId::proportional(theme.typography.small().size),
-                    if active {
-                        theme.colors.sidebar_accent_foreground
-                    } else {
-                        theme.colors.sidebar_foreground
-                    },
-                );
-            }
-        }
+/// Extra left offset of a row's content at `depth`
+fn indent(depth: usize) -> f32 {
+    if depth == 0 {
+        0.0
+    } else {
+        32.0 + (depth - 1) as f32 * 12.0
+    }
+}
 
-        response
-    }).inner
+/// How a node row should be drawn this frame
+struct RowState {
+    depth: usize,
+    active: bool,
+    /// `Some` for collapsible sections
+    expanded: Option<bool>,
+    is_collapsed: bool,
+    /// Whether the row can be dragged to reorder its siblings
+    draggable: bool,
 }
 
-/// Draw a navigation item with three dots menu
-fn draw_nav_item_with_menu(
+/// Draw a node row, returning its response and the menu action picked, if any
+fn draw_row<K>(
     ui: &mut Ui,
     theme: &ShadcnTheme,
-    label: &str,
-    icon: Option<&str>,
-    active: bool,
-    is_collapsed: bool,
-    menu_items: &[String],
-    item_idx: usize,
-) -> egui::Response {
-    let height = 36.0; // Slightly smaller for lighter feel
+    node: &SidebarNode<K>,
+    state: &RowState,
+    node_id: Id,
+) -> (egui::Response, Option<usize>) {
+    let popup_id = node_id.with("menu");
+    // Top-level sections are a little taller, nested rows more compact
+    let height = match (state.depth, state.expanded) {
+        (0, Some(_)) => 40.0,
+        (0, None) => 36.0,
+        _ => 32.0,
+    };
+    let font_size = if state.depth == 0 {
+        theme.typography.small().size
+    } else {
+        theme.typography.small().size - 1.0
+    };
     let width = ui.available_width() - 16.0;
+    let mut picked_action = None;
 
-    ui.horizontal(|ui| {
+    let response = ui.horizontal(|ui| {
         ui.add_space(8.0);
 
-        let (rect, response) = ui.allocate_exact_size(
-            Vec2::new(width, height),
-            Sense::click(),
-        );
+        let sense = if state.draggable { Sense::click_and_drag() } else { Sense::click() };
+        let (rect, _) = ui.allocate_exact_size(Vec2::new(width, height), Sense::hover());
+        let response = ui.interact(rect, row_id(node_id), sense);
 
         let hovered = response.hovered() || ui.rect_contains_pointer(rect);
-        let popup_id = Id::new(("sidebar_item_menu", item_idx));
         let popup_open = ui.memory(|mem| mem.is_popup_open(popup_id));
 
         if ui.is_rect_visible(rect) {
             // Background - lighter styling like shadcn
-            let bg_color = if active {
-                theme.colors.sidebar_accent
-            } else if hovered || popup_open {
-                theme.colors.foreground.linear_multiply(0.08)
-            } else {
-                egui::Color32::TRANSPARENT
-            };
-
-            ui.painter().rect_filled(rect, theme.radii.sm, bg_color);
+            if state.active {
+                ui.painter().rect_filled(rect, theme.radii.sm, theme.colors.sidebar_accent);
 
-            // Subtle left border indicator for active item
-            if active {
+                // Subtle left border indicator for active item
                 let indicator_rect = egui::Rect::from_min_size(
                     rect.min,
                     egui::vec2(3.0, rect.height()),
                 );
                 ui.painter().rect_filled(indicator_rect, 1.5, theme.colors.primary);
+            } else if state.expanded.is_some() && hovered {
+                ui.painter().rect_filled(rect, theme.radii.md, theme.colors.sidebar_accent.linear_multiply(0.5));
+            } else if hovered || popup_open {
+                ui.painter().rect_filled(rect, theme.radii.sm, theme.colors.foreground.linear_multiply(0.08));
             }
 
+            let text_color = if state.active {
+                theme.colors.sidebar_accent_foreground
+            } else {
+                theme.colors.sidebar_foreground
+            };
+            let content_x = rect.min.x + indent(state.depth);
+
             // Icon
-            if let Some(icon) = icon {
+            if let Some(icon) = &node.icon {
                 ui.painter().text(
-                    Pos2::new(rect.min.x + 12.0, rect.center().y),
+                    Pos2::new(content_x + 12.0, rect.center().y),
                     egui::Align2::LEFT_CENTER,
                     icon,
                     egui::FontId::proportional(16.0),
-                    if active {
-                        theme.colors.sidebar_accent_foreground
-                    } else {
-                        theme.colors.sidebar_foreground
-                    },
+                    text_color,
                 );
             }
 
-            // Label (only when expanded)
-            if !is_collapsed {
-                let text_x = if icon.is_some() { rect.min.x + 36.0 } else { rect.min.x + 12.0 };
-                ui.painter().text(
-                    Pos2::new(text_x, rect.center().y),
-                    egui::Align2::LEFT_CENTER,
-                    label,
-                    egui::FontId::proportional(theme.typography.small().size),
-                    if active {
-                        theme.colors.sidebar_accent_foreground
-                    } else {
-                        theme.colors.sidebar_foreground
-                    },
-                );
+            if state.is_collapsed {
+                // Only the icon is visible, so mark unread counts with a dot
+                if node.badge.is_some() {
+                    ui.painter().circle_filled(Pos2::new(content_x + 26.0, rect.min.y + 9.0), 3.0, theme.colors.primary);
+                }
+                return response;
+            }
 
-                // Three dots on the right (show on hover or when popup open)
-                if hovered || popup_open {
-                    let dots_rect = egui::Rect::from_min_size(
-                        Pos2::new(rect.max.x - 28.0, rect.min.y),
-                        Vec2::new(24.0, height),
-                    );
+            // Label (only when expanded)
+            let text_x = if node.icon.is_some() { content_x + 36.0 } else { content_x + 12.0 };
+            ui.painter().text(
+                Pos2::new(text_x, rect.center().y),
+                egui::Align2::LEFT_CENTER,
+                &node.label,
+                egui::FontId::proportional(font_size),
+                text_color,
+            );
 
-                    // Draw three dots vertically
-                    let dot_x = dots_rect.center().x;
-                    let dot_y = dots_rect.center().y;
-                    let dot_spacing = 4.0;
-                    let dot_radius = 1.5;
-                    let dot_color = theme.colors.sidebar_foreground.linear_multiply(0.7);
-
-                    ui.painter().circle_filled(Pos2::new(dot_x, dot_y - dot_spacing), dot_radius, dot_color);
-                    ui.painter().circle_filled(Pos2::new(dot_x, dot_y), dot_radius, dot_color);
-                    ui.painter().circle_filled(Pos2::new(dot_x, dot_y + dot_spacing), dot_radius, dot_color);
-
-                    // Make dots clickable
-                    let dots_response = ui.interact(dots_rect, popup_id.with("dots"), Sense::click());
-                    if dots_response.clicked() {
-                        ui.memory_mut(|mem| mem.toggle_popup(popup_id));
-                    }
+            // Trailing controls: chevron for sections, "..." menu on hover
+            let mut trailing_x = rect.max.x;
+            if let Some(expanded) = state.expanded {
+                draw_chevron(ui.painter(), Pos2::new(rect.max.x - 16.0, rect.center().y), expanded, theme.colors.sidebar_foreground.linear_multiply(0.5));
+                trailing_x -= 28.0;
+            }
 
-                    // Show popup menu
-                    egui::popup_below_widget(ui, popup_id, &dots_response, egui::PopupCloseBehavior::CloseOnClickOutside, |ui| {
-                        ui.set_min_width(160.0);
-                        egui::Frame::NONE
-                            .fill(theme.colors.popover)
-                            .stroke(egui::Stroke::new(1.0, theme.colors.border))
-                            .corner_radius(theme.radii.md)
-                            .shadow(theme.shadows.md)
-                            .inner_margin(4.0)
-                            .show(ui, |ui| {
-                                for menu_item in menu_items {
-                                    let item_response = ui.allocate_response(
-                                        Vec2::new(ui.available_width(), 32.0),
-                                        Sense::click(),
-                                    );
-
-                                    if ui.is_rect_visible(item_response.rect) {
-                                        if item_response.hovered() {
-                                            ui.painter().rect_filled(
-                                                item_response.rect,
-                                                theme.radii.sm,
-                                                theme.colors.accent,
-                                            );
-                                        }
-
-                                        let text_color = if item_response.hovered() {
-                                            theme.colors.accent_foreground
-                                        } else {
-                                            theme.colors.popover_foreground
-                                        };
-
-                                        ui.painter().text(
-                                            Pos2::new(item_response.rect.min.x + 12.0, item_response.rect.center().y),
-                                            egui::Align2::LEFT_CENTER,
-                                            menu_item,
-                                            egui::FontId::proportional(theme.typography.small().size),
-                                            text_color,
-                                        );
-                                    }
-
-                                    if item_response.clicked() {
-                                        ui.memory_mut(|mem| mem.close_popup(popup_id));
-                                    }
-                                }
-                            });
-                    });
-                }
+            let show_menu = !node.actions.is_empty() && (hovered || popup_open);
+            if show_menu {
+                let dots_rect = egui::Rect::from_min_size(
+                    Pos2::new(trailing_x - 28.0, rect.min.y),
+                    Vec2::new(24.0, height),
+                );
+                picked_action = show_action_menu(ui, theme, &node.actions, dots_rect, popup_id);
+            } else if let Some(count) = node.badge {
+                draw_count_badge(ui, theme, count, Pos2::new(trailing_x - 8.0, rect.center().y));
             }
         }
 
         response
-    }).inner
-}
-
-/// Draw a collapsible menu item with chevron
-fn draw_collapsible_item(
-    ui: &mut Ui,
-    theme: &ShadcnTheme,
-    label: &str,
-    icon: Option<&str>,
-    is_expanded: bool,
-    is_collapsed: bool,
-) -> egui::Response {
-    let height = 40.0;
-    let width = ui.available_width() - 16.0;
-
-    ui.horizontal(|ui| {
-        ui.add_space(8.0);
+    }).inner;
 
-        let (rect, response) = ui.allocate_exact_size(
-            Vec2::new(width, height),
-            Sense::click(),
-        );
+    (response, picked_action)
+}
 
-        if ui.is_rect_visible(rect) {
-            let hovered = response.hovered();
+/// Draw the "..." button and its popup menu, returning the picked action
+fn show_action_menu(ui: &mut Ui, theme: &ShadcnTheme, actions: &[String], dots_rect: egui::Rect, popup_id: Id) -> Option<usize> {
+    // Draw three dots vertically
+    let dot_x = dots_rect.center().x;
+    let dot_y = dots_rect.center().y;
+    let dot_spacing = 4.0;
+    let dot_radius = 1.5;
+    let dot_color = theme.colors.sidebar_foreground.linear_multiply(0.7);
+
+    ui.painter().circle_filled(Pos2::new(dot_x, dot_y - dot_spacing), dot_radius, dot_color);
+    ui.painter().circle_filled(Pos2::new(dot_x, dot_y), dot_radius, dot_color);
+    ui.painter().circle_filled(Pos2::new(dot_x, dot_y + dot_spacing), dot_radius, dot_color);
+
+    // Make dots clickable
+    let dots_response = ui.interact(dots_rect, popup_id.with("dots"), Sense::click());
+    if dots_response.clicked() {
+        ui.memory_mut(|mem| mem.toggle_popup(popup_id));
+    }
 
-            // Background on hover
-            if hovered {
-                ui.painter().rect_filled(rect, theme.radii.md, theme.colors.sidebar_accent.linear_multiply(0.5));
-            }
+    // Show popup menu
+    let mut picked = None;
+    egui::popup_below_widget(ui, popup_id, &dots_response, egui::PopupCloseBehavior::CloseOnClickOutside, |ui| {
+        ui.set_min_width(160.0);
+        egui::Frame::NONE
+            .fill(theme.colors.popover)
+            .stroke(egui::Stroke::new(1.0, theme.colors.border))
+            .corner_radius(theme.radii.md)
+            .shadow(theme.shadows.md)
+            .inner_margin(4.0)
+            .show(ui, |ui| {
+                for (index, action) in actions.iter().enumerate() {
+                    let item_response = ui.allocate_response(
+                        Vec2::new(ui.available_width(), 32.0),
+                        Sense::click(),
+                    );
 
-            // Icon
-            if let Some(icon) = icon {
-                ui.painter().text(
-                    Pos2::new(rect.min.x + 12.0, rect.center().y),
-                    egui::Align2::LEFT_CENTER,
-                    icon,
-                    egui::FontId::proportional(16.0),
-                    theme.colors.sidebar_foreground,
-                );
-            }
+                    if ui.is_rect_visible(item_response.rect) {
+                        if item_response.hovered() {
+                            ui.painter().rect_filled(
+                                item_response.rect,
+                                theme.radii.sm,
+                                theme.colors.accent,
+                            );
+                        }
 
-            // Label (only when expanded)
-            if !is_collapsed {
-                let text_x = if icon.is_some() { rect.min.x + 36.0 } else { rect.min.x + 12.0 };
-                ui.painter().text(
-                    Pos2::new(text_x, rect.center().y),
-                    egui::Align2::LEFT_CENTER,
-                    label,
-                    egui::FontId::proportional(theme.typography.small().size),
-                    theme.colors.sidebar_foreground,
-                );
+                        let text_color = if item_response.hovered() {
+                            theme.colors.accent_foreground
+                        } else {
+                            theme.colors.popover_foreground
+                        };
+
+                        ui.painter().text(
+                            Pos2::new(item_response.rect.min.x + 12.0, item_response.rect.center().y),
+                            egui::Align2::LEFT_CENTER,
+                            action,
+                            egui::FontId::proportional(theme.typography.small().size),
+                            text_color,
+                        );
+                    }
 
-                // Chevron on the right - draw a proper chevron shape
-                let chevron_x = rect.max.x - 16.0;
-                let chevron_y = rect.center().y;
-                let chevron_size = 4.0;
-                let chevron_color = theme.colors.sidebar_foreground.linear_multiply(0.5);
-                let stroke = egui::Stroke::new(1.5, chevron_color);
-
-                if is_expanded {
-                    // Down chevron (v shape)
-                    ui.painter().line_segment(
-                        [Pos2::new(chevron_x - chevron_size, chevron_y - chevron_size * 0.5),
-                         Pos2::new(chevron_x, chevron_y + chevron_size * 0.5)],
-                        stroke,
-                    );
-                    ui.painter().line_segment(
-                        [Pos2::new(chevron_x, chevron_y + chevron_size * 0.5),
-                         Pos2::new(chevron_x + chevron_size, chevron_y - chevron_size * 0.5)],
-                        stroke,
-                    );
-                } else {
-                    // Right chevron (> shape)
-                    ui.painter().line_segment(
-                        [Pos2::new(chevron_x - chevron_size * 0.5, chevron_y - chevron_size),
-                         Pos2::new(chevron_x + chevron_size * 0.5, chevron_y)],
-                        stroke,
-                    );
-                    ui.painter().line_segment(
-                        [Pos2::new(chevron_x + chevron_size * 0.5, chevron_y),
-                         Pos2::new(chevron_x - chevron_size * 0.5, chevron_y + chevron_size)],
-                        stroke,
-                    );
+                    if item_response.clicked() {
+                        picked = Some(index);
+                        ui.memory_mut(|mem| mem.close_popup(popup_id));
+                    }
                 }
-            }
-        }
-
-        response
-    }).inner
+            });
+    });
+    picked
 }
 
-/// Draw an indented sub-item (child of collapsible)
-fn draw_sub_item(
-    ui: &mut Ui,
-    theme: &ShadcnTheme,
-    label: &str,
-    active: bool,
-) -> egui::Response {
-    let height = 32.0;
-    let width = ui.available_width() - 16.0;
+/// Draw a right-aligned count pill ending at `right_center`
+fn draw_count_badge(ui: &Ui, theme: &ShadcnTheme, count: u32, right_center: Pos2) {
+    let text = if count > 99 { "99+".to_string() } else { count.to_string() };
+    let font = egui::FontId::proportional(theme.typography.small().size - 2.0);
+    let galley = ui.painter().layout_no_wrap(text, font, theme.colors.primary_foreground);
+    let size = Vec2::new((galley.size().x + 10.0).max(18.0), 18.0);
+    let rect = egui::Rect::from_min_size(Pos2::new(right_center.x - size.x, right_center.y - size.y / 2.0), size);
+    ui.painter().rect_filled(rect, size.y / 2.0, theme.colors.primary);
+    ui.painter().galley(rect.center() - galley.size() / 2.0, galley, theme.colors.primary_foreground);
+}
 
-    ui.horizontal(|ui| {
-        ui.add_space(8.0);
+fn draw_chevron(painter: &egui::Painter, center: Pos2, expanded: bool, color: egui::Color32) {
+    let (chevron_x, chevron_y) = (center.x, center.y);
+    let chevron_size = 4.0;
+    let stroke = egui::Stroke::new(1.5, color);
 
-        let (rect, response) = ui.allocate_exact_size(
-            Vec2::new(width, height),
-            Sense::click(),
+    if expanded {
+        // Down chevron (v shape)
+        painter.line_segment(
+            [Pos2::new(chevron_x - chevron_size, chevron_y - chevron_size * 0.5),
+             Pos2::new(chevron_x, chevron_y + chevron_size * 0.5)],
+            stroke,
+        );
+        painter.line_segment(
+            [Pos2::new(chevron_x, chevron_y + chevron_size * 0.5),
+             Pos2::new(chevron_x + chevron_size, chevron_y - chevron_size * 0.5)],
+            stroke,
+        );
+    } else {
+        // Right chevron (> shape)
+        painter.line_segment(
+            [Pos2::new(chevron_x - chevron_size * 0.5, chevron_y - chevron_size),
+             Pos2::new(chevron_x + chevron_size * 0.5, chevron_y)],
+            stroke,
         );
+        painter.line_segment(
+            [Pos2::new(chevron_x + chevron_size * 0.5, chevron_y),
+             Pos2::new(chevron_x - chevron_size * 0.5, chevron_y + chevron_size)],
+            stroke,
+        );
+    }
+}
 
-        if ui.is_rect_visible(rect) {
-            let hovered = response.hovered();
+fn draw_toggle_button(ui: &mut Ui, theme: &ShadcnTheme, _is_collapsed: bool) -> egui::Response {
+    let size = Vec2::splat(32.0);
+    let (rect, response) = ui.allocate_exact_size(size, Sense::click());
 
-            // Background - lighter styling like shadcn
-            let bg_color = if active {
-                theme.colors.sidebar_accent
-            } else if hovered {
-                theme.colors.foreground.linear_multiply(0.08)
-            } else {
-                egui::Color32::TRANSPARENT
-            };
+    if ui.is_rect_visible(rect) {
+        let hovered = response.hovered();
 
-            ui.painter().rect_filled(rect, theme.radii.sm, bg_color);
+        if hovered {
+            ui.painter().rect_filled(rect, theme.radii.sm, theme.colors.sidebar_accent);
+        }
 
-            // Subtle left border indicator for active sub-item
-            if active {
-                let indicator_rect = egui::Rect::from_min_size(
-                    rect.min,
-                    egui::vec2(3.0, rect.height()),
-                );
-                ui.painter().rect_filled(indicator_rect, 1.5, theme.colors.primary);
-            }
+        // Draw panel icon - shadcn style (rectangle with sidebar indicator on left)
+        let icon_size = 16.0;
+        let icon_rect = egui::Rect::from_center_size(rect.center(), Vec2::splat(icon_size));
+        let stroke = egui::Stroke::new(1.5, theme.colors.sidebar_foreground);
 
-            // Indented label (extra left padding for sub-items)
-            ui.painter().text(
-                Pos2::new(rect.min.x + 44.0, rect.center().y),
-                egui::Align2::LEFT_CENTER,
-                label,
-                egui::FontId::proportional(theme.typography.small().size - 1.0),
-                if active {
-                    theme.colors.sidebar_accent_foreground
-                } else {
-                    theme.colors.sidebar_foreground
-                },
-            );
-        }
+        // Outer rectangle (panel) with rounded corners
+        ui.painter().rect_stroke(icon_rect, 2.0, stroke, egui::StrokeKind::Inside);
 
-        response
-    }).inner
+        // Vertical line on left side (sidebar indicator) - slightly inset
+        let line_x = icon_rect.min.x + icon_size * 0.33;
+        ui.painter().line_segment(
+            [
+                Pos2::new(line_x, icon_rect.min.y + 3.0),
+                Pos2::new(line_x, icon_rect.max.y - 3.0),
+            ],
+            stroke,
+        );
+    }
+
+    response
 }
 
-/// Builder for sidebar sections
+/// Builder for sidebar sections (index-based API)
 pub struct SidebarBuilder {
-    items: Vec<SidebarItem>,
+    items: Vec<SidebarNode<usize>>,
+    next_index: usize,
 }
 
 impl SidebarBuilder {
     /// Add a navigation item with icon
     pub fn item(&mut self, label: impl Into<String>, icon: impl Into<String>, active: bool) -> &mut Self {
-        self.items.push(SidebarItem::Link {
-            label: label.into(),
-            icon: Some(icon.into()),
-            active,
-        });
+        let node = SidebarNode::item(self.next_index, label).icon(icon).active(active);
+        self.items.push(node);
+        self.next_index += 1;
         self
     }
 
     /// Add a sub-item (no icon, used inside collapsible sections)
     pub fn sub_item(&mut self, label: impl Into<String>, active: bool) -> &mut Self {
-        self.items.push(SidebarItem::Link {
-            label: label.into(),
-            icon: None,
-            active,
-        });
+        self.items.push(SidebarNode::item(self.next_index, label).active(active));
+        self.next_index += 1;
         self
     }
 }
 
 /// Response from showing a sidebar
-pub struct SidebarResponse {
-    /// Index of clicked navigation item, if any
-    pub clicked_item: Option<usize>,
+pub struct SidebarResponse<K = usize> {
+    /// ID of the navigation item clicked this frame, if any
+    pub clicked_item: Option<K>,
     /// Whether the toggle button was clicked
     pub toggle_clicked: bool,
+    /// Everything that happened this frame, in order
+    pub events: Vec<SidebarEvent<K>>,
 }
 
 #[cfg(test)]
@@ -832,4 +903,136 @@ mod tests {
         assert_eq!(sidebar.width, 280.0);
         assert_eq!(sidebar.items.len(), 3);
     }
+
+    #[test]
+    fn test_index_api_numbers_items_in_order() {
+        let mut open = true;
+        let sidebar = Sidebar::new("test", &mut open)
+            .item("Home", "H", false)
+            .menu("Playground", "P", |s| {
+                s.sub_item("History", false);
+                s.sub_item("Starred", true);
+            })
+            .project_item("Design", "D", false, vec!["View", "Delete"]);
+
+        let ids: Vec<Vec<usize>> = sidebar
+            .items
+            .iter()
+            .map(|entry| match entry {
+                SidebarEntry::Node(node) => std::iter::once(node.id).chain(node.children.iter().map(|c| c.id)).collect(),
+                SidebarEntry::Separator => Vec::new(),
+            })
+            .collect();
+        assert_eq!(ids, vec![vec![0], vec![usize::MAX - 1, 1, 2], vec![3]]);
+
+        let SidebarEntry::Node(project) = &sidebar.items[2] else { panic!("expected a node") };
+        assert_eq!(project.actions, vec!["View", "Delete"]);
+    }
+
+    #[test]
+    fn test_node_builder() {
+        let node = SidebarNode::item("inbox", "Inbox").icon("I").badge(3).action("Mark read");
+        assert_eq!(node.badge, Some(3));
+        assert_eq!(SidebarNode::item("x", "X").badge(0).badge, None);
+
+        let tree = SidebarNode::item("projects", "Projects")
+            .child(SidebarNode::item("web", "Website").child(SidebarNode::item("assets", "Assets")))
+            .expanded(true);
+        assert_eq!(tree.children[0].children[0].id(), &"assets");
+        assert!(tree.expanded);
+    }
+
+    #[test]
+    fn test_expansion_is_persisted() {
+        let ctx = egui::Context::default();
+        let node_id = Id::new("sidebar").with(("node", "projects"));
+
+        assert!(!is_expanded(&ctx, node_id, false));
+        assert!(is_expanded(&ctx, node_id, true));
+
+        set_expanded(&ctx, node_id, true);
+        assert!(is_expanded(&ctx, node_id, false));
+        assert!(ctx.data_mut(|d| d.get_persisted::<bool>(node_id.with("expanded"))) == Some(true));
+    }
+
+    #[test]
+    fn test_clicks_report_typed_events() {
+        use crate::test_util::{click_events as click, context_with_motion, run_frame_with_events};
+        use crate::theme::MotionPreference;
+        use egui::Event;
+
+        let ctx = context_with_motion(MotionPreference::Full);
+        let mut open = true;
+        let mut selected = None;
+        let mut frame = |time: f64, events: Vec<Event>, selected: &mut Option<&'static str>| {
+            let mut result = None;
+            run_frame_with_events(&ctx, time, events, |ui| {
+                let response = Sidebar::tree("nav", &mut open, selected)
+                    .collapsible(false)
+                    .node(
+                        SidebarNode::item("projects", "Projects")
+                            .child(SidebarNode::item("web", "Website").badge(2)),
+                    )
+                    .show(ui);
+                result = Some((response.clicked_item, response.events));
+            });
+            result.unwrap()
+        };
+
+        frame(0.0, Vec::new(), &mut selected);
+
+        // First row is the section header: clicking toggles it
+        let (clicked, events) = frame(0.1, click(Pos2::new(100.0, 34.0)), &mut selected);
+        assert_eq!(clicked, None);
+        assert_eq!(events, vec![SidebarEvent::Toggled { node: "projects", expanded: true }]);
+
+        // The child row now sits below it and selects
+        let (clicked, events) = {
+            frame(0.2, Vec::new(), &mut selected);
+            frame(0.3, click(Pos2::new(100.0, 95.0)), &mut selected)
+        };
+        assert_eq!(clicked, Some("web"));
+        assert_eq!(events, vec![SidebarEvent::Selected("web")]);
+        assert_eq!(selected, Some("web"));
+    }
+
+    #[test]
+    fn test_keyboard_reorder_of_children() {
+        use crate::test_util::{context_with_motion, key_press, run_frame_with_events};
+        use crate::theme::MotionPreference;
+        use egui::{Event, Key, Modifiers};
+
+        let key = |key| key_press(key, Modifiers::NONE);
+        let ctx = context_with_motion(MotionPreference::Full);
+        let mut open = true;
+        let mut selected = None;
+        let mut frame = |time: f64, events: Vec<Event>| {
+            let mut result = Vec::new();
+            run_frame_with_events(&ctx, time, events, |ui| {
+                result = Sidebar::tree("nav", &mut open, &mut selected)
+                    .collapsible(false)
+                    .node(
+                        SidebarNode::group("projects", "Projects")
+                            .reorderable(true)
+                            .children(["alpha", "beta", "gamma"].map(|p| SidebarNode::item(p, p))),
+                    )
+                    .show(ui)
+                    .events;
+            });
+            result
+        };
+
+        frame(0.0, Vec::new());
+        // Tab focuses the first row, Space picks it up without selecting it
+        frame(0.1, vec![key(Key::Tab)]);
+        assert_eq!(frame(0.2, vec![key(Key::Space)]), Vec::new());
+        assert_eq!(frame(0.3, vec![key(Key::ArrowDown), key(Key::ArrowDown)]), Vec::new());
+        assert_eq!(
+            frame(0.4, vec![key(Key::Enter)]),
+            vec![SidebarEvent::Reorder { parent: "projects", from: 0, to: 2 }]
+        );
+
+        // Once dropped, Enter activates the row again
+        assert_eq!(frame(0.5, vec![key(Key::Enter)]), vec![SidebarEvent::Selected("alpha")]);
+    }
 }
@@ -57,15 +57,20 @@ impl Widget for Skeleton {
             };
 
             // Animated pulse effect (shadcn/ui style)
-            // Get time for animation
-            let time = ui.input(|i| i.time);
-
-            // Pulse animation: 2 second cycle (1s fade in, 1s fade out)
-            let cycle = (time % 2.0) as f32;
-            let pulse = if cycle < 1.0 {
-                cycle // 0.0 -> 1.0
+            // Without looping motion the skeleton rests at the middle of the pulse
+            let pulsing = theme.motion.allows_loops();
+            let pulse = if pulsing {
+                let time = ui.input(|i| i.time);
+
+                // Pulse animation: 2 second cycle (1s fade in, 1s fade out)
+                let cycle = (time % 2.0) as f32;
+                if cycle < 1.0 {
+                    cycle // 0.0 -> 1.0
+                } else {
+                    2.0 - cycle // 1.0 -> 0.0
+                }
             } else {
-                2.0 - cycle // 1.0 -> 0.0
+                0.5
             };
 
             // Detect light vs dark mode for better contrast
@@ -96,7 +101,9 @@ impl Widget for Skeleton {
             );
 
             // Request repaint to continue animation
-            ui.ctx().request_repaint();
+            if pulsing {
+                ui.ctx().request_repaint();
+            }
         }
 
         response
@@ -127,3 +134,20 @@ fn blend_colors(color1: egui::Color32, color2: egui::Color32, t: f32) -> egui::C
     let b = color1.b() as f32 * (1.0 - t) + color2.b() as f32 * t;
     egui::Color32::from_rgb(r as u8, g as u8, b as u8)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::looping_frames;
+    use crate::theme::MotionPreference;
+
+    #[test]
+    fn test_skeleton_motion() {
+        let skeleton = |ui: &mut egui::Ui| {
+            ui.add(Skeleton::new(Vec2::new(200.0, 20.0)));
+        };
+        assert_eq!(looping_frames(MotionPreference::Full, skeleton), (true, true));
+        assert_eq!(looping_frames(MotionPreference::Reduced, skeleton), (false, false));
+        assert_eq!(looping_frames(MotionPreference::None, skeleton), (false, false));
+    }
+}
@@ -0,0 +1,243 @@
+//! SortableList component
+//!
+//! A vertical list whose rows can be reordered by dragging their grip handle
+//! or with the keyboard (focus a handle, Space to pick up, arrows to move,
+//! Space to drop). Built on [`crate::dnd`].
+
+use egui::{Id, Response, Sense, Ui, Vec2, Pos2};
+use crate::dnd::{DragAxis, DragList, Reorder};
+use crate::theme::ShadcnTheme;
+
+/// Reorderable list of items
+///
+/// The list moves the items in the bound `Vec` itself and reports the move.
+///
+/// ## Example
+/// ```rust,ignore
+/// let mut columns = vec!["Home", "Notifications", "Messages"];
+///
+/// let response = SortableList::new("deck_columns", &mut columns).show(ui, |ui, column| {
+///     ui.label(*column);
+/// });
+/// if let Some(reorder) = response.reorder {
+///     save_column_order(reorder);
+/// }
+/// ```
+pub struct SortableList<'a, T> {
+    id: Id,
+    items: &'a mut Vec<T>,
+    row_height: f32,
+}
+
+/// Response from [`SortableList::show`]
+pub struct SortableListResponse {
+    /// Response covering the whole list
+    pub response: Response,
+    /// The move made this frame, already applied to the items
+    pub reorder: Option<Reorder>,
+}
+
+impl<'a, T> SortableList<'a, T> {
+    /// Create a sortable list over `items`
+    pub fn new(id: impl std::hash::Hash, items: &'a mut Vec<T>) -> Self {
+        Self {
+            id: Id::new(id),
+            items,
+            row_height: 40.0,
+        }
+    }
+
+    /// Set the minimum row height (default: 40px)
+    pub fn row_height(mut self, height: f32) -> Self {
+        self.row_height = height;
+        self
+    }
+
+    /// Show the list, drawing each row's content with `row`
+    pub fn show(self, ui: &mut Ui, mut row: impl FnMut(&mut Ui, &mut T)) -> SortableListResponse {
+        let theme = ui.ctx().data(|d| {
+            d.get_temp::<ShadcnTheme>(Id::new("shadcn_theme"))
+                .unwrap_or_else(ShadcnTheme::light)
+        });
+
+        let mut list = DragList::new(ui.ctx(), self.id, DragAxis::Vertical);
+        let dragged = list.dragged();
+
+        let response = ui
+            .vertical(|ui| {
+                ui.spacing_mut().item_spacing.y = 4.0;
+                for (index, item) in self.items.iter_mut().enumerate() {
+                    list.keys(ui, index, handle_id(self.id, index));
+                    let frame = egui::Frame::NONE
+                        .fill(theme.colors.card)
+                        .stroke(egui::Stroke::new(1.0, theme.colors.border))
+                        .corner_radius(theme.radii.md)
+                        .inner_margin(egui::Margin::symmetric(8, 4));
+                    let inner = frame.show(ui, |ui| {
+                        // The row left behind while dragging is faded out
+                        if dragged == Some(index) {
+                            ui.multiply_opacity(0.4);
+                        }
+                        ui.set_min_height(self.row_height - 8.0);
+                        ui.horizontal_centered(|ui| {
+                            let handle = draw_handle(ui, &theme, handle_id(self.id, index));
+                            row(ui, item);
+                            handle
+                        })
+                        .inner
+                    });
+                    list.item(ui, index, &inner.inner, inner.response.rect);
+                }
+            })
+            .response;
+
+        let reorder = list.finish(ui);
+        if let Some(reorder) = reorder {
+            reorder.apply(self.items);
+            // Keyboard focus follows the moved item
+            let had_focus = ui.memory(|m| m.has_focus(handle_id(self.id, reorder.from)));
+            if had_focus {
+                ui.memory_mut(|m| m.request_focus(handle_id(self.id, reorder.to)));
+            }
+        }
+
+        SortableListResponse { response, reorder }
+    }
+}
+
+fn handle_id(list: Id, index: usize) -> Id {
+    list.with(("handle", index))
+}
+
+/// Draw the six-dot grip that starts a drag
+fn draw_handle(ui: &mut Ui, theme: &ShadcnTheme, id: Id) -> Response {
+    let (rect, _) = ui.allocate_exact_size(Vec2::new(16.0, 24.0), Sense::hover());
+    let response = ui.interact(rect, id, Sense::click_and_drag());
+
+    if ui.is_rect_visible(rect) {
+        let color = if response.hovered() || response.dragged() {
+            theme.colors.foreground
+        } else {
+            theme.colors.muted_foreground
+        };
+        for row in 0..3 {
+            for column in 0..2 {
+                let center = Pos2::new(
+                    rect.center().x + (column as f32 - 0.5) * 5.0,
+                    rect.center().y + (row as f32 - 1.0) * 5.0,
+                );
+                ui.painter().circle_filled(center, 1.3, color);
+            }
+        }
+        theme.draw_focus_ring(ui.painter(), rect, theme.radii.sm, response.has_focus());
+    }
+
+    if response.hovered() && !response.dragged() {
+        ui.ctx().set_cursor_icon(egui::CursorIcon::Grab);
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{context_with_motion, key_press, pointer_press, pointer_release, run_frame_with_events};
+    use crate::theme::MotionPreference;
+    use egui::{Event, Key, Modifiers};
+
+    fn key(key: Key) -> Event {
+        key_press(key, Modifiers::NONE)
+    }
+
+    #[test]
+    fn test_keyboard_reorder() {
+        let ctx = context_with_motion(MotionPreference::Full);
+        let mut items = vec!["a", "b", "c"];
+        let frame = |time: f64, events: Vec<Event>, items: &mut Vec<&str>| {
+            let mut reorder = None;
+            run_frame_with_events(&ctx, time, events, |ui| {
+                reorder = SortableList::new("list", items).show(ui, |ui, item| {
+                    ui.label(*item);
+                }).reorder;
+            });
+            reorder
+        };
+
+        frame(0.0, Vec::new(), &mut items);
+        ctx.memory_mut(|m| m.request_focus(handle_id(Id::new("list"), 0)));
+        frame(0.1, Vec::new(), &mut items);
+
+        // Pick up, move down twice, drop
+        assert_eq!(frame(0.2, vec![key(Key::Space)], &mut items), None);
+        assert_eq!(frame(0.3, vec![key(Key::ArrowDown), key(Key::ArrowDown)], &mut items), None);
+        assert_eq!(items, vec!["a", "b", "c"]);
+        assert_eq!(frame(0.4, vec![key(Key::Space)], &mut items), Some(Reorder { from: 0, to: 2 }));
+        assert_eq!(items, vec!["b", "c", "a"]);
+
+        // Focus followed the item, so Escape cancels a second move
+        frame(0.5, Vec::new(), &mut items);
+        assert!(ctx.memory(|m| m.has_focus(handle_id(Id::new("list"), 2))));
+        frame(0.6, vec![key(Key::Space)], &mut items);
+        frame(0.7, vec![key(Key::ArrowUp)], &mut items);
+        assert_eq!(frame(0.8, vec![key(Key::Escape)], &mut items), None);
+        assert_eq!(items, vec!["b", "c", "a"]);
+    }
+
+    #[test]
+    fn test_losing_focus_cancels_keyboard_move() {
+        let ctx = context_with_motion(MotionPreference::Full);
+        let mut items = vec!["a", "b", "c"];
+        let frame = |time: f64, events: Vec<Event>, items: &mut Vec<&str>| {
+            run_frame_with_events(&ctx, time, events, |ui| {
+                SortableList::new("list", items).show(ui, |ui, item| {
+                    ui.label(*item);
+                });
+            });
+        };
+        let dragged = || DragList::new(&ctx, Id::new("list"), DragAxis::Vertical).dragged();
+
+        frame(0.0, Vec::new(), &mut items);
+        ctx.memory_mut(|m| m.request_focus(handle_id(Id::new("list"), 0)));
+        frame(0.1, Vec::new(), &mut items);
+        frame(0.2, vec![key(Key::Space)], &mut items);
+        assert_eq!(dragged(), Some(0));
+
+        // Tab moves focus to the next handle and drops the carry
+        frame(0.3, vec![key(Key::Tab)], &mut items);
+        frame(0.4, Vec::new(), &mut items);
+        assert_eq!(dragged(), None);
+        assert!(ctx.memory(|m| m.has_focus(handle_id(Id::new("list"), 1))));
+
+        frame(0.5, vec![key(Key::Space)], &mut items);
+        assert_eq!(dragged(), Some(1));
+    }
+
+    #[test]
+    fn test_pointer_reorder() {
+        let ctx = context_with_motion(MotionPreference::Full);
+        let mut items = vec!["a", "b", "c"];
+        let mut rects = Vec::new();
+        let frame = |time: f64, events: Vec<Event>, items: &mut Vec<&str>, rects: &mut Vec<egui::Rect>| {
+            let mut reorder = None;
+            run_frame_with_events(&ctx, time, events, |ui| {
+                rects.clear();
+                reorder = SortableList::new("list", items).show(ui, |ui, item| {
+                    rects.push(ui.label(*item).rect);
+                }).reorder;
+            });
+            reorder
+        };
+
+        frame(0.0, Vec::new(), &mut items, &mut rects);
+        // The handle sits just left of each label
+        let grab = rects[0].left_center() - Vec2::new(12.0, 0.0);
+        let target = rects[2].center() + Vec2::new(0.0, 10.0);
+
+        frame(0.1, pointer_press(grab), &mut items, &mut rects);
+        frame(0.2, vec![Event::PointerMoved(grab + Vec2::new(0.0, 20.0))], &mut items, &mut rects);
+        frame(0.3, vec![Event::PointerMoved(target)], &mut items, &mut rects);
+        let reorder = frame(0.4, vec![pointer_release(target)], &mut items, &mut rects);
+        assert_eq!(reorder, Some(Reorder { from: 0, to: 2 }));
+        assert_eq!(items, vec!["b", "c", "a"]);
+    }
+}
@@ -107,10 +107,15 @@ impl Widget for Spinner {
         );
 
         if ui.is_rect_visible(rect) {
-            let time = ui.input(|i| i.time);
-
             // Rotation speed: full rotation every 0.8 seconds
-            let angle = (time * std::f64::consts::TAU * 1.25) as f32;
+            // Without looping motion the arc rests at the top of the ring
+            let spinning = theme.motion.allows_loops();
+            let angle = if spinning {
+                let time = ui.input(|i| i.time);
+                (time * std::f64::consts::TAU * 1.25) as f32
+            } else {
+                -std::f32::consts::FRAC_PI_2
+            };
 
             let center = rect.center();
             let radius = size / 2.0 - stroke_width;
@@ -141,7 +146,9 @@ impl Widget for Spinner {
             }
 
             // Request continuous repaint for animation
-            ui.ctx().request_repaint();
+            if spinning {
+                ui.ctx().request_repaint();
+            }
         }
 
         response
@@ -151,6 +158,8 @@ impl Widget for Spinner {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::looping_frames;
+    use crate::theme::MotionPreference;
 
     #[test]
     fn test_spinner_creation() {
@@ -168,6 +177,16 @@ mod tests {
         assert_eq!(SpinnerSize::Large.pixels(), 32.0);
     }
 
+    #[test]
+    fn test_spinner_motion() {
+        let spinner = |ui: &mut egui::Ui| {
+            ui.add(Spinner::new());
+        };
+        assert_eq!(looping_frames(MotionPreference::Full, spinner), (true, true));
+        assert_eq!(looping_frames(MotionPreference::Reduced, spinner), (false, false));
+        assert_eq!(looping_frames(MotionPreference::None, spinner), (false, false));
+    }
+
     #[test]
     fn test_spinner_stroke() {
         assert_eq!(SpinnerSize::Small.stroke_width(), 2.0);
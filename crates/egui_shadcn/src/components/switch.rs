@@ -5,6 +5,7 @@
 //! Reference: <https://ui.shadcn.com/docs/components/switch>
 
 use egui::{Response, Sense, Ui, Widget};
+use crate::animation::{self, Spring};
 use crate::theme::ShadcnTheme;
 
 /// Switch/Toggle widget matching shadcn/ui design
@@ -133,14 +134,17 @@ impl<'a> Widget for Switch<'a> {
                 theme.draw_focus_ring(&painter, track_rect, visual_height / 2.0, true);
             }
 
-            // Calculate thumb position with animation (simplified - egui will smooth this)
+            // Spring the thumb between the two ends of the track
             let thumb_padding = 2.0;
             let thumb_travel = width - thumb_size - 2.0 * thumb_padding;
-            let thumb_x = if *self.checked {
-                track_rect.min.x + thumb_padding + thumb_travel
+            let position = if theme.motion.allows_movement() {
+                animation::spring_bool(ui.ctx(), response.id.with("thumb"), *self.checked, Spring::SNAPPY)
+            } else if *self.checked {
+                1.0
             } else {
-                track_rect.min.x + thumb_padding
+                0.0
             };
+            let thumb_x = track_rect.min.x + thumb_padding + thumb_travel * position.clamp(0.0, 1.0);
 
             let thumb_center = egui::pos2(
                 thumb_x + thumb_size / 2.0,
@@ -218,6 +222,8 @@ impl Switch<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::transition_frames;
+    use crate::theme::MotionPreference;
 
     #[test]
     fn test_switch_creation() {
@@ -232,4 +238,14 @@ mod tests {
         let switch = Switch::new(&mut checked).label("Test");
         assert_eq!(switch.label, Some("Test".to_string()));
     }
+
+    #[test]
+    fn test_switch_motion() {
+        let switch = |ui: &mut Ui, on: bool| {
+            ui.add(Switch::new(&mut { on }));
+        };
+        assert_eq!(transition_frames(MotionPreference::Full, switch), (true, true));
+        assert_eq!(transition_frames(MotionPreference::Reduced, switch), (false, false));
+        assert_eq!(transition_frames(MotionPreference::None, switch), (false, false));
+    }
 }
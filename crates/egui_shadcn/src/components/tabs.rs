@@ -4,11 +4,48 @@
 //!
 //! Reference: <https://ui.shadcn.com/docs/components/tabs>
 
-use egui::{Response, Ui};
+use std::hash::Hash;
+use std::sync::Arc;
+use egui::{EventFilter, Galley, Id, Key, Modifiers, Pos2, Rect, Response, Sense, Ui, Vec2};
+use crate::animation::{self, Spring};
+use crate::dnd::{DragAxis, DragList, Reorder};
+use crate::overlay::{OverlayKind, OverlayStack};
 use crate::theme::ShadcnTheme;
 
+/// Height of a tab trigger (Apple HIG touch target)
+const TAB_HEIGHT: f32 = 44.0;
+/// Horizontal padding inside a tab trigger
+const TAB_PADDING: f32 = 12.0;
+/// Width reserved for the close button of a closable tab
+const CLOSE_WIDTH: f32 = 20.0;
+/// Width of the scroll arrows and the "more" button
+const OVERFLOW_BUTTON_WIDTH: f32 = 28.0;
+
+/// Direction the tab triggers are laid out in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TabsOrientation {
+    /// Triggers in a row above the content (default)
+    #[default]
+    Horizontal,
+    /// Triggers in a column left of the content
+    Vertical,
+}
+
+/// What horizontal tabs offer when their triggers don't fit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TabsOverflow {
+    /// Arrow buttons on either side scroll the triggers (default)
+    #[default]
+    Scroll,
+    /// A "more" button lists every tab in a menu
+    Menu,
+}
+
 /// Tabs component for organizing content
 ///
+/// Only the selected tab's content is built each frame. The selection lives
+/// in egui memory unless it is bound with [`Tabs::selected`].
+///
 /// ## Example
 /// ```rust,ignore
 /// Tabs::new(ui, "settings-tabs")
@@ -20,110 +57,845 @@ use crate::theme::ShadcnTheme;
 ///     })
 ///     .show();
 /// ```
-pub struct Tabs<'a> {
+///
+/// Tabs can be dragged (or moved with Space and the arrow keys) to reorder
+/// them once [`Tabs::on_reorder`] is set; the selection follows its tab.
+/// With [`Tabs::on_close`] each tab gets a close button:
+///
+/// ```rust,ignore
+/// let mut closed = None;
+/// let mut tabs = Tabs::new(ui, "editor").selected(&mut self.open_file);
+/// for file in &self.files {
+///     tabs = tabs.tab(file.id, file.name.clone(), |ui| editor(ui, file));
+/// }
+/// tabs.on_close(|id| closed = Some(id)).show();
+/// if let Some(id) = closed {
+///     self.files.retain(|file| file.id != id);
+/// }
+/// ```
+pub struct Tabs<'a, T = &'a str> {
     ui: &'a mut Ui,
     id: &'a str,
-    tabs: Vec<(&'a str, &'a str, Box<dyn FnOnce(&mut Ui) + 'a>)>,
+    tabs: Vec<Tab<'a, T>>,
+    selected: Option<&'a mut T>,
+    on_reorder: Option<ReorderCallback<'a>>,
+    on_close: Option<CloseCallback<'a, T>>,
+    orientation: TabsOrientation,
+    overflow: TabsOverflow,
+    keep_state: bool,
 }
 
-impl<'a> Tabs<'a> {
+struct Tab<'a, T> {
+    value: T,
+    label: String,
+    content: Box<dyn FnOnce(&mut Ui) + 'a>,
+}
+
+type ReorderCallback<'a> = Box<dyn FnOnce(Reorder) + 'a>;
+type CloseCallback<'a, T> = Box<dyn FnOnce(T) + 'a>;
+
+/// Scroll position of the horizontal trigger strip, kept between frames
+#[derive(Clone, Copy, Default)]
+struct StripState {
+    overflowing: bool,
+    offset: f32,
+    max_offset: f32,
+    viewport: f32,
+    /// Offset requested by the arrow buttons, applied next frame
+    pending: Option<f32>,
+}
+
+/// What happened in the trigger strip this frame
+#[derive(Default)]
+struct StripOutput {
+    clicked: Option<usize>,
+    closed: Option<usize>,
+    reorder: Option<Reorder>,
+}
+
+impl<'a, T: Clone + PartialEq + Hash> Tabs<'a, T> {
     /// Create new tabs
     pub fn new(ui: &'a mut Ui, id: &'a str) -> Self {
         Self {
             ui,
             id,
             tabs: Vec::new(),
+            selected: None,
+            on_reorder: None,
+            on_close: None,
+            orientation: TabsOrientation::Horizontal,
+            overflow: TabsOverflow::Scroll,
+            keep_state: false,
         }
     }
 
+    /// Bind the selection to `selected`, the value of the active tab
+    ///
+    /// When no tab has that value the first tab is selected.
+    pub fn selected(mut self, selected: &'a mut T) -> Self {
+        self.selected = Some(selected);
+        self
+    }
+
+    /// Let the user reorder the tabs; `on_reorder` is told about each move so
+    /// the caller can reorder the tabs it adds next frame
+    pub fn on_reorder(mut self, on_reorder: impl FnOnce(Reorder) + 'a) -> Self {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    /// Give each tab a close button; `on_close` gets the closed tab's value
+    /// so the caller can drop it from the tabs it adds next frame
+    ///
+    /// Middle-clicking a tab or pressing Delete on a focused tab also closes
+    /// it. Closing the selected tab selects its neighbour.
+    pub fn on_close(mut self, on_close: impl FnOnce(T) + 'a) -> Self {
+        self.on_close = Some(Box::new(on_close));
+        self
+    }
+
+    /// Set the orientation (default: horizontal)
+    pub fn orientation(mut self, orientation: TabsOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Set how horizontal tabs handle triggers that don't fit (default: scroll arrows)
+    pub fn overflow(mut self, overflow: TabsOverflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Keep widget state (scroll offsets, text cursors, collapsed sections)
+    /// of tabs while they are hidden (default: false, revisited tabs start fresh)
+    pub fn keep_state(mut self, keep: bool) -> Self {
+        self.keep_state = keep;
+        self
+    }
+
     /// Add a tab
     pub fn tab(
         mut self,
-        tab_id: &'a str,
-        label: &'a str,
+        value: T,
+        label: impl Into<String>,
         content: impl FnOnce(&mut Ui) + 'a,
     ) -> Self {
-        self.tabs.push((tab_id, label, Box::new(content)));
+        self.tabs.push(Tab {
+            value,
+            label: label.into(),
+            content: Box::new(content),
+        });
         self
     }
 
     /// Show the tabs
     pub fn show(self) -> Response {
+        let Self { ui, id, tabs, selected: binding, on_reorder, on_close, orientation, overflow, keep_state } = self;
+
         // Get theme from context or fall back to light mode
-        let theme = self.ui.ctx().data(|d| {
-            d.get_temp::<ShadcnTheme>(egui::Id::new("shadcn_theme"))
+        let theme = ui.ctx().data(|d| {
+            d.get_temp::<ShadcnTheme>(Id::new("shadcn_theme"))
                 .unwrap_or_else(ShadcnTheme::light)
         });
-        let id = egui::Id::new(self.id);
+        let id = Id::new(id);
+
+        if tabs.is_empty() {
+            return ui.allocate_response(Vec2::ZERO, Sense::hover());
+        }
+
+        // Resolve the selected index from the binding or memory
+        let stored = match &binding {
+            Some(value) => tabs.iter().position(|tab| tab.value == **value).unwrap_or(0),
+            None => ui.ctx().data(|d| d.get_temp::<usize>(id).unwrap_or(0)).min(tabs.len() - 1),
+        };
+        let mut selected = stored;
+
+        let font = egui::FontId::proportional(theme.typography.body().size);
+        let galleys: Vec<Arc<Galley>> = tabs
+            .iter()
+            .map(|tab| ui.painter().layout_no_wrap(tab.label.clone(), font.clone(), egui::Color32::PLACEHOLDER))
+            .collect();
+        let strip = Strip {
+            id,
+            theme: &theme,
+            galleys: &galleys,
+            selected,
+            closable: on_close.is_some(),
+            reorderable: on_reorder.is_some(),
+            orientation,
+        };
+
+        // Settle the selection, report events and build the selected tab's
+        // content; vertical tabs place it beside the column
+        let finish = |ui: &mut Ui, strip_response: Response, output: StripOutput| {
+            settle_focus(ui, &output, tabs.len(), id);
+            if let Some(clicked) = output.clicked {
+                selected = clicked;
+            }
+            let StripOutput { closed, reorder, .. } = output;
+            if let Some(closed) = closed {
+                if closed == selected && tabs.len() > 1 {
+                    selected = if closed + 1 < tabs.len() { closed + 1 } else { closed - 1 };
+                }
+            }
+            let changed = selected != stored || binding.as_ref().is_some_and(|value| **value != tabs[selected].value);
+
+            // Keep the selection on its tab; this frame still shows the old order
+            let remembered = match (closed, reorder) {
+                (Some(closed), _) if selected > closed => selected - 1,
+                (_, Some(moved)) => moved.map_index(selected),
+                _ => selected,
+            };
+            if let Some(value) = binding {
+                *value = tabs[selected].value.clone();
+            } else {
+                ui.ctx().data_mut(|d| d.insert_temp(id, remembered));
+            }
+
+            // Revisited tabs get fresh widget state unless it is kept
+            let generation_id = id.with("generation");
+            let mut generation = ui.ctx().data(|d| d.get_temp::<u64>(generation_id).unwrap_or(0));
+            if changed && !keep_state {
+                generation += 1;
+                ui.ctx().data_mut(|d| d.insert_temp(generation_id, generation));
+            }
+
+            if let (Some(moved), Some(on_reorder)) = (reorder, on_reorder) {
+                on_reorder(moved);
+            }
+            if let (Some(closed), Some(on_close)) = (closed, on_close) {
+                on_close(tabs[closed].value.clone());
+            }
+
+            // Render the selected tab's content only
+            let tab = tabs.into_iter().nth(selected).expect("selected index is clamped");
+            let generation = if keep_state { 0 } else { generation };
+            ui.add_space(theme.spacing.md);
+            let content_response = show_content(ui, &theme, id.with((Id::new(&tab.value), generation)), tab.content);
+
+            let mut response = strip_response.union(content_response);
+            if changed {
+                response.mark_changed();
+            }
+            response
+        };
+
+        match orientation {
+            TabsOrientation::Horizontal => {
+                let (strip_response, output) = strip.show_horizontal(ui, overflow);
+                finish(ui, strip_response, output)
+            }
+            TabsOrientation::Vertical => {
+                ui.horizontal_top(|ui| {
+                    let (strip_response, output) = strip.show_vertical(ui);
+                    finish(ui, strip_response, output)
+                })
+                .inner
+            }
+        }
+    }
+}
 
-        // Get or create selected tab state
-        let mut selected = self.ui.ctx().data_mut(|d| {
-            d.get_temp::<usize>(id).unwrap_or(0)
+/// Move keyboard focus along with closed and moved tabs
+fn settle_focus(ui: &Ui, output: &StripOutput, len: usize, id: Id) {
+    if let Some(closed) = output.closed {
+        // Focus moves to the tab taking the closed one's place
+        if len > 1 && ui.memory(|m| m.has_focus(tab_id(id, closed))) {
+            let next = closed.min(len - 2);
+            ui.memory_mut(|m| m.request_focus(tab_id(id, next)));
+        }
+    } else if let Some(moved) = output.reorder {
+        // Keyboard focus follows the moved tab
+        if ui.memory(|m| m.has_focus(tab_id(id, moved.from))) {
+            ui.memory_mut(|m| m.request_focus(tab_id(id, moved.to)));
+        }
+    }
+}
+
+/// Draw the bordered content frame for a tab
+fn show_content(ui: &mut Ui, theme: &ShadcnTheme, id: Id, content: Box<dyn FnOnce(&mut Ui) + '_>) -> Response {
+    ui.push_id(id, |ui| {
+        // Use foreground at 30% for visible border while maintaining visual hierarchy
+        egui::Frame::NONE
+            .fill(theme.colors.background)
+            .stroke(egui::Stroke::new(1.0, theme.colors.foreground.linear_multiply(0.3)))
+            .corner_radius(theme.radii.md)
+            .inner_margin(theme.spacing.md)
+            .show(ui, |ui| content(ui))
+            .response
+    })
+    .inner
+}
+
+fn tab_id(tabs: Id, index: usize) -> Id {
+    tabs.with(("tab", index))
+}
+
+/// The row or column of tab triggers
+struct Strip<'s> {
+    id: Id,
+    theme: &'s ShadcnTheme,
+    galleys: &'s [Arc<Galley>],
+    selected: usize,
+    closable: bool,
+    reorderable: bool,
+    orientation: TabsOrientation,
+}
+
+impl Strip<'_> {
+    /// Horizontal triggers in a scroll area with overflow controls
+    fn show_horizontal(&self, ui: &mut Ui, overflow: TabsOverflow) -> (Response, StripOutput) {
+        let state_id = self.id.with("strip");
+        let mut state = ui.ctx().data(|d| d.get_temp::<StripState>(state_id).unwrap_or_default());
+        let reveal_id = self.id.with("reveal");
+        let reveal = ui.ctx().data_mut(|d| d.remove_temp::<usize>(reveal_id));
+
+        let inner = ui.horizontal(|ui| {
+            let mut output = StripOutput::default();
+            let reserved = match (state.overflowing, overflow) {
+                (false, _) => 0.0,
+                (true, TabsOverflow::Scroll) => 2.0 * OVERFLOW_BUTTON_WIDTH,
+                (true, TabsOverflow::Menu) => OVERFLOW_BUTTON_WIDTH,
+            };
+
+            if state.overflowing && overflow == TabsOverflow::Scroll {
+                let enabled = state.offset > 0.5;
+                if overflow_button(ui, self.theme, self.id.with("scroll_prev"), enabled, OverflowIcon::Previous).clicked() {
+                    state.pending = Some((state.offset - state.viewport * 0.8).max(0.0));
+                }
+            }
+
+            let mut scroll = egui::ScrollArea::horizontal()
+                .id_salt(self.id.with("scroll"))
+                .scroll_bar_visibility(egui::scroll_area::ScrollBarVisibility::AlwaysHidden)
+                .max_width((ui.available_width() - reserved).max(0.0));
+            if let Some(offset) = state.pending.take() {
+                scroll = scroll.horizontal_scroll_offset(offset);
+            }
+            let scrolled = scroll.show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.spacing_mut().item_spacing.x = 0.0;
+                    self.show_triggers(ui, reveal)
+                })
+                .inner
+            });
+            let (clicked, closed, reorder) = scrolled.inner;
+            output.clicked = clicked;
+            output.closed = closed;
+            output.reorder = reorder;
+
+            let viewport = scrolled.inner_rect.width();
+            state.viewport = viewport;
+            state.offset = scrolled.state.offset.x;
+            state.max_offset = (scrolled.content_size.x - viewport).max(0.0);
+            state.overflowing = state.max_offset > 0.5;
+
+            if state.overflowing {
+                match overflow {
+                    TabsOverflow::Scroll => {
+                        let enabled = state.offset < state.max_offset - 0.5;
+                        if overflow_button(ui, self.theme, self.id.with("scroll_next"), enabled, OverflowIcon::Next).clicked() {
+                            state.pending = Some((state.offset + viewport * 0.8).min(state.max_offset));
+                        }
+                    }
+                    TabsOverflow::Menu => {
+                        let button = overflow_button(ui, self.theme, self.id.with("more"), true, OverflowIcon::More);
+                        if let Some(picked) = self.more_menu(ui, &button) {
+                            output.clicked = Some(picked);
+                            ui.ctx().data_mut(|d| d.insert_temp(reveal_id, picked));
+                        }
+                    }
+                }
+            } else {
+                OverlayStack::remove(ui.ctx(), self.id.with("more"));
+            }
+
+            if state.pending.is_some() {
+                ui.ctx().request_repaint();
+            }
+            output
         });
 
-        // Render tab triggers with underline indicator (shadcn style)
-        let mut response = self.ui.horizontal(|ui| {
-            for (idx, (_, label, _)) in self.tabs.iter().enumerate() {
-                let is_selected = idx == selected;
+        ui.ctx().data_mut(|d| d.insert_temp(state_id, state));
+        (inner.response, inner.inner)
+    }
 
-                // Use foreground for both, but with different opacity for inactive
-                // This ensures good contrast in both light and dark modes
-                let text_color = if is_selected {
-                    theme.colors.foreground
-                } else {
-                    // Use foreground with reduced opacity for inactive tabs
-                    // This maintains readability while showing inactive state
-                    theme.colors.foreground.linear_multiply(0.6)
-                };
-
-                // Use transparent button for cleaner look
-                // 44px minimum height for Apple HIG touch target
-                let button = egui::Button::new(
-                    egui::RichText::new(*label)
-                        .color(text_color)
-                        .size(theme.typography.body().size)
+    /// Vertical triggers in a column as wide as the widest label
+    fn show_vertical(&self, ui: &mut Ui) -> (Response, StripOutput) {
+        let inner = ui.vertical(|ui| {
+            ui.spacing_mut().item_spacing.y = 0.0;
+            let (clicked, closed, reorder) = self.show_triggers(ui, None);
+            StripOutput { clicked, closed, reorder }
+        });
+        (inner.response, inner.inner)
+    }
+
+    /// Draw every trigger plus the active indicator and handle keyboard
+    /// navigation between them
+    fn show_triggers(&self, ui: &mut Ui, reveal: Option<usize>) -> (Option<usize>, Option<usize>, Option<Reorder>) {
+        let vertical = self.orientation == TabsOrientation::Vertical;
+        let close_width = if self.closable { CLOSE_WIDTH } else { 0.0 };
+        let column_width = self
+            .galleys
+            .iter()
+            .map(|galley| galley.size().x)
+            .fold(0.0, f32::max)
+            + 2.0 * TAB_PADDING
+            + close_width;
+
+        let axis = if vertical { DragAxis::Vertical } else { DragAxis::Horizontal };
+        let mut list = DragList::new(ui.ctx(), self.id, axis);
+        let mut rects = Vec::with_capacity(self.galleys.len());
+        let mut focused = None;
+        let mut clicked = None;
+        let mut closed = None;
+
+        for (index, galley) in self.galleys.iter().enumerate() {
+            let width = if vertical {
+                column_width
+            } else {
+                galley.size().x + 2.0 * TAB_PADDING + close_width
+            };
+            let (rect, _) = ui.allocate_exact_size(Vec2::new(width, TAB_HEIGHT), Sense::hover());
+            let sense = if self.reorderable { Sense::click_and_drag() } else { Sense::click() };
+            if self.reorderable {
+                // Before the trigger, so picking up or dropping doesn't select it
+                list.keys(ui, index, tab_id(self.id, index));
+            }
+            let response = ui.interact(rect, tab_id(self.id, index), sense);
+
+            // Drawn after the trigger so it takes the click
+            let close = self.closable.then(|| {
+                let close_rect = Rect::from_center_size(
+                    Pos2::new(rect.right() - TAB_PADDING - 6.0, rect.center().y),
+                    Vec2::splat(16.0),
+                );
+                // Not focusable: keyboard users close with Delete
+                (close_rect, ui.interact(close_rect, self.id.with(("close", index)), Sense::CLICK))
+            });
+
+            if ui.is_rect_visible(rect) {
+                self.paint_trigger(ui, index, galley, rect, &response, close.as_ref());
+            }
+
+            if response.has_focus() {
+                focused = Some(index);
+            }
+            if response.clicked() {
+                clicked = Some(index);
+            }
+            let close_clicked = close.as_ref().is_some_and(|(_, close)| close.clicked());
+            if self.closable && (close_clicked || response.middle_clicked()) {
+                closed = Some(index);
+            }
+            if self.reorderable {
+                list.item(ui, index, &response, rect);
+            }
+            if reveal == Some(index) {
+                ui.scroll_to_rect(rect, None);
+            }
+            rects.push(rect);
+        }
+
+        // Arrow keys move between tabs unless a tab is being carried
+        if let (Some(index), None) = (focused, list.dragged()) {
+            let focus_id = tab_id(self.id, index);
+            ui.memory_mut(|m| {
+                m.set_focus_lock_filter(
+                    focus_id,
+                    EventFilter {
+                        horizontal_arrows: !vertical,
+                        vertical_arrows: vertical,
+                        ..Default::default()
+                    },
                 )
-                .fill(egui::Color32::TRANSPARENT)
-                .stroke(egui::Stroke::NONE)
-                .min_size(egui::vec2(0.0, 44.0));
-
-                let button_response = ui.add(button);
-
-                // Draw underline indicator for selected tab
-                if is_selected {
-                    let underline_y = button_response.rect.bottom();
-                    ui.painter().line_segment(
-                        [
-                            egui::pos2(button_response.rect.left(), underline_y),
-                            egui::pos2(button_response.rect.right(), underline_y),
-                        ],
-                        egui::Stroke::new(2.0, theme.colors.primary),
-                    );
+            });
+            let (previous, next) = if vertical {
+                (Key::ArrowUp, Key::ArrowDown)
+            } else {
+                (Key::ArrowLeft, Key::ArrowRight)
+            };
+            let last = rects.len() - 1;
+            let target = ui.input_mut(|i| {
+                if i.consume_key(Modifiers::NONE, Key::Home) {
+                    Some(0)
+                } else if i.consume_key(Modifiers::NONE, Key::End) {
+                    Some(last)
+                } else if i.consume_key(Modifiers::NONE, previous) {
+                    Some(if index == 0 { last } else { index - 1 })
+                } else if i.consume_key(Modifiers::NONE, next) {
+                    Some(if index == last { 0 } else { index + 1 })
+                } else {
+                    None
                 }
+            });
+            if let Some(target) = target {
+                clicked = Some(target);
+                // The lock filter only applies from a tab's second focused
+                // frame, so also cancel egui's own arrow navigation
+                ui.memory_mut(|m| {
+                    m.request_focus(tab_id(self.id, target));
+                    m.move_focus(egui::FocusDirection::None);
+                });
+                ui.scroll_to_rect(rects[target], None);
+            }
+            if self.closable && ui.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Delete)) {
+                closed = Some(index);
+            }
+        }
+
+        let reorder = list.finish(ui);
+
+        // Slide the indicator to the selected tab (or the one just picked)
+        let active = clicked.unwrap_or(self.selected);
+        if let Some(rect) = rects.get(active) {
+            self.paint_indicator(ui, *rect);
+        }
+
+        (clicked, closed, reorder)
+    }
 
-                if button_response.clicked() {
-                    selected = idx;
-                    ui.ctx().data_mut(|d| d.insert_temp(id, selected));
+    fn paint_trigger(
+        &self,
+        ui: &Ui,
+        index: usize,
+        galley: &Arc<Galley>,
+        rect: Rect,
+        response: &Response,
+        close: Option<&(Rect, Response)>,
+    ) {
+        let theme = self.theme;
+        let painter = ui.painter();
+
+        // Use foreground for both, but with different opacity for inactive
+        // This ensures good contrast in both light and dark modes
+        let text_color = if index == self.selected {
+            theme.colors.foreground
+        } else if response.hovered() {
+            theme.colors.foreground.linear_multiply(0.8)
+        } else {
+            theme.colors.foreground.linear_multiply(0.6)
+        };
+        let text_pos = Pos2::new(rect.left() + TAB_PADDING, rect.center().y - galley.size().y / 2.0);
+        painter.galley(text_pos, galley.clone(), text_color);
+
+        if let Some((close_rect, close)) = close {
+            if close.hovered() {
+                painter.rect_filled(*close_rect, theme.radii.sm, theme.colors.accent);
+            }
+            let color = if close.hovered() {
+                theme.colors.accent_foreground
+            } else {
+                theme.colors.muted_foreground
+            };
+            let stroke = egui::Stroke::new(1.5, color);
+            let half = 3.5;
+            let c = close_rect.center();
+            painter.line_segment([c + Vec2::new(-half, -half), c + Vec2::new(half, half)], stroke);
+            painter.line_segment([c + Vec2::new(-half, half), c + Vec2::new(half, -half)], stroke);
+        }
+
+        theme.draw_focus_ring(painter, rect.shrink(2.0), theme.radii.sm, response.has_focus());
+    }
+
+    /// Underline (horizontal) or side bar (vertical) under the active tab,
+    /// springing between tabs when motion is allowed
+    fn paint_indicator(&self, ui: &Ui, rect: Rect) {
+        let theme = self.theme;
+        let vertical = self.orientation == TabsOrientation::Vertical;
+        let origin = ui.min_rect().min;
+        let (mut start, mut end) = if vertical {
+            (rect.top() - origin.y, rect.bottom() - origin.y)
+        } else {
+            (rect.left() - origin.x, rect.right() - origin.x)
+        };
+        if theme.motion.allows_movement() {
+            start = animation::spring_value(ui.ctx(), self.id.with("indicator_left"), start, Spring::SNAPPY);
+            end = animation::spring_value(ui.ctx(), self.id.with("indicator_right"), end, Spring::SNAPPY);
+        }
+
+        let stroke = egui::Stroke::new(2.0, theme.colors.primary);
+        let segment = if vertical {
+            let x = rect.right() - 1.0;
+            [Pos2::new(x, origin.y + start), Pos2::new(x, origin.y + end)]
+        } else {
+            // Inset by a pixel so the scroll area doesn't clip the stroke
+            let y = rect.bottom() - 1.0;
+            [Pos2::new(origin.x + start, y), Pos2::new(origin.x + end, y)]
+        };
+        ui.painter().line_segment(segment, stroke);
+    }
+
+    /// Popover listing every tab, opened by the "more" button
+    fn more_menu(&self, ui: &Ui, button: &Response) -> Option<usize> {
+        let theme = self.theme;
+        let menu_id = self.id.with("more");
+        let open_id = menu_id.with("open");
+        let was_open = ui.ctx().data(|d| d.get_temp::<bool>(open_id).unwrap_or(false));
+        let mut open = was_open != button.clicked();
+        let mut picked = None;
+
+        if open {
+            let overlay = OverlayStack::register(ui.ctx(), menu_id, OverlayKind::Popover);
+            let area_response = egui::Area::new(menu_id.with("area"))
+                .order(egui::Order::Foreground)
+                .pivot(egui::Align2::RIGHT_TOP)
+                .fixed_pos(button.rect.right_bottom() + Vec2::new(0.0, 4.0))
+                .show(ui.ctx(), |ui| {
+                    egui::Frame::NONE
+                        .fill(theme.colors.popover)
+                        .stroke(egui::Stroke::new(1.0, theme.colors.border))
+                        .corner_radius(theme.radii.md)
+                        .shadow(theme.shadows.md)
+                        .inner_margin(4.0)
+                        .show(ui, |ui| {
+                            ui.set_min_width(160.0);
+                            for (index, galley) in self.galleys.iter().enumerate() {
+                                let item = ui.allocate_response(Vec2::new(ui.available_width().max(160.0), 32.0), Sense::click());
+                                if item.hovered() {
+                                    ui.painter().rect_filled(item.rect, theme.radii.sm, theme.colors.accent);
+                                }
+                                let color = if item.hovered() {
+                                    theme.colors.accent_foreground
+                                } else {
+                                    theme.colors.popover_foreground
+                                };
+                                let pos = Pos2::new(item.rect.left() + 12.0, item.rect.center().y - galley.size().y / 2.0);
+                                ui.painter().galley(pos, galley.clone(), color);
+                                if index == self.selected {
+                                    ui.painter().circle_filled(
+                                        Pos2::new(item.rect.right() - 12.0, item.rect.center().y),
+                                        3.0,
+                                        color,
+                                    );
+                                }
+                                if item.clicked() {
+                                    picked = Some(index);
+                                }
+                            }
+                        });
+                });
+
+            overlay.raise(ui.ctx(), area_response.response.layer_id);
+            if picked.is_some() {
+                open = false;
+            } else if was_open {
+                // Close on Escape or when clicking outside (but not on the button)
+                let clicked_outside = !button.clicked() && overlay.clicked_outside(ui.ctx(), area_response.response.rect);
+                if clicked_outside || overlay.escape_pressed(ui.ctx()) {
+                    open = false;
                 }
             }
-        }).response;
+        }
+
+        ui.ctx().data_mut(|d| d.insert_temp(open_id, open));
+        if !open {
+            OverlayStack::remove(ui.ctx(), menu_id);
+        }
+        picked
+    }
+}
 
-        // Render selected tab content with visible border
-        if let Some((_, _, content)) = self.tabs.into_iter().nth(selected) {
-            self.ui.add_space(theme.spacing.md);
+#[derive(Clone, Copy)]
+enum OverflowIcon {
+    Previous,
+    Next,
+    More,
+}
 
-            // Use foreground at 30% for visible border while maintaining visual hierarchy
-            let frame = egui::Frame::NONE
-                .fill(theme.colors.background)
-                .stroke(egui::Stroke::new(1.0, theme.colors.foreground.linear_multiply(0.3)))
-                .corner_radius(theme.radii.md)
-                .inner_margin(theme.spacing.md);
+/// Small ghost button beside overflowing triggers
+fn overflow_button(ui: &mut Ui, theme: &ShadcnTheme, id: Id, enabled: bool, icon: OverflowIcon) -> Response {
+    let (rect, _) = ui.allocate_exact_size(Vec2::new(OVERFLOW_BUTTON_WIDTH, TAB_HEIGHT), Sense::hover());
+    let sense = if enabled { Sense::click() } else { Sense::hover() };
+    let response = ui.interact(rect, id, sense);
 
-            response = frame.show(self.ui, |ui| {
-                content(ui);
-            }).response;
+    if ui.is_rect_visible(rect) {
+        let painter = ui.painter();
+        let icon_rect = Rect::from_center_size(rect.center(), Vec2::splat(24.0));
+        if enabled && response.hovered() {
+            painter.rect_filled(icon_rect, theme.radii.sm, theme.colors.accent);
+        }
+        let color = if enabled {
+            theme.colors.foreground
+        } else {
+            theme.colors.muted_foreground.linear_multiply(0.5)
+        };
+        let c = rect.center();
+        let stroke = egui::Stroke::new(1.5, color);
+        match icon {
+            OverflowIcon::Previous | OverflowIcon::Next => {
+                let dir = if matches!(icon, OverflowIcon::Next) { 1.0 } else { -1.0 };
+                let size = 4.0;
+                painter.line_segment([c + Vec2::new(-size * 0.5 * dir, -size), c + Vec2::new(size * 0.5 * dir, 0.0)], stroke);
+                painter.line_segment([c + Vec2::new(size * 0.5 * dir, 0.0), c + Vec2::new(-size * 0.5 * dir, size)], stroke);
+            }
+            OverflowIcon::More => {
+                for offset in [-5.0, 0.0, 5.0] {
+                    painter.circle_filled(c + Vec2::new(offset, 0.0), 1.5, color);
+                }
+            }
         }
+        theme.draw_focus_ring(painter, icon_rect, theme.radii.sm, response.has_focus());
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::transition_frames;
+    use crate::test_util::{context_with_motion, key_press, pointer_press, pointer_release, run_frame_with_events};
+    use crate::theme::MotionPreference;
+    use egui::Event;
+
+    fn key(key: Key) -> Event {
+        key_press(key, Modifiers::NONE)
+    }
+
+    #[test]
+    fn test_keyboard_reorder_keeps_selection() {
+        let ctx = context_with_motion(MotionPreference::Full);
+        let mut moves = Vec::new();
+        let frame = |time: f64, events: Vec<Event>, moves: &mut Vec<Reorder>| {
+            run_frame_with_events(&ctx, time, events, |ui| {
+                Tabs::new(ui, "tabs")
+                    .tab("a", "Alpha", |_| {})
+                    .tab("b", "Beta", |_| {})
+                    .tab("c", "Gamma", |_| {})
+                    .on_reorder(|reorder| moves.push(reorder))
+                    .show();
+            });
+        };
+
+        frame(0.0, Vec::new(), &mut moves);
+        frame(0.1, vec![key(Key::Tab)], &mut moves);
+        frame(0.2, vec![key(Key::Space)], &mut moves);
+        frame(0.3, vec![key(Key::ArrowRight)], &mut moves);
+        frame(0.4, vec![key(Key::Space)], &mut moves);
+
+        assert_eq!(moves, vec![Reorder { from: 0, to: 1 }]);
+        assert_eq!(ctx.data(|d| d.get_temp::<usize>(Id::new("tabs"))), Some(1));
+
+        // Carrying an unselected tab doesn't select it
+        ctx.memory_mut(|m| m.request_focus(tab_id(Id::new("tabs"), 2)));
+        frame(0.5, Vec::new(), &mut moves);
+        frame(0.6, vec![key(Key::Space)], &mut moves);
+        frame(0.7, vec![key(Key::ArrowLeft), key(Key::ArrowLeft)], &mut moves);
+        frame(0.8, vec![key(Key::Enter)], &mut moves);
+
+        assert_eq!(moves, vec![Reorder { from: 0, to: 1 }, Reorder { from: 2, to: 0 }]);
+        assert_eq!(ctx.data(|d| d.get_temp::<usize>(Id::new("tabs"))), Some(2));
+    }
+
+    #[test]
+    fn test_keyboard_navigation_controlled() {
+        let ctx = context_with_motion(MotionPreference::Full);
+        let mut selected = 1u32;
+        let built = std::cell::RefCell::new(Vec::new());
+        let frame = |time: f64, events: Vec<Event>, selected: &mut u32, built: &std::cell::RefCell<Vec<u32>>| {
+            run_frame_with_events(&ctx, time, events, |ui| {
+                let mut tabs = Tabs::new(ui, "nav").selected(selected);
+                for value in 0..4u32 {
+                    tabs = tabs.tab(value, format!("Tab {value}"), move |_| built.borrow_mut().push(value));
+                }
+                tabs.show();
+            });
+        };
+
+        frame(0.0, Vec::new(), &mut selected, &built);
+        // Only the selected tab's content is built
+        assert_eq!(*built.borrow(), vec![1]);
+
+        ctx.memory_mut(|m| m.request_focus(tab_id(Id::new("nav"), 1)));
+        frame(0.1, Vec::new(), &mut selected, &built);
+        frame(0.2, vec![key(Key::ArrowRight)], &mut selected, &built);
+        assert_eq!(selected, 2);
+        frame(0.3, vec![key(Key::End)], &mut selected, &built);
+        assert_eq!(selected, 3);
+        // Wraps around past the last tab
+        frame(0.4, vec![key(Key::ArrowRight)], &mut selected, &built);
+        assert_eq!(selected, 0);
+        frame(0.5, vec![key(Key::ArrowLeft)], &mut selected, &built);
+        assert_eq!(selected, 3);
+        frame(0.6, vec![key(Key::Home)], &mut selected, &built);
+        assert_eq!(selected, 0);
+        assert!(ctx.memory(|m| m.has_focus(tab_id(Id::new("nav"), 0))));
+    }
+
+    #[test]
+    fn test_close_selects_neighbour() {
+        let ctx = context_with_motion(MotionPreference::Full);
+        let mut files = vec!["a.rs", "b.rs", "c.rs"];
+        let mut selected = "b.rs";
+        let frame = |time: f64, events: Vec<Event>, files: &mut Vec<&'static str>, selected: &mut &'static str| {
+            let mut closed = None;
+            run_frame_with_events(&ctx, time, events, |ui| {
+                let mut tabs = Tabs::new(ui, "files").selected(selected);
+                for &file in files.iter() {
+                    tabs = tabs.tab(file, file, |_| {});
+                }
+                tabs.on_close(|file| closed = Some(file)).show();
+            });
+            if let Some(file) = closed {
+                files.retain(|f| *f != file);
+            }
+            closed
+        };
+
+        frame(0.0, Vec::new(), &mut files, &mut selected);
+        ctx.memory_mut(|m| m.request_focus(tab_id(Id::new("files"), 1)));
+        frame(0.1, Vec::new(), &mut files, &mut selected);
+
+        assert_eq!(frame(0.2, vec![key(Key::Delete)], &mut files, &mut selected), Some("b.rs"));
+        assert_eq!(files, vec!["a.rs", "c.rs"]);
+        assert_eq!(selected, "c.rs");
+
+        // Focus stays on the tab that took the closed one's place
+        frame(0.3, Vec::new(), &mut files, &mut selected);
+        assert!(ctx.memory(|m| m.has_focus(tab_id(Id::new("files"), 1))));
+        assert_eq!(frame(0.4, vec![key(Key::Delete)], &mut files, &mut selected), Some("c.rs"));
+        assert_eq!(selected, "a.rs");
+    }
+
+    #[test]
+    fn test_overflow_shows_scroll_arrows() {
+        let ctx = context_with_motion(MotionPreference::Full);
+        let frame = |time: f64, events: Vec<Event>| {
+            run_frame_with_events(&ctx, time, events, |ui| {
+                ui.allocate_ui(Vec2::new(200.0, 300.0), |ui| {
+                    let mut tabs = Tabs::new(ui, "wide");
+                    for value in 0..10 {
+                        tabs = tabs.tab(value, format!("Long tab {value}"), |_| {});
+                    }
+                    tabs.show();
+                });
+            });
+        };
+
+        frame(0.0, Vec::new());
+        let state = ctx.data(|d| d.get_temp::<StripState>(Id::new("wide").with("strip"))).unwrap();
+        assert!(state.overflowing);
+        assert_eq!(state.offset, 0.0);
+
+        // The next arrow scrolls right once the strip has settled
+        frame(0.1, Vec::new());
+        frame(0.15, Vec::new());
+        let next = ctx.read_response(Id::new("wide").with("scroll_next")).unwrap().rect.center();
+        frame(0.2, pointer_press(next));
+        frame(0.3, vec![pointer_release(next)]);
+        frame(0.4, Vec::new());
+        let state = ctx.data(|d| d.get_temp::<StripState>(Id::new("wide").with("strip"))).unwrap();
+        assert!(state.offset > 0.0);
+    }
 
-        response
+    #[test]
+    fn test_indicator_motion() {
+        let tabs = |ui: &mut Ui, second: bool| {
+            let mut selected = usize::from(second);
+            Tabs::new(ui, "motion").selected(&mut selected).tab(0, "One", |_| {}).tab(1, "Two", |_| {}).show();
+        };
+        assert_eq!(transition_frames(MotionPreference::Full, tabs), (true, true));
+        assert_eq!(transition_frames(MotionPreference::Reduced, tabs), (false, false));
+        assert_eq!(transition_frames(MotionPreference::None, tabs), (false, false));
     }
 }
@@ -5,6 +5,7 @@
 //! Reference: <https://ui.shadcn.com/docs/components/toast>
 
 use egui::{Context, Id, Pos2};
+use crate::animation::{self, Spring};
 use crate::theme::ShadcnTheme;
 use std::time::{Duration, Instant};
 
@@ -76,6 +77,11 @@ impl Toast {
     }
 }
 
+/// Id of the enter/exit animation for a toast
+fn presence_id(toast_id: u64) -> Id {
+    Id::new("toast").with(toast_id).with("presence")
+}
+
 /// Simple pseudo-random ID generator
 fn rand_id() -> u64 {
     use std::time::SystemTime;
@@ -149,8 +155,11 @@ impl Toaster {
     ///
     /// Call this once per frame in your update function.
     pub fn show(&mut self, ctx: &Context) {
-        // Remove expired toasts
-        self.toasts.retain(|t| !t.is_expired());
+        // Remove expired toasts once they have animated out
+        self.toasts.retain(|t| {
+            !t.is_expired()
+                || animation::spring_state(ctx, presence_id(t.id)).is_some_and(|s| s.value > 0.01)
+        });
 
         if self.toasts.is_empty() {
             return;
@@ -180,14 +189,29 @@ impl Toaster {
             let has_description = toast.description.is_some();
             let toast_height = if has_description { 80.0 } else { 56.0 };
 
-            current_y -= toast_height + toast_spacing;
+            // Slide in from the right on enter, out on expiry (fade only with reduced motion)
+            let target = if toast.is_expired() { 0.0 } else { 1.0 };
+            let presence = animation::spring_value_from(ctx, presence_id(toast.id), 0.0, target, Spring::DEFAULT)
+                .clamp(0.0, 1.0);
 
-            let toast_pos = Pos2::new(base_x, current_y);
+            current_y -= (toast_height + toast_spacing) * presence;
+
+            let slide = if theme.motion.allows_movement() {
+                (1.0 - presence) * (toast_width + margin)
+            } else {
+                0.0
+            };
+            let toast_pos = Pos2::new(base_x + slide, current_y);
 
             egui::Area::new(toast_id)
                 .order(egui::Order::Foreground)
                 .fixed_pos(toast_pos)
+                .interactable(!toast.is_expired())
                 .show(ctx, |ui| {
+                    if presence < 1.0 {
+                        ui.set_opacity(presence);
+                    }
+
                     // Colors based on variant
                     let (bg_color, border_color, title_color, desc_color) = match toast.variant {
                         ToastVariant::Default => (
@@ -292,6 +316,8 @@ impl Toaster {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::{context_with_motion, run_frame};
+    use crate::theme::MotionPreference;
 
     #[test]
     fn test_toast_creation() {
@@ -312,4 +338,30 @@ mod tests {
 
         assert_eq!(toaster.toasts.len(), 2);
     }
+
+    /// Presence and left edge of a toast two frames after it was added under `motion`
+    /// (egui only places a new area on its second frame)
+    fn entering_toast(motion: MotionPreference) -> (f32, f32) {
+        let ctx = context_with_motion(motion);
+        let mut toaster = Toaster::new();
+        toaster.add(Toast::new("Saved"));
+        run_frame(&ctx, 0.0, |ui| toaster.show(ui.ctx()));
+        run_frame(&ctx, 1.0 / 60.0, |ui| toaster.show(ui.ctx()));
+        let id = toaster.toasts[0].id;
+        let presence = animation::spring_state(&ctx, presence_id(id)).unwrap().value;
+        let left = ctx.memory(|m| m.area_rect(Id::new("toast").with(id))).unwrap().left();
+        (presence, left)
+    }
+
+    #[test]
+    fn test_toast_motion() {
+        // Resting left edge: screen width - toast width - margin
+        let resting = 800.0 - 360.0 - 16.0;
+        let (presence, left) = entering_toast(MotionPreference::Full);
+        assert!(presence < 1.0 && left > resting);
+        // Reduced motion fades in place
+        let (presence, left) = entering_toast(MotionPreference::Reduced);
+        assert!(presence < 1.0 && left == resting);
+        assert_eq!(entering_toast(MotionPreference::None), (1.0, resting));
+    }
 }
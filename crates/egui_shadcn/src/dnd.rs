@@ -0,0 +1,415 @@
+//! Drag-and-drop reordering for runs of widgets
+//!
+//! [`DragList`] turns the widgets of a list, a tab strip or a sidebar section
+//! into drag sources and drop targets. While an item is dragged it paints an
+//! insertion indicator between items and a ghost under the pointer, and
+//! scrolls the surrounding `ScrollArea` when the pointer nears its edges.
+//! Items can also be moved with the keyboard: Space picks up the focused item,
+//! the arrow keys move it, Space or Enter drops it and Escape (or moving focus
+//! away) cancels.
+//! egui also reports Space and Enter on a focused widget as a click, so pass
+//! the handle's id to [`DragList::keys`] before creating it to keep picking
+//! up and dropping from activating the item.
+//!
+//! The list never moves anything itself: [`DragList::finish`] reports a
+//! [`Reorder`] for the caller (or the component) to apply.
+//!
+//! ## Example
+//! ```rust,ignore
+//! let mut list = DragList::new(ui.ctx(), id, DragAxis::Vertical);
+//! for (index, item) in items.iter().enumerate() {
+//!     let item_id = id.with(index);
+//!     list.keys(ui, index, item_id);
+//!     let (rect, _) = ui.allocate_exact_size(egui::vec2(200.0, 32.0), egui::Sense::hover());
+//!     let response = ui.interact(rect, item_id, egui::Sense::click_and_drag());
+//!     paint_item(ui, rect, item);
+//!     list.item(ui, index, &response, rect);
+//! }
+//! if let Some(reorder) = list.finish(ui) {
+//!     reorder.apply(&mut items);
+//! }
+//! ```
+
+use egui::{EventFilter, Id, Key, LayerId, Modifiers, Order, Pos2, Rect, Response, Ui, Vec2};
+use crate::theme::ShadcnTheme;
+
+/// Distance from a scroll area's edge at which dragging starts scrolling
+const AUTO_SCROLL_MARGIN: f32 = 32.0;
+
+/// Fastest auto-scroll, in points per frame, reached at the very edge
+const AUTO_SCROLL_SPEED: f32 = 12.0;
+
+/// An item moved from index `from` to index `to`
+///
+/// `to` is the item's index after the move, so applying the reorder is a
+/// `remove(from)` followed by `insert(to, ..)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reorder {
+    /// Index of the item before the move
+    pub from: usize,
+    /// Index of the item after the move
+    pub to: usize,
+}
+
+impl Reorder {
+    /// Move the item within `items` (moves outside the slice are ignored)
+    pub fn apply<T>(self, items: &mut Vec<T>) {
+        if self.from < items.len() && self.to < items.len() {
+            let item = items.remove(self.from);
+            items.insert(self.to, item);
+        }
+    }
+
+    /// The index after the move of the item that was at `index`
+    ///
+    /// Handy for keeping a selection on the same item.
+    pub fn map_index(self, index: usize) -> usize {
+        if index == self.from {
+            self.to
+        } else if self.from < index && index <= self.to {
+            index - 1
+        } else if self.to <= index && index < self.from {
+            index + 1
+        } else {
+            index
+        }
+    }
+}
+
+/// Direction in which the items of a [`DragList`] are laid out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DragAxis {
+    /// Items side by side (tabs, columns)
+    Horizontal,
+    /// Items stacked top to bottom (lists, sidebar sections)
+    Vertical,
+}
+
+/// The item being dragged, kept between frames
+#[derive(Clone, Copy, Debug)]
+struct DragState {
+    from: usize,
+    /// Target index while moving with the keyboard; `None` while the pointer drags
+    keyboard: Option<usize>,
+}
+
+/// Drag source and drop targets for one list of widgets
+///
+/// Create it before showing the items, pass every item to [`DragList::item`]
+/// and call [`DragList::finish`] after the last one.
+pub struct DragList {
+    id: Id,
+    axis: DragAxis,
+    state: Option<DragState>,
+    rects: Vec<Rect>,
+    dropped: Option<Reorder>,
+    /// Item whose keys [`DragList::keys`] already handled this frame
+    keys_handled: Option<usize>,
+    /// Whether the item carried with the keyboard had focus this frame
+    carry_focused: bool,
+}
+
+impl DragList {
+    /// Start a list; `id` must be stable across frames
+    pub fn new(ctx: &egui::Context, id: Id, axis: DragAxis) -> Self {
+        let id = id.with("dnd");
+        Self {
+            id,
+            axis,
+            state: ctx.data(|d| d.get_temp::<DragState>(id)),
+            rects: Vec::new(),
+            dropped: None,
+            keys_handled: None,
+            carry_focused: false,
+        }
+    }
+
+    /// Index of the item being dragged, if any
+    ///
+    /// Components use it to dim the item left behind.
+    pub fn dragged(&self) -> Option<usize> {
+        self.state.map(|state| state.from)
+    }
+
+    /// Register the item at `index`
+    ///
+    /// `response` is the drag handle (it needs `Sense::drag` and should be
+    /// focusable for keyboard moves) and `rect` the item's full extent.
+    pub fn item(&mut self, ui: &Ui, index: usize, response: &Response, rect: Rect) {
+        if self.rects.len() <= index {
+            self.rects.resize(index + 1, Rect::NOTHING);
+        }
+        self.rects[index] = rect;
+
+        if response.drag_started() && self.state.is_none() {
+            self.state = Some(DragState { from: index, keyboard: None });
+        }
+        if response.dragged() && self.dragged() == Some(index) {
+            ui.ctx().set_cursor_icon(egui::CursorIcon::Grabbing);
+        }
+        if response.has_focus() && self.keys_handled != Some(index) {
+            self.handle_keys(ui, index, response.id);
+        }
+    }
+
+    /// Handle the keyboard moves of the item at `index` before its handle exists
+    ///
+    /// `id` is the id the handle's response will get. Without this the keys
+    /// are handled in [`DragList::item`], after egui has already turned the
+    /// Space or Enter that picked up or dropped the item into a click.
+    pub fn keys(&mut self, ui: &Ui, index: usize, id: Id) {
+        if ui.memory(|m| m.has_focus(id)) {
+            self.handle_keys(ui, index, id);
+            self.keys_handled = Some(index);
+        }
+    }
+
+    fn handle_keys(&mut self, ui: &Ui, index: usize, focus_id: Id) {
+        let (back, forward) = match self.axis {
+            DragAxis::Horizontal => (Key::ArrowLeft, Key::ArrowRight),
+            DragAxis::Vertical => (Key::ArrowUp, Key::ArrowDown),
+        };
+
+        match self.state {
+            None if ui.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Space)) => {
+                self.state = Some(DragState { from: index, keyboard: Some(index) });
+            }
+            Some(DragState { from, keyboard: Some(target) }) if from == index => {
+                let (steps_back, steps_forward) = ui.input_mut(|i| {
+                    (i.count_and_consume_key(Modifiers::NONE, back), i.count_and_consume_key(Modifiers::NONE, forward))
+                });
+                let target = (target + steps_forward).saturating_sub(steps_back);
+                self.state = Some(DragState { from, keyboard: Some(target) });
+
+                let drop = ui.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Space) || i.consume_key(Modifiers::NONE, Key::Enter));
+                if drop {
+                    self.dropped = Some(Reorder { from, to: target });
+                    self.state = None;
+                } else if ui.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Escape)) {
+                    self.state = None;
+                }
+            }
+            _ => {}
+        }
+
+        // Keep the arrows (and Escape) from moving focus away while carrying
+        if let Some(DragState { from, keyboard: Some(_) }) = self.state {
+            self.carry_focused |= from == index;
+            ui.memory_mut(|m| {
+                m.set_focus_lock_filter(
+                    focus_id,
+                    EventFilter {
+                        horizontal_arrows: true,
+                        vertical_arrows: true,
+                        escape: true,
+                        ..Default::default()
+                    },
+                )
+            });
+        }
+    }
+
+    /// Paint the drag feedback and return the move completed this frame
+    pub fn finish(mut self, ui: &Ui) -> Option<Reorder> {
+        let len = self.rects.len();
+        let mut result = self.dropped.map(|reorder| Reorder {
+            from: reorder.from,
+            to: reorder.to.min(len.saturating_sub(1)),
+        });
+
+        match self.state {
+            // The list shrank under the dragged item
+            Some(DragState { from, .. }) if from >= len => self.state = None,
+            // Focus left the carried item (Tab, a click elsewhere): cancel the move
+            Some(DragState { keyboard: Some(_), .. }) if !self.carry_focused => self.state = None,
+            Some(DragState { from, keyboard: Some(target) }) => {
+                let target = target.min(len - 1);
+                self.state = Some(DragState { from, keyboard: Some(target) });
+                let gap = if target > from { target + 1 } else { target };
+                self.paint_feedback(ui, from, gap, None);
+            }
+            Some(DragState { from, keyboard: None }) => {
+                let pointer = ui.ctx().pointer_interact_pos();
+                let released = !ui.input(|i| i.pointer.any_down());
+                if let Some(pos) = pointer {
+                    let gap = gap_index(&self.rects, pos, self.axis);
+                    if released {
+                        result = Some(Reorder { from, to: final_index(from, gap) });
+                    } else {
+                        self.paint_feedback(ui, from, gap, Some(pos));
+                        self.auto_scroll(ui, pos);
+                    }
+                }
+                if released {
+                    self.state = None;
+                }
+            }
+            None => {}
+        }
+
+        ui.ctx().data_mut(|d| match self.state {
+            Some(state) => d.insert_temp(self.id, state),
+            None => d.remove::<DragState>(self.id),
+        });
+
+        result.filter(|reorder| reorder.from != reorder.to)
+    }
+
+    fn paint_feedback(&self, ui: &Ui, from: usize, gap: usize, pointer: Option<Pos2>) {
+        let theme = ui.ctx().data(|d| {
+            d.get_temp::<ShadcnTheme>(Id::new("shadcn_theme"))
+                .unwrap_or_else(ShadcnTheme::light)
+        });
+
+        // Ghost of the dragged item following the pointer
+        let source = self.rects[from];
+        if let (Some(pos), true) = (pointer, source.is_positive()) {
+            let ghost = Rect::from_center_size(pos, source.size());
+            let painter = ui.ctx().layer_painter(LayerId::new(Order::Tooltip, self.id.with("ghost")));
+            painter.rect(
+                ghost,
+                theme.radii.sm,
+                theme.colors.background.gamma_multiply(0.8),
+                egui::Stroke::new(1.0, theme.colors.primary),
+                egui::StrokeKind::Inside,
+            );
+        }
+
+        if final_index(from, gap) != from {
+            if let Some([start, end]) = indicator_segment(&self.rects, gap, self.axis) {
+                let stroke = egui::Stroke::new(2.0, theme.colors.primary);
+                ui.painter().line_segment([start, end], stroke);
+                ui.painter().circle_stroke(start, 3.0, stroke);
+            }
+            ui.ctx().request_repaint();
+        }
+    }
+
+    /// Scroll the enclosing scroll area while the pointer is near its edge
+    fn auto_scroll(&self, ui: &Ui, pointer: Pos2) {
+        let clip = ui.clip_rect();
+        let (pos, min, max) = match self.axis {
+            DragAxis::Horizontal => (pointer.x, clip.min.x, clip.max.x),
+            DragAxis::Vertical => (pointer.y, clip.min.y, clip.max.y),
+        };
+        let speed = if pos < min + AUTO_SCROLL_MARGIN {
+            AUTO_SCROLL_SPEED * (1.0 - (pos - min).max(0.0) / AUTO_SCROLL_MARGIN)
+        } else if pos > max - AUTO_SCROLL_MARGIN {
+            -AUTO_SCROLL_SPEED * (1.0 - (max - pos).max(0.0) / AUTO_SCROLL_MARGIN)
+        } else {
+            return;
+        };
+        let delta = match self.axis {
+            DragAxis::Horizontal => Vec2::new(speed, 0.0),
+            DragAxis::Vertical => Vec2::new(0.0, speed),
+        };
+        ui.scroll_with_delta(delta);
+        ui.ctx().request_repaint();
+    }
+}
+
+fn along(pos: Pos2, axis: DragAxis) -> f32 {
+    match axis {
+        DragAxis::Horizontal => pos.x,
+        DragAxis::Vertical => pos.y,
+    }
+}
+
+/// The gap (0 = before the first item, `len` = after the last) the pointer is over
+fn gap_index(rects: &[Rect], pointer: Pos2, axis: DragAxis) -> usize {
+    rects
+        .iter()
+        .position(|rect| rect.is_positive() && along(pointer, axis) < along(rect.center(), axis))
+        .unwrap_or(rects.len())
+}
+
+/// The index an item dragged from `from` ends up at when dropped into `gap`
+fn final_index(from: usize, gap: usize) -> usize {
+    if gap > from {
+        gap - 1
+    } else {
+        gap
+    }
+}
+
+/// End points of the insertion line drawn at `gap`
+fn indicator_segment(rects: &[Rect], gap: usize, axis: DragAxis) -> Option<[Pos2; 2]> {
+    let before = gap.checked_sub(1).and_then(|i| rects.get(i)).filter(|r| r.is_positive());
+    let after = rects.get(gap).filter(|r| r.is_positive());
+    let cross = match (before, after) {
+        (Some(a), Some(b)) => a.union(*b),
+        (Some(rect), None) | (None, Some(rect)) => *rect,
+        (None, None) => return None,
+    };
+    Some(match axis {
+        DragAxis::Vertical => {
+            let y = match (before, after) {
+                (Some(a), Some(b)) => (a.bottom() + b.top()) / 2.0,
+                (Some(a), None) => a.bottom(),
+                (None, Some(b)) => b.top(),
+                (None, None) => unreachable!(),
+            };
+            [Pos2::new(cross.left(), y), Pos2::new(cross.right(), y)]
+        }
+        DragAxis::Horizontal => {
+            let x = match (before, after) {
+                (Some(a), Some(b)) => (a.right() + b.left()) / 2.0,
+                (Some(a), None) => a.right(),
+                (None, Some(b)) => b.left(),
+                (None, None) => unreachable!(),
+            };
+            [Pos2::new(x, cross.top()), Pos2::new(x, cross.bottom())]
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows() -> Vec<Rect> {
+        (0..3)
+            .map(|i| Rect::from_min_size(Pos2::new(0.0, i as f32 * 40.0), Vec2::new(100.0, 36.0)))
+            .collect()
+    }
+
+    #[test]
+    fn test_apply_and_map_index() {
+        let mut items = vec!['a', 'b', 'c', 'd'];
+        let reorder = Reorder { from: 0, to: 2 };
+        reorder.apply(&mut items);
+        assert_eq!(items, vec!['b', 'c', 'a', 'd']);
+        assert_eq!((0..4).map(|i| reorder.map_index(i)).collect::<Vec<_>>(), vec![2, 0, 1, 3]);
+
+        let back = Reorder { from: 3, to: 1 };
+        back.apply(&mut items);
+        assert_eq!(items, vec!['b', 'd', 'c', 'a']);
+        assert_eq!((0..4).map(|i| back.map_index(i)).collect::<Vec<_>>(), vec![0, 2, 3, 1]);
+
+        // Out of range moves leave the list alone
+        Reorder { from: 9, to: 0 }.apply(&mut items);
+        assert_eq!(items.len(), 4);
+    }
+
+    #[test]
+    fn test_gap_and_final_index() {
+        let rects = rows();
+        assert_eq!(gap_index(&rects, Pos2::new(50.0, 5.0), DragAxis::Vertical), 0);
+        assert_eq!(gap_index(&rects, Pos2::new(50.0, 30.0), DragAxis::Vertical), 1);
+        assert_eq!(gap_index(&rects, Pos2::new(50.0, 500.0), DragAxis::Vertical), 3);
+
+        assert_eq!(final_index(0, 3), 2);
+        assert_eq!(final_index(2, 0), 0);
+        assert_eq!(final_index(1, 1), 1);
+        assert_eq!(final_index(1, 2), 1);
+    }
+
+    #[test]
+    fn test_indicator_segment() {
+        let rects = rows();
+        assert_eq!(indicator_segment(&rects, 0, DragAxis::Vertical), Some([Pos2::new(0.0, 0.0), Pos2::new(100.0, 0.0)]));
+        assert_eq!(indicator_segment(&rects, 1, DragAxis::Vertical), Some([Pos2::new(0.0, 38.0), Pos2::new(100.0, 38.0)]));
+        assert_eq!(indicator_segment(&rects, 3, DragAxis::Vertical), Some([Pos2::new(0.0, 116.0), Pos2::new(100.0, 116.0)]));
+        assert_eq!(indicator_segment(&[], 0, DragAxis::Vertical), None);
+    }
+}
@@ -39,17 +39,33 @@
 #![warn(missing_docs)]
 
 pub mod animation;
+pub mod dnd;
+pub mod overlay;
+pub mod shortcuts;
 pub mod theme;
 pub mod components;
 pub mod notedeck;
 
+#[cfg(test)]
+mod test_util;
+
+/// Re-exports used by this crate's macros
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "regex")]
+    pub use lazy_regex;
+}
+
 // Re-export commonly used items
 pub use theme::ShadcnTheme;
+pub use overlay::{Overlay, OverlayKind, OverlayStack};
+pub use dnd::{DragAxis, DragList, Reorder};
+pub use shortcuts::{shortcuts_dialog, Shortcut, ShortcutConflict, ShortcutPlatform, ShortcutRegistry, ShortcutScope};
 pub use notedeck::{NotedeckTheme, NotedeckContextExt};
 pub use components::{
     // Phase 2: Core Components
-    Badge, BadgeVariant,
-    Avatar, AvatarSize,
+    Badge, BadgeResponse, BadgeVariant,
+    Avatar, AvatarGroup, AvatarShape, AvatarSize, AvatarStatus, avatar_color,
     Card, card_title, card_description,
     Alert, AlertVariant,
     Skeleton,
@@ -58,6 +74,7 @@ pub use components::{
     Spinner, SpinnerSize,
     // Phase 3: Form Components
     shadcn_input, shadcn_input_with_error, shadcn_textarea, form_label, form_helper,
+    InputMask, MaskedInput, MaskedInputResponse, NumberInput, InputOTP, InputOTPResponse,
     Checkbox,
     Switch,
     Slider,
@@ -65,15 +82,16 @@ pub use components::{
     Toggle, ToggleVariant, ToggleSize,
     ToggleGroup, ToggleGroupType, ToggleGroupVariant,
     RadioGroup, RadioButton,
-    Select,
+    Select, SelectGroupBuilder, SelectOption,
+    MenuModel, MenuItem,
     DropdownMenu, DropdownMenuResponse,
-    Combobox, ComboboxOption,
+    Combobox, ComboboxOption, OptionSource, OptionStatus, StaticOptions, AsyncOptions, OptionPage, OptionsFuture,
     // Phase 4: Navigation & Layout
     Separator, SeparatorOrientation,
-    Tabs,
+    Tabs, TabsOrientation, TabsOverflow,
     Collapsible, collapsible_trigger,
     Accordion, AccordionType,
-    Breadcrumb, BreadcrumbSeparator,
+    Breadcrumb, BreadcrumbResponse, BreadcrumbSeparator,
     // Phase 5: Overlays & Feedback
     Dialog, confirm_dialog, ConfirmResult,
     Tooltip, TooltipExt, shadcn_tooltip_for,
@@ -89,15 +107,17 @@ pub use components::{
     AspectRatio, AspectRatioPreset, AspectRatioResponse,
     Table, TableBody, TableResponse, simple_table,
     Command, CommandGroupBuilder,
-    Calendar, CalendarMode, CalendarSelection,
-    DatePicker,
+    Calendar, CalendarLocale, CalendarMode, CalendarSelection, DayDecoration, EnglishLocale,
+    DatePicker, DatePreset, DateRangePicker, DateTimePicker,
     Carousel, CarouselOrientation,
     Chart, ChartType, DataPoint,
-    ResizablePanelGroup, ResizableDirection,
+    ResizablePanelGroup, ResizableDirection, ResizablePanel, PanelLayout,
+    SortableList, SortableListResponse,
     // Phase 7: Navigation & Forms
-    Menubar, MenuBuilder, MenubarResponse,
-    Sidebar, SidebarBuilder, SidebarResponse,
+    Menubar, MenubarResponse,
+    Sidebar, SidebarBuilder, SidebarEvent, SidebarNode, SidebarResponse,
     NavigationMenu, NavDropdownBuilder, NavigationMenuResponse,
     Field, FieldResponse, labeled_input, required_input,
-    FormState, validators,
+    FieldError, FieldKey, FormState, FormValue, PollExecutor, ValidationExecutor, ValidationFuture,
+    ValidationTask, validators,
 };
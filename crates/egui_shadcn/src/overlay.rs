@@ -0,0 +1,351 @@
+//! Overlay stack for nested modals, sheets and popovers
+//!
+//! Every overlay component (Dialog, Sheet, Drawer, Popover, Select, ...) registers
+//! itself here each frame it is open. The stack remembers the order in which
+//! overlays were opened and uses it to:
+//! - keep the most recently opened overlay on top of the others
+//! - route `Escape` and outside clicks to the top-most overlay only
+//! - dim backdrops progressively when modals are nested
+//!
+//! ## Example
+//! ```rust,ignore
+//! let overlay = OverlayStack::register(ctx, id, OverlayKind::Modal);
+//! overlay.paint_backdrop(ctx, screen_rect, 128);
+//!
+//! let area = egui::Area::new(id.with("panel")).show(ctx, |ui| { /* ... */ });
+//! overlay.raise(ctx, area.response.layer_id);
+//!
+//! if overlay.escape_pressed(ctx) || overlay.clicked_outside(ctx, area.response.rect) {
+//!     OverlayStack::remove(ctx, id);
+//!     *open = false;
+//! }
+//! ```
+
+use egui::{Color32, Context, Id, Key, LayerId, Modifiers, Order, Rect};
+
+/// Backdrop dimming factor applied for each modal nested below the current one
+///
+/// The first modal uses its full backdrop alpha, the second 60% of it, the third 36%...
+/// Stacked backdrops still get progressively darker, without turning the screen black.
+const NESTED_BACKDROP_FACTOR: f32 = 0.6;
+
+/// Kind of overlay registered on the stack
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverlayKind {
+    /// Blocks interaction with everything beneath it and draws a backdrop
+    /// (Dialog, AlertDialog, Sheet, Drawer, Command)
+    Modal,
+    /// Floats next to its trigger without a backdrop
+    /// (Popover, Select, Combobox, menus)
+    Popover,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct OverlayEntry {
+    id: Id,
+    kind: OverlayKind,
+    last_pass: u64,
+}
+
+/// Ordered stack of the currently open overlays
+///
+/// Stored in egui's temporary memory; components interact with it through
+/// [`OverlayStack::register`] and the returned [`Overlay`] handle.
+#[derive(Debug, Clone, Default)]
+pub struct OverlayStack {
+    entries: Vec<OverlayEntry>,
+    /// Pass in which an outside click was already used to dismiss an overlay
+    click_consumed_pass: Option<u64>,
+}
+
+impl OverlayStack {
+    fn storage_id() -> Id {
+        Id::new("shadcn_overlay_stack")
+    }
+
+    /// Load the overlay stack from egui's temporary storage
+    pub fn load(ctx: &Context) -> Self {
+        ctx.data(|d| d.get_temp(Self::storage_id()).unwrap_or_default())
+    }
+
+    /// Store the overlay stack in egui's temporary storage
+    pub fn store(self, ctx: &Context) {
+        ctx.data_mut(|d| d.insert_temp(Self::storage_id(), self));
+    }
+
+    /// Register an open overlay for this frame
+    ///
+    /// Call this every frame the overlay is open. Overlays seen for the first time
+    /// are pushed on top; overlays that stop registering are dropped after a frame.
+    pub fn register(ctx: &Context, id: Id, kind: OverlayKind) -> Overlay {
+        let mut stack = Self::load(ctx);
+        stack.touch(id, kind, ctx.cumulative_pass_nr());
+        let overlay = stack.handle(id);
+        stack.store(ctx);
+        overlay
+    }
+
+    /// Remove an overlay from the stack (call when it closes)
+    pub fn remove(ctx: &Context, id: Id) {
+        let mut stack = Self::load(ctx);
+        stack.entries.retain(|e| e.id != id);
+        stack.store(ctx);
+    }
+
+    /// Id of the top-most open overlay
+    pub fn top(ctx: &Context) -> Option<Id> {
+        Self::load(ctx).entries.last().map(|e| e.id)
+    }
+
+    /// Whether `id` is the top-most open overlay
+    pub fn is_top(ctx: &Context, id: Id) -> bool {
+        Self::top(ctx) == Some(id)
+    }
+
+    /// Whether any overlay is open
+    pub fn any_open(ctx: &Context) -> bool {
+        !Self::load(ctx).entries.is_empty()
+    }
+
+    /// Number of overlays on the stack
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the stack is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Drop stale entries and insert or refresh `id` for the given pass
+    fn touch(&mut self, id: Id, kind: OverlayKind, pass: u64) {
+        // Keep overlays that registered this pass or the previous one
+        self.entries.retain(|e| e.last_pass + 1 >= pass);
+
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.id == id) {
+            entry.kind = kind;
+            entry.last_pass = pass;
+        } else {
+            self.entries.push(OverlayEntry { id, kind, last_pass: pass });
+        }
+    }
+
+    fn handle(&self, id: Id) -> Overlay {
+        let depth = self.entries.iter().position(|e| e.id == id).unwrap_or(0);
+        let entry = self.entries[depth];
+        let modal_depth = self.entries[..depth]
+            .iter()
+            .filter(|e| e.kind == OverlayKind::Modal)
+            .count();
+
+        Overlay {
+            id,
+            kind: entry.kind,
+            depth,
+            modal_depth,
+        }
+    }
+}
+
+/// Handle to an overlay registered on the [`OverlayStack`] for this frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overlay {
+    id: Id,
+    kind: OverlayKind,
+    depth: usize,
+    modal_depth: usize,
+}
+
+impl Overlay {
+    /// The id this overlay was registered with
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    /// The overlay kind
+    pub fn kind(&self) -> OverlayKind {
+        self.kind
+    }
+
+    /// Position on the stack (0 = bottom-most overlay)
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Number of modals open beneath this overlay
+    pub fn modal_depth(&self) -> usize {
+        self.modal_depth
+    }
+
+    /// Whether this is currently the top-most overlay
+    pub fn is_top(&self, ctx: &Context) -> bool {
+        OverlayStack::is_top(ctx, self.id)
+    }
+
+    /// Layer used for this overlay's backdrop
+    pub fn backdrop_layer(&self) -> LayerId {
+        LayerId::new(Order::Foreground, self.id.with("overlay_backdrop"))
+    }
+
+    /// Backdrop alpha after applying progressive dimming for nested modals
+    pub fn backdrop_alpha(&self, alpha: u8) -> u8 {
+        (alpha as f32 * NESTED_BACKDROP_FACTOR.powi(self.modal_depth as i32)).round() as u8
+    }
+
+    /// Backdrop color for this overlay given the un-nested alpha
+    pub fn backdrop_color(&self, alpha: u8) -> Color32 {
+        Color32::from_black_alpha(self.backdrop_alpha(alpha))
+    }
+
+    /// Paint the backdrop into [`Overlay::backdrop_layer`]
+    pub fn paint_backdrop(&self, ctx: &Context, rect: Rect, alpha: u8) {
+        ctx.layer_painter(self.backdrop_layer())
+            .rect_filled(rect, 0.0, self.backdrop_color(alpha));
+    }
+
+    /// Keep this overlay's layers ordered above the overlays beneath it
+    ///
+    /// `content_layer` is the layer of the overlay's `Area` (`response.layer_id`).
+    /// Modals also block interaction with every layer below their content.
+    pub fn raise(&self, ctx: &Context, content_layer: LayerId) {
+        let is_top = self.is_top(ctx);
+        ctx.memory_mut(|m| {
+            match self.kind {
+                OverlayKind::Modal => {
+                    // Content always renders directly above its own backdrop
+                    if content_layer.order == Order::Foreground {
+                        m.areas_mut().set_sublayer(self.backdrop_layer(), content_layer);
+                    }
+                    if is_top {
+                        m.areas_mut().move_to_top(self.backdrop_layer());
+                    }
+                    m.set_modal_layer(content_layer);
+                }
+                OverlayKind::Popover => {
+                    if is_top {
+                        m.areas_mut().move_to_top(content_layer);
+                    }
+                }
+            }
+        });
+    }
+
+    /// Whether `Escape` was pressed while this overlay is top-most
+    ///
+    /// The key press is consumed, so overlays beneath never see it.
+    pub fn escape_pressed(&self, ctx: &Context) -> bool {
+        self.is_top(ctx) && ctx.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Escape))
+    }
+
+    /// Whether the pointer was clicked outside `rect` while this overlay is top-most
+    ///
+    /// Only one overlay can be dismissed per click.
+    pub fn clicked_outside(&self, ctx: &Context, rect: Rect) -> bool {
+        let clicked_outside = ctx.input(|i| {
+            i.pointer.any_click()
+                && i.pointer.interact_pos().is_some_and(|p| !rect.contains(p))
+        });
+        clicked_outside && self.claim_click(ctx)
+    }
+
+    /// Claim this frame's click to dismiss the overlay
+    ///
+    /// Returns `false` if this overlay is not top-most or another overlay already
+    /// used the click. Use this with a backdrop response, e.g.
+    /// `backdrop.clicked() && overlay.claim_click(ctx)`.
+    pub fn claim_click(&self, ctx: &Context) -> bool {
+        if !self.is_top(ctx) {
+            return false;
+        }
+
+        let pass = ctx.cumulative_pass_nr();
+        let mut stack = OverlayStack::load(ctx);
+        if stack.click_consumed_pass == Some(pass) {
+            return false;
+        }
+        stack.click_consumed_pass = Some(pass);
+        stack.store(ctx);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_touch_pushes_new_overlays_on_top() {
+        let mut stack = OverlayStack::default();
+        stack.touch(Id::new("dialog"), OverlayKind::Modal, 1);
+        stack.touch(Id::new("popover"), OverlayKind::Popover, 1);
+
+        assert_eq!(stack.len(), 2);
+        assert_eq!(stack.entries.last().unwrap().id, Id::new("popover"));
+
+        // Re-registering the parent keeps the existing order
+        stack.touch(Id::new("dialog"), OverlayKind::Modal, 2);
+        assert_eq!(stack.entries.last().unwrap().id, Id::new("popover"));
+    }
+
+    #[test]
+    fn test_touch_prunes_stale_overlays() {
+        let mut stack = OverlayStack::default();
+        stack.touch(Id::new("sheet"), OverlayKind::Modal, 1);
+        stack.touch(Id::new("select"), OverlayKind::Popover, 1);
+
+        // Only the sheet keeps registering
+        stack.touch(Id::new("sheet"), OverlayKind::Modal, 2);
+        stack.touch(Id::new("sheet"), OverlayKind::Modal, 3);
+
+        assert_eq!(stack.len(), 1);
+        assert_eq!(stack.entries[0].id, Id::new("sheet"));
+    }
+
+    #[test]
+    fn test_handle_depths() {
+        let mut stack = OverlayStack::default();
+        stack.touch(Id::new("dialog"), OverlayKind::Modal, 1);
+        stack.touch(Id::new("popover"), OverlayKind::Popover, 1);
+        stack.touch(Id::new("sheet"), OverlayKind::Modal, 1);
+
+        let dialog = stack.handle(Id::new("dialog"));
+        let sheet = stack.handle(Id::new("sheet"));
+        assert_eq!(dialog.depth(), 0);
+        assert_eq!(dialog.modal_depth(), 0);
+        assert_eq!(sheet.depth(), 2);
+        assert_eq!(sheet.modal_depth(), 1);
+    }
+
+    #[test]
+    fn test_nested_backdrops_dim_progressively() {
+        let mut stack = OverlayStack::default();
+        stack.touch(Id::new("a"), OverlayKind::Modal, 1);
+        stack.touch(Id::new("b"), OverlayKind::Modal, 1);
+        stack.touch(Id::new("c"), OverlayKind::Modal, 1);
+
+        let a = stack.handle(Id::new("a")).backdrop_alpha(128);
+        let b = stack.handle(Id::new("b")).backdrop_alpha(128);
+        let c = stack.handle(Id::new("c")).backdrop_alpha(128);
+        assert_eq!(a, 128);
+        assert!(b < a && b > 0);
+        assert!(c < b && c > 0);
+    }
+
+    #[test]
+    fn test_register_and_remove() {
+        let ctx = Context::default();
+        let dialog = Id::new("dialog");
+        let popover = Id::new("popover");
+
+        OverlayStack::register(&ctx, dialog, OverlayKind::Modal);
+        OverlayStack::register(&ctx, popover, OverlayKind::Popover);
+        assert!(OverlayStack::is_top(&ctx, popover));
+        assert!(!OverlayStack::is_top(&ctx, dialog));
+
+        OverlayStack::remove(&ctx, popover);
+        assert!(OverlayStack::is_top(&ctx, dialog));
+
+        OverlayStack::remove(&ctx, dialog);
+        assert!(!OverlayStack::any_open(&ctx));
+    }
+}
@@ -0,0 +1,659 @@
+//! Keyboard shortcut registry
+//!
+//! Apps register each shortcut once with an action id and a scope. Every
+//! frame [`ShortcutRegistry::dispatch`] returns the action whose shortcut was
+//! pressed, looking in the focused scope before the global one. Registering a
+//! shortcut already bound in the same scope is reported as a conflict.
+//!
+//! Scopes are regions of the UI wrapped in [`ShortcutRegistry::scope`]; the
+//! scope holding keyboard focus (or clicked last) is the focused one.
+//!
+//! Menus and the command palette register their item shortcuts here too,
+//! each in its own [`ShortcutScope::Menu`], so they are listed in the help
+//! dialog and give way to the app's own shortcuts for the same keys.
+//!
+//! ## Example
+//! ```rust,ignore
+//! use egui::{Key, KeyboardShortcut, Modifiers};
+//!
+//! // Once, at startup
+//! ShortcutRegistry::register(ctx, Shortcut::new("save", "Save", KeyboardShortcut::new(Modifiers::COMMAND, Key::S)))?;
+//! ShortcutRegistry::register(ctx, Shortcut::new("submit", "Send note", KeyboardShortcut::new(Modifiers::COMMAND, Key::Enter))
+//!     .scope(ShortcutScope::named("Composer")))?;
+//!
+//! // Every frame
+//! match ShortcutRegistry::dispatch(ctx).as_deref() {
+//!     Some("save") => save(),
+//!     Some("submit") => send_note(),
+//!     _ => {}
+//! }
+//! ShortcutRegistry::scope(ui, ShortcutScope::named("Composer"), |ui| composer(ui));
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use egui::{Context, Event, Id, InnerResponse, KeyboardShortcut, Ui};
+use crate::components::dialog::Dialog;
+use crate::components::kbd::Kbd;
+use crate::theme::ShadcnTheme;
+
+/// Where a shortcut applies
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ShortcutScope {
+    /// Everywhere, unless the focused scope binds the same keys
+    Global,
+    /// Only while the named region has focus
+    Named(String),
+    /// Items of a menu or command palette, active wherever it is shown
+    ///
+    /// Registered by the component every frame it is shown and dropped once
+    /// it is not, with the item's position in the menu as the action. Global
+    /// shortcuts and earlier menus win on the same keys.
+    Menu(String),
+}
+
+impl ShortcutScope {
+    /// A named scope
+    pub fn named(name: impl Into<String>) -> Self {
+        Self::Named(name.into())
+    }
+
+    /// Name shown in the help dialog
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Global => "Global",
+            Self::Named(name) | Self::Menu(name) => name,
+        }
+    }
+
+    /// Precedence when dispatching (lower first); `None` while inactive
+    fn tier(&self, focused: Option<&ShortcutScope>) -> Option<u8> {
+        match self {
+            scope if focused == Some(scope) => Some(0),
+            Self::Global => Some(1),
+            Self::Menu(_) => Some(2),
+            Self::Named(_) => None,
+        }
+    }
+}
+
+/// A registered shortcut
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shortcut {
+    /// Action id returned by [`ShortcutRegistry::dispatch`]
+    pub action: String,
+    /// Description shown in the help dialog
+    pub label: String,
+    /// The key combination
+    pub keys: KeyboardShortcut,
+    /// Where the shortcut applies
+    pub scope: ShortcutScope,
+}
+
+impl Shortcut {
+    /// Create a global shortcut
+    pub fn new(action: impl Into<String>, label: impl Into<String>, keys: KeyboardShortcut) -> Self {
+        Self {
+            action: action.into(),
+            label: label.into(),
+            keys,
+            scope: ShortcutScope::Global,
+        }
+    }
+
+    /// Set the scope (default: global)
+    pub fn scope(mut self, scope: ShortcutScope) -> Self {
+        self.scope = scope;
+        self
+    }
+}
+
+/// Keys bound to two actions that are active at the same time
+///
+/// Returned when registering keys already bound in the same scope, and
+/// listed by [`ShortcutRegistry::conflicts`] for menu items.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShortcutConflict {
+    /// The contested key combination
+    pub keys: KeyboardShortcut,
+    /// Scope the keys are already bound in
+    pub scope: ShortcutScope,
+    /// Action already bound to the keys
+    pub existing: String,
+    /// Action that failed to register
+    pub action: String,
+}
+
+impl fmt::Display for ShortcutConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} is already bound to {:?} in scope {:?}, cannot bind it to {:?}",
+            format_keys(&self.keys, ShortcutPlatform::Other),
+            self.existing,
+            self.scope.name(),
+            self.action,
+        )
+    }
+}
+
+impl std::error::Error for ShortcutConflict {}
+
+/// How key combinations are spelled out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShortcutPlatform {
+    /// Modifier symbols in macOS order (⌃⌥⇧⌘)
+    Mac,
+    /// Modifier names (Ctrl+Alt+Shift)
+    Other,
+}
+
+impl ShortcutPlatform {
+    /// The platform used to render shortcuts in `ctx`
+    ///
+    /// Follows the OS unless overridden with [`ShortcutRegistry::set_platform`].
+    pub fn of(ctx: &Context) -> Self {
+        ShortcutRegistry::load(ctx).platform.unwrap_or_else(|| {
+            if ctx.os().is_mac() {
+                Self::Mac
+            } else {
+                Self::Other
+            }
+        })
+    }
+}
+
+/// The keys of `shortcut`, one entry per key cap
+///
+/// `Modifiers::COMMAND` is ⌘ on Mac and Ctrl elsewhere.
+pub fn shortcut_keys(shortcut: &KeyboardShortcut, platform: ShortcutPlatform) -> Vec<String> {
+    let m = shortcut.modifiers;
+    let mut keys = Vec::new();
+    match platform {
+        ShortcutPlatform::Mac => {
+            keys.extend(m.ctrl.then_some("⌃"));
+            keys.extend(m.alt.then_some("⌥"));
+            keys.extend(m.shift.then_some("⇧"));
+            keys.extend((m.mac_cmd || m.command).then_some("⌘"));
+        }
+        ShortcutPlatform::Other => {
+            keys.extend((m.ctrl || m.command).then_some("Ctrl"));
+            keys.extend(m.mac_cmd.then_some("Cmd"));
+            keys.extend(m.alt.then_some("Alt"));
+            keys.extend(m.shift.then_some("Shift"));
+        }
+    }
+    keys.push(shortcut.logical_key.symbol_or_name());
+    keys.into_iter().map(str::to_owned).collect()
+}
+
+/// `shortcut` as one string: `⇧⌘Z` on Mac, `Ctrl+Shift+Z` elsewhere
+pub fn format_keys(shortcut: &KeyboardShortcut, platform: ShortcutPlatform) -> String {
+    let separator = match platform {
+        ShortcutPlatform::Mac => "",
+        ShortcutPlatform::Other => "+",
+    };
+    shortcut_keys(shortcut, platform).join(separator)
+}
+
+/// `shortcut` as one string for the platform of `ctx`
+pub fn format_shortcut(ctx: &Context, shortcut: &KeyboardShortcut) -> String {
+    format_keys(shortcut, ShortcutPlatform::of(ctx))
+}
+
+/// The first of `candidates` pressed this frame, without consuming it
+///
+/// Ctrl+Shift+Z also matches a Ctrl+Z shortcut, so candidates with more
+/// modifiers are tried first; ties keep their order.
+fn pressed<'s>(ctx: &Context, candidates: &[&'s Shortcut]) -> Option<&'s Shortcut> {
+    let mut order = candidates.to_vec();
+    order.sort_by_key(|s| std::cmp::Reverse(s.keys.modifiers.shift as u8 + s.keys.modifiers.alt as u8));
+    ctx.input(|input| {
+        order.into_iter().find(|s| {
+            input.events.iter().any(|event| {
+                matches!(
+                    event,
+                    Event::Key { key, modifiers, pressed: true, .. }
+                        if *key == s.keys.logical_key && modifiers.matches_logically(s.keys.modifiers)
+                )
+            })
+        })
+    })
+}
+
+/// Registered shortcuts and the focused scope
+///
+/// Stored in egui's temporary memory, like the
+/// [`OverlayStack`](crate::overlay::OverlayStack).
+#[derive(Debug, Clone, Default)]
+pub struct ShortcutRegistry {
+    shortcuts: Vec<Shortcut>,
+    focused: Option<ShortcutScope>,
+    platform: Option<ShortcutPlatform>,
+    /// Frame each menu scope was last synced in
+    menus_seen: HashMap<ShortcutScope, u64>,
+}
+
+impl ShortcutRegistry {
+    fn storage_id() -> Id {
+        Id::new("shadcn_shortcut_registry")
+    }
+
+    /// Load the registry from egui's temporary storage
+    ///
+    /// Menus not synced this frame or the last are left out.
+    pub fn load(ctx: &Context) -> Self {
+        let mut registry: Self = ctx.data(|d| d.get_temp(Self::storage_id()).unwrap_or_default());
+        registry.drop_stale_menus(ctx.cumulative_frame_nr());
+        registry
+    }
+
+    /// Forget the shortcuts of menus last synced before the previous frame
+    fn drop_stale_menus(&mut self, frame: u64) {
+        self.menus_seen.retain(|_, seen| *seen + 1 >= frame);
+        let seen = &self.menus_seen;
+        self.shortcuts
+            .retain(|s| !matches!(s.scope, ShortcutScope::Menu(_)) || seen.contains_key(&s.scope));
+    }
+
+    /// Store the registry in egui's temporary storage
+    pub fn store(self, ctx: &Context) {
+        ctx.data_mut(|d| d.insert_temp(Self::storage_id(), self));
+    }
+
+    /// Register `shortcut` in the registry of `ctx`
+    ///
+    /// Registering the same shortcut again is a no-op, so this may be called
+    /// every frame.
+    pub fn register(ctx: &Context, shortcut: Shortcut) -> Result<(), ShortcutConflict> {
+        let mut registry = Self::load(ctx);
+        registry.insert(shortcut)?;
+        registry.store(ctx);
+        Ok(())
+    }
+
+    /// Remove every shortcut bound to `action`
+    pub fn unregister(ctx: &Context, action: &str) {
+        let mut registry = Self::load(ctx);
+        registry.shortcuts.retain(|s| s.action != action);
+        registry.store(ctx);
+    }
+
+    /// Add `shortcut`, failing if its keys are bound to another action in its scope
+    pub fn insert(&mut self, shortcut: Shortcut) -> Result<(), ShortcutConflict> {
+        let bound = self
+            .shortcuts
+            .iter()
+            .find(|s| s.scope == shortcut.scope && s.keys == shortcut.keys);
+        match bound {
+            Some(existing) if existing.action == shortcut.action => Ok(()),
+            Some(existing) => Err(ShortcutConflict {
+                keys: shortcut.keys,
+                scope: shortcut.scope,
+                existing: existing.action.clone(),
+                action: shortcut.action,
+            }),
+            None => {
+                self.shortcuts.push(shortcut);
+                Ok(())
+            }
+        }
+    }
+
+    /// All registered shortcuts, in registration order
+    pub fn shortcuts(&self) -> &[Shortcut] {
+        &self.shortcuts
+    }
+
+    /// The scope whose shortcuts take precedence, if any
+    pub fn focused_scope(&self) -> Option<&ShortcutScope> {
+        self.focused.as_ref()
+    }
+
+    /// Focus `scope` directly (`None` leaves only global shortcuts active)
+    pub fn focus_scope(ctx: &Context, scope: Option<ShortcutScope>) {
+        let mut registry = Self::load(ctx);
+        registry.focused = scope;
+        registry.store(ctx);
+    }
+
+    /// Render shortcuts for `platform` instead of the OS (`None` follows the OS)
+    ///
+    /// Handy for screenshots and tests.
+    pub fn set_platform(ctx: &Context, platform: Option<ShortcutPlatform>) {
+        let mut registry = Self::load(ctx);
+        registry.platform = platform;
+        registry.store(ctx);
+    }
+
+    /// Shortcuts of menus bound to keys an earlier active shortcut already binds
+    ///
+    /// Menus register their items every frame and have no caller to return
+    /// an error to, so their conflicts are collected here. The earlier
+    /// binding (a global shortcut, or the menu registered first) wins.
+    pub fn conflicts(&self) -> Vec<ShortcutConflict> {
+        self.shortcuts
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| matches!(s.scope, ShortcutScope::Menu(_)))
+            .filter_map(|(index, shortcut)| {
+                let existing = self.shortcuts.iter().enumerate().find(|&(i, s)| {
+                    s.keys == shortcut.keys
+                        && (s.scope == ShortcutScope::Global || (matches!(s.scope, ShortcutScope::Menu(_)) && i < index))
+                })?;
+                Some(ShortcutConflict {
+                    keys: shortcut.keys,
+                    scope: existing.1.scope.clone(),
+                    existing: existing.1.action.clone(),
+                    action: shortcut.action.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// The shortcut that fires for this frame's key presses, if any
+    ///
+    /// The focused scope comes first, so it can override global shortcuts,
+    /// then global shortcuts and then menus; other named scopes are ignored.
+    fn pressed(&self, ctx: &Context) -> Option<&Shortcut> {
+        let focused = self.focused.as_ref();
+        let mut active: Vec<&Shortcut> = self.shortcuts.iter().filter(|s| s.scope.tier(focused).is_some()).collect();
+        active.sort_by_key(|s| s.scope.tier(focused));
+        pressed(ctx, &active)
+    }
+
+    /// Consume the shortcut pressed this frame and return its action
+    ///
+    /// The focused scope is searched first, so it can override global
+    /// shortcuts; shortcuts of other named scopes are ignored, and those of
+    /// menus are left for the menu to consume.
+    pub fn dispatch(ctx: &Context) -> Option<String> {
+        let registry = Self::load(ctx);
+        let shortcut = registry.pressed(ctx).filter(|s| !matches!(s.scope, ShortcutScope::Menu(_)))?;
+        ctx.input_mut(|i| i.consume_shortcut(&shortcut.keys));
+        Some(shortcut.action.clone())
+    }
+
+    /// Replace the shortcuts of the menu `scope` with `shortcuts`
+    ///
+    /// The scope keeps its place in the registry, so the precedence between
+    /// menus stays the same from frame to frame. A menu that stops syncing
+    /// is dropped after a frame.
+    pub(crate) fn sync_menu(ctx: &Context, scope: &ShortcutScope, shortcuts: Vec<Shortcut>) {
+        let mut registry = Self::load(ctx);
+        registry.menus_seen.insert(scope.clone(), ctx.cumulative_frame_nr());
+        let shortcuts: Vec<Shortcut> = shortcuts.into_iter().map(|s| s.scope(scope.clone())).collect();
+        let registered: Vec<&Shortcut> = registry.shortcuts.iter().filter(|s| &s.scope == scope).collect();
+        if registered.len() == shortcuts.len() && registered.iter().zip(&shortcuts).all(|(a, b)| *a == b) {
+            registry.store(ctx);
+            return;
+        }
+        let at = registry
+            .shortcuts
+            .iter()
+            .position(|s| &s.scope == scope)
+            .unwrap_or(registry.shortcuts.len());
+        registry.shortcuts.retain(|s| &s.scope != scope);
+        registry.shortcuts.splice(at..at, shortcuts);
+        registry.store(ctx);
+    }
+
+    /// Consume the shortcut of the menu `scope` pressed this frame
+    ///
+    /// Returns its index among the shortcuts passed to
+    /// [`ShortcutRegistry::sync_menu`]. Keys another active scope takes are
+    /// left alone.
+    pub(crate) fn dispatch_menu(ctx: &Context, scope: &ShortcutScope) -> Option<usize> {
+        let registry = Self::load(ctx);
+        let shortcut = registry.pressed(ctx).filter(|s| &s.scope == scope)?;
+        ctx.input_mut(|i| i.consume_shortcut(&shortcut.keys));
+        registry
+            .shortcuts
+            .iter()
+            .filter(|s| &s.scope == scope)
+            .position(|s| std::ptr::eq(s, shortcut))
+    }
+
+    /// Show `add_contents` as the region of `scope`
+    ///
+    /// The scope becomes focused when a widget inside it has keyboard focus
+    /// or the region is clicked, and stays focused until another scope is.
+    pub fn scope<R>(ui: &mut Ui, scope: ShortcutScope, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        let inner = ui.scope(add_contents);
+        let rect = inner.response.rect;
+        let ctx = ui.ctx();
+
+        let focus_inside = ctx
+            .memory(|m| m.focused())
+            .and_then(|id| ctx.read_response(id))
+            .is_some_and(|focused| focused.layer_id == ui.layer_id() && rect.contains_rect(focused.rect));
+        let pressed_inside = ctx.input(|i| {
+            i.pointer.any_pressed() && i.pointer.interact_pos().is_some_and(|pos| rect.contains(pos))
+        }) && ctx.layer_id_at(ctx.pointer_interact_pos().unwrap_or_default()) == Some(ui.layer_id());
+
+        if focus_inside || pressed_inside {
+            let mut registry = Self::load(ctx);
+            if registry.focused.as_ref() != Some(&scope) {
+                registry.focused = Some(scope);
+                registry.store(ctx);
+            }
+        }
+        inner
+    }
+}
+
+/// Show the "Keyboard shortcuts" dialog listing every registered shortcut
+///
+/// Shortcuts are grouped by scope, global ones first.
+pub fn shortcuts_dialog(ctx: &Context, id: impl Into<Id>, open: &mut bool) {
+    let registry = ShortcutRegistry::load(ctx);
+    let theme = ctx.data(|d| {
+        d.get_temp::<ShadcnTheme>(Id::new("shadcn_theme"))
+            .unwrap_or_else(ShadcnTheme::light)
+    });
+
+    let mut scopes: Vec<&ShortcutScope> = vec![&ShortcutScope::Global];
+    for shortcut in registry.shortcuts() {
+        if !scopes.contains(&&shortcut.scope) {
+            scopes.push(&shortcut.scope);
+        }
+    }
+
+    Dialog::new(id)
+        .title("Keyboard shortcuts")
+        .max_width(480.0)
+        .show(ctx, open, |ui| {
+            if registry.shortcuts().is_empty() {
+                ui.label(egui::RichText::new("No shortcuts registered").color(theme.colors.muted_foreground));
+                return;
+            }
+            egui::ScrollArea::vertical().max_height(400.0).show(ui, |ui| {
+                for scope in scopes {
+                    let mut in_scope = registry.shortcuts().iter().filter(|s| &s.scope == scope).peekable();
+                    if in_scope.peek().is_none() {
+                        continue;
+                    }
+                    ui.add_space(theme.spacing.sm);
+                    ui.label(
+                        egui::RichText::new(scope.name())
+                            .size(theme.typography.small().size)
+                            .strong()
+                            .color(theme.colors.muted_foreground),
+                    );
+                    for shortcut in in_scope {
+                        ui.horizontal(|ui| {
+                            ui.label(&shortcut.label);
+                            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                ui.add(Kbd::shortcut(shortcut.keys));
+                            });
+                        });
+                    }
+                }
+            });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{context_with_motion, key_press as press, run_frame_with_events};
+    use crate::theme::MotionPreference;
+    use egui::{Key, Modifiers};
+
+    fn dispatch(ctx: &Context, events: Vec<Event>) -> Option<String> {
+        let mut action = None;
+        run_frame_with_events(ctx, 0.0, events, |_| action = ShortcutRegistry::dispatch(ctx));
+        action
+    }
+
+    #[test]
+    fn test_conflicts() {
+        let mut registry = ShortcutRegistry::default();
+        let save = KeyboardShortcut::new(Modifiers::COMMAND, Key::S);
+        assert!(registry.insert(Shortcut::new("save", "Save", save)).is_ok());
+        // Registering again is fine, other scopes may reuse the keys
+        assert!(registry.insert(Shortcut::new("save", "Save", save)).is_ok());
+        assert!(registry.insert(Shortcut::new("send", "Send", save).scope(ShortcutScope::named("Composer"))).is_ok());
+
+        let conflict = registry.insert(Shortcut::new("search", "Search", save)).unwrap_err();
+        assert_eq!(conflict.existing, "save");
+        assert_eq!(conflict.action, "search");
+        assert_eq!(
+            conflict.to_string(),
+            "Ctrl+S is already bound to \"save\" in scope \"Global\", cannot bind it to \"search\""
+        );
+        assert_eq!(registry.shortcuts().len(), 2);
+    }
+
+    #[test]
+    fn test_dispatch_prefers_focused_scope() {
+        let ctx = context_with_motion(MotionPreference::Full);
+        let enter = KeyboardShortcut::new(Modifiers::COMMAND, Key::Enter);
+        ShortcutRegistry::register(&ctx, Shortcut::new("open", "Open", enter)).unwrap();
+        ShortcutRegistry::register(&ctx, Shortcut::new("send", "Send", enter).scope(ShortcutScope::named("Composer"))).unwrap();
+        ShortcutRegistry::register(&ctx, Shortcut::new("bold", "Bold", KeyboardShortcut::new(Modifiers::COMMAND, Key::B))
+            .scope(ShortcutScope::named("Composer"))).unwrap();
+
+        assert_eq!(dispatch(&ctx, vec![press(Key::Enter, Modifiers::COMMAND)]).as_deref(), Some("open"));
+        // Other scopes' shortcuts are inactive
+        assert_eq!(dispatch(&ctx, vec![press(Key::B, Modifiers::COMMAND)]), None);
+
+        ShortcutRegistry::focus_scope(&ctx, Some(ShortcutScope::named("Composer")));
+        assert_eq!(dispatch(&ctx, vec![press(Key::Enter, Modifiers::COMMAND)]).as_deref(), Some("send"));
+        assert_eq!(dispatch(&ctx, vec![press(Key::B, Modifiers::COMMAND)]).as_deref(), Some("bold"));
+    }
+
+    #[test]
+    fn test_dispatch_prefers_more_modifiers() {
+        let ctx = context_with_motion(MotionPreference::Full);
+        ShortcutRegistry::register(&ctx, Shortcut::new("undo", "Undo", KeyboardShortcut::new(Modifiers::COMMAND, Key::Z))).unwrap();
+        ShortcutRegistry::register(&ctx, Shortcut::new("redo", "Redo", KeyboardShortcut::new(Modifiers::COMMAND | Modifiers::SHIFT, Key::Z))).unwrap();
+
+        assert_eq!(dispatch(&ctx, vec![press(Key::Z, Modifiers::COMMAND | Modifiers::SHIFT)]).as_deref(), Some("redo"));
+        assert_eq!(dispatch(&ctx, vec![press(Key::Z, Modifiers::COMMAND)]).as_deref(), Some("undo"));
+    }
+
+    #[test]
+    fn test_menu_shortcuts_yield_to_app_shortcuts() {
+        let ctx = context_with_motion(MotionPreference::Full);
+        let command = |key| KeyboardShortcut::new(Modifiers::COMMAND, key);
+        ShortcutRegistry::register(&ctx, Shortcut::new("save", "Save", command(Key::S))).unwrap();
+        ShortcutRegistry::register(&ctx, Shortcut::new("bold", "Bold", command(Key::B))
+            .scope(ShortcutScope::named("Composer"))).unwrap();
+
+        let file = ShortcutScope::Menu("File".into());
+        let menu = || vec![
+            Shortcut::new("Save", "Save", command(Key::S)),
+            Shortcut::new("Open", "Open", command(Key::O)),
+            Shortcut::new("Bookmark", "Bookmark", command(Key::B)),
+        ];
+        let frame = |events: Vec<Event>| {
+            let mut fired = (None, None);
+            run_frame_with_events(&ctx, 0.0, events, |_| {
+                ShortcutRegistry::sync_menu(&ctx, &file, menu());
+                fired = (ShortcutRegistry::dispatch_menu(&ctx, &file), ShortcutRegistry::dispatch(&ctx));
+            });
+            fired
+        };
+
+        assert_eq!(frame(vec![press(Key::O, Modifiers::COMMAND)]), (Some(1), None));
+        // Global shortcuts win over menus, the focused scope over both
+        assert_eq!(frame(vec![press(Key::S, Modifiers::COMMAND)]), (None, Some("save".into())));
+        assert_eq!(frame(vec![press(Key::B, Modifiers::COMMAND)]), (Some(2), None));
+        ShortcutRegistry::focus_scope(&ctx, Some(ShortcutScope::named("Composer")));
+        assert_eq!(frame(vec![press(Key::B, Modifiers::COMMAND)]), (None, Some("bold".into())));
+
+        // Syncing again keeps one copy; the clash with "save" is reported
+        let registry = ShortcutRegistry::load(&ctx);
+        assert_eq!(registry.shortcuts().iter().filter(|s| s.scope == file).count(), 3);
+        let conflicts = registry.conflicts();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!((conflicts[0].existing.as_str(), conflicts[0].action.as_str()), ("save", "Save"));
+    }
+
+    #[test]
+    fn test_hidden_menus_are_dropped() {
+        let ctx = context_with_motion(MotionPreference::Full);
+        let open = KeyboardShortcut::new(Modifiers::COMMAND, Key::O);
+        let file = ShortcutScope::Menu("File".into());
+        let recent = ShortcutScope::Menu("Recent".into());
+        let frame = |menus: &[&ShortcutScope]| {
+            run_frame_with_events(&ctx, 0.0, Vec::new(), |_| {
+                for scope in menus {
+                    ShortcutRegistry::sync_menu(&ctx, scope, vec![Shortcut::new("0", "Open", open)]);
+                }
+            });
+        };
+        let listed = || {
+            let registry = ShortcutRegistry::load(&ctx);
+            (registry.shortcuts().iter().map(|s| s.scope.name().to_string()).collect::<Vec<_>>(), registry.conflicts().len())
+        };
+
+        frame(&[&file, &recent]);
+        assert_eq!(listed(), (vec!["File".to_string(), "Recent".to_string()], 1));
+        frame(&[&recent]);
+        frame(&[&recent]);
+        // "File" is gone, so "Recent" no longer clashes with it
+        assert_eq!(listed(), (vec!["Recent".to_string()], 0));
+    }
+
+    #[test]
+    fn test_scope_focus_follows_keyboard_focus() {
+        let ctx = context_with_motion(MotionPreference::Full);
+        let frame = |events: Vec<Event>| {
+            run_frame_with_events(&ctx, 0.0, events, |ui| {
+                ShortcutRegistry::scope(ui, ShortcutScope::named("Timeline"), |ui| {
+                    let _ = ui.button("Like");
+                });
+                ShortcutRegistry::scope(ui, ShortcutScope::named("Composer"), |ui| {
+                    let _ = ui.button("Send");
+                });
+            });
+        };
+
+        frame(Vec::new());
+        assert_eq!(ShortcutRegistry::load(&ctx).focused_scope(), None);
+        frame(vec![press(Key::Tab, Modifiers::NONE)]);
+        frame(Vec::new());
+        assert_eq!(ShortcutRegistry::load(&ctx).focused_scope(), Some(&ShortcutScope::named("Timeline")));
+        frame(vec![press(Key::Tab, Modifiers::NONE)]);
+        frame(Vec::new());
+        assert_eq!(ShortcutRegistry::load(&ctx).focused_scope(), Some(&ShortcutScope::named("Composer")));
+    }
+
+    #[test]
+    fn test_platform_formatting() {
+        let redo = KeyboardShortcut::new(Modifiers::COMMAND | Modifiers::SHIFT, Key::Z);
+        assert_eq!(shortcut_keys(&redo, ShortcutPlatform::Mac), vec!["⇧", "⌘", "Z"]);
+        assert_eq!(format_keys(&redo, ShortcutPlatform::Mac), "⇧⌘Z");
+        assert_eq!(format_keys(&redo, ShortcutPlatform::Other), "Ctrl+Shift+Z");
+        let ctrl_alt = KeyboardShortcut::new(Modifiers::CTRL | Modifiers::ALT, Key::Delete);
+        assert_eq!(format_keys(&ctrl_alt, ShortcutPlatform::Mac), "⌃⌥Delete");
+
+        let ctx = context_with_motion(MotionPreference::Full);
+        ShortcutRegistry::set_platform(&ctx, Some(ShortcutPlatform::Mac));
+        assert_eq!(format_shortcut(&ctx, &redo), "⇧⌘Z");
+        ShortcutRegistry::set_platform(&ctx, Some(ShortcutPlatform::Other));
+        assert_eq!(format_shortcut(&ctx, &redo), "Ctrl+Shift+Z");
+    }
+}
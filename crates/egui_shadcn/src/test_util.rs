@@ -0,0 +1,102 @@
+//! Helpers for running components in a headless egui context in unit tests
+
+use std::time::Duration;
+
+use egui::{Context, Event, FullOutput, Key, Modifiers, PointerButton, Pos2, RawInput, Rect, Ui, Vec2};
+
+use crate::theme::{MotionPreference, ShadcnTheme};
+
+/// A context with the light theme applied using `motion`
+pub(crate) fn context_with_motion(motion: MotionPreference) -> Context {
+    let ctx = Context::default();
+    ShadcnTheme::light().with_motion(motion).apply(&ctx);
+    ctx
+}
+
+/// Run one frame at `time` seconds with `add_contents` inside a central panel
+pub(crate) fn run_frame(ctx: &Context, time: f64, add_contents: impl FnMut(&mut Ui)) -> FullOutput {
+    run_frame_with_events(ctx, time, Vec::new(), add_contents)
+}
+
+/// Like [`run_frame`], delivering `events` as this frame's input
+pub(crate) fn run_frame_with_events(
+    ctx: &Context,
+    time: f64,
+    events: Vec<Event>,
+    mut add_contents: impl FnMut(&mut Ui),
+) -> FullOutput {
+    let input = RawInput {
+        time: Some(time),
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, Vec2::new(800.0, 600.0))),
+        events,
+        ..Default::default()
+    };
+    ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| add_contents(ui));
+    })
+}
+
+/// A key press
+pub(crate) fn key_press(key: Key, modifiers: Modifiers) -> Event {
+    Event::Key { key, physical_key: None, pressed: true, repeat: false, modifiers }
+}
+
+/// Moving the pointer to `pos` and pressing the primary button
+pub(crate) fn pointer_press(pos: Pos2) -> Vec<Event> {
+    vec![Event::PointerMoved(pos), primary_button(pos, true)]
+}
+
+/// Releasing the primary button at `pos`
+pub(crate) fn pointer_release(pos: Pos2) -> Event {
+    primary_button(pos, false)
+}
+
+/// Moving to `pos`, pressing and releasing the primary button within one frame
+pub(crate) fn click_events(pos: Pos2) -> Vec<Event> {
+    let mut events = pointer_press(pos);
+    events.push(pointer_release(pos));
+    events
+}
+
+/// Click at `pos` over two frames starting at `time`: press, then release
+pub(crate) fn click(ctx: &Context, time: f64, pos: Pos2, mut add_contents: impl FnMut(&mut Ui)) {
+    run_frame_with_events(ctx, time, pointer_press(pos), &mut add_contents);
+    run_frame_with_events(ctx, time + 0.05, vec![pointer_release(pos)], &mut add_contents);
+}
+
+/// Center of the widget `id` drawn last frame
+pub(crate) fn center(ctx: &Context, id: egui::Id) -> Pos2 {
+    ctx.read_response(id).expect("widget was not drawn").rect.center()
+}
+
+fn primary_button(pos: Pos2, pressed: bool) -> Event {
+    Event::PointerButton { pos, button: PointerButton::Primary, pressed, modifiers: Modifiers::NONE }
+}
+
+/// Run `add_contents` at 0.0, 0.2 and 0.6 seconds under `motion`, returning whether the
+/// last two frames painted differently and whether the last one asked for a repaint
+pub(crate) fn looping_frames(motion: MotionPreference, mut add_contents: impl FnMut(&mut Ui)) -> (bool, bool) {
+    let ctx = context_with_motion(motion);
+    let frames = [0.0, 0.2, 0.6].map(|time| run_frame(&ctx, time, &mut add_contents));
+    (frames[1].shapes != frames[2].shapes, repaint_requested(&frames[2]))
+}
+
+/// Show `add_contents(ui, false)` for a frame, then `add_contents(ui, true)` for two
+/// seconds of 60 Hz frames under `motion`, returning whether the first frame after the
+/// change painted differently from the last and whether it asked for a repaint
+pub(crate) fn transition_frames(motion: MotionPreference, mut add_contents: impl FnMut(&mut Ui, bool)) -> (bool, bool) {
+    let ctx = context_with_motion(motion);
+    run_frame(&ctx, 0.0, |ui| add_contents(ui, false));
+    let frames: Vec<FullOutput> = (1..=120)
+        .map(|frame| run_frame(&ctx, frame as f64 / 60.0, |ui| add_contents(ui, true)))
+        .collect();
+    (frames[0].shapes != frames[119].shapes, repaint_requested(&frames[0]))
+}
+
+/// Whether the frame asked to be repainted immediately (i.e. it is animating)
+pub(crate) fn repaint_requested(output: &FullOutput) -> bool {
+    output
+        .viewport_output
+        .values()
+        .any(|viewport| viewport.repaint_delay == Duration::ZERO)
+}
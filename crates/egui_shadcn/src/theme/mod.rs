@@ -6,6 +6,7 @@
 //! - Typography (font sizes and weights) ✓
 //! - Corner radii (border radius values) ✓
 //! - Shadows (elevation system) ✓
+//! - Motion (reduced-motion preference) ✓
 //!
 //! The theme can be applied to an egui context to style all components consistently.
 
@@ -14,12 +15,14 @@ pub mod spacing;
 pub mod typography;
 pub mod radii;
 pub mod shadows;
+pub mod motion;
 
 pub use colors::ShadcnColors;
 pub use spacing::ShadcnSpacing;
 pub use typography::ShadcnTypography;
 pub use radii::ShadcnRadii;
 pub use shadows::ShadcnShadows;
+pub use motion::MotionPreference;
 
 /// The main shadcn theme struct that aggregates all design tokens
 ///
@@ -29,6 +32,7 @@ pub use shadows::ShadcnShadows;
 /// - Typography: Tailwind font size scale with semantic names
 /// - Corner radii: Tailwind border-radius scale for rounded corners
 /// - Shadows: Tailwind box-shadow scale for elevation and depth
+/// - Motion: how much animation components may use
 #[derive(Debug, Clone)]
 pub struct ShadcnTheme {
     /// Semantic color palette
@@ -41,6 +45,8 @@ pub struct ShadcnTheme {
     pub radii: ShadcnRadii,
     /// Shadow/elevation scale based on Tailwind box-shadows
     pub shadows: ShadcnShadows,
+    /// Motion preference honored by every animated component
+    pub motion: MotionPreference,
 }
 
 impl Default for ShadcnTheme {
@@ -63,6 +69,7 @@ impl ShadcnTheme {
             typography: ShadcnTypography::new(),
             radii: ShadcnRadii::new(),
             shadows: ShadcnShadows::light(),
+            motion: MotionPreference::Full,
         }
     }
 
@@ -74,9 +81,16 @@ impl ShadcnTheme {
             typography: ShadcnTypography::new(),
             radii: ShadcnRadii::new(),
             shadows: ShadcnShadows::dark(),
+            motion: MotionPreference::Full,
         }
     }
 
+    /// Set the motion preference (e.g. from the OS reduced-motion setting)
+    pub fn with_motion(mut self, motion: MotionPreference) -> Self {
+        self.motion = motion;
+        self
+    }
+
     /// Lighten a color by a factor (0.0 to 1.0)
     fn lighten_color(&self, color: egui::Color32, factor: f32) -> egui::Color32 {
         let [r, g, b, a] = color.to_array();
@@ -153,6 +167,11 @@ impl ShadcnTheme {
         // Indent for collapsing sections
         style.spacing.indent = self.spacing.md_lg; // 20px
 
+        // Built-in egui animations (collapsing headers, scroll) follow the motion preference
+        if !self.motion.animates() {
+            style.animation_time = 0.0;
+        }
+
         // Apply shadcn corner radii to egui visuals
         // Widget corner radius for buttons, inputs, etc.
         visuals.widgets.noninteractive.corner_radius = self.radii.input();
@@ -0,0 +1,81 @@
+//! Motion preference for animated components
+//!
+//! Mirrors the CSS `prefers-reduced-motion` media query. Every animated
+//! component reads the preference from the theme:
+//! - [`MotionPreference::Full`]: slides, springs and looping indicators
+//! - [`MotionPreference::Reduced`]: slides become fades, looping animations stop
+//! - [`MotionPreference::None`]: every state change is instant
+
+/// How much motion animated components may use
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MotionPreference {
+    /// All animations (default)
+    #[default]
+    Full,
+    /// No movement: slides become fades and loops stop
+    Reduced,
+    /// No animation at all
+    None,
+}
+
+impl MotionPreference {
+    /// Whether transitions animate at all (false = jump to the end state)
+    pub fn animates(self) -> bool {
+        self != Self::None
+    }
+
+    /// Whether surfaces may move (slide, spring, rotate)
+    pub fn allows_movement(self) -> bool {
+        self == Self::Full
+    }
+
+    /// Whether looping animations (spinners, shimmer) may run
+    pub fn allows_loops(self) -> bool {
+        self == Self::Full
+    }
+
+    /// Translate and opacity for a sliding surface
+    ///
+    /// `offset` is the slide progress (0.0 = fully shown, 1.0 = fully hidden).
+    /// Returns `(slide, opacity)`: the fraction of its size the surface is
+    /// translated by, and the opacity to draw it with. Without movement the
+    /// surface stays in place and fades instead.
+    pub fn slide_and_fade(self, offset: f32) -> (f32, f32) {
+        let offset = offset.clamp(0.0, 1.0);
+        if self.allows_movement() {
+            (offset, 1.0)
+        } else {
+            (0.0, 1.0 - offset)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_is_full() {
+        assert_eq!(MotionPreference::default(), MotionPreference::Full);
+    }
+
+    #[test]
+    fn test_capabilities() {
+        assert!(MotionPreference::Full.allows_movement());
+        assert!(MotionPreference::Full.allows_loops());
+
+        assert!(MotionPreference::Reduced.animates());
+        assert!(!MotionPreference::Reduced.allows_movement());
+        assert!(!MotionPreference::Reduced.allows_loops());
+
+        assert!(!MotionPreference::None.animates());
+        assert!(!MotionPreference::None.allows_loops());
+    }
+
+    #[test]
+    fn test_slide_and_fade() {
+        assert_eq!(MotionPreference::Full.slide_and_fade(0.25), (0.25, 1.0));
+        assert_eq!(MotionPreference::Reduced.slide_and_fade(0.25), (0.0, 0.75));
+        assert_eq!(MotionPreference::None.slide_and_fade(1.0), (0.0, 0.0));
+    }
+}